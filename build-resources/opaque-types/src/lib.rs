@@ -2,10 +2,12 @@
 #![allow(dead_code)]
 #![allow(deprecated)]
 use core::ffi::c_void;
-#[cfg(all(feature = "shared-memory", feature = "unstable"))]
-use std::sync::Arc;
+#[cfg(all(feature = "rest-gateway", feature = "unstable"))]
+use std::net::SocketAddr;
+#[cfg(feature = "unstable")]
+use std::sync::{atomic::AtomicBool, Weak};
 use std::{
-    sync::{Condvar, Mutex, MutexGuard},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
     thread::JoinHandle,
 };
 
@@ -24,7 +26,10 @@ use zenoh::{
 };
 #[cfg(feature = "unstable")]
 use zenoh::{
-    matching::MatchingListener, query::Querier, sample::SourceInfo, session::EntityGlobalId,
+    matching::MatchingListener,
+    query::Querier,
+    sample::SourceInfo,
+    session::EntityGlobalId,
 };
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 use zenoh::{
@@ -34,6 +39,13 @@ use zenoh::{
     shm::StaticProtocolID, shm::ZLayoutError, shm::ZShm, shm::ZShmMut, shm::POSIX_PROTOCOL_ID,
 };
 
+/// A zenoh object along with the state kept by zenoh-c for it.
+/// Only the unstable API keeps some state, the stable builds store the zenoh object alone.
+#[cfg(feature = "unstable")]
+type Stateful<T> = (T, Arc<u8>);
+#[cfg(not(feature = "unstable"))]
+type Stateful<T> = T;
+
 #[macro_export]
 macro_rules! get_opaque_type_data {
     ($src_type:ty, $name:ident) => {
@@ -120,25 +132,107 @@ get_opaque_type_data!(ReplyError, z_loaned_reply_err_t);
 /// An owned Zenoh query received by a queryable.
 ///
 /// Queries are atomically reference-counted, letting you extract them from the callback that handed them to you by cloning.
-get_opaque_type_data!(Option<Query>, z_owned_query_t);
+get_opaque_type_data!(Option<Stateful<Query>>, z_owned_query_t);
 /// A loaned Zenoh query.
-get_opaque_type_data!(Query, z_loaned_query_t);
+get_opaque_type_data!(Stateful<Query>, z_loaned_query_t);
 
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#queryable"> queryable </a>.
 ///
 /// Responds to queries sent via `z_get()` with intersecting key expression.
-get_opaque_type_data!(Option<Queryable<()>>, z_owned_queryable_t);
+get_opaque_type_data!(Option<Stateful<Queryable<()>>>, z_owned_queryable_t);
 /// A loaned Zenoh queryable.
-get_opaque_type_data!(Queryable<()>, z_loaned_queryable_t);
+get_opaque_type_data!(Stateful<Queryable<()>>, z_loaned_queryable_t);
 
 #[cfg(feature = "unstable")]
 /// An owned Zenoh querier.
 ///
 /// Sends queries to matching queryables.
-get_opaque_type_data!(Option<Querier>, z_owned_querier_t);
+get_opaque_type_data!(Option<Stateful<Querier>>, z_owned_querier_t);
 #[cfg(feature = "unstable")]
 /// A loaned Zenoh queryable.
-get_opaque_type_data!(Querier, z_loaned_querier_t);
+get_opaque_type_data!(Stateful<Querier>, z_loaned_querier_t);
+
+// Mirrors the state shared by the copies of a cancellation token, the reply callbacks it holds being replaced by
+// placeholders of the same size.
+#[cfg(feature = "unstable")]
+struct CancellationToken {
+    cancelled: AtomicBool,
+    callbacks: Mutex<Vec<Weak<Mutex<Option<Arc<u8>>>>>>,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned cancellation token, allowing to cancel the queries it is passed to.
+get_opaque_type_data!(Option<Arc<CancellationToken>>, z_owned_cancellation_token_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned cancellation token.
+get_opaque_type_data!(Arc<CancellationToken>, z_loaned_cancellation_token_t);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned adminspace metrics subscriber, polling the metrics of the local zenoh node.
+get_opaque_type_data!(Option<flume::Sender<()>>, zc_owned_metrics_subscriber_t);
+
+#[cfg(all(feature = "rest-gateway", feature = "unstable"))]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned local HTTP gateway to a session, stopped when dropped.
+get_opaque_type_data!(
+    Option<(Arc<AtomicBool>, SocketAddr, Option<JoinHandle<()>>)>,
+    zc_owned_rest_gateway_t
+);
+#[cfg(all(feature = "rest-gateway", feature = "unstable"))]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned local HTTP gateway.
+get_opaque_type_data!(
+    (Arc<AtomicBool>, SocketAddr, Option<JoinHandle<()>>),
+    zc_loaned_rest_gateway_t
+);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned ordered and flow-controlled byte stream, closed when dropped.
+get_opaque_type_data!(
+    Option<(
+        Arc<u8>,
+        Publisher<'static>,
+        Publisher<'static>,
+        Subscriber<()>,
+        Subscriber<()>,
+    )>,
+    zc_owned_stream_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned byte stream.
+get_opaque_type_data!(
+    (
+        Arc<u8>,
+        Publisher<'static>,
+        Publisher<'static>,
+        Subscriber<()>,
+        Subscriber<()>,
+    ),
+    zc_loaned_stream_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned watch, delivering the current values of a key expression and then their updates.
+get_opaque_type_data!(Option<(Subscriber<()>, Arc<u8>)>, zc_owned_watch_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned stream listener, accepting the byte streams opened on its key expression.
+get_opaque_type_data!(
+    Option<(Queryable<()>, Arc<u8>, (Session, Arc<u8>))>,
+    zc_owned_stream_listener_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned stream listener.
+get_opaque_type_data!(
+    (Queryable<()>, Arc<u8>, (Session, Arc<u8>)),
+    zc_loaned_stream_listener_t
+);
 
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -193,14 +287,14 @@ get_opaque_type_data!(
 /// In addition to publishing the data,
 /// it also maintains the storage, allowing matching subscribers to retrive missed samples.
 get_opaque_type_data!(
-    Option<zenoh_ext::AdvancedPublisher<'static>>,
+    Option<Stateful<zenoh_ext::AdvancedPublisher<'static>>>,
     ze_owned_advanced_publisher_t
 );
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief A loaned Zenoh advanced publisher.
 get_opaque_type_data!(
-    zenoh_ext::AdvancedPublisher<'static>,
+    Stateful<zenoh_ext::AdvancedPublisher<'static>>,
     ze_loaned_advanced_publisher_t
 );
 /// A Zenoh-allocated <a href="https://zenoh.io/docs/manual/abstractions/#key-expression"> key expression </a>.
@@ -235,9 +329,9 @@ get_opaque_type_data!(Option<KeyExpr<'static>>, z_view_keyexpr_t);
 get_opaque_type_data!(KeyExpr<'static>, z_loaned_keyexpr_t);
 
 /// An owned Zenoh session.
-get_opaque_type_data!(Option<Session>, z_owned_session_t);
+get_opaque_type_data!(Option<Stateful<Session>>, z_owned_session_t);
 /// A loaned Zenoh session.
-get_opaque_type_data!(Session, z_loaned_session_t);
+get_opaque_type_data!(Stateful<Session>, z_loaned_session_t);
 
 #[cfg(feature = "unstable")]
 /// An owned Close handle
@@ -262,9 +356,9 @@ get_opaque_type_data!(ZenohId, z_id_t);
 get_opaque_type_data!(Timestamp, z_timestamp_t);
 
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#publisher"> publisher </a>.
-get_opaque_type_data!(Option<Publisher<'static>>, z_owned_publisher_t);
+get_opaque_type_data!(Option<Stateful<Publisher<'static>>>, z_owned_publisher_t);
 /// A loaned Zenoh publisher.
-get_opaque_type_data!(Publisher<'static>, z_loaned_publisher_t);
+get_opaque_type_data!(Stateful<Publisher<'static>>, z_loaned_publisher_t);
 
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -272,23 +366,26 @@ get_opaque_type_data!(Publisher<'static>, z_loaned_publisher_t);
 ///
 /// A listener that sends notifications when the [`MatchingStatus`] of a publisher or querier changes.
 /// Dropping the corresponding publisher, also drops matching listener.
-get_opaque_type_data!(Option<MatchingListener<()>>, zc_owned_matching_listener_t);
+get_opaque_type_data!(
+    Option<(MatchingListener<()>, Arc<AtomicBool>)>,
+    zc_owned_matching_listener_t
+);
 
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#subscriber"> subscriber </a>.
 ///
 /// Receives data from publication on intersecting key expressions.
 /// Destroying the subscriber cancels the subscription.
-get_opaque_type_data!(Option<Subscriber<()>>, z_owned_subscriber_t);
+get_opaque_type_data!(Option<Stateful<Subscriber<()>>>, z_owned_subscriber_t);
 /// A loaned Zenoh subscriber.
-get_opaque_type_data!(Subscriber<()>, z_loaned_subscriber_t);
+get_opaque_type_data!(Stateful<Subscriber<()>>, z_loaned_subscriber_t);
 
 /// @brief A liveliness token that can be used to provide the network with information about connectivity to its
 /// declarer: when constructed, a PUT sample will be received by liveliness subscribers on intersecting key
 /// expressions.
 ///
 /// A DELETE on the token's key expression will be received by subscribers if the token is destroyed, or if connectivity between the subscriber and the token's creator is lost.
-get_opaque_type_data!(Option<LivelinessToken>, z_owned_liveliness_token_t);
-get_opaque_type_data!(LivelinessToken, z_loaned_liveliness_token_t);
+get_opaque_type_data!(Option<Stateful<LivelinessToken>>, z_owned_liveliness_token_t);
+get_opaque_type_data!(Stateful<LivelinessToken>, z_loaned_liveliness_token_t);
 
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -500,19 +597,25 @@ get_opaque_type_data!(RingChannelHandler<Sample>, z_loaned_ring_handler_sample_t
 
 /// An owned Zenoh fifo query handler.
 get_opaque_type_data!(
-    Option<FifoChannelHandler<Query>>,
+    Option<FifoChannelHandler<Stateful<Query>>>,
     z_owned_fifo_handler_query_t
 );
 /// An loaned Zenoh fifo query handler.
-get_opaque_type_data!(FifoChannelHandler<Query>, z_loaned_fifo_handler_query_t);
+get_opaque_type_data!(
+    FifoChannelHandler<Stateful<Query>>,
+    z_loaned_fifo_handler_query_t
+);
 
 /// An owned Zenoh ring query handler.
 get_opaque_type_data!(
-    Option<RingChannelHandler<Query>>,
+    Option<RingChannelHandler<Stateful<Query>>>,
     z_owned_ring_handler_query_t
 );
 /// An loaned Zenoh ring query handler.
-get_opaque_type_data!(RingChannelHandler<Query>, z_loaned_ring_handler_query_t);
+get_opaque_type_data!(
+    RingChannelHandler<Stateful<Query>>,
+    z_loaned_ring_handler_query_t
+);
 
 /// An owned Zenoh fifo reply handler.
 get_opaque_type_data!(
@@ -530,6 +633,46 @@ get_opaque_type_data!(
 /// An loaned Zenoh ring reply handler.
 get_opaque_type_data!(RingChannelHandler<Reply>, z_loaned_ring_handler_reply_t);
 
+// The matching status channels are fed through closures, so that they carry `zc_matching_status_t` rather than
+// the zenoh `MatchingStatus`: it is mirrored here so that the handlers are declared with the same item type.
+#[cfg(feature = "unstable")]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct zc_matching_status_t {
+    matching: bool,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh fifo matching status handler.
+get_opaque_type_data!(
+    Option<FifoChannelHandler<zc_matching_status_t>>,
+    zc_owned_fifo_handler_matching_status_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh fifo matching status handler.
+get_opaque_type_data!(
+    FifoChannelHandler<zc_matching_status_t>,
+    zc_loaned_fifo_handler_matching_status_t
+);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh ring matching status handler.
+get_opaque_type_data!(
+    Option<RingChannelHandler<zc_matching_status_t>>,
+    zc_owned_ring_handler_matching_status_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh ring matching status handler.
+get_opaque_type_data!(
+    RingChannelHandler<zc_matching_status_t>,
+    zc_loaned_ring_handler_matching_status_t
+);
+
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief An owned Zenoh-allocated source info`.
@@ -40,6 +40,8 @@ const HEADER: &str = r"//
 static RUST_TO_C_FEATURES: phf::Map<&'static str, &'static str> = phf_map! {
     "unstable" => "Z_FEATURE_UNSTABLE_API",
     "shared-memory" => "Z_FEATURE_SHARED_MEMORY",
+    "plugins" => "Z_FEATURE_PLUGINS",
+    "rest-gateway" => "Z_FEATURE_REST_GATEWAY",
     "auth_pubkey" => "Z_FEATURE_AUTH_PUBKEY",
     "auth_usrpwd" => "Z_FEATURE_AUTH_USRPWD",
     "transport_multilink" => "Z_FEATURE_TRANSPORT_MULTILINK",
@@ -1370,14 +1372,14 @@ pub fn find_call_functions(path_in: &str) -> Vec<FunctionSignature> {
 pub fn find_closure_constructors(path_in: &str) -> Vec<FunctionSignature> {
     let bindings = std::fs::read_to_string(path_in).unwrap();
     let re = Regex::new(
-        r"(\w+) (\w+)_closure_(\w+)\(struct\s+(\w+)\s+\*(\w+),\s+void\s+\(\*call\)(\([\s\w,\*]*\)),\s+void\s+\(\*drop\)(\(.*\)),\s+void\s+\*context\);"
+        r"(\w+) (\w+)_closure_(\w+)\(struct\s+(\w+)\s+\*(\w+),\s+(\w+)\s+\(\*call\)(\([\s\w,\*]*\)),\s+void\s+\(\*drop\)(\(.*\)),\s+void\s+\*context\);"
     )
     .unwrap();
     let mut res = Vec::<FunctionSignature>::new();
 
     for (
         _,
-        [return_type, prefix, suffix, closure_type, closure_name, call_signature_raw, drop_signature],
+        [return_type, prefix, suffix, closure_type, closure_name, call_return_type, call_signature_raw, drop_signature],
     ) in re.captures_iter(&bindings).map(|c| c.extract())
     {
         let mut call_signature: String = call_signature_raw.to_string().replace("struct ", "");
@@ -1393,7 +1395,10 @@ pub fn find_closure_constructors(path_in: &str) -> Vec<FunctionSignature> {
             prefix.to_string() + "_closure_" + suffix,
             vec![
                 FuncArg::new(&(closure_type.to_string() + "*"), closure_name),
-                FuncArg::new(&("void (*call)".to_string() + &call_signature), "call"),
+                FuncArg::new(
+                    &(call_return_type.to_string() + " (*call)" + &call_signature),
+                    "call",
+                ),
                 FuncArg::new(&("void (*drop)".to_string() + drop_signature), "drop"),
                 FuncArg::new("void*", "context"),
             ],
@@ -1405,17 +1410,19 @@ pub fn find_closure_constructors(path_in: &str) -> Vec<FunctionSignature> {
 
 pub fn find_recv_functions(path_in: &str) -> Vec<FunctionSignature> {
     let bindings = std::fs::read_to_string(path_in).unwrap();
-    let re = Regex::new(r"(\w+)\s+z_(\w+)_handler_(\w+)_recv\(const\s+struct\s+(\w+)\s+\*(\w+),\s+struct\s+(\w+)\s+\*(\w+)\);").unwrap();
+    let re = Regex::new(r"(\w+)\s+(zc?)_(\w+)_handler_(\w+)_recv\(const\s+struct\s+(\w+)\s+\*(\w+),\s+struct\s+(\w+)\s+\*(\w+)\);").unwrap();
     let mut res = Vec::<FunctionSignature>::new();
 
-    for (_, [return_type, handler_type, value_type, arg1_type, arg1_name, arg2_type, arg2_name]) in
-        re.captures_iter(&bindings).map(|c| c.extract())
+    for (
+        _,
+        [return_type, prefix, handler_type, value_type, arg1_type, arg1_name, arg2_type, arg2_name],
+    ) in re.captures_iter(&bindings).map(|c| c.extract())
     {
         let (_, _, semantic, _) = split_type_name(arg1_type);
         let f = FunctionSignature::new(
             semantic,
             return_type,
-            "z_".to_string() + handler_type + "_handler_" + value_type + "_recv",
+            prefix.to_string() + "_" + handler_type + "_handler_" + value_type + "_recv",
             vec![
                 FuncArg::new(&("const ".to_string() + arg1_type + "*"), arg1_name),
                 FuncArg::new(&(arg2_type.to_string() + "*"), arg2_name),
@@ -1787,6 +1794,10 @@ fn test_feature(feature: &str) -> bool {
         "shared-memory" => true,
         #[cfg(feature = "unstable")]
         "unstable" => true,
+        #[cfg(feature = "plugins")]
+        "plugins" => true,
+        #[cfg(feature = "rest-gateway")]
+        "rest-gateway" => true,
         #[cfg(feature = "auth_pubkey")]
         "auth_pubkey" => true,
         #[cfg(feature = "auth_usrpwd")]
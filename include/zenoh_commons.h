@@ -237,6 +237,42 @@ typedef enum zc_reply_keyexpr_t {
   ZC_REPLY_KEYEXPR_MATCHING_QUERY = 1,
 } zc_reply_keyexpr_t;
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The kind of a time range bound.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum zc_time_bound_kind_t {
+  /**
+   * The range is not bounded on this side.
+   */
+  ZC_TIME_BOUND_KIND_UNBOUNDED = 0,
+  /**
+   * The bound is included in the range.
+   */
+  ZC_TIME_BOUND_KIND_INCLUSIVE = 1,
+  /**
+   * The bound is excluded from the range.
+   */
+  ZC_TIME_BOUND_KIND_EXCLUSIVE = 2,
+} zc_time_bound_kind_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The watermark crossed by the occupancy of a fifo channel.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum zc_watermark_t {
+  /**
+   * The occupancy of the channel reached the high watermark: the receiving end is falling behind.
+   */
+  ZC_WATERMARK_HIGH = 0,
+  /**
+   * The occupancy of the channel went back down to the low watermark after having reached the high one.
+   */
+  ZC_WATERMARK_LOW = 1,
+} zc_watermark_t;
+#endif
 typedef struct z_moved_alloc_layout_t {
   struct z_owned_alloc_layout_t _this;
 } z_moved_alloc_layout_t;
@@ -391,7 +427,44 @@ typedef struct z_queryable_options_t {
    * The completeness of the Queryable.
    */
   bool complete;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * Restricts the queries that will be received by this Queryable to the ones
+   * that have the compatible allowed_destination.
+   */
+  enum zc_locality_t allowed_origin;
+#endif
 } z_queryable_options_t;
+typedef struct z_moved_string_array_t {
+  struct z_owned_string_array_t _this;
+} z_moved_string_array_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A payload transformation closure.
+ *
+ * The closure is called with the original payload and should construct the transformed payload
+ * (e.g. compressed or encrypted) in `transformed`, which is initialized to an empty payload before the call.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_payload_transform_t {
+  void *_context;
+  void (*_call)(const struct z_loaned_bytes_t *payload, struct z_owned_bytes_t *transformed, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_payload_transform_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_payload_transform_t {
+  struct zc_owned_closure_payload_transform_t _this;
+} zc_moved_closure_payload_transform_t;
+#endif
 /**
  * Options passed to the `z_declare_subscriber()` function.
  */
@@ -409,6 +482,35 @@ typedef struct z_subscriber_options_t {
    */
   enum zc_locality_t allowed_origin;
 #endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * An optional array of key expressions to exclude from the subscription. Samples whose key expression
+   * intersects with any of them are discarded before reaching the callback.
+   */
+  struct z_moved_string_array_t *excluded_key_exprs;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * An optional closure applied to the payload of each received sample before it reaches the callback
+   * (e.g. to decompress or decrypt it), usually reverting the `payload_transform` of the matching publishers.
+   */
+  struct zc_moved_closure_payload_transform_t *payload_transform;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of samples buffered by the subscriber in pull mode, 0 means push mode (the default).
+   * In pull mode the received samples are buffered (dropping the oldest ones when the buffer is full) instead of being passed to the callback,
+   * until `z_subscriber_pull()` is called. Only applies to subscribers declared with `z_declare_subscriber()`.
+   */
+  size_t pull_capacity;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * If set to ``true``, the subscriber counts the samples received on each concrete key expression, which can then be
+   * retrieved with `zc_subscriber_key_stats()`. Only applies to subscribers declared with `z_declare_subscriber()`.
+   */
+  bool key_stats;
+#endif
 } z_subscriber_options_t;
 typedef struct z_moved_encoding_t {
   struct z_owned_encoding_t _this;
@@ -449,6 +551,36 @@ typedef struct z_publisher_options_t {
    */
   enum zc_locality_t allowed_destination;
 #endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``false``, publications will not be delivered to the subscribers declared on the publisher's own session,
+   * while remote subscribers will still receive them. Disabling self delivery is not compatible with `ZC_LOCALITY_SESSION_LOCAL`
+   * allowed destination.
+   */
+  bool self_delivery;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * An optional closure applied to the payload of each publication made with `z_publisher_put()` or `zc_forward()`
+   * before it is sent (e.g. to compress or encrypt it). For advanced publishers it applies to `ze_advanced_publisher_put()`. Subscribers can revert it with the `payload_transform` field of `z_subscriber_options_t`.
+   * The closure is dropped once the publisher is undeclared or dropped.
+   */
+  struct zc_moved_closure_payload_transform_t *payload_transform;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``false``, the publications whose payload and attachment do not fit in a single batch of the session
+   * links are rejected with `Z_EFRAGMENT` instead of being fragmented. If set to ``true``, they are fragmented, and
+   * only rejected beyond the size the remote nodes can reassemble. See `zc_publisher_max_payload_size()`.
+   */
+  bool allow_fragmentation;
+#endif
 } z_publisher_options_t;
 /**
  * The replies consolidation strategy to apply on replies to a `z_get()`.
@@ -504,6 +636,9 @@ typedef struct z_querier_options_t {
   uint64_t timeout_ms;
 } z_querier_options_t;
 #endif
+typedef struct z_moved_source_info_t {
+  struct z_owned_source_info_t _this;
+} z_moved_source_info_t;
 /**
  * Options passed to the `z_delete()` function.
  */
@@ -540,6 +675,18 @@ typedef struct z_delete_options_t {
    */
   enum zc_locality_t allowed_destination;
 #endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The source info for the delete operation.
+   */
+  struct z_moved_source_info_t *source_info;
+#endif
+  /**
+   * The attachment to this delete message.
+   */
+  struct z_moved_bytes_t *attachment;
 } z_delete_options_t;
 typedef struct z_moved_fifo_handler_query_t {
   struct z_owned_fifo_handler_query_t _this;
@@ -550,9 +697,83 @@ typedef struct z_moved_fifo_handler_reply_t {
 typedef struct z_moved_fifo_handler_sample_t {
   struct z_owned_fifo_handler_sample_t _this;
 } z_moved_fifo_handler_sample_t;
-typedef struct z_moved_source_info_t {
-  struct z_owned_source_info_t _this;
-} z_moved_source_info_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Routing diagnostics of a query, reported once all its replies have been received.
+ *
+ * The number of hops to the repliers is not reported, since it is not exposed by the zenoh routing layer.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_query_diagnostics_t {
+  /**
+   * The total number of replies delivered to the reply callback, including the error ones. The error reply notifying
+   * the query timeout is not counted, and neither are the replies discarded by the `replier_id` filter.
+   */
+  size_t replies;
+  /**
+   * The number of error replies delivered to the reply callback.
+   */
+  size_t errors;
+  /**
+   * The number of distinct sessions whose queryables replied to the query.
+   */
+  size_t repliers;
+  /**
+   * The finalization status of the query: `Z_OK` if all the replies were received, `Z_QUERY_TIMED_OUT` if the query
+   * timeout fired before. Like the `Z_CHANNEL_*` statuses, `Z_QUERY_TIMED_OUT` is positive, since it is not an error.
+   */
+  z_result_t status;
+} zc_query_diagnostics_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Statistics of the queries handled by a queryable.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_queryable_stats_t {
+  /**
+   * The number of queries received by the queryable.
+   */
+  uint64_t queries;
+  /**
+   * The number of replies (including delete replies) sent to the queries received by the queryable.
+   */
+  uint64_t replies;
+  /**
+   * The number of error replies sent to the queries received by the queryable.
+   */
+  uint64_t errors;
+  /**
+   * The average time spent in the queryable callback per query, in microseconds.
+   */
+  uint64_t average_handling_time_us;
+} zc_queryable_stats_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A query diagnostics-processing closure.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_query_diagnostics_t {
+  void *_context;
+  void (*_call)(const struct zc_query_diagnostics_t *diagnostics, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_query_diagnostics_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_query_diagnostics_t {
+  struct zc_owned_closure_query_diagnostics_t _this;
+} zc_moved_closure_query_diagnostics_t;
+#endif
+typedef struct z_moved_cancellation_token_t {
+  struct z_owned_cancellation_token_t _this;
+} z_moved_cancellation_token_t;
 /**
  * Options passed to the `z_get()` function.
  */
@@ -617,6 +838,50 @@ typedef struct z_get_options_t {
    * The timeout for the query in milliseconds. 0 means default query timeout from zenoh configuration.
    */
   uint64_t timeout_ms;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * An optional closure called once the query is complete, with the routing diagnostics of the query.
+   * It helps to debug queries that silently return no replies.
+   */
+  struct zc_moved_closure_query_diagnostics_t *diagnostics;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * An optional cancellation token, allowing to cancel the query with `z_cancellation_token_cancel()` called on a copy of it.
+   * Once cancelled, the reply callback is not called anymore and is dropped, even if the query timeout is not reached.
+   * Cancellation only stops the delivery of the replies: the query is not withdrawn from the network, so the queryables
+   * still process it (see `z_cancellation_token_cancel()`).
+   */
+  struct z_moved_cancellation_token_t *cancellation_token;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, the error reply notifying that the query timeout fired is not passed to the reply callback.
+   * The replies received before the timeout are still delivered, and the timeout is instead reported by the
+   * `Z_QUERY_TIMED_OUT` status of the query diagnostics (see `diagnostics`), so that they can knowingly be used as partial results.
+   * Since zenoh does not flag the timeout reply, it is recognized as a `"Timeout"` string error sent by the querying session
+   * itself once the timeout is passed: an identical error reply sent by a local queryable at the very instant the timeout
+   * fires would be taken for it.
+   */
+  bool suppress_timeout_error_reply;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If not NULL, only the replies sent by the zenoh instance with this id (e.g. obtained with `z_reply_replier_id()`
+   * from a previous reply) are passed to the reply callback, allowing to stick to a given replica of a storage.
+   * Since the query is still routed by key expression, it is then sent to all the matching queryables without
+   * consolidating their replies, whatever the `target` and `consolidation` options.
+   */
+  const struct z_id_t *replier_id;
+#endif
 } z_get_options_t;
 typedef struct z_moved_hello_t {
   struct z_owned_hello_t _this;
@@ -661,17 +926,94 @@ typedef struct z_moved_mutex_t {
  * Options passed to the `z_open()` function.
  */
 typedef struct z_open_options_t {
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of publishers (including advanced ones) that can be declared on the session at the same time, 0 means unlimited.
+   * Declaring more publishers fails with `Z_ELIMIT`.
+   */
+  size_t max_publishers;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of subscribers (including background, liveliness, querying and advanced ones) that can be declared on the session at the same time, 0 means unlimited.
+   * Declaring more subscribers fails with `Z_ELIMIT`.
+   */
+  size_t max_subscribers;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of queryables (including background ones) that can be declared on the session at the same time, 0 means unlimited.
+   * Declaring more queryables fails with `Z_ELIMIT`.
+   */
+  size_t max_queryables;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of queriers that can be declared on the session at the same time, 0 means unlimited.
+   * Declaring more queriers fails with `Z_ELIMIT`.
+   */
+  size_t max_queriers;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The number of additional attempts to open the session if the first one fails, 0 means no retry.
+   * This allows applications started before their router to wait for it to become available.
+   */
+  size_t retry_attempts;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The delay in milliseconds before the first retry, it is doubled after each failed attempt.
+   */
+  uint64_t retry_backoff_ms;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * If set to ``true``, the callbacks of the subscribers, queryables and gets of the session are not executed on the zenoh threads,
+   * but queued until `zc_session_drain_callbacks()` executes them on the calling thread.
+   * This gives applications with a main loop (e.g. GUI) control over the thread and the moment their callbacks are executed.
+   *
+   * Channel handlers are deferred as well, so `z_recv()` must not be called on the thread draining the callbacks
+   * before draining them, or it never returns (see `zc_session_drain_callbacks()`).
+   */
+  bool deferred_callbacks;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The maximum number of callbacks pending in deferred dispatch mode, 0 means unbounded.
+   * The messages arriving while the queue is full are dropped and counted in the `full_buffers` drop counter.
+   */
+  size_t deferred_callbacks_capacity;
+#endif
+#if !defined(Z_FEATURE_UNSTABLE_API)
   uint8_t _dummy;
+#endif
 } z_open_options_t;
 /**
  * Represents the set of options that can be applied to the delete operation by a previously declared publisher,
  * whenever issued via `z_publisher_delete()`.
+ *
+ * The priority, congestion control and express flag of the delete operation are the ones of the publisher, since they can not be
+ * overridden per operation. Use `z_delete()` with `z_delete_options_t` to issue a delete operation with a different QoS,
+ * e.g. to prioritize tombstones above the bulk data sent by the publisher.
  */
 typedef struct z_publisher_delete_options_t {
   /**
    * The timestamp of this message.
    */
   const struct z_timestamp_t *timestamp;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The source info for the delete operation.
+   */
+  struct z_moved_source_info_t *source_info;
+#endif
+  /**
+   * The attachment to attach to the delete operation.
+   */
+  struct z_moved_bytes_t *attachment;
 } z_publisher_delete_options_t;
 typedef struct z_moved_publisher_t {
   struct z_owned_publisher_t _this;
@@ -698,6 +1040,7 @@ typedef struct z_publisher_put_options_t {
 #endif
   /**
    * The attachment to attach to the publication.
+   * The attachment is consumed, to attach the same data to many publications pass a shallow copy made with `z_bytes_clone()`, which does not copy the underlying data.
    */
   struct z_moved_bytes_t *attachment;
 } z_publisher_put_options_t;
@@ -751,6 +1094,7 @@ typedef struct z_put_options_t {
 #endif
   /**
    * The attachment to this message.
+   * The attachment is consumed, to attach the same data to many publications pass a shallow copy made with `z_bytes_clone()`, which does not copy the underlying data.
    */
   struct z_moved_bytes_t *attachment;
 } z_put_options_t;
@@ -870,6 +1214,33 @@ typedef struct z_query_reply_err_options_t {
    */
   struct z_moved_encoding_t *encoding;
 } z_query_reply_err_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Represents the set of options that can be applied to a reply forwarding a sample,
+ * sent via `z_query_reply_sample()`.
+ *
+ * Unset fields keep the value of the forwarded sample.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_query_reply_sample_options_t {
+  /**
+   * The key expression of the reply, instead of the key expression of the sample.
+   */
+  const struct z_loaned_keyexpr_t *key_expr;
+  /**
+   * The encoding of the reply payload, instead of the encoding of the sample.
+   */
+  struct z_moved_encoding_t *encoding;
+  /**
+   * The timestamp of the reply, instead of the timestamp of the sample.
+   */
+  struct z_timestamp_t *timestamp;
+  /**
+   * The attachment to this reply, instead of the attachment of the sample.
+   */
+  struct z_moved_bytes_t *attachment;
+} z_query_reply_sample_options_t;
+#endif
 typedef struct z_moved_queryable_t {
   struct z_owned_queryable_t _this;
 } z_moved_queryable_t;
@@ -925,9 +1296,6 @@ typedef struct z_moved_shm_provider_t {
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 typedef uint32_t z_protocol_id_t;
 #endif
-typedef struct z_moved_string_array_t {
-  struct z_owned_string_array_t _this;
-} z_moved_string_array_t;
 typedef struct z_moved_subscriber_t {
   struct z_owned_subscriber_t _this;
 } z_moved_subscriber_t;
@@ -961,47 +1329,472 @@ typedef struct zc_moved_closure_log_t {
 } zc_moved_closure_log_t;
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief A struct that indicates if there exist Subscribers matching the Publisher's key expression or Queryables matching Querier's key expression and target.
+ * @brief A log record-processing closure.
+ *
+ * The closure receives the severity, the target (i.e. the module that emitted the record) and the message of each log record.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-typedef struct zc_matching_status_t {
-  /**
-   * True if there exist matching Zenoh entities, false otherwise.
-   */
-  bool matching;
-} zc_matching_status_t;
+typedef struct zc_owned_closure_log_record_t {
+  void *_context;
+  void (*_call)(enum zc_log_severity_t severity,
+                const struct z_loaned_string_t *target,
+                const struct z_loaned_string_t *msg,
+                void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_log_record_t;
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief A matching status-processing closure.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_log_record_t {
+  struct zc_owned_closure_log_record_t _this;
+} zc_moved_closure_log_record_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A declaration key expression interceptor closure.
+ *
+ * The closure is called with the key expression of each entity being declared. It should return `Z_OK` to accept the declaration,
+ * optionally constructing a rewritten key expression (e.g. with a tenant prefix) in `rewritten`, which is in its gravestone state
+ * before the call, or a negative error code (usually `Z_EDENIED`) to reject it.
  *
  * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-typedef struct zc_owned_closure_matching_status_t {
+typedef struct zc_owned_closure_keyexpr_interceptor_t {
   void *_context;
-  void (*_call)(const struct zc_matching_status_t *matching_status, void *context);
+  z_result_t (*_call)(const struct z_loaned_keyexpr_t *key_expr,
+                      struct z_owned_keyexpr_t *rewritten,
+                      void *context);
   void (*_drop)(void *context);
-} zc_owned_closure_matching_status_t;
+} zc_owned_closure_keyexpr_interceptor_t;
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Moved closure.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-typedef struct zc_moved_closure_matching_status_t {
-  struct zc_owned_closure_matching_status_t _this;
-} zc_moved_closure_matching_status_t;
+typedef struct zc_moved_closure_keyexpr_interceptor_t {
+  struct zc_owned_closure_keyexpr_interceptor_t _this;
+} zc_moved_closure_keyexpr_interceptor_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A closure looking up the value of a key in a table served by `zc_queryable_serve_table()`.
+ *
+ * The closure is called with a key of the table, relative to the table prefix. It should construct the value of the key in `value`,
+ * which is initialized to an empty payload before the call, and return ``true`` if the key is found, ``false`` otherwise.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_table_get_t {
+  void *_context;
+  bool (*_call)(const struct z_loaned_string_t *key,
+                struct z_owned_bytes_t *value,
+                void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_table_get_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_table_get_t {
+  struct zc_owned_closure_table_get_t _this;
+} zc_moved_closure_table_get_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A closure enumerating the keys of a table served by `zc_queryable_serve_table()`.
+ *
+ * The closure should push all the keys of the table, relative to the table prefix, into `keys`, which is empty before the call.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_table_list_t {
+  void *_context;
+  void (*_call)(struct z_loaned_string_array_t *keys,
+                void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_table_list_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_table_list_t {
+  struct zc_owned_closure_table_list_t _this;
+} zc_moved_closure_table_list_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A closure enumerating the entries replied by `zc_query_reply_entries()`.
+ *
+ * Each call should construct the concrete key expression and the payload of the next entry in `key_expr` and `payload`,
+ * which are in their gravestone and empty states respectively before the call, and return ``true``, or return ``false`` once all
+ * entries have been enumerated.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_reply_entry_t {
+  void *_context;
+  bool (*_call)(struct z_owned_keyexpr_t *key_expr,
+                struct z_owned_bytes_t *payload,
+                void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_reply_entry_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_reply_entry_t {
+  struct zc_owned_closure_reply_entry_t _this;
+} zc_moved_closure_reply_entry_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A subscriber key statistics closure.
+ *
+ * The closure is called with each concrete key expression received by a subscriber and the number of samples received on it.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_key_stats_t {
+  void *_context;
+  void (*_call)(const struct z_loaned_keyexpr_t *key_expr, uint64_t samples, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_key_stats_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_key_stats_t {
+  struct zc_owned_closure_key_stats_t _this;
+} zc_moved_closure_key_stats_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A watch closure.
+ *
+ * The closure is called with each sample delivered by a watch, and whether the sample is one of the current values
+ * returned by its initial query (``true``) or a live update (``false``), see `zc_declare_watch()`.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_watch_t {
+  void *_context;
+  void (*_call)(struct z_loaned_sample_t *sample, bool is_initial, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_watch_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_watch_t {
+  struct zc_owned_closure_watch_t _this;
+} zc_moved_closure_watch_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The metrics of the local zenoh node, as reported by its adminspace.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_metrics_t {
+  /**
+   * The number of transport sessions with remote zenoh nodes.
+   */
+  size_t sessions;
+  /**
+   * The number of links of these transport sessions.
+   */
+  size_t links;
+  /**
+   * The number of subscriber routes known by the node.
+   */
+  size_t subscribers;
+  /**
+   * The number of queryable routes known by the node.
+   */
+  size_t queryables;
+} zc_metrics_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief An adminspace metrics closure.
+ *
+ * The closure is called periodically with the metrics of the local zenoh node, see `zc_metrics_subscribe()`.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_metrics_t {
+  void *_context;
+  void (*_call)(const struct zc_metrics_t *metrics, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_metrics_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_metrics_t {
+  struct zc_owned_closure_metrics_t _this;
+} zc_moved_closure_metrics_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A fifo channel watermark closure.
+ *
+ * The closure is called with the crossed watermark and the occupancy of the channel,
+ * see `zc_fifo_channel_sample_new_with_watermarks()`.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_watermark_t {
+  void *_context;
+  void (*_call)(enum zc_watermark_t watermark, size_t occupancy, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_watermark_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_watermark_t {
+  struct zc_owned_closure_watermark_t _this;
+} zc_moved_closure_watermark_t;
+#endif
+typedef struct zc_moved_metrics_subscriber_t {
+  struct zc_owned_metrics_subscriber_t _this;
+} zc_moved_metrics_subscriber_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A struct that indicates if there exist Subscribers matching the Publisher's key expression or Queryables matching Querier's key expression and target.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_matching_status_t {
+  /**
+   * True if there exist matching Zenoh entities, false otherwise.
+   */
+  bool matching;
+} zc_matching_status_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A matching status-processing closure.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_owned_closure_matching_status_t {
+  void *_context;
+  void (*_call)(const struct zc_matching_status_t *matching_status, void *context);
+  void (*_drop)(void *context);
+} zc_owned_closure_matching_status_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_moved_closure_matching_status_t {
+  struct zc_owned_closure_matching_status_t _this;
+} zc_moved_closure_matching_status_t;
 #endif
 typedef struct zc_moved_concurrent_close_handle_t {
   struct zc_owned_concurrent_close_handle_t _this;
 } zc_moved_concurrent_close_handle_t;
+/**
+ * A report describing why a configuration failed to validate.
+ */
+typedef struct zc_config_error_report_t {
+  /**
+   * One-based line of the error in the validated string, 0 if unknown.
+   */
+  size_t line;
+  /**
+   * One-based column of the error in the validated string, 0 if unknown.
+   */
+  size_t column;
+  /**
+   * Path of the offending configuration key, such as "connect/endpoints/0", empty if unknown.
+   */
+  struct z_owned_string_t key_path;
+  /**
+   * Human-readable description of the error.
+   */
+  struct z_owned_string_t message;
+} zc_config_error_report_t;
+/**
+ * Options passed to the `zc_config_to_string_with_options()` function.
+ */
+typedef struct zc_config_to_string_options_t {
+  /**
+   * If set to ``true``, the credentials and private keys of the configuration are replaced with `"***"`,
+   * so that the resulting string can be safely logged.
+   */
+  bool redact_secrets;
+} zc_config_to_string_options_t;
+typedef struct zc_moved_fifo_handler_matching_status_t {
+  struct zc_owned_fifo_handler_matching_status_t _this;
+} zc_moved_fifo_handler_matching_status_t;
 typedef struct zc_moved_matching_listener_t {
   struct zc_owned_matching_listener_t _this;
 } zc_moved_matching_listener_t;
+typedef struct zc_moved_rest_gateway_t {
+  struct zc_owned_rest_gateway_t _this;
+} zc_moved_rest_gateway_t;
+typedef struct zc_moved_ring_handler_matching_status_t {
+  struct zc_owned_ring_handler_matching_status_t _this;
+} zc_moved_ring_handler_matching_status_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The number of entities currently declared on a session.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_session_entity_counts_t {
+  /**
+   * The number of declared publishers, including advanced ones.
+   */
+  size_t publishers;
+  /**
+   * The number of declared subscribers, including background, liveliness, querying and advanced ones.
+   */
+  size_t subscribers;
+  /**
+   * The number of declared queryables, including background ones.
+   */
+  size_t queryables;
+  /**
+   * The number of declared queriers.
+   */
+  size_t queriers;
+} zc_session_entity_counts_t;
+#endif
 typedef struct zc_moved_shm_client_list_t {
   struct zc_owned_shm_client_list_t _this;
 } zc_moved_shm_client_list_t;
+typedef struct zc_moved_stream_t {
+  struct zc_owned_stream_t _this;
+} zc_moved_stream_t;
+typedef struct zc_moved_stream_listener_t {
+  struct zc_owned_stream_listener_t _this;
+} zc_moved_stream_listener_t;
+typedef struct zc_moved_watch_t {
+  struct zc_owned_watch_t _this;
+} zc_moved_watch_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The numbers of incoming messages dropped by zenoh-c itself, before reaching the application, since the
+ * session was opened.
+ *
+ * The messages lost on the network or discarded by the zenoh runtime are not counted.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_session_drop_counters_t {
+  /**
+   * The number of messages discarded because the buffer of a pull mode subscriber or the deferred callbacks queue was full.
+   */
+  uint64_t full_buffers;
+  /**
+   * The number of messages whose delivery was interrupted by a panic.
+   */
+  uint64_t panicked_callbacks;
+  /**
+   * The number of messages that could not be decoded, e.g. stream messages without a valid sequence number.
+   */
+  uint64_t decode_failures;
+} zc_session_drop_counters_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to `zc_stream_open()` and `zc_declare_stream_listener()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_stream_options_t {
+  /**
+   * The maximum number of messages sent on the stream and not yet read by the peer.
+   */
+  size_t window;
+  /**
+   * The maximum size in bytes of the messages the written data is split into.
+   */
+  size_t chunk_size;
+  /**
+   * The time in ms to wait for the stream to be accepted. Ignored by the stream listeners.
+   */
+  uint64_t open_timeout_ms;
+  /**
+   * The maximum time in ms `zc_stream_write()` waits for the peer to read the data, after which the stream is failed.
+   * 0 means no timeout.
+   */
+  uint64_t write_timeout_ms;
+} zc_stream_options_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A bound of a time range.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_time_bound_t {
+  /**
+   * The kind of the bound.
+   */
+  enum zc_time_bound_kind_t kind;
+  /**
+   * The bound, in milliseconds since UNIX epoch. Ignored for unbounded bounds.
+   */
+  uint64_t epoch_ms;
+} zc_time_bound_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A time range, as carried by the `_time` parameter of a selector to query the samples stored in that range.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_time_range_t {
+  /**
+   * The start of the range.
+   */
+  struct zc_time_bound_t start;
+  /**
+   * The end of the range.
+   */
+  struct zc_time_bound_t end;
+} zc_time_range_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to the `zc_declare_watch()` function.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct zc_watch_options_t {
+  /**
+   * The timeout for the initial query in milliseconds. 0 means default query timeout from zenoh configuration.
+   */
+  uint64_t query_timeout_ms;
+} zc_watch_options_t;
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Setting for advanced publisher's cache. The cache allows advanced subscribers to recover history and/or lost samples.
@@ -1262,20 +2055,54 @@ typedef struct ze_publication_cache_options_t {
 } ze_publication_cache_options_t;
 #endif
 /**
- * @warning This API is deprecated. Please use ze_advanced_subscriber.
- * @brief A set of options that can be applied to a querying subscriber,
- * upon its declaration via `ze_declare_querying_subscriber()`.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A history end closure.
+ *
+ * The closure is called once a querying subscriber has delivered all the samples returned by its initial query,
+ * see `ze_querying_subscriber_options_t::history_end`.
  *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-typedef struct ze_querying_subscriber_options_t {
+typedef struct ze_owned_closure_history_end_t {
+  void *_context;
+  void (*_call)(void *context);
+  void (*_drop)(void *context);
+} ze_owned_closure_history_end_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
 #if defined(Z_FEATURE_UNSTABLE_API)
-  /**
-   * The restriction for the matching publications that will be receive by this subscriber.
-   */
-  enum zc_locality_t allowed_origin;
+typedef struct ze_moved_closure_history_end_t {
+  struct ze_owned_closure_history_end_t _this;
+} ze_moved_closure_history_end_t;
 #endif
-  /**
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Loaned closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct ze_loaned_closure_history_end_t {
+  size_t _0[3];
+} ze_loaned_closure_history_end_t;
+#endif
+/**
+ * @warning This API is deprecated. Please use ze_advanced_subscriber.
+ * @brief A set of options that can be applied to a querying subscriber,
+ * upon its declaration via `ze_declare_querying_subscriber()`.
+ *
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct ze_querying_subscriber_options_t {
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The restriction for the matching publications that will be receive by this subscriber.
+   */
+  enum zc_locality_t allowed_origin;
+#endif
+  /**
    * The selector to be used for queries.
    */
   const struct z_loaned_keyexpr_t *query_selector;
@@ -1297,6 +2124,13 @@ typedef struct ze_querying_subscriber_options_t {
    * The timeout to be used for queries.
    */
   uint64_t query_timeout_ms;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * An optional closure called once all the samples returned by the initial query have been delivered
+   * to the subscriber callback, including when the query fails or times out.
+   */
+  struct ze_moved_closure_history_end_t *history_end;
+#endif
 } ze_querying_subscriber_options_t;
 #endif
 typedef struct ze_moved_publication_cache_t {
@@ -1477,6 +2311,15 @@ ZENOHC_API z_result_t z_bytes_copy_from_str(struct z_owned_bytes_t *this_, const
 ZENOHC_API
 void z_bytes_copy_from_string(struct z_owned_bytes_t *this_,
                               const struct z_loaned_string_t *str);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the CRC-32 (IEEE) checksum of the payload.
+ *
+ * The checksum is computed over the slices of the payload, without copying them to contiguous memory.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API uint32_t z_bytes_crc32(const struct z_loaned_bytes_t *this_);
+#endif
 /**
  * Drops `this_`, resetting it to gravestone value. If there are any shallow copies
  * created by `z_bytes_clone()`, they would still stay valid.
@@ -1504,6 +2347,7 @@ z_result_t z_bytes_from_buf(struct z_owned_bytes_t *this_,
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Converts from an immutable SHM buffer consuming it.
+ * @return 0 in case of success, `Z_ENULL` if `shm` is NULL or in its gravestone state (in this case empty bytes are constructed).
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -1513,6 +2357,7 @@ z_result_t z_bytes_from_shm(struct z_owned_bytes_t *this_,
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Converts a mutable SHM buffer consuming it.
+ * @return 0 in case of success, `Z_ENULL` if `shm` is NULL or in its gravestone state (in this case empty bytes are constructed).
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -1521,11 +2366,13 @@ z_result_t z_bytes_from_shm_mut(struct z_owned_bytes_t *this_,
 #endif
 /**
  * Converts a slice into `z_owned_bytes_t`.
- * The slice is consumed upon function return.
+ * The slice is consumed upon function return. If `slice` is NULL, empty bytes are constructed.
  */
 ZENOHC_API void z_bytes_from_slice(struct z_owned_bytes_t *this_, struct z_moved_slice_t *slice);
 /**
  * Converts a statically allocated constant buffer into `z_owned_bytes_t`.
+ *
+ * The data is aliased without copy and is never freed, which makes it the cheapest way to repeatedly publish a constant payload.
  * @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
  * @param data: A pointer to the statically allocated constant data.
  * @param len: A length of the buffer.
@@ -1533,7 +2380,7 @@ ZENOHC_API void z_bytes_from_slice(struct z_owned_bytes_t *this_, struct z_moved
  */
 ZENOHC_API
 z_result_t z_bytes_from_static_buf(struct z_owned_bytes_t *this_,
-                                   uint8_t *data,
+                                   const uint8_t *data,
                                    size_t len);
 /**
  * Converts a statically allocated constant null-terminated string into `z_owned_bytes_t` by aliasing.
@@ -1559,7 +2406,7 @@ z_result_t z_bytes_from_str(struct z_owned_bytes_t *this_,
                             void *context);
 /**
  * Converts a string into `z_owned_bytes_t`.
- * The string is consumed upon function return.
+ * The string is consumed upon function return. If `s` is NULL, empty bytes are constructed.
  */
 ZENOHC_API void z_bytes_from_string(struct z_owned_bytes_t *this_, struct z_moved_string_t *s);
 /**
@@ -1590,6 +2437,9 @@ ZENOHC_API struct z_bytes_reader_t z_bytes_get_reader(const struct z_loaned_byte
  * then allows to access raw data directly without any attempt of deserializing it.
  * Please note that no guarantee is provided on the internal memory layout.
  * The only provided guarantee is on the bytes order that is preserved.
+ *
+ * The slices returned by `z_bytes_slice_iterator_next()` are views on the memory of the data, no copy is made:
+ * they stay valid as long as the data is neither modified nor dropped.
  */
 ZENOHC_API
 struct z_bytes_slice_iterator_t z_bytes_get_slice_iterator(const struct z_loaned_bytes_t *this_);
@@ -1615,7 +2465,7 @@ ZENOHC_API struct z_loaned_bytes_t *z_bytes_loan_mut(struct z_owned_bytes_t *thi
  * @param this_: Data reader to read from.
  * @param dst: Buffer where the read data is written.
  * @param len: Maximum number of bytes to read.
- * @return number of bytes read. If return value is smaller than `len`, it means that  theend of the data was reached.
+ * @return number of bytes read. If return value is smaller than `len`, it means that the end of the data was reached.
  */
 ZENOHC_API
 size_t z_bytes_reader_read(struct z_bytes_reader_t *this_,
@@ -1664,6 +2514,8 @@ z_result_t z_bytes_to_owned_shm(const struct z_loaned_bytes_t *this_,
 /**
  * Converts data into an owned slice.
  *
+ * The data is always copied into the slice. To access it without copying, use `z_bytes_get_slice_iterator()`.
+ *
  * @param this_: Data to convert.
  * @param dst: An uninitialized memory location where to construct a slice.
  */
@@ -1684,7 +2536,7 @@ z_result_t z_bytes_to_string(const struct z_loaned_bytes_t *this_,
  * This allows to compose a serialized data out of multiple `z_owned_bytes_t` that may point to different memory regions.
  * Said in other terms, it allows to create a linear view on different memory regions without copy.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `bytes` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_bytes_writer_append(struct z_loaned_bytes_writer_t *this_,
@@ -1702,7 +2554,8 @@ ZENOHC_API z_result_t z_bytes_writer_empty(struct z_owned_bytes_writer_t *this_)
 /**
  * @brief Drop writer and extract underlying `bytes` object it was writing to.
  * @param this_: A writer instance.
- * @param bytes: An uninitialized memory location where `bytes` object` will be written to.
+ * @param bytes: An uninitialized memory location where `bytes` object` will be written to. If `this_` is NULL or in its
+ * gravestone state, empty bytes are written.
  */
 ZENOHC_API
 void z_bytes_writer_finish(struct z_moved_bytes_writer_t *this_,
@@ -1726,6 +2579,70 @@ ZENOHC_API
 z_result_t z_bytes_writer_write_all(struct z_loaned_bytes_writer_t *this_,
                                     const uint8_t *src,
                                     size_t len);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the XXH64 hash of the payload, for the given seed.
+ *
+ * The hash is computed over the slices of the payload, without copying them to contiguous memory.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API uint64_t z_bytes_xxhash64(const struct z_loaned_bytes_t *this_, uint64_t seed);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Cancels the queries the token was passed to.
+ *
+ * Their reply callbacks will not be called anymore and are dropped, once the replies currently being processed
+ * are done with them. The token stays cancelled: the queries it is later passed to are cancelled right away.
+ *
+ * Cancellation only suppresses the delivery of the replies, on the querying side: zenoh provides no way to withdraw
+ * a query, so it is still routed to the matching queryables, which keep processing it and replying until it is
+ * finalized or times out. These replies are discarded on reception.
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_cancellation_token_cancel(const struct z_loaned_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a shallow copy of the cancellation token: cancelling any copy cancels all of them.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_cancellation_token_clone(struct z_owned_cancellation_token_t *dst,
+                                const struct z_loaned_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the cancellation token, resetting it to its gravestone state. The queries it was passed to are not cancelled.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void z_cancellation_token_drop(struct z_moved_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the cancellation token was cancelled, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_cancellation_token_is_cancelled(const struct z_loaned_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows the cancellation token.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_cancellation_token_t *z_cancellation_token_loan(const struct z_owned_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a new cancellation token, which can be passed to `z_get()` to cancel the query.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API z_result_t z_cancellation_token_new(struct z_owned_cancellation_token_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Deletes Chunk Alloc Result.
@@ -1771,6 +2688,11 @@ ZENOHC_API struct z_clock_t z_clock_now(void);
 /**
  * Closes zenoh session. This also drops all the closure callbacks remaining from dropped, but not undeclared subscribers.
  *
+ * All the entities declared on the session (publishers, subscribers, queryables, queriers, liveliness tokens
+ * and matching listeners) are undeclared as part of the close, before the session transports are shut down,
+ * so there is no need to undeclare them beforehand in any particular order. Dropping them after the session is closed
+ * only releases their memory.
+ *
  * @return `0` in case of success, a negative value if an error occured while closing the session.
  */
 ZENOHC_API
@@ -2039,7 +2961,7 @@ ZENOHC_API struct z_loaned_config_t *z_config_loan_mut(struct z_owned_config_t *
  * @param callback: The callback function that will be called each time a matching query is received. Its ownership is passed to queryable.
  * @param options: Options for the queryable.
  *
- * @return 0 in case of success, negative error code otherwise (in this case )
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_declare_background_queryable(const struct z_loaned_session_t *session,
@@ -2055,7 +2977,7 @@ z_result_t z_declare_background_queryable(const struct z_loaned_session_t *sessi
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: The options to be passed to the subscriber declaration.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_declare_background_subscriber(const struct z_loaned_session_t *session,
@@ -2098,6 +3020,8 @@ z_result_t z_declare_publisher(const struct z_loaned_session_t *session,
  * @brief Constructs and declares a querier on the given key expression.
  *
  * The queries can be send with the help of the `z_querier_get()` function.
+ * The key expression declaration and the query options are resolved once at querier declaration, so only the payload,
+ * encoding and attachment are provided per query. Queriers should thus be preferred over repeated `z_get()` calls for periodic queries.
  *
  * @param session: The Zenoh session.
  * @param querier: An uninitialized location in memory where querier will be constructed.
@@ -2122,7 +3046,7 @@ z_result_t z_declare_querier(const struct z_loaned_session_t *session,
  * @param callback: The callback function that will be called each time a matching query is received. Its ownership is passed to queryable.
  * @param options: Options for the queryable.
  *
- * @return 0 in case of success, negative error code otherwise (in this case )
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case queryable will be in its gravestone state).
  */
 ZENOHC_API
 z_result_t z_declare_queryable(const struct z_loaned_session_t *session,
@@ -2139,7 +3063,7 @@ z_result_t z_declare_queryable(const struct z_loaned_session_t *session,
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: The options to be passed to the subscriber declaration.
  *
- * @return 0 in case of success, negative error code otherwise (in this case subscriber will be in its gravestone state).
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case subscriber will be in its gravestone state).
  */
 ZENOHC_API
 z_result_t z_declare_subscriber(const struct z_loaned_session_t *session,
@@ -2152,7 +3076,7 @@ z_result_t z_declare_subscriber(const struct z_loaned_session_t *session,
  *
  * @param session: The zenoh session.
  * @param key_expr: The key expression to delete.
- * @param options: The delete options.
+ * @param options: The delete options. All owned fields will be consumed.
  *
  * @return 0 in case of success, negative values in case of failure.
  */
@@ -2685,7 +3609,7 @@ z_result_t z_fifo_handler_sample_try_recv(const struct z_loaned_fifo_handler_sam
  * @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
  * @param options: Additional options for the get. All owned fields will be consumed.
  *
- * @return 0 in case of success, a negative error value upon failure.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, a negative error value upon failure.
  */
 ZENOHC_API
 z_result_t z_get(const struct z_loaned_session_t *session,
@@ -2725,6 +3649,27 @@ ZENOHC_API enum z_whatami_t z_hello_whatami(const struct z_loaned_hello_t *this_
  * @brief Returns id of Zenoh entity that transmitted hello message.
  */
 ZENOHC_API struct z_id_t z_hello_zid(const struct z_loaned_hello_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Parses a `z_id_t` from its binary representation, as constructed by `z_id_to_bytes()`.
+ *
+ * @param dst: The location where the parsed id will be written.
+ * @param data: A pointer to the binary representation.
+ * @param len: The length of the binary representation, which should be 16.
+ * @return 0 in case of success, `Z_EINVAL` if `len` is not 16 or if the id is all zeros.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API z_result_t z_id_from_bytes(struct z_id_t *dst, const uint8_t *data, size_t len);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the binary representation of the `z_id_t`: its 16 bytes in LSB-first order.
+ *
+ * This representation is stable and can be parsed back with `z_id_from_bytes()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void z_id_to_bytes(const struct z_id_t *zid, struct z_owned_slice_t *dst);
+#endif
 /**
  * @brief Formats the `z_id_t` into 16-digit hex string (LSB-first order)
  */
@@ -2735,7 +3680,7 @@ ZENOHC_API void z_id_to_string(const struct z_id_t *zid, struct z_owned_string_t
  * `callback` will be called once for each ID, is guaranteed to never be called concurrently,
  * and is guaranteed to be dropped before this function exits.
  *
- * Retuns 0 on success, negative values on failure
+ * Retuns 0 on success, `Z_EINVAL` if `callback` is NULL, negative values on failure
  */
 ZENOHC_API
 z_result_t z_info_peers_zid(const struct z_loaned_session_t *session,
@@ -2746,7 +3691,7 @@ z_result_t z_info_peers_zid(const struct z_loaned_session_t *session,
  * `callback` will be called once for each ID, is guaranteed to never be called concurrently,
  * and is guaranteed to be dropped before this function exits.
  *
- * Retuns 0 on success, negative values on failure.
+ * Retuns 0 on success, `Z_EINVAL` if `callback` is NULL, negative values on failure.
  */
 ZENOHC_API
 z_result_t z_info_routers_zid(const struct z_loaned_session_t *session,
@@ -2791,6 +3736,21 @@ ZENOHC_API bool z_internal_bytes_writer_check(const struct z_owned_bytes_writer_
  * Constructs a writer in a gravestone state.
  */
 ZENOHC_API void z_internal_bytes_writer_null(struct z_owned_bytes_writer_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the cancellation token is valid, ``false`` if it is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_cancellation_token_check(const struct z_owned_cancellation_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a cancellation token in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void z_internal_cancellation_token_null(struct z_owned_cancellation_token_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @return ``true`` if `this` is valid.
@@ -3327,7 +4287,7 @@ enum z_keyexpr_intersection_level_t z_keyexpr_relation_to(const struct z_loaned_
  * @param callback: The callback function that will be called each time a liveliness token status is changed.
  * @param options: The options to be passed to the liveliness subscriber declaration.
  *
- * @return 0 in case of success, negative error values otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
  */
 ZENOHC_API
 z_result_t z_liveliness_declare_subscriber(const struct z_loaned_session_t *session,
@@ -3358,6 +4318,8 @@ z_result_t z_liveliness_declare_token(const struct z_loaned_session_t *session,
  * @param key_expr: The key expression to query liveliness tokens for.
  * @param callback: The callback function that will be called for each received reply.
  * @param options: Additional options for the liveliness get operation.
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
  */
 ZENOHC_API
 z_result_t z_liveliness_get(const struct z_loaned_session_t *session,
@@ -3458,7 +4420,8 @@ z_result_t z_mutex_unlock(struct z_loaned_mutex_t *this_);
 /**
  * Constructs and opens a new Zenoh session.
  *
- * @return 0 in case of success, negative error code otherwise (in this case the session will be in its gravestone state).
+ * @return 0 in case of success, `Z_EINVAL` if `config` is NULL or in its gravestone state, negative error code otherwise
+ * (in this case the session will be in its gravestone state).
  */
 ZENOHC_API
 z_result_t z_open(struct z_owned_session_t *this_,
@@ -3472,7 +4435,8 @@ ZENOHC_API void z_open_options_default(struct z_open_options_t *this_);
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Constructs and opens a new Zenoh session with specified client storage.
  *
- * @return 0 in case of success, negative error code otherwise (in this case the session will be in its gravestone state).
+ * @return 0 in case of success, `Z_EINVAL` if `config` is NULL or in its gravestone state, negative error code otherwise
+ * (in this case the session will be in its gravestone state).
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -3504,6 +4468,8 @@ ZENOHC_API enum z_priority_t z_priority_default(void);
 /**
  * Sends a `DELETE` message onto the publisher's key expression.
  *
+ * All owned options fields are consumed upon function return.
+ *
  * @return 0 in case of success, negative error code in case of failure.
  */
 ZENOHC_API
@@ -3554,7 +4520,7 @@ ZENOHC_API void z_publisher_options_default(struct z_publisher_options_t *this_)
  * @param payload: The data to publish. Will be consumed.
  * @param options: The publisher put options. All owned fields will be consumed.
  *
- * @return 0 in case of success, negative error values in case of failure.
+ * @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error values in case of failure.
  */
 ZENOHC_API
 z_result_t z_publisher_put(const struct z_loaned_publisher_t *this_,
@@ -3572,17 +4538,54 @@ ZENOHC_API void z_publisher_put_options_default(struct z_publisher_put_options_t
  * @param payload: The value to put (consumed upon function return).
  * @param options: The put options (all owned values will be consumed upon function return).
  *
- * @return 0 in case of success, negative error values in case of failure.
+ * @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error values in case of failure.
  */
 ZENOHC_API
 z_result_t z_put(const struct z_loaned_session_t *session,
                  const struct z_loaned_keyexpr_t *key_expr,
                  struct z_moved_bytes_t *payload,
                  struct z_put_options_t *options);
+/**
+ * Publishes a null-terminated JSON string on specified key expression.
+ *
+ * The string is copied and published with `application/json` encoding, unless another encoding is provided in `options`.
+ * The content of the string is not validated.
+ *
+ * @param session: The Zenoh session.
+ * @param key_expr: The key expression to publish to.
+ * @param json: A pointer to the null-terminated JSON string to publish. It is only read during the call, and must be
+ *     either NULL or a valid null-terminated string.
+ * @param options: The put options (all owned values will be consumed upon function return).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `json` is NULL, other negative error values in case of failure.
+ */
+ZENOHC_API
+z_result_t z_put_json(const struct z_loaned_session_t *session,
+                      const struct z_loaned_keyexpr_t *key_expr,
+                      const char *json,
+                      struct z_put_options_t *options);
 /**
  * Constructs the default value for `z_put_options_t`.
  */
 ZENOHC_API void z_put_options_default(struct z_put_options_t *this_);
+/**
+ * Publishes a null-terminated string on specified key expression.
+ *
+ * The string is copied and published with `text/plain` encoding, unless another encoding is provided in `options`.
+ *
+ * @param session: The Zenoh session.
+ * @param key_expr: The key expression to publish to.
+ * @param value: A pointer to the null-terminated string to publish. It is only read during the call, and must be
+ *     either NULL or a valid null-terminated string.
+ * @param options: The put options (all owned values will be consumed upon function return).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `value` is NULL, other negative error values in case of failure.
+ */
+ZENOHC_API
+z_result_t z_put_string(const struct z_loaned_session_t *session,
+                        const struct z_loaned_keyexpr_t *key_expr,
+                        const char *value,
+                        struct z_put_options_t *options);
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Frees memory and resets querier to its gravestone state.
@@ -3602,7 +4605,7 @@ void z_querier_drop(struct z_moved_querier_t *this_);
  * @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
  * @param options: Additional options for the get. All owned fields will be consumed.
  *
- * @return 0 in case of success, a negative error value upon failure.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, a negative error value upon failure.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -3768,7 +4771,7 @@ struct z_loaned_bytes_t *z_query_payload_mut(struct z_loaned_query_t *this_);
  * @param payload: The payload of this reply. Will be consumed.
  * @param options: The options of this reply. All owned fields will be consumed.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_query_reply(const struct z_loaned_query_t *this_,
@@ -3809,7 +4812,7 @@ ZENOHC_API void z_query_reply_del_options_default(struct z_query_reply_del_optio
  * @param payload: The payload carrying error message. Will be consumed.
  * @param options: The options of this reply. All owned fields will be consumed.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_query_reply_err(const struct z_loaned_query_t *this_,
@@ -3823,6 +4826,37 @@ ZENOHC_API void z_query_reply_err_options_default(struct z_query_reply_err_optio
  * Constructs the default value for `z_query_reply_options_t`.
  */
 ZENOHC_API void z_query_reply_options_default(struct z_query_reply_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sends a previously received sample as a reply to a query.
+ *
+ * The payload of the sample is shared with the reply rather than copied, and its kind, encoding, timestamp,
+ * attachment, source info and QoS are preserved, unless overridden by `options`. This allows caches and proxies
+ * to answer queries with the samples they hold without re-serializing them.
+ *
+ * This function must be called inside of a Queryable callback passing the
+ * query received as parameters of the callback function.
+ *
+ * @param this_: The query to reply to.
+ * @param sample: The sample to send as a reply.
+ * @param options: The options of this reply. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_query_reply_sample(const struct z_loaned_query_t *this_,
+                                const struct z_loaned_sample_t *sample,
+                                struct z_query_reply_sample_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_query_reply_sample_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_query_reply_sample_options_default(struct z_query_reply_sample_options_t *this_);
+#endif
 /**
  * Create a default `z_query_target_t`.
  */
@@ -3840,6 +4874,11 @@ ZENOHC_API void z_queryable_drop(struct z_moved_queryable_t *this_);
 ZENOHC_API
 struct z_entity_global_id_t z_queryable_id(const struct z_loaned_queryable_t *queryable);
 #endif
+/**
+ * Returns the key expression of the queryable.
+ */
+ZENOHC_API
+const struct z_loaned_keyexpr_t *z_queryable_keyexpr(const struct z_loaned_queryable_t *queryable);
 ZENOHC_API
 const struct z_loaned_queryable_t *z_queryable_loan(const struct z_owned_queryable_t *this_);
 /**
@@ -4099,8 +5138,21 @@ const struct z_loaned_encoding_t *z_sample_encoding(const struct z_loaned_sample
 ZENOHC_API bool z_sample_express(const struct z_loaned_sample_t *this_);
 /**
  * Returns the key expression of the sample.
+ * The returned key expression is only valid as long as the sample is, use `z_sample_keyexpr_clone()` to keep it longer.
  */
 ZENOHC_API const struct z_loaned_keyexpr_t *z_sample_keyexpr(const struct z_loaned_sample_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an owned copy of the key expression of the sample.
+ *
+ * This is equivalent to calling `z_keyexpr_clone()` on the result of `z_sample_keyexpr()`.
+ * @param this_: The sample.
+ * @param dst: An uninitialized memory location where the key expression will be constructed.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_sample_keyexpr_clone(const struct z_loaned_sample_t *this_, struct z_owned_keyexpr_t *dst);
+#endif
 /**
  * Returns the sample kind.
  */
@@ -4154,7 +5206,7 @@ ZENOHC_API const struct z_timestamp_t *z_sample_timestamp(const struct z_loaned_
  * @param callback: A closure that will be called on each hello message received from discoverd Zenoh entities.
  * @param options: A set of scouting options
  *
- * @return 0 if successful, negative error values upon failure.
+ * @return 0 if successful, `Z_EINVAL` if `config` or `callback` is NULL, negative error values upon failure.
  */
 ZENOHC_API
 z_result_t z_scout(struct z_moved_config_t *config,
@@ -4267,7 +5319,7 @@ void z_shm_drop(struct z_moved_shm_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs ZShm slice from ZShmMut slice.
+ * @brief Constructs ZShm slice from ZShmMut slice. If `that` is NULL, `this_` is constructed in its gravestone state.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -4354,7 +5406,7 @@ struct z_loaned_shm_mut_t *z_shm_mut_loan_mut(struct z_owned_shm_mut_t *this_);
  * @param immut: immutable SHM buffer returned back to caller's side
  * ONLY in case of Z_EUNAVAILABLE failure
  * @return Z_OK in case of success, Z_EUNAVAILABLE in case of unsuccessful write access,
- * Z_EINVAL if moved value is incorrect.
+ * Z_EINVAL if moved value is NULL or incorrect.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -4509,6 +5561,7 @@ void z_shm_provider_threadsafe_new(struct z_owned_shm_provider_t *this_,
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Mutably borrows ZShm slice as borrowed ZShmMut slice.
+ * @return NULL if the slice is not uniquely owned, or if `this_` is NULL or in its gravestone state.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -4772,6 +5825,16 @@ const struct z_loaned_subscriber_t *z_subscriber_loan(const struct z_owned_subsc
  * Constructs the default value for `z_subscriber_options_t`.
  */
 ZENOHC_API void z_subscriber_options_default(struct z_subscriber_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Passes the samples buffered by a pull mode subscriber to its callback, in the calling thread.
+ *
+ * See `z_subscriber_options_t.pull_capacity` for declaring a pull mode subscriber.
+ * @return The number of samples passed to the callback, 0 for subscribers in push mode.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API size_t z_subscriber_pull(const struct z_loaned_subscriber_t *this_);
+#endif
 /**
  * Detaches the task and releases all allocated resources.
  */
@@ -4823,6 +5886,19 @@ ZENOHC_API struct z_time_t z_time_now(void);
 ZENOHC_API
 const char *z_time_now_as_str(const char *buf,
                               size_t len);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Parses a timestamp from its binary representation, as constructed by `z_timestamp_to_bytes()`.
+ *
+ * @param dst: The location where the parsed timestamp will be written.
+ * @param data: A pointer to the binary representation.
+ * @param len: The length of the binary representation, which should be 24.
+ * @return 0 in case of success, `Z_EINVAL` if `len` is not 24 or if the id is all zeros.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_timestamp_from_bytes(struct z_timestamp_t *dst, const uint8_t *data, size_t len);
+#endif
 /**
  * @brief Returns id associated with this timestamp.
  */
@@ -4837,10 +5913,21 @@ z_result_t z_timestamp_new(struct z_timestamp_t *this_,
  * Returns NPT64 time associated with this timestamp.
  */
 ZENOHC_API uint64_t z_timestamp_ntp64_time(const struct z_timestamp_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the binary representation of the timestamp: its NTP64 time as 8 bytes in little-endian order,
+ * followed by the 16 bytes of its id in LSB-first order.
+ *
+ * This representation is stable and can be parsed back with `z_timestamp_from_bytes()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_timestamp_to_bytes(const struct z_timestamp_t *this_, struct z_owned_slice_t *dst);
+#endif
 /**
  * Undeclares the key expression generated by a call to `z_declare_keyexpr()`.
- * The key expression is consumed.
- * @return 0 in case of success, negative error code otherwise.
+ * The key expression is consumed. Passing NULL is a no-op.
+ * @return 0 in case of success or if `key_expr` is NULL, negative error code otherwise.
  */
 ZENOHC_API
 z_result_t z_undeclare_keyexpr(const struct z_loaned_session_t *session,
@@ -5021,6 +6108,51 @@ ZENOHC_API const struct z_loaned_string_t *z_view_string_loan(const struct z_vie
 ZENOHC_API
 z_result_t z_whatami_to_view_string(enum z_whatami_t whatami,
                                     struct z_view_string_t *str_out);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Decrypts and authenticates data encrypted by `zc_bytes_encrypt()`.
+ *
+ * @param this_: Data to decrypt.
+ * @param key: A pointer to the 32 bytes encryption key.
+ * @param key_len: Length of the key.
+ * @param nonce: A pointer to the 12 bytes nonce used for encryption.
+ * @param nonce_len: Length of the nonce.
+ * @param dst: An uninitialized memory location where the decrypted data will be constructed.
+ * @return 0 in case of success, `Z_EINVAL` if key or nonce are invalid, `Z_EDESERIALIZE` if the data could not be authenticated.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_bytes_decrypt(const struct z_loaned_bytes_t *this_,
+                            const uint8_t *key,
+                            size_t key_len,
+                            const uint8_t *nonce,
+                            size_t nonce_len,
+                            struct z_owned_bytes_t *dst);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Encrypts data with the ChaCha20-Poly1305 AEAD cipher.
+ *
+ * The authentication tag is appended to the encrypted data. The same nonce must never be used twice with the same key.
+ * Encrypted payloads should be published with an encoding marked by `zc_encoding_set_encrypted()`.
+ *
+ * @param this_: Data to encrypt.
+ * @param key: A pointer to the 32 bytes encryption key.
+ * @param key_len: Length of the key.
+ * @param nonce: A pointer to the 12 bytes nonce.
+ * @param nonce_len: Length of the nonce.
+ * @param dst: An uninitialized memory location where the encrypted data will be constructed.
+ * @return 0 in case of success, `Z_EINVAL` if key or nonce are invalid, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_bytes_encrypt(const struct z_loaned_bytes_t *this_,
+                            const uint8_t *key,
+                            size_t key_len,
+                            const uint8_t *nonce,
+                            size_t nonce_len,
+                            struct z_owned_bytes_t *dst);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Linux: Trigger cleanup for orphaned SHM segments
@@ -5040,7 +6172,7 @@ ZENOHC_API
 void zc_cleanup_orphaned_shm_segments(void);
 #endif
 /**
- * @brief Constructs closure.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  *
  * Closures are not guaranteed not to be called concurrently.
  *
@@ -5048,34 +6180,47 @@ void zc_cleanup_orphaned_shm_segments(void);
  *   - `call` will never be called once `drop` has started.
  *   - `drop` will only be called **once**, and **after every** `call` has ended.
  *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
  * @param this_: uninitialized memory location where new closure will be constructed.
  * @param call: a closure body.
  * @param drop: an optional function to be called once on closure drop.
  * @param context: closure context.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_closure_log(struct zc_owned_closure_log_t *this_,
-                    void (*call)(enum zc_log_severity_t severity,
-                                 const struct z_loaned_string_t *msg,
-                                 void *context),
-                    void (*drop)(void *context),
-                    void *context);
+void zc_closure_key_stats(struct zc_owned_closure_key_stats_t *this_,
+                          void (*call)(const struct z_loaned_keyexpr_t *key_expr,
+                                       uint64_t samples,
+                                       void *context),
+                          void (*drop)(void *context),
+                          void *context);
+#endif
 /**
- * Calls the closure. Calling an uninitialized closure is a no-op.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_closure_log_call(const struct zc_loaned_closure_log_t *closure,
-                         enum zc_log_severity_t severity,
-                         const struct z_loaned_string_t *msg);
+void zc_closure_key_stats_call(const struct zc_loaned_closure_key_stats_t *closure,
+                               const struct z_loaned_keyexpr_t *key_expr,
+                               uint64_t samples);
+#endif
 /**
- * Drops the closure. Droping an uninitialized closure is a no-op.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
  */
-ZENOHC_API void zc_closure_log_drop(struct zc_moved_closure_log_t *closure_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_key_stats_drop(struct zc_moved_closure_key_stats_t *closure_);
+#endif
 /**
- * Borrows closure.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-const struct zc_loaned_closure_log_t *zc_closure_log_loan(const struct zc_owned_closure_log_t *closure);
+const struct zc_loaned_closure_key_stats_t *zc_closure_key_stats_loan(const struct zc_owned_closure_key_stats_t *closure);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  *
@@ -5093,20 +6238,22 @@ const struct zc_loaned_closure_log_t *zc_closure_log_loan(const struct zc_owned_
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_closure_matching_status(struct zc_owned_closure_matching_status_t *this_,
-                                void (*call)(const struct zc_matching_status_t *matching_status,
-                                             void *context),
-                                void (*drop)(void *context),
-                                void *context);
+void zc_closure_keyexpr_interceptor(struct zc_owned_closure_keyexpr_interceptor_t *this_,
+                                    z_result_t (*call)(const struct z_loaned_keyexpr_t *key_expr,
+                                                       struct z_owned_keyexpr_t *rewritten,
+                                                       void *context),
+                                    void (*drop)(void *context),
+                                    void *context);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ * @brief Calls the closure. Calling an uninitialized closure returns `Z_EINVAL`.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_closure_matching_status_call(const struct zc_loaned_closure_matching_status_t *closure,
-                                     const struct zc_matching_status_t *mathing_status);
+z_result_t zc_closure_keyexpr_interceptor_call(const struct zc_loaned_closure_keyexpr_interceptor_t *closure,
+                                               const struct z_loaned_keyexpr_t *key_expr,
+                                               struct z_owned_keyexpr_t *rewritten);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -5114,7 +6261,7 @@ void zc_closure_matching_status_call(const struct zc_loaned_closure_matching_sta
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_closure_matching_status_drop(struct zc_moved_closure_matching_status_t *closure_);
+void zc_closure_keyexpr_interceptor_drop(struct zc_moved_closure_keyexpr_interceptor_t *closure_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -5122,353 +6269,2024 @@ void zc_closure_matching_status_drop(struct zc_moved_closure_matching_status_t *
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-const struct zc_loaned_closure_matching_status_t *zc_closure_matching_status_loan(const struct zc_owned_closure_matching_status_t *closure);
-#endif
-/**
- * @brief Drops the close handle. The concurrent close task will not be interrupted.
- */
-#if defined(Z_FEATURE_UNSTABLE_API)
-ZENOHC_API void zc_concurrent_close_handle_drop(struct zc_moved_concurrent_close_handle_t *this_);
-#endif
-/**
- * @brief Blocking wait on close handle to complete. Returns `Z_EIO` if close finishes with error.
- */
-#if defined(Z_FEATURE_UNSTABLE_API)
-ZENOHC_API
-z_result_t zc_concurrent_close_handle_wait(struct zc_moved_concurrent_close_handle_t *handle);
+const struct zc_loaned_closure_keyexpr_interceptor_t *zc_closure_keyexpr_interceptor_loan(const struct zc_owned_closure_keyexpr_interceptor_t *closure);
 #endif
 /**
- * Constructs a configuration by parsing a file path stored in ZENOH_CONFIG environmental variable.
+ * @brief Constructs closure.
  *
- * Returns 0 in case of success, negative error code otherwise.
- */
-ZENOHC_API z_result_t zc_config_from_env(struct z_owned_config_t *this_);
-/**
- * Constructs a configuration by parsing a file at `path`. Currently supported format is JSON5, a superset of JSON.
+ * Closures are not guaranteed not to be called concurrently.
  *
- * Returns 0 in case of success, negative error code otherwise.
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+ZENOHC_API
+void zc_closure_log(struct zc_owned_closure_log_t *this_,
+                    void (*call)(enum zc_log_severity_t severity,
+                                 const struct z_loaned_string_t *msg,
+                                 void *context),
+                    void (*drop)(void *context),
+                    void *context);
+/**
+ * Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+ZENOHC_API
+void zc_closure_log_call(const struct zc_loaned_closure_log_t *closure,
+                         enum zc_log_severity_t severity,
+                         const struct z_loaned_string_t *msg);
+/**
+ * Drops the closure. Droping an uninitialized closure is a no-op.
+ */
+ZENOHC_API void zc_closure_log_drop(struct zc_moved_closure_log_t *closure_);
+/**
+ * Borrows closure.
+ */
+ZENOHC_API
+const struct zc_loaned_closure_log_t *zc_closure_log_loan(const struct zc_owned_closure_log_t *closure);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs closure.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_log_record(struct zc_owned_closure_log_record_t *this_,
+                           void (*call)(enum zc_log_severity_t severity,
+                                        const struct z_loaned_string_t *target,
+                                        const struct z_loaned_string_t *msg,
+                                        void *context),
+                           void (*drop)(void *context),
+                           void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_log_record_call(const struct zc_loaned_closure_log_record_t *closure,
+                                enum zc_log_severity_t severity,
+                                const struct z_loaned_string_t *target,
+                                const struct z_loaned_string_t *msg);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_closure_log_record_drop(struct zc_moved_closure_log_record_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_log_record_t *zc_closure_log_record_loan(const struct zc_owned_closure_log_record_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_matching_status(struct zc_owned_closure_matching_status_t *this_,
+                                void (*call)(const struct zc_matching_status_t *matching_status,
+                                             void *context),
+                                void (*drop)(void *context),
+                                void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_matching_status_call(const struct zc_loaned_closure_matching_status_t *closure,
+                                     const struct zc_matching_status_t *mathing_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_matching_status_drop(struct zc_moved_closure_matching_status_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_matching_status_t *zc_closure_matching_status_loan(const struct zc_owned_closure_matching_status_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_metrics(struct zc_owned_closure_metrics_t *this_,
+                        void (*call)(const struct zc_metrics_t *metrics, void *context),
+                        void (*drop)(void *context),
+                        void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_metrics_call(const struct zc_loaned_closure_metrics_t *closure,
+                             const struct zc_metrics_t *metrics);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_metrics_drop(struct zc_moved_closure_metrics_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_metrics_t *zc_closure_metrics_loan(const struct zc_owned_closure_metrics_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_payload_transform(struct zc_owned_closure_payload_transform_t *this_,
+                                  void (*call)(const struct z_loaned_bytes_t *payload,
+                                               struct z_owned_bytes_t *transformed,
+                                               void *context),
+                                  void (*drop)(void *context),
+                                  void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_payload_transform_call(const struct zc_loaned_closure_payload_transform_t *closure,
+                                       const struct z_loaned_bytes_t *payload,
+                                       struct z_owned_bytes_t *transformed);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_payload_transform_drop(struct zc_moved_closure_payload_transform_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_payload_transform_t *zc_closure_payload_transform_loan(const struct zc_owned_closure_payload_transform_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_query_diagnostics(struct zc_owned_closure_query_diagnostics_t *this_,
+                                  void (*call)(const struct zc_query_diagnostics_t *diagnostics,
+                                               void *context),
+                                  void (*drop)(void *context),
+                                  void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_query_diagnostics_call(const struct zc_loaned_closure_query_diagnostics_t *closure,
+                                       const struct zc_query_diagnostics_t *diagnostics);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_query_diagnostics_drop(struct zc_moved_closure_query_diagnostics_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_query_diagnostics_t *zc_closure_query_diagnostics_loan(const struct zc_owned_closure_query_diagnostics_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_reply_entry(struct zc_owned_closure_reply_entry_t *this_,
+                            bool (*call)(struct z_owned_keyexpr_t *key_expr,
+                                         struct z_owned_bytes_t *payload,
+                                         void *context),
+                            void (*drop)(void *context),
+                            void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure returns ``false``.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_closure_reply_entry_call(const struct zc_loaned_closure_reply_entry_t *closure,
+                                 struct z_owned_keyexpr_t *key_expr,
+                                 struct z_owned_bytes_t *payload);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_reply_entry_drop(struct zc_moved_closure_reply_entry_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_reply_entry_t *zc_closure_reply_entry_loan(const struct zc_owned_closure_reply_entry_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_table_get(struct zc_owned_closure_table_get_t *this_,
+                          bool (*call)(const struct z_loaned_string_t *key,
+                                       struct z_owned_bytes_t *value,
+                                       void *context),
+                          void (*drop)(void *context),
+                          void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure returns ``false``.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_closure_table_get_call(const struct zc_loaned_closure_table_get_t *closure,
+                               const struct z_loaned_string_t *key,
+                               struct z_owned_bytes_t *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_table_get_drop(struct zc_moved_closure_table_get_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_table_get_t *zc_closure_table_get_loan(const struct zc_owned_closure_table_get_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_table_list(struct zc_owned_closure_table_list_t *this_,
+                           void (*call)(struct z_loaned_string_array_t *keys,
+                                        void *context),
+                           void (*drop)(void *context),
+                           void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_table_list_call(const struct zc_loaned_closure_table_list_t *closure,
+                                struct z_loaned_string_array_t *keys);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_table_list_drop(struct zc_moved_closure_table_list_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_table_list_t *zc_closure_table_list_loan(const struct zc_owned_closure_table_list_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs closure.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watch(struct zc_owned_closure_watch_t *this_,
+                      void (*call)(struct z_loaned_sample_t *sample, bool is_initial, void *context),
+                      void (*drop)(void *context),
+                      void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watch_call(const struct zc_loaned_closure_watch_t *closure,
+                           struct z_loaned_sample_t *sample,
+                           bool is_initial);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watch_drop(struct zc_moved_closure_watch_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_watch_t *zc_closure_watch_loan(const struct zc_owned_closure_watch_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watermark(struct zc_owned_closure_watermark_t *this_,
+                          void (*call)(enum zc_watermark_t watermark,
+                                       size_t occupancy,
+                                       void *context),
+                          void (*drop)(void *context),
+                          void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watermark_call(const struct zc_loaned_closure_watermark_t *closure,
+                               enum zc_watermark_t watermark,
+                               size_t occupancy);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_closure_watermark_drop(struct zc_moved_closure_watermark_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_closure_watermark_t *zc_closure_watermark_loan(const struct zc_owned_closure_watermark_t *closure);
+#endif
+/**
+ * @brief Drops the close handle. The concurrent close task will not be interrupted.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_concurrent_close_handle_drop(struct zc_moved_concurrent_close_handle_t *this_);
+#endif
+/**
+ * @brief Blocking wait on close handle to complete. Returns `Z_EIO` if close finishes with error,
+ * `Z_EINVAL` if `handle` is NULL or in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_concurrent_close_handle_wait(struct zc_moved_concurrent_close_handle_t *handle);
+#endif
+/**
+ * Constructs a configuration by parsing a file path stored in ZENOH_CONFIG environmental variable.
+ *
+ * Returns 0 in case of success, negative error code otherwise.
+ */
+ZENOHC_API z_result_t zc_config_from_env(struct z_owned_config_t *this_);
+/**
+ * Constructs a configuration by parsing a file at `path`. Currently supported format is JSON5, a superset of JSON.
+ *
+ * Returns 0 in case of success, negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_from_file(struct z_owned_config_t *this_,
+                               const char *path);
+/**
+ * Reads a configuration from a JSON-serialized string, such as '{mode:"client",connect:{endpoints:["tcp/127.0.0.1:7447"]}}'.
+ *
+ * Returns 0 in case of success, negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_from_str(struct z_owned_config_t *this_,
+                              const char *s);
+/**
+ * Gets the property with the given path key from the configuration, and constructs and owned string from it.
+ */
+ZENOHC_API
+z_result_t zc_config_get_from_str(const struct z_loaned_config_t *this_,
+                                  const char *key,
+                                  struct z_owned_string_t *out_value_string);
+/**
+ * Gets the property with the given path key from the configuration, and constructs and owned string from it.
+ */
+ZENOHC_API
+z_result_t zc_config_get_from_substr(const struct z_loaned_config_t *this_,
+                                     const char *key,
+                                     size_t key_len,
+                                     struct z_owned_string_t *out_value_string);
+/**
+ * Inserts a JSON-serialized `value` at the `key` position of the configuration.
+ *
+ * Returns 0 if successful, a negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_insert_json5(struct z_loaned_config_t *this_,
+                                  const char *key,
+                                  const char *value);
+/**
+ * Inserts a JSON-serialized `value` at the `key` position of the configuration.
+ *
+ * Returns 0 if successful, a negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_insert_json5_from_substr(struct z_loaned_config_t *this_,
+                                              const char *key,
+                                              size_t key_len,
+                                              const char *value,
+                                              size_t value_len);
+/**
+ * Constructs a json string representation of the `config`, such as '{"mode":"client","connect":{"endpoints":["tcp/127.0.0.1:7447"]}}'.
+ *
+ * Returns 0 in case of success, negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_to_string(const struct z_loaned_config_t *config,
+                               struct z_owned_string_t *out_config_string);
+/**
+ * Constructs the default value for `zc_config_to_string_options_t`.
+ */
+ZENOHC_API void zc_config_to_string_options_default(struct zc_config_to_string_options_t *this_);
+/**
+ * Constructs a json string representation of the `config`, such as '{"mode":"client","connect":{"endpoints":["tcp/127.0.0.1:7447"]}}'.
+ *
+ * With the `redact_secrets` option, the user names, passwords and private keys of the authentication and TLS
+ * settings are replaced with `"***"`.
+ *
+ * Returns 0 in case of success, negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_to_string_with_options(const struct z_loaned_config_t *config,
+                                            struct z_owned_string_t *out_config_string,
+                                            struct zc_config_to_string_options_t *options);
+/**
+ * Parses and validates a JSON5-serialized configuration without constructing it or opening a session.
+ *
+ * If `error_report` is not null, it is always initialized: on failure it describes the first error found,
+ * on success its strings are in gravestone state (see `z_internal_string_null()`) and its line and column are 0.
+ * The strings of the report should be dropped by the caller in both cases.
+ *
+ * Returns 0 if the configuration is valid, negative error code otherwise.
+ */
+ZENOHC_API
+z_result_t zc_config_validate(const char *s,
+                              struct zc_config_error_report_t *error_report);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Declares a stream listener, accepting the streams opened with `zc_stream_open()` on the key expression.
+ *
+ * @param session: The zenoh session.
+ * @param listener: An uninitialized memory location where the listener will be constructed.
+ * @param key_expr: The key expression to accept the streams on.
+ * @param options: The options of the accepted streams, default options are used if NULL.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_declare_stream_listener(const struct z_loaned_session_t *session,
+                                      struct zc_owned_stream_listener_t *listener,
+                                      const struct z_loaned_keyexpr_t *key_expr,
+                                      const struct zc_stream_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs and declares a watch on a key expression.
+ *
+ * A watch queries the current values of the key expression, and then keeps delivering their updates, through a single
+ * callback. It is called with each reply to the initial query with `is_initial` set to ``true``, and with each
+ * publication on the key expression with `is_initial` set to ``false``.
+ *
+ * The subscriber is declared before the query is issued, so that no update is missed. The updates received while
+ * the query is in progress are delivered once it completes, so that they are always delivered after the initial
+ * values they supersede.
+ *
+ * The subscriber of the watch is declared like the ones of `z_declare_subscriber()`: it is counted in the session
+ * limits, its key expression is passed to the key expression interceptor of the session, and the callback is deferred
+ * if the session is in deferred dispatch mode.
+ *
+ * @param session: The zenoh session.
+ * @param watch: An uninitialized location in memory where the watch will be constructed.
+ * @param key_expr: The key expression to watch.
+ * @param callback: The callback function that will be called with the initial values and their updates.
+ * @param options: The options for the watch, pass NULL for default options.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_declare_watch(const struct z_loaned_session_t *session,
+                            struct zc_owned_watch_t *watch,
+                            const struct z_loaned_keyexpr_t *key_expr,
+                            struct zc_moved_closure_watch_t *callback,
+                            struct zc_watch_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the encoding is marked as the one of an encrypted payload, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API bool zc_encoding_is_encrypted(const struct z_loaned_encoding_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Marks the encoding as the one of an encrypted payload, by appending `;aead=chacha20-poly1305` suffix to it.
+ * Marking an already marked encoding is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_encoding_set_encrypted(struct z_loaned_encoding_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Removes the encrypted payload mark set by `zc_encoding_set_encrypted()` from the encoding, restoring the original one.
+ *
+ * @return ``true`` if the encoding was marked as encrypted, ``false`` otherwise (the encoding is not modified in this case).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API bool zc_encoding_unset_encrypted(struct z_loaned_encoding_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel.
+ *
+ * The callback end can be passed to any function declaring a matching listener,
+ * matching status changes can then be polled from the handler end.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_fifo_channel_matching_status_new(struct zc_owned_closure_matching_status_t *callback,
+                                         struct zc_owned_fifo_handler_matching_status_t *handler,
+                                         size_t capacity);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel, notifying `watermark_callback` when the number of pending querys
+ * reaches `high` and when it goes back down to `low` afterwards.
+ *
+ * This gives an early warning that the receiving end is falling behind, before the channel is full and the sending end blocks.
+ * The occupancy of the channel is evaluated each time a query is pushed into it.
+ * The watermark callback is dropped along with the send end of the channel.
+ *
+ * @param callback: An uninitialized location in memory where the send end of the channel will be constructed.
+ * @param handler: An uninitialized location in memory where the receive end of the channel will be constructed.
+ * @param capacity: The capacity of the channel.
+ * @param high: The high watermark, should not exceed `capacity`.
+ * @param low: The low watermark, should be lower than `high`.
+ * @param watermark_callback: The closure called when the occupancy of the channel crosses a watermark.
+ * @return 0 in case of success, `Z_EINVAL` if `watermark_callback` is NULL or the watermarks are invalid (`callback` and `handler` are then in gravestone state).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_fifo_channel_query_new_with_watermarks(struct z_owned_closure_query_t *callback,
+                                                     struct z_owned_fifo_handler_query_t *handler,
+                                                     size_t capacity,
+                                                     size_t high,
+                                                     size_t low,
+                                                     struct zc_moved_closure_watermark_t *watermark_callback);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel, notifying `watermark_callback` when the number of pending replys
+ * reaches `high` and when it goes back down to `low` afterwards.
+ *
+ * This gives an early warning that the receiving end is falling behind, before the channel is full and the sending end blocks.
+ * The occupancy of the channel is evaluated each time a reply is pushed into it.
+ * The watermark callback is dropped along with the send end of the channel.
+ *
+ * @param callback: An uninitialized location in memory where the send end of the channel will be constructed.
+ * @param handler: An uninitialized location in memory where the receive end of the channel will be constructed.
+ * @param capacity: The capacity of the channel.
+ * @param high: The high watermark, should not exceed `capacity`.
+ * @param low: The low watermark, should be lower than `high`.
+ * @param watermark_callback: The closure called when the occupancy of the channel crosses a watermark.
+ * @return 0 in case of success, `Z_EINVAL` if `watermark_callback` is NULL or the watermarks are invalid (`callback` and `handler` are then in gravestone state).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_fifo_channel_reply_new_with_watermarks(struct z_owned_closure_reply_t *callback,
+                                                     struct z_owned_fifo_handler_reply_t *handler,
+                                                     size_t capacity,
+                                                     size_t high,
+                                                     size_t low,
+                                                     struct zc_moved_closure_watermark_t *watermark_callback);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel, notifying `watermark_callback` when the number of pending samples
+ * reaches `high` and when it goes back down to `low` afterwards.
+ *
+ * This gives an early warning that the receiving end is falling behind, before the channel is full and the sending end blocks.
+ * The occupancy of the channel is evaluated each time a sample is pushed into it.
+ * The watermark callback is dropped along with the send end of the channel.
+ *
+ * @param callback: An uninitialized location in memory where the send end of the channel will be constructed.
+ * @param handler: An uninitialized location in memory where the receive end of the channel will be constructed.
+ * @param capacity: The capacity of the channel.
+ * @param high: The high watermark, should not exceed `capacity`.
+ * @param low: The low watermark, should be lower than `high`.
+ * @param watermark_callback: The closure called when the occupancy of the channel crosses a watermark.
+ * @return 0 in case of success, `Z_EINVAL` if `watermark_callback` is NULL or the watermarks are invalid (`callback` and `handler` are then in gravestone state).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_fifo_channel_sample_new_with_watermarks(struct z_owned_closure_sample_t *callback,
+                                                      struct z_owned_fifo_handler_sample_t *handler,
+                                                      size_t capacity,
+                                                      size_t high,
+                                                      size_t low,
+                                                      struct zc_moved_closure_watermark_t *watermark_callback);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_fifo_handler_matching_status_drop(struct zc_moved_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_fifo_handler_matching_status_t *zc_fifo_handler_matching_status_loan(const struct zc_owned_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the fifo buffer. If there are no more pending statuses will block until next status is received, or until
+ * the channel is dropped (normally when the matching listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (`matching_status` is not updated in this case).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_fifo_handler_matching_status_recv(const struct zc_loaned_fifo_handler_matching_status_t *this_,
+                                                struct zc_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the fifo buffer.
+ * If there are no more pending statuses will return immediately.
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped,
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (`matching_status` is not updated in both cases).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_fifo_handler_matching_status_try_recv(const struct zc_loaned_fifo_handler_matching_status_t *this_,
+                                                    struct zc_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Republishes a received sample onto the publisher's key expression.
+ *
+ * The payload of the sample is shared with the publication rather than copied, which makes this function
+ * the building block of bridges and relays. `PUT` samples are republished with `z_publisher_put()` semantics,
+ * `DELETE` samples with `z_publisher_delete()` semantics. The encoding, timestamp, source info and attachment
+ * of the sample are preserved, unless overridden by `options`, while the QoS are the ones of the publisher.
+ *
+ * @param sample: The sample to republish.
+ * @param publisher: The publisher.
+ * @param options: The options overriding the metadata of the sample. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error code in case of failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_forward(const struct z_loaned_sample_t *sample,
+                      const struct z_loaned_publisher_t *publisher,
+                      struct z_publisher_put_options_t *options);
+#endif
+/**
+ * Initializes the zenoh runtime logger, using rust environment settings or the provided fallback level.
+ * E.g.: `RUST_LOG=info` will enable logging at info level. Similarly, you can set the variable to `error` or `debug`.
+ *
+ * Note that if the environment variable is not set, then fallback filter will be used instead.
+ * See https://docs.rs/env_logger/latest/env_logger/index.html for accepted filter format.
+ *
+ * @param fallback_filter: The fallback filter if the `RUST_LOG` environment variable is not set.
+ */
+ZENOHC_API
+z_result_t zc_init_log_from_env_or(const char *fallback_filter);
+/**
+ * Initializes the zenoh runtime logger with custom callback.
+ *
+ * @param min_severity: Minimum severity level of log message to be be passed to the `callback`.
+ * Messages with lower severity levels will be ignored. It can be changed later with `zc_log_set_min_severity()`.
+ * @param callback: A closure that will be called with each log message severity level and content.
+ * If NULL, the logger is not initialized.
+ */
+ZENOHC_API
+void zc_init_log_with_callback(enum zc_log_severity_t min_severity,
+                               struct zc_moved_closure_log_t *callback);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Initializes the zenoh runtime logger with custom callback receiving the target of each log record.
+ *
+ * The target is the module path of the code that emitted the record (e.g. `zenoh_transport::unicast`),
+ * allowing host loggers to map Zenoh modules to their own categories.
+ *
+ * @param min_severity: Minimum severity level of log message to be be passed to the `callback`.
+ * Messages with lower severity levels will be ignored. It can be changed later with `zc_log_set_min_severity()`.
+ * @param callback: A closure that will be called with each log record severity level, target and message.
+ * If NULL, the logger is not initialized.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_init_log_with_record_callback(enum zc_log_severity_t min_severity,
+                                      struct zc_moved_closure_log_record_t *callback);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_key_stats_check(const struct zc_owned_closure_key_stats_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_key_stats_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_key_stats_null(struct zc_owned_closure_key_stats_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_keyexpr_interceptor_check(const struct zc_owned_closure_keyexpr_interceptor_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_keyexpr_interceptor_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_keyexpr_interceptor_null(struct zc_owned_closure_keyexpr_interceptor_t *this_);
+#endif
+/**
+ * Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+ZENOHC_API bool zc_internal_closure_log_check(const struct zc_owned_closure_log_t *this_);
+/**
+ * Constructs a closure in a gravestone state.
+ */
+ZENOHC_API void zc_internal_closure_log_null(struct zc_owned_closure_log_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_log_record_check(const struct zc_owned_closure_log_record_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a closure in a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_internal_closure_log_record_null(struct zc_owned_closure_log_record_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_matching_status_check(const struct zc_owned_closure_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_matching_status_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_matching_status_null(struct zc_owned_closure_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_metrics_check(const struct zc_owned_closure_metrics_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_metrics_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_metrics_null(struct zc_owned_closure_metrics_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_payload_transform_check(const struct zc_owned_closure_payload_transform_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_payload_transform_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_payload_transform_null(struct zc_owned_closure_payload_transform_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_query_diagnostics_check(const struct zc_owned_closure_query_diagnostics_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_query_diagnostics_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_query_diagnostics_null(struct zc_owned_closure_query_diagnostics_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_reply_entry_check(const struct zc_owned_closure_reply_entry_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_reply_entry_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_reply_entry_null(struct zc_owned_closure_reply_entry_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_table_get_check(const struct zc_owned_closure_table_get_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_table_get_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_table_get_null(struct zc_owned_closure_table_get_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_table_list_check(const struct zc_owned_closure_table_list_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_table_list_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_table_list_null(struct zc_owned_closure_table_list_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_watch_check(const struct zc_owned_closure_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_watch_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_watch_null(struct zc_owned_closure_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_closure_watermark_check(const struct zc_owned_closure_watermark_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'zc_owned_closure_watermark_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_closure_watermark_null(struct zc_owned_closure_watermark_t *this_);
+#endif
+/**
+ * @brief Returns ``true`` if concurrent close handle is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_concurrent_close_handle_check(const struct zc_owned_concurrent_close_handle_t *this_);
+#endif
+/**
+ * @brief Constructs concurrent close handle in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_concurrent_close_handle_null(struct zc_owned_concurrent_close_handle_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_fifo_handler_matching_status_check(const struct zc_owned_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_fifo_handler_matching_status_null(struct zc_owned_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Checks the matching listener is for the gravestone state
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_matching_listener_check(const struct zc_owned_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an empty matching listener.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_matching_listener_null(struct zc_owned_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the metrics subscriber is valid, ``false`` if it is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_metrics_subscriber_check(const struct zc_owned_metrics_subscriber_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a metrics subscriber in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_metrics_subscriber_null(struct zc_owned_metrics_subscriber_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the gateway is valid, ``false`` if it is in its gravestone state.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API bool zc_internal_rest_gateway_check(const struct zc_owned_rest_gateway_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a gateway in its gravestone state.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API void zc_internal_rest_gateway_null(struct zc_owned_rest_gateway_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_ring_handler_matching_status_check(const struct zc_owned_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_ring_handler_matching_status_null(struct zc_owned_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if `this` is valid.
+ */
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+bool zc_internal_shm_client_list_check(const struct zc_owned_shm_client_list_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs SHM client list in its gravestone value.
+ */
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+void zc_internal_shm_client_list_null(struct zc_owned_shm_client_list_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the stream is valid, ``false`` if it is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API bool zc_internal_stream_check(const struct zc_owned_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the stream listener is valid, ``false`` if it is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API bool zc_internal_stream_listener_check(const struct zc_owned_stream_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a stream listener in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_internal_stream_listener_null(struct zc_owned_stream_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a stream in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_internal_stream_null(struct zc_owned_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the watch is valid, ``false`` if it is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool zc_internal_watch_check(const struct zc_owned_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a watch in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_internal_watch_null(struct zc_owned_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Declares a background subscriber on liveliness tokens that intersect `key_expr`. Subscriber callback will be called to process the messages,
+ * until the corresponding session is closed or dropped.
+ * @param session: The Zenoh session.
+ * @param key_expr: The key expression to subscribe to.
+ * @param callback: The callback function that will be called each time a liveliness token status is changed.
+ * @param options: The options to be passed to the liveliness subscriber declaration.
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_liveliness_declare_background_subscriber(const struct z_loaned_session_t *session,
+                                                       const struct z_loaned_keyexpr_t *key_expr,
+                                                       struct z_moved_closure_sample_t *callback,
+                                                       struct z_liveliness_subscriber_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the key expression of the liveliness token.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_keyexpr_t *zc_liveliness_token_keyexpr(const struct z_loaned_liveliness_token_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns default value of `zc_locality_t`
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+enum zc_locality_t zc_locality_default(void);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Changes the minimum severity level of the log messages passed to the callback registered with
+ * `zc_init_log_with_callback()` or `zc_init_log_with_record_callback()`.
+ *
+ * It has no effect on the loggers initialized from the environment.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_log_set_min_severity(enum zc_log_severity_t min_severity);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares the given matching listener, droping and invalidating it.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_matching_listener_drop(struct zc_moved_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the matching listener is paused, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API bool zc_matching_listener_is_paused(const struct zc_owned_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Pauses the matching listener: its callback is not called on matching status changes until
+ * `zc_matching_listener_resume()` is called, while the listener stays declared.
+ *
+ * The changes happening while the listener is paused are not replayed on resume, the current matching status
+ * can be retrieved with `zc_publisher_get_matching_status()` or `zc_querier_get_matching_status()`.
+ * @return 0 in case of success, `Z_EINVAL` if the matching listener is in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API z_result_t zc_matching_listener_pause(const struct zc_owned_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Resumes a matching listener paused with `zc_matching_listener_pause()`.
+ * @return 0 in case of success, `Z_EINVAL` if the matching listener is in its gravestone state.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API z_result_t zc_matching_listener_resume(const struct zc_owned_matching_listener_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Periodically queries the adminspace of the local zenoh node, and calls the callback with its parsed metrics.
+ *
+ * The adminspace must be enabled, with read permissions, in the session configuration (`adminspace/enabled` and
+ * `adminspace/permissions/read` keys). The callback is called from a dedicated thread, first right away and then
+ * every `period_ms` milliseconds, until the subscriber is dropped or the session is closed. The subscriber keeps
+ * the session alive: it should be dropped before the session, unless the session is closed with `z_close()`.
+ *
+ * @param session: The zenoh session.
+ * @param subscriber: An uninitialized location in memory where the metrics subscriber will be constructed.
+ * @param callback: The callback function that will be called with the metrics.
+ * @param period_ms: The polling period in milliseconds, must be non-zero.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL or `period_ms` is zero, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_config_from_file(struct z_owned_config_t *this_,
-                               const char *path);
+z_result_t zc_metrics_subscribe(const struct z_loaned_session_t *session,
+                                struct zc_owned_metrics_subscriber_t *subscriber,
+                                struct zc_moved_closure_metrics_t *callback,
+                                uint64_t period_ms);
+#endif
 /**
- * Reads a configuration from a JSON-serialized string, such as '{mode:"client",connect:{endpoints:["tcp/127.0.0.1:7447"]}}'.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Stops the metrics polling and resets the subscriber to its gravestone state.
  *
- * Returns 0 in case of success, negative error code otherwise.
+ * The callback is dropped once its ongoing call, if any, returns.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_metrics_subscriber_drop(struct zc_moved_metrics_subscriber_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Declares a matching listener, registering a callback for notifying subscribers matching with a given publisher.
+ * The callback will be run in the background until the corresponding publisher is dropped.
+ *
+ * @param publisher: A publisher to associate with matching listener.
+ * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_config_from_str(struct z_owned_config_t *this_,
-                              const char *s);
+z_result_t zc_publisher_declare_background_matching_listener(const struct z_loaned_publisher_t *publisher,
+                                                             struct zc_moved_closure_matching_status_t *callback);
+#endif
 /**
- * Gets the property with the given path key from the configuration, and constructs and owned string from it.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs matching listener, registering a callback for notifying subscribers matching with a given publisher.
+ *
+ * @param publisher: A publisher to associate with matching listener.
+ * @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the publisher is dropped.
+ * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_config_get_from_str(const struct z_loaned_config_t *this_,
-                                  const char *key,
-                                  struct z_owned_string_t *out_value_string);
+z_result_t zc_publisher_declare_matching_listener(const struct z_loaned_publisher_t *publisher,
+                                                  struct zc_owned_matching_listener_t *matching_listener,
+                                                  struct zc_moved_closure_matching_status_t *callback);
+#endif
 /**
- * Gets the property with the given path key from the configuration, and constructs and owned string from it.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets publisher matching status - i.e. if there are any subscribers matching its key expression.
+ *
+ * @return 0 in case of success, negative error code otherwise (in this case matching_status is not updated).
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_config_get_from_substr(const struct z_loaned_config_t *this_,
-                                     const char *key,
-                                     size_t key_len,
-                                     struct z_owned_string_t *out_value_string);
+z_result_t zc_publisher_get_matching_status(const struct z_loaned_publisher_t *this_,
+                                            struct zc_matching_status_t *matching_status);
+#endif
 /**
- * Inserts a JSON-serialized `value` at the `key` position of the configuration.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the maximum size in bytes of the payload and attachment of a publication made with `z_publisher_put()`
+ * or `zc_forward()`.
  *
- * Returns 0 if successful, a negative error code otherwise.
+ * If the publisher was declared with fragmentation disallowed (see `allow_fragmentation` field of `z_publisher_options_t`),
+ * this is a conservative estimate of the largest publication that fits in a single batch of the session links
+ * (`transport/link/tx/batch_size` configuration key), so that the accepted publications are never fragmented.
+ * Otherwise, this is the maximum size of the messages that can be reassembled from fragments
+ * (`transport/link/rx/max_message_size` configuration key), assuming the remote nodes use the same configuration.
+ * Larger publications are rejected with `Z_EFRAGMENT`, so file-transfer applications can use this size to split their
+ * data in chunks. The same limits apply to `ze_advanced_publisher_put()`, and the fragmented one to `z_put()`.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API size_t zc_publisher_max_payload_size(const struct z_loaned_publisher_t *publisher);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Waits until the publisher has matching subscribers.
+ *
+ * A publication is only routed to the subscribers whose declarations were propagated to the publisher session.
+ * Calling this function after declaring the publisher ensures the first publication reaches the already declared
+ * subscribers, which is useful for benchmarks and latency-critical first messages. It does not wait for the
+ * declaration of the publisher itself to be acknowledged by the network, which Zenoh does not report, so it
+ * times out when no subscriber matches the publisher.
+ *
+ * @param this_: The publisher.
+ * @param timeout_ms: The maximum time to wait in milliseconds.
+ * @return 0 if the publisher has matching subscribers, `Z_EUNAVAILABLE` if it still has none once the timeout elapsed,
+ * other negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_config_insert_json5(struct z_loaned_config_t *this_,
-                                  const char *key,
-                                  const char *value);
+z_result_t zc_publisher_wait_matching(const struct z_loaned_publisher_t *this_,
+                                      uint64_t timeout_ms);
+#endif
 /**
- * Inserts a JSON-serialized `value` at the `key` position of the configuration.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Declares a matching listener, registering a callback for notifying queryables matching the given querier key expression and target.
+ * The callback will be run in the background until the corresponding querier is dropped.
  *
- * Returns 0 if successful, a negative error code otherwise.
+ * @param querier: A querier to associate with matching listener.
+ * @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
+#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-z_result_t zc_config_insert_json5_from_substr(struct z_loaned_config_t *this_,
-                                              const char *key,
-                                              size_t key_len,
-                                              const char *value,
-                                              size_t value_len);
+z_result_t zc_querier_declare_background_matching_listener(const struct z_loaned_querier_t *querier,
+                                                           struct zc_moved_closure_matching_status_t *callback);
+#endif
 /**
- * Constructs a json string representation of the `config`, such as '{"mode":"client","connect":{"endpoints":["tcp/127.0.0.1:7447"]}}'.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs matching listener, registering a callback for notifying queryables matching with a given querier's key expression and target.
  *
- * Returns 0 in case of success, negative error code otherwise.
+ * @param querier: A querier to associate with matching listener.
+ * @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the querier is dropped.
+ * @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
+#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-z_result_t zc_config_to_string(const struct z_loaned_config_t *config,
-                               struct z_owned_string_t *out_config_string);
+z_result_t zc_querier_declare_matching_listener(const struct z_loaned_querier_t *querier,
+                                                struct zc_owned_matching_listener_t *matching_listener,
+                                                struct zc_moved_closure_matching_status_t *callback);
+#endif
 /**
- * Initializes the zenoh runtime logger, using rust environment settings or the provided fallback level.
- * E.g.: `RUST_LOG=info` will enable logging at info level. Similarly, you can set the variable to `error` or `debug`.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets querier matching status - i.e. if there are any queryables matching its key expression and target.
  *
- * Note that if the environment variable is not set, then fallback filter will be used instead.
- * See https://docs.rs/env_logger/latest/env_logger/index.html for accepted filter format.
+ * @return 0 in case of success, negative error code otherwise (in this case matching_status is not updated).
+ */
+#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+z_result_t zc_querier_get_matching_status(const struct z_loaned_querier_t *this_,
+                                          struct zc_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Replies to a query with the entries enumerated by a user-provided iterator, then finalizes the query.
  *
- * @param fallback_filter: The fallback filter if the `RUST_LOG` environment variable is not set.
+ * `next` is called repeatedly until it returns ``false``. One reply is sent per entry whose key expression intersects the query key expression,
+ * the other entries (including the ones whose key expression was left in its gravestone state) are skipped and counted in `skipped`.
+ * This simplifies the storages answering queries on wildcard key expressions, which typically enumerate many keys.
+ *
+ * The query is consumed, so that it is finalized once all entries have been sent, unless other copies of it are still alive.
+ *
+ * @param query: The query to reply to. Will be consumed.
+ * @param next: The closure constructing the key expression and the payload of the next entry. Will be consumed and dropped
+ * once all entries have been enumerated.
+ * @param replied: If not NULL, will be set to the number of replies sent.
+ * @param skipped: If not NULL, will be set to the number of entries skipped for not matching the query key expression.
+ *
+ * @return 0 in case of success, `Z_EINVAL` if `query` or `next` is NULL or in its gravestone state, negative error code otherwise.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_init_log_from_env_or(const char *fallback_filter);
+z_result_t zc_query_reply_entries(struct z_moved_query_t *query,
+                                  struct zc_moved_closure_reply_entry_t *next,
+                                  size_t *replied,
+                                  size_t *skipped);
+#endif
 /**
- * Initializes the zenoh runtime logger with custom callback.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the time range of a query from the `_time` parameter of its selector.
  *
- * @param min_severity: Minimum severity level of log message to be be passed to the `callback`.
- * Messages with lower severity levels will be ignored.
- * @param callback: A closure that will be called with each log message severity level and content.
+ * Time expressions relative to the current time, such as `now(-1h)`, are resolved when calling this function.
+ *
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if the query has no `_time` parameter, `Z_EPARSE` if it is malformed
+ * (`dst` is not updated in both cases).
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_init_log_with_callback(enum zc_log_severity_t min_severity,
-                               struct zc_moved_closure_log_t *callback);
+z_result_t zc_query_time_range(const struct z_loaned_query_t *this_,
+                               struct zc_time_range_t *dst);
+#endif
 /**
- * Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a Queryable serving the values of a user-provided key-value table.
+ *
+ * The queryable is declared on `prefix` joined with `**`. For each received query, the `list` closure is called to enumerate the keys of the table,
+ * relative to `prefix`, and `get` is called for each key whose full key expression `prefix/key` intersects the query key expression.
+ * The found values are sent as replies, and the query is finalized once all keys have been processed.
+ *
+ * @param session: A Zenoh session.
+ * @param queryable: An uninitialized memory location where queryable will be constructed.
+ * @param prefix: The key expression prefix of the table keys.
+ * @param get: The closure looking up the value of a key.
+ * @param list: The closure pushing all the keys of the table into the provided string array.
+ *
+ * Both closures are dropped once the queryable is undeclared, or immediately if the declaration fails.
+ * They may be called concurrently.
+ *
+ * @return 0 in case of success, negative error code otherwise.
  */
-ZENOHC_API bool zc_internal_closure_log_check(const struct zc_owned_closure_log_t *this_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_queryable_serve_table(const struct z_loaned_session_t *session,
+                                    struct z_owned_queryable_t *queryable,
+                                    const struct z_loaned_keyexpr_t *prefix,
+                                    struct zc_moved_closure_table_get_t *get,
+                                    struct zc_moved_closure_table_list_t *list);
+#endif
 /**
- * Constructs a closure in a gravestone state.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the statistics of the queries handled by the queryable.
+ *
+ * The replies are counted whenever they are sent, including from the queries cloned out of the callback or received
+ * through a channel, while the handling time only covers the time spent in the queryable callback.
+ * All statistics are 0 for the queryables not declared with `z_declare_queryable()`.
  */
-ZENOHC_API void zc_internal_closure_log_null(struct zc_owned_closure_log_t *this_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct zc_queryable_stats_t zc_queryable_stats(const struct z_loaned_queryable_t *queryable);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ * @brief Returns the default value of #zc_reply_keyexpr_t.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-bool zc_internal_closure_matching_status_check(const struct zc_owned_closure_matching_status_t *this_);
+enum zc_reply_keyexpr_t zc_reply_keyexpr_default(void);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs a null value of 'zc_owned_closure_matching_status_t' type
+ * @brief Stops the gateway and resets it to its gravestone state.
+ *
+ * The requests being served when the gateway is dropped are completed in the background.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API void zc_rest_gateway_drop(struct zc_moved_rest_gateway_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows the gateway.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+const struct zc_loaned_rest_gateway_t *zc_rest_gateway_loan(const struct zc_owned_rest_gateway_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the port the gateway listens on.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API uint16_t zc_rest_gateway_port(const struct zc_loaned_rest_gateway_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Starts a local HTTP gateway to the session, for debugging or interoperability purposes.
+ *
+ * The gateway listens on `127.0.0.1:<port>`, and maps the HTTP requests on the `/<key_expr>[?<parameters>]` paths to:
+ *   - `GET`: a `z_get()` on the selector, replying with a JSON array of the received replies (with `key`, `value`,
+ *     `encoding` and `timestamp` fields for valid replies, and `error` and `encoding` fields for error replies).
+ *   - `PUT`: a `z_put()` of the request body on the key expression, with the request content type as encoding.
+ *   - `DELETE`: a `z_delete()` on the key expression.
+ *
+ * The key expression of the path is percent-decoded, and must be a valid key expression. The names and values of the
+ * `;` or `&` separated query-string parameters are percent-decoded, and must not contain `;`, nor `=` for the names.
+ * Requests whose `Host` header is not `127.0.0.1:<port>` or `localhost:<port>` are rejected with `403 Forbidden`,
+ * so that web pages can't reach the gateway through DNS rebinding.
+ * Requests are served by a pool of 8 threads until the gateway is dropped, and rejected with `503 Service Unavailable`
+ * when more than 64 are waiting for a thread. Request bodies larger than 16 MiB are rejected with `413 Payload Too Large`,
+ * and connections stalled for more than 10 seconds are closed.
+ *
+ * @param session: The zenoh session.
+ * @param gateway: An uninitialized location in memory where the gateway will be constructed.
+ * @param port: The port to listen on, an available port is picked if 0 (see `zc_rest_gateway_port()`).
+ * @return 0 in case of success, `Z_EIO` if the port can't be listened on, negative error code otherwise.
+ */
+#if (defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+z_result_t zc_rest_gateway_start(const struct z_loaned_session_t *session,
+                                 struct zc_owned_rest_gateway_t *gateway,
+                                 uint16_t port);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the ring channel.
+ *
+ * The callback end can be passed to any function declaring a matching listener,
+ * the most recent matching status changes can then be polled from the handler end.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_internal_closure_matching_status_null(struct zc_owned_closure_matching_status_t *this_);
+void zc_ring_channel_matching_status_new(struct zc_owned_closure_matching_status_t *callback,
+                                         struct zc_owned_ring_handler_matching_status_t *handler,
+                                         size_t capacity);
 #endif
 /**
- * @brief Returns ``true`` if concurrent close handle is valid, ``false`` if it is in gravestone state.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_ring_handler_matching_status_drop(struct zc_moved_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct zc_loaned_ring_handler_matching_status_t *zc_ring_handler_matching_status_loan(const struct zc_owned_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the ring buffer. If there are no more pending statuses will block until next status is received, or until
+ * the channel is dropped (normally when the matching listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (`matching_status` is not updated in this case).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_ring_handler_matching_status_recv(const struct zc_loaned_ring_handler_matching_status_t *this_,
+                                                struct zc_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the ring buffer. If there are no more pending statuses will return immediately.
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped,
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (`matching_status` is not updated in both cases).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_ring_handler_matching_status_try_recv(const struct zc_loaned_ring_handler_matching_status_t *this_,
+                                                    struct zc_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Checks the local publication datapath of the session.
+ *
+ * Declares a throwaway publisher and subscriber pair on a unique key expression (in the `@selftest/<zid>/**`
+ * verbatim space, so that no other subscriber receives it), publishes a message and waits for the subscriber
+ * to receive it. The publisher and subscriber are undeclared before returning.
+ *
+ * The message is delivered within the session, and never sent on the network: this checks that the session is
+ * open and delivers the publications to its callbacks, but not the connectivity to the remote nodes, and the
+ * latency does not include any network transfer.
+ *
+ * @param session: The zenoh session.
+ * @param timeout_ms: The maximum time to wait for the message in milliseconds.
+ * @param latency_us: If not NULL, set to the time elapsed between the publication and the reception of the message
+ *     in microseconds in case of success.
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if the message is not received before the timeout,
+ * `Z_ESESSION_CLOSED` if the session is closed, other negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_selftest_pubsub_loopback(const struct z_loaned_session_t *session,
+                                       uint64_t timeout_ms,
+                                       uint64_t *latency_us);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Executes on the calling thread the pending callbacks of a session opened in deferred dispatch mode
+ * (see `deferred_callbacks` field of `z_open_options_t`).
+ *
+ * Waits up to `timeout_ms` milliseconds for a callback to become pending if there are none, then executes
+ * the pending callbacks in the order they were triggered.
+ *
+ * Channel handlers are deferred as well, since they can not be told apart from other callbacks:
+ * their samples, queries or replies are only sent to the channel when this function executes the corresponding callbacks,
+ * so calling the blocking `z_recv()` on the thread draining the callbacks before draining them never returns.
+ * Use `z_try_recv()` after this function, or drain the callbacks on another thread.
+ *
+ * @param session: The zenoh session.
+ * @param max_items: The maximum number of callbacks to execute, 0 means all pending callbacks.
+ * @param timeout_ms: The maximum time to wait for a pending callback in milliseconds, 0 means no waiting.
+ * @return The number of executed callbacks, always 0 if the session is not in deferred dispatch mode.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t zc_session_drain_callbacks(const struct z_loaned_session_t *session,
+                                  size_t max_items,
+                                  uint64_t timeout_ms);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the numbers of incoming messages dropped by zenoh-c itself since the session was opened.
+ *
+ * These counters allow to tell the messages lost by the application side (full buffers, panics, undecodable
+ * messages) from the ones lost on the network.
+ *
+ * @param session: The zenoh session.
+ * @param counters: An uninitialized location in memory where the counters will be written.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_session_drop_counters(const struct z_loaned_session_t *session,
+                              struct zc_session_drop_counters_t *counters);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the number of publishers, subscribers, queryables and queriers currently declared on the session.
+ *
+ * Entities are counted from their declaration until they are undeclared or dropped, background entities
+ * are counted until the session is closed or dropped.
+ * The corresponding limits can be set with `z_open_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_session_get_entity_counts(const struct z_loaned_session_t *session,
+                                  struct zc_session_entity_counts_t *counts);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Loads and starts a plugin (e.g. `rest` or `storage_manager`) in the session, as zenohd would do.
+ *
+ * The plugin is loaded from the `zenoh_plugin_<name>` dynamic library, looked up in the `plugins_search_dirs`
+ * of the session configuration, which must also have `plugins_loading/enabled` set to `true`.
+ * The plugin is started asynchronously, and is stopped when the session is closed.
+ *
+ * @param session: The zenoh session.
+ * @param name: The name of the plugin.
+ * @param config_json: The JSON5-serialized configuration of the plugin, `"{}"` if NULL.
+ *
+ * @return 0 in case of success, `Z_EINVAL` if the name or the configuration are invalid, negative error code otherwise.
  */
-#if defined(Z_FEATURE_UNSTABLE_API)
+#if (defined(Z_FEATURE_PLUGINS) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-bool zc_internal_concurrent_close_handle_check(const struct zc_owned_concurrent_close_handle_t *this_);
+z_result_t zc_session_load_plugin(const struct z_loaned_session_t *session,
+                                  const char *name,
+                                  const char *config_json);
 #endif
 /**
- * @brief Constructs concurrent close handle in its gravestone state.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sets the interceptor called with the key expression of each entity declared on the session.
+ *
+ * It is called for the publishers, subscribers, queryables and queriers, including the advanced, liveliness and querying
+ * ones, and for the key expressions of the streams, stream listeners and watches. The key expressions of the accepted
+ * streams are derived from the one of the opening end, so they can be rejected but not rewritten.
+ *
+ * The interceptor can rewrite the key expression (e.g. to add a tenant prefix) or reject the declaration, in which case
+ * the declaring function fails with the error code returned by the interceptor (usually `Z_EDENIED`).
+ * It replaces the previously set interceptor, if any. Passing NULL or a closure in its gravestone state removes the interceptor.
+ *
+ * @param session: The zenoh session.
+ * @param interceptor: The interceptor closure.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_internal_concurrent_close_handle_null(struct zc_owned_concurrent_close_handle_t *this_);
+void zc_session_set_keyexpr_interceptor(const struct z_loaned_session_t *session,
+                                        struct zc_moved_closure_keyexpr_interceptor_t *interceptor);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Checks the matching listener is for the gravestone state
+ * @brief Add client to the list.
+ * @return 0 in case of success, `Z_EINVAL` if `client` is NULL or in its gravestone state.
  */
-#if defined(Z_FEATURE_UNSTABLE_API)
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-bool zc_internal_matching_listener_check(const struct zc_owned_matching_listener_t *this_);
+z_result_t zc_shm_client_list_add_client(struct zc_loaned_shm_client_list_t *this_,
+                                         z_protocol_id_t id,
+                                         struct z_moved_shm_client_t *client);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs an empty matching listener.
+ * @brief Deletes list of SHM Clients.
  */
-#if defined(Z_FEATURE_UNSTABLE_API)
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-void zc_internal_matching_listener_null(struct zc_owned_matching_listener_t *this_);
+void zc_shm_client_list_drop(struct zc_moved_shm_client_list_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Returns ``true`` if `this` is valid.
+ * @brief Borrows list of SHM Clients.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-bool zc_internal_shm_client_list_check(const struct zc_owned_shm_client_list_t *this_);
+const struct zc_loaned_shm_client_list_t *zc_shm_client_list_loan(const struct zc_owned_shm_client_list_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs SHM client list in its gravestone value.
+ * @brief Mutably borrows list of SHM Clients.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-void zc_internal_shm_client_list_null(struct zc_owned_shm_client_list_t *this_);
+struct zc_loaned_shm_client_list_t *zc_shm_client_list_loan_mut(struct zc_owned_shm_client_list_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Declares a background subscriber on liveliness tokens that intersect `key_expr`. Subscriber callback will be called to process the messages,
- * until the corresponding session is closed or dropped.
- * @param session: The Zenoh session.
- * @param key_expr: The key expression to subscribe to.
- * @param callback: The callback function that will be called each time a liveliness token status is changed.
- * @param options: The options to be passed to the liveliness subscriber declaration.
- *
- * @return 0 in case of success, negative error values otherwise.
+ * @brief Creates a new empty list of SHM Clients.
  */
-#if defined(Z_FEATURE_UNSTABLE_API)
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-z_result_t zc_liveliness_declare_background_subscriber(const struct z_loaned_session_t *session,
-                                                       const struct z_loaned_keyexpr_t *key_expr,
-                                                       struct z_moved_closure_sample_t *callback,
-                                                       struct z_liveliness_subscriber_options_t *options);
+void zc_shm_client_list_new(struct zc_owned_shm_client_list_t *this_);
 #endif
 /**
- * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Returns default value of `zc_locality_t`
+ * Stops all Zenoh tasks and drops all related static variables.
+ * All Zenoh-related structures should be properly dropped/undeclared PRIOR to this call.
+ * None of Zenoh functionality can be used after this call.
+ * Useful to suppress memory leaks messages due to Zenoh static variables (since they are never destroyed due to Rust language design).
  */
-#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-enum zc_locality_t zc_locality_default(void);
-#endif
+void zc_stop_z_runtime(void);
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Undeclares the given matching listener, droping and invalidating it.
+ * @brief Accepts a stream opened on the key expression of the listener.
+ *
+ * Blocks until a stream is opened or the timeout expires, the streams opened before being accepted in order.
+ * The streams whose opening end stopped waiting for them to be accepted are dropped.
+ *
+ * @param listener: The stream listener.
+ * @param stream: An uninitialized memory location where the accepted stream will be constructed.
+ * @param timeout_ms: The maximum time to wait for a stream to be opened in milliseconds, 0 means no timeout.
+ *
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if no stream was opened before the timeout, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_matching_listener_drop(struct zc_moved_matching_listener_t *this_);
+z_result_t zc_stream_accept(const struct zc_loaned_stream_listener_t *listener,
+                            struct zc_owned_stream_t *stream,
+                            uint64_t timeout_ms);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Declares a matching listener, registering a callback for notifying subscribers matching with a given publisher.
- * The callback will be run in the background until the corresponding publisher is dropped.
- *
- * @param publisher: A publisher to associate with matching listener.
- * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
+ * @brief Closes the stream and resets it to its gravestone state.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * The peer reads the data written before the stream was closed, then gets `Z_CHANNEL_DISCONNECTED`.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-ZENOHC_API
-z_result_t zc_publisher_declare_background_matching_listener(const struct z_loaned_publisher_t *publisher,
-                                                             struct zc_moved_closure_matching_status_t *callback);
+ZENOHC_API void zc_stream_drop(struct zc_moved_stream_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs matching listener, registering a callback for notifying subscribers matching with a given publisher.
- *
- * @param publisher: A publisher to associate with matching listener.
- * @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the publisher is dropped.
- * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
+ * @brief Undeclares the stream listener and resets it to its gravestone state.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * The streams opened and not yet accepted are rejected, the accepted ones are unaffected.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
-ZENOHC_API
-z_result_t zc_publisher_declare_matching_listener(const struct z_loaned_publisher_t *publisher,
-                                                  struct zc_owned_matching_listener_t *matching_listener,
-                                                  struct zc_moved_closure_matching_status_t *callback);
+ZENOHC_API void zc_stream_listener_drop(struct zc_moved_stream_listener_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Gets publisher matching status - i.e. if there are any subscribers matching its key expression.
- *
- * @return 0 in case of success, negative error code otherwise (in this case matching_status is not updated).
+ * @brief Borrows the stream listener.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_publisher_get_matching_status(const struct z_loaned_publisher_t *this_,
-                                            struct zc_matching_status_t *matching_status);
+const struct zc_loaned_stream_listener_t *zc_stream_listener_loan(const struct zc_owned_stream_listener_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Declares a matching listener, registering a callback for notifying queryables matching the given querier key expression and target.
- * The callback will be run in the background until the corresponding querier is dropped.
- *
- * @param querier: A querier to associate with matching listener.
- * @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
- *
- * @return 0 in case of success, negative error code otherwise.
+ * @brief Borrows the stream.
  */
-#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
-ZENOHC_API
-z_result_t zc_querier_declare_background_matching_listener(const struct z_loaned_querier_t *querier,
-                                                           struct zc_moved_closure_matching_status_t *callback);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API const struct zc_loaned_stream_t *zc_stream_loan(const struct zc_owned_stream_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs matching listener, registering a callback for notifying queryables matching with a given querier's key expression and target.
+ * @brief Opens a stream to the stream listener declared on the key expression.
  *
- * @param querier: A querier to associate with matching listener.
- * @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the querier is dropped.
- * @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
+ * A stream provides an ordered and flow-controlled byte stream between two applications, on top of publications and queries.
+ * The written data is split into sequence-numbered messages, and each end acknowledges the messages it has read, so that
+ * no more than `window` messages are ever waiting to be read by the peer.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * The messages are published reliably and never retransmitted by the stream itself: a lost message, a message received
+ * beyond the window, or data not read by the peer within `write_timeout_ms` fails the stream, in which case
+ * `zc_stream_read()` and `zc_stream_write()` return `Z_EIO`. Streams should not be used over lossy links.
+ *
+ * @param session: The zenoh session.
+ * @param stream: An uninitialized memory location where the stream will be constructed.
+ * @param key_expr: The key expression the stream listener is declared on.
+ * @param options: The stream options, default options are used if NULL.
+ *
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if the stream was not accepted before the timeout, negative error code otherwise.
  */
-#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_querier_declare_matching_listener(const struct z_loaned_querier_t *querier,
-                                                struct zc_owned_matching_listener_t *matching_listener,
-                                                struct zc_moved_closure_matching_status_t *callback);
+z_result_t zc_stream_open(const struct z_loaned_session_t *session,
+                          struct zc_owned_stream_t *stream,
+                          const struct z_loaned_keyexpr_t *key_expr,
+                          const struct zc_stream_options_t *options);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Gets querier matching status - i.e. if there are any queryables matching its key expression and target.
- *
- * @return 0 in case of success, negative error code otherwise (in this case matching_status is not updated).
+ * @brief Constructs the default value for `zc_stream_options_t`.
  */
-#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
-ZENOHC_API
-z_result_t zc_querier_get_matching_status(const struct z_loaned_querier_t *this_,
-                                          struct zc_matching_status_t *matching_status);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_stream_options_default(struct zc_stream_options_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Returns the default value of #zc_reply_keyexpr_t.
+ * @brief Reads data from the stream.
+ *
+ * Blocks until some data is available, then reads up to `len` bytes of it, in the order it was written by the peer.
+ *
+ * @param this_: The stream.
+ * @param buf: The buffer to read into.
+ * @param len: The size of the buffer.
+ * @param read: Will be set to the number of bytes read.
+ *
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if the peer closed the stream and all its data was read,
+ * `Z_EIO` if the stream failed, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-enum zc_reply_keyexpr_t zc_reply_keyexpr_default(void);
+z_result_t zc_stream_read(const struct zc_loaned_stream_t *this_,
+                          uint8_t *buf,
+                          size_t len,
+                          size_t *read);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Add client to the list.
+ * @brief Writes data on the stream.
+ *
+ * Blocks until all the data is sent, i.e. until the peer has read enough data for the remaining one to fit in the window.
+ * If the peer does not read the data within the `write_timeout_ms` of the stream options, e.g. because it crashed, the
+ * stream is failed.
+ *
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if the peer closed the stream, `Z_EIO` if the stream failed,
+ * negative error code otherwise.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-z_result_t zc_shm_client_list_add_client(struct zc_loaned_shm_client_list_t *this_,
-                                         z_protocol_id_t id,
-                                         struct z_moved_shm_client_t *client);
+z_result_t zc_stream_write(const struct zc_loaned_stream_t *this_,
+                           const uint8_t *data,
+                           size_t len);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Deletes list of SHM Clients.
+ * @brief Returns the locality of the publications received by the subscriber, as set by the `allowed_origin` field
+ * of `z_subscriber_options_t` when it was declared.
+ *
+ * Subscribers have no reliability setting: it is chosen by the publishers, see `z_sample_reliability()`.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_shm_client_list_drop(struct zc_moved_shm_client_list_t *this_);
+enum zc_locality_t zc_subscriber_allowed_origin(const struct z_loaned_subscriber_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Borrows list of SHM Clients.
+ * @brief Calls the closure with each concrete key expression received by the subscriber, in an unspecified order,
+ * and the number of samples received on it, then drops the closure.
+ *
+ * This allows to discover the key expressions actually published under a wildcard subscription.
+ * See `z_subscriber_options_t.key_stats` for enabling the statistics.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, `Z_EUNAVAILABLE` if the statistics are not enabled for the subscriber.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-const struct zc_loaned_shm_client_list_t *zc_shm_client_list_loan(const struct zc_owned_shm_client_list_t *this_);
+z_result_t zc_subscriber_key_stats(const struct z_loaned_subscriber_t *this_,
+                                   struct zc_moved_closure_key_stats_t *callback);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Mutably borrows list of SHM Clients.
+ * @brief Constructs an unbounded time range.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
-ZENOHC_API
-struct zc_loaned_shm_client_list_t *zc_shm_client_list_loan_mut(struct zc_owned_shm_client_list_t *this_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API void zc_time_range_default(struct zc_time_range_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Creates a new empty list of SHM Clients.
+ * @brief Constructs the time range including the times between two timestamps.
+ *
+ * @param this_: An uninitialized location in memory where the time range will be constructed.
+ * @param start: The start of the range, the range is not bounded on this side if NULL.
+ * @param end: The end of the range, the range is not bounded on this side if NULL.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_shm_client_list_new(struct zc_owned_shm_client_list_t *this_);
+void zc_time_range_from_timestamps(struct zc_time_range_t *this_,
+                                   const struct z_timestamp_t *start,
+                                   const struct z_timestamp_t *end);
 #endif
 /**
- * Stops all Zenoh tasks and drops all related static variables.
- * All Zenoh-related structures should be properly dropped/undeclared PRIOR to this call.
- * None of Zenoh functionality can be used after this call.
- * Useful to suppress memory leaks messages due to Zenoh static variables (since they are never destroyed due to Rust language design).
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the `_time=[start..end]` selector parameter for the time range.
+ *
+ * The parameter can be passed as, or appended with a `;` separator to, the parameters of `z_get()` or `z_querier_get()`.
  */
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void zc_stop_z_runtime(void);
+void zc_time_range_to_parameter(const struct zc_time_range_t *this_,
+                                struct z_owned_string_t *dst);
+#endif
 /**
  * Initializes the zenoh runtime logger, using rust environment settings.
  * E.g.: `RUST_LOG=info` will enable logging at info level. Similarly, you can set the variable to `error` or `debug`.
@@ -5481,12 +8299,45 @@ void zc_try_init_log_from_env(void);
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Undeclares the given matching listener, droping and invalidating it.
+ *
+ * Matching listeners are undeclared along with the publisher or querier they were declared on, or when its session
+ * is closed: undeclaring them afterwards only drops them, and succeeds.
  * @return 0 in case of success, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
 z_result_t zc_undeclare_matching_listener(struct zc_moved_matching_listener_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares the watch.
+ *
+ * The callback is not called anymore once this function returns (except by the calls already in progress),
+ * but it is only dropped once the initial query, if still in progress, completes.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t zc_undeclare_watch(struct zc_moved_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares the watch and resets it to its gravestone state.
+ * This is equivalent to calling `zc_undeclare_watch()` and discarding its return value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_watch_drop(struct zc_moved_watch_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `zc_watch_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void zc_watch_options_default(struct zc_watch_options_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Constructs the default value for `ze_advanced_publisher_cache_options_t`.
@@ -5503,7 +8354,7 @@ void ze_advanced_publisher_cache_options_default(struct ze_advanced_publisher_ca
  * @param publisher: An advanced publisher to associate with matching listener.
  * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -5518,7 +8369,7 @@ z_result_t ze_advanced_publisher_declare_background_matching_listener(const stru
  * @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the publisher is dropped.
  * @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
@@ -5614,7 +8465,8 @@ void ze_advanced_publisher_options_default(struct ze_advanced_publisher_options_
  * @param payload: The data to publish. Will be consumed.
  * @param options: The advanced publisher put options. All owned fields will be consumed.
  *
- * @return 0 in case of success, negative error values in case of failure.
+ * @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, `Z_EFRAGMENT` if the publication exceeds the maximum
+ * payload size of the publisher (see `zc_publisher_max_payload_size()`), negative error values in case of failure.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5638,7 +8490,7 @@ void ze_advanced_publisher_put_options_default(struct ze_advanced_publisher_put_
  * @param subscriber: A subscriber to associate with sample miss listener.
  * @param callback: A closure that will be called every time the sample miss is detected.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5653,7 +8505,7 @@ z_result_t ze_advanced_subscriber_declare_background_sample_miss_listener(const
  * @param sample_miss_listener: An uninitialized memory location where sample miss listener will be constructed. The sample miss listener's callback will be automatically dropped when the subscriber is dropped.
  * @param callback: A closure that will be called every time the sample miss is detected.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5670,7 +8522,7 @@ z_result_t ze_advanced_subscriber_declare_sample_miss_listener(const struct ze_l
  * @param callback: The callback function that will be called each time a liveliness token status is changed.
  * @param options: The options to be passed to the liveliness subscriber declaration.
  *
- * @return 0 in case of success, negative error values otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5687,7 +8539,7 @@ z_result_t ze_advanced_subscriber_detect_publishers(const struct ze_loaned_advan
  * @param callback: The callback function that will be called each time a liveliness token status is changed.
  * @param options: The options to be passed to the liveliness subscriber declaration.
  *
- * @return 0 in case of success, negative error values otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5750,6 +8602,52 @@ void ze_advanced_subscriber_options_default(struct ze_advanced_subscriber_option
 ZENOHC_API
 void ze_advanced_subscriber_recovery_options_default(struct ze_advanced_subscriber_recovery_options_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs closure.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_closure_history_end(struct ze_owned_closure_history_end_t *this_,
+                            void (*call)(void *context),
+                            void (*drop)(void *context),
+                            void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_closure_history_end_call(const struct ze_loaned_closure_history_end_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_closure_history_end_drop(struct ze_moved_closure_history_end_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct ze_loaned_closure_history_end_t *ze_closure_history_end_loan(const struct ze_owned_closure_history_end_t *closure);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  *
@@ -5827,7 +8725,7 @@ z_result_t ze_declare_advanced_publisher(const struct z_loaned_session_t *sessio
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: The options to be passed to the subscriber declaration.
  *
- * @return 0 in case of success, negative error code otherwise (in this case subscriber will be in its gravestone state).
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case subscriber will be in its gravestone state).
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5846,7 +8744,7 @@ z_result_t ze_declare_advanced_subscriber(const struct z_loaned_session_t *sessi
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: The options to be passed to the subscriber declaration.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5881,7 +8779,7 @@ z_result_t ze_declare_background_publication_cache(const struct z_loaned_session
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: Additional options for the querying subscriber.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5912,13 +8810,18 @@ z_result_t ze_declare_publication_cache(const struct z_loaned_session_t *session
  * @warning This API is deprecated. Please use ze_advanced_subscriber.
  * @brief Constructs and declares a querying subscriber for a given key expression.
  *
+ * Samples received as replies to the initial query and live publications are delivered through the same callback.
+ * While the query is in progress, both are merged and delivered in timestamp order once it completes.
+ * Passing the closure of a FIFO or ring channel thus yields a single ordered stream that can be consumed with `z_recv()`.
+ * The optional `history_end` closure of the options is called once the samples of the initial query have been delivered.
+ *
  * @param session: A Zenoh session.
  * @param querying_subscriber: An uninitialized memory location where querying subscriber will be constructed.
  * @param key_expr: A key expression to subscribe to.
  * @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
  * @param options: Additional options for the querying subscriber.
  *
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
@@ -5930,69 +8833,71 @@ z_result_t ze_declare_querying_subscriber(const struct z_loaned_session_t *sessi
 #endif
 /**
  * @brief Deserializes into a bool.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_bool(const struct z_loaned_bytes_t *this_, bool *dst);
 /**
- * @brief Deserializes into a signed integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @brief Deserializes into a double.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_double(const struct z_loaned_bytes_t *this_, double *dst);
 /**
  * @brief Deserializes into a float.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_float(const struct z_loaned_bytes_t *this_, float *dst);
 /**
  * @brief Deserializes into a signed integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_int16(const struct z_loaned_bytes_t *this_, int16_t *dst);
 /**
  * @brief Deserializes into a signed integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_int32(const struct z_loaned_bytes_t *this_, int32_t *dst);
 /**
  * @brief Deserializes into a signed integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_int64(const struct z_loaned_bytes_t *this_, int64_t *dst);
 /**
  * @brief Deserializes into a signed integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_int8(const struct z_loaned_bytes_t *this_, int8_t *dst);
 /**
  * @brief Deserializes into a slice.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API
 z_result_t ze_deserialize_slice(const struct z_loaned_bytes_t *this_,
                                 struct z_owned_slice_t *slice);
 /**
  * @brief Deserializes into a UTF-8 string.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API
 z_result_t ze_deserialize_string(const struct z_loaned_bytes_t *this_,
                                  struct z_owned_string_t *str);
 /**
  * @brief Deserializes into an unsigned integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_uint16(const struct z_loaned_bytes_t *this_, uint16_t *dst);
 /**
  * @brief Deserializes into an unsigned integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_uint32(const struct z_loaned_bytes_t *this_, uint32_t *dst);
 /**
  * @brief Deserializes into an unsigned integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_uint64(const struct z_loaned_bytes_t *this_, uint64_t *dst);
 /**
  * @brief Deserializes into an unsigned integer.
- * @return 0 in case of success, negative error code otherwise.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API z_result_t ze_deserialize_uint8(const struct z_loaned_bytes_t *this_, uint8_t *dst);
 /**
@@ -6053,6 +8958,7 @@ z_result_t ze_deserializer_deserialize_sequence_length(struct ze_deserializer_t
                                                        size_t *len);
 /**
  * @brief Deserializes into a slice.
+ * @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
  */
 ZENOHC_API
 z_result_t ze_deserializer_deserialize_slice(struct ze_deserializer_t *this_,
@@ -6136,6 +9042,22 @@ ZENOHC_API void ze_internal_advanced_subscriber_null(struct ze_owned_advanced_su
  */
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
+bool ze_internal_closure_history_end_check(const struct ze_owned_closure_history_end_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'ze_owned_closure_history_end_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_internal_closure_history_end_null(struct ze_owned_closure_history_end_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
 bool ze_internal_closure_miss_check(const struct ze_owned_closure_miss_t *this_);
 #endif
 /**
@@ -6375,7 +9297,8 @@ ZENOHC_API z_result_t ze_serializer_empty(struct ze_owned_serializer_t *this_);
 /**
  * @brief Drop serializer and extract underlying `bytes` object it was writing to.
  * @param this_: A serializer instance.
- * @param bytes: An uninitialized memory location where `bytes` object` will be written to.
+ * @param bytes: An uninitialized memory location where `bytes` object` will be written to. If `this_` is NULL or in its
+ * gravestone state, empty bytes are written.
  */
 ZENOHC_API
 void ze_serializer_finish(struct ze_moved_serializer_t *this_,
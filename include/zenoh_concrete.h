@@ -24,6 +24,7 @@
 #define DEFAULT_SCOUTING_TIMEOUT 1000
 #define Z_CHANNEL_DISCONNECTED 1
 #define Z_CHANNEL_NODATA 2
+#define Z_QUERY_TIMED_OUT 3
 #define Z_OK 0
 #define Z_EINVAL -1
 #define Z_EPARSE -2
@@ -38,4 +39,7 @@
 #define Z_EINVAL_MUTEX -22
 #define Z_EAGAIN_MUTEX -11
 #define Z_EPOISON_MUTEX -22
+#define Z_ELIMIT -100
+#define Z_EDENIED -101
+#define Z_EFRAGMENT -102
 #define Z_EGENERIC INT8_MIN
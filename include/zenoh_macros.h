@@ -7,6 +7,7 @@
 static inline z_moved_alloc_layout_t* z_alloc_layout_move(z_owned_alloc_layout_t* x) { return (z_moved_alloc_layout_t*)(x); }
 static inline z_moved_bytes_t* z_bytes_move(z_owned_bytes_t* x) { return (z_moved_bytes_t*)(x); }
 static inline z_moved_bytes_writer_t* z_bytes_writer_move(z_owned_bytes_writer_t* x) { return (z_moved_bytes_writer_t*)(x); }
+static inline z_moved_cancellation_token_t* z_cancellation_token_move(z_owned_cancellation_token_t* x) { return (z_moved_cancellation_token_t*)(x); }
 static inline z_moved_chunk_alloc_result_t* z_chunk_alloc_result_move(z_owned_chunk_alloc_result_t* x) { return (z_moved_chunk_alloc_result_t*)(x); }
 static inline z_moved_closure_hello_t* z_closure_hello_move(z_owned_closure_hello_t* x) { return (z_moved_closure_hello_t*)(x); }
 static inline z_moved_closure_query_t* z_closure_query_move(z_owned_closure_query_t* x) { return (z_moved_closure_query_t*)(x); }
@@ -46,13 +47,31 @@ static inline z_moved_string_array_t* z_string_array_move(z_owned_string_array_t
 static inline z_moved_string_t* z_string_move(z_owned_string_t* x) { return (z_moved_string_t*)(x); }
 static inline z_moved_subscriber_t* z_subscriber_move(z_owned_subscriber_t* x) { return (z_moved_subscriber_t*)(x); }
 static inline z_moved_task_t* z_task_move(z_owned_task_t* x) { return (z_moved_task_t*)(x); }
+static inline zc_moved_closure_key_stats_t* zc_closure_key_stats_move(zc_owned_closure_key_stats_t* x) { return (zc_moved_closure_key_stats_t*)(x); }
+static inline zc_moved_closure_keyexpr_interceptor_t* zc_closure_keyexpr_interceptor_move(zc_owned_closure_keyexpr_interceptor_t* x) { return (zc_moved_closure_keyexpr_interceptor_t*)(x); }
 static inline zc_moved_closure_log_t* zc_closure_log_move(zc_owned_closure_log_t* x) { return (zc_moved_closure_log_t*)(x); }
+static inline zc_moved_closure_log_record_t* zc_closure_log_record_move(zc_owned_closure_log_record_t* x) { return (zc_moved_closure_log_record_t*)(x); }
 static inline zc_moved_closure_matching_status_t* zc_closure_matching_status_move(zc_owned_closure_matching_status_t* x) { return (zc_moved_closure_matching_status_t*)(x); }
+static inline zc_moved_closure_metrics_t* zc_closure_metrics_move(zc_owned_closure_metrics_t* x) { return (zc_moved_closure_metrics_t*)(x); }
+static inline zc_moved_closure_payload_transform_t* zc_closure_payload_transform_move(zc_owned_closure_payload_transform_t* x) { return (zc_moved_closure_payload_transform_t*)(x); }
+static inline zc_moved_closure_query_diagnostics_t* zc_closure_query_diagnostics_move(zc_owned_closure_query_diagnostics_t* x) { return (zc_moved_closure_query_diagnostics_t*)(x); }
+static inline zc_moved_closure_reply_entry_t* zc_closure_reply_entry_move(zc_owned_closure_reply_entry_t* x) { return (zc_moved_closure_reply_entry_t*)(x); }
+static inline zc_moved_closure_table_get_t* zc_closure_table_get_move(zc_owned_closure_table_get_t* x) { return (zc_moved_closure_table_get_t*)(x); }
+static inline zc_moved_closure_table_list_t* zc_closure_table_list_move(zc_owned_closure_table_list_t* x) { return (zc_moved_closure_table_list_t*)(x); }
+static inline zc_moved_closure_watch_t* zc_closure_watch_move(zc_owned_closure_watch_t* x) { return (zc_moved_closure_watch_t*)(x); }
+static inline zc_moved_closure_watermark_t* zc_closure_watermark_move(zc_owned_closure_watermark_t* x) { return (zc_moved_closure_watermark_t*)(x); }
 static inline zc_moved_concurrent_close_handle_t* zc_concurrent_close_handle_move(zc_owned_concurrent_close_handle_t* x) { return (zc_moved_concurrent_close_handle_t*)(x); }
+static inline zc_moved_fifo_handler_matching_status_t* zc_fifo_handler_matching_status_move(zc_owned_fifo_handler_matching_status_t* x) { return (zc_moved_fifo_handler_matching_status_t*)(x); }
 static inline zc_moved_matching_listener_t* zc_matching_listener_move(zc_owned_matching_listener_t* x) { return (zc_moved_matching_listener_t*)(x); }
+static inline zc_moved_metrics_subscriber_t* zc_metrics_subscriber_move(zc_owned_metrics_subscriber_t* x) { return (zc_moved_metrics_subscriber_t*)(x); }
+static inline zc_moved_ring_handler_matching_status_t* zc_ring_handler_matching_status_move(zc_owned_ring_handler_matching_status_t* x) { return (zc_moved_ring_handler_matching_status_t*)(x); }
 static inline zc_moved_shm_client_list_t* zc_shm_client_list_move(zc_owned_shm_client_list_t* x) { return (zc_moved_shm_client_list_t*)(x); }
+static inline zc_moved_stream_t* zc_stream_move(zc_owned_stream_t* x) { return (zc_moved_stream_t*)(x); }
+static inline zc_moved_stream_listener_t* zc_stream_listener_move(zc_owned_stream_listener_t* x) { return (zc_moved_stream_listener_t*)(x); }
+static inline zc_moved_watch_t* zc_watch_move(zc_owned_watch_t* x) { return (zc_moved_watch_t*)(x); }
 static inline ze_moved_advanced_publisher_t* ze_advanced_publisher_move(ze_owned_advanced_publisher_t* x) { return (ze_moved_advanced_publisher_t*)(x); }
 static inline ze_moved_advanced_subscriber_t* ze_advanced_subscriber_move(ze_owned_advanced_subscriber_t* x) { return (ze_moved_advanced_subscriber_t*)(x); }
+static inline ze_moved_closure_history_end_t* ze_closure_history_end_move(ze_owned_closure_history_end_t* x) { return (ze_moved_closure_history_end_t*)(x); }
 static inline ze_moved_closure_miss_t* ze_closure_miss_move(ze_owned_closure_miss_t* x) { return (ze_moved_closure_miss_t*)(x); }
 static inline ze_moved_publication_cache_t* ze_publication_cache_move(ze_owned_publication_cache_t* x) { return (ze_moved_publication_cache_t*)(x); }
 static inline ze_moved_querying_subscriber_t* ze_querying_subscriber_move(ze_owned_querying_subscriber_t* x) { return (ze_moved_querying_subscriber_t*)(x); }
@@ -65,6 +84,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_alloc_layout_t : z_alloc_layout_loan, \
         z_owned_bytes_t : z_bytes_loan, \
         z_owned_bytes_writer_t : z_bytes_writer_loan, \
+        z_owned_cancellation_token_t : z_cancellation_token_loan, \
         z_owned_closure_hello_t : z_closure_hello_loan, \
         z_owned_closure_query_t : z_closure_query_loan, \
         z_owned_closure_reply_t : z_closure_reply_loan, \
@@ -103,11 +123,27 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_view_keyexpr_t : z_view_keyexpr_loan, \
         z_view_slice_t : z_view_slice_loan, \
         z_view_string_t : z_view_string_loan, \
+        zc_owned_closure_key_stats_t : zc_closure_key_stats_loan, \
+        zc_owned_closure_keyexpr_interceptor_t : zc_closure_keyexpr_interceptor_loan, \
         zc_owned_closure_log_t : zc_closure_log_loan, \
+        zc_owned_closure_log_record_t : zc_closure_log_record_loan, \
         zc_owned_closure_matching_status_t : zc_closure_matching_status_loan, \
+        zc_owned_closure_metrics_t : zc_closure_metrics_loan, \
+        zc_owned_closure_payload_transform_t : zc_closure_payload_transform_loan, \
+        zc_owned_closure_query_diagnostics_t : zc_closure_query_diagnostics_loan, \
+        zc_owned_closure_reply_entry_t : zc_closure_reply_entry_loan, \
+        zc_owned_closure_table_get_t : zc_closure_table_get_loan, \
+        zc_owned_closure_table_list_t : zc_closure_table_list_loan, \
+        zc_owned_closure_watch_t : zc_closure_watch_loan, \
+        zc_owned_closure_watermark_t : zc_closure_watermark_loan, \
+        zc_owned_fifo_handler_matching_status_t : zc_fifo_handler_matching_status_loan, \
+        zc_owned_ring_handler_matching_status_t : zc_ring_handler_matching_status_loan, \
         zc_owned_shm_client_list_t : zc_shm_client_list_loan, \
+        zc_owned_stream_listener_t : zc_stream_listener_loan, \
+        zc_owned_stream_t : zc_stream_loan, \
         ze_owned_advanced_publisher_t : ze_advanced_publisher_loan, \
         ze_owned_advanced_subscriber_t : ze_advanced_subscriber_loan, \
+        ze_owned_closure_history_end_t : ze_closure_history_end_loan, \
         ze_owned_closure_miss_t : ze_closure_miss_loan, \
         ze_owned_publication_cache_t : ze_publication_cache_loan, \
         ze_owned_querying_subscriber_t : ze_querying_subscriber_loan, \
@@ -146,6 +182,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_alloc_layout_t* : z_alloc_layout_drop, \
         z_moved_bytes_t* : z_bytes_drop, \
         z_moved_bytes_writer_t* : z_bytes_writer_drop, \
+        z_moved_cancellation_token_t* : z_cancellation_token_drop, \
         z_moved_chunk_alloc_result_t* : z_chunk_alloc_result_drop, \
         z_moved_closure_hello_t* : z_closure_hello_drop, \
         z_moved_closure_query_t* : z_closure_query_drop, \
@@ -185,13 +222,31 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_string_t* : z_string_drop, \
         z_moved_subscriber_t* : z_subscriber_drop, \
         z_moved_task_t* : z_task_drop, \
+        zc_moved_closure_key_stats_t* : zc_closure_key_stats_drop, \
+        zc_moved_closure_keyexpr_interceptor_t* : zc_closure_keyexpr_interceptor_drop, \
         zc_moved_closure_log_t* : zc_closure_log_drop, \
+        zc_moved_closure_log_record_t* : zc_closure_log_record_drop, \
         zc_moved_closure_matching_status_t* : zc_closure_matching_status_drop, \
+        zc_moved_closure_metrics_t* : zc_closure_metrics_drop, \
+        zc_moved_closure_payload_transform_t* : zc_closure_payload_transform_drop, \
+        zc_moved_closure_query_diagnostics_t* : zc_closure_query_diagnostics_drop, \
+        zc_moved_closure_reply_entry_t* : zc_closure_reply_entry_drop, \
+        zc_moved_closure_table_get_t* : zc_closure_table_get_drop, \
+        zc_moved_closure_table_list_t* : zc_closure_table_list_drop, \
+        zc_moved_closure_watch_t* : zc_closure_watch_drop, \
+        zc_moved_closure_watermark_t* : zc_closure_watermark_drop, \
         zc_moved_concurrent_close_handle_t* : zc_concurrent_close_handle_drop, \
+        zc_moved_fifo_handler_matching_status_t* : zc_fifo_handler_matching_status_drop, \
         zc_moved_matching_listener_t* : zc_matching_listener_drop, \
+        zc_moved_metrics_subscriber_t* : zc_metrics_subscriber_drop, \
+        zc_moved_ring_handler_matching_status_t* : zc_ring_handler_matching_status_drop, \
         zc_moved_shm_client_list_t* : zc_shm_client_list_drop, \
+        zc_moved_stream_t* : zc_stream_drop, \
+        zc_moved_stream_listener_t* : zc_stream_listener_drop, \
+        zc_moved_watch_t* : zc_watch_drop, \
         ze_moved_advanced_publisher_t* : ze_advanced_publisher_drop, \
         ze_moved_advanced_subscriber_t* : ze_advanced_subscriber_drop, \
+        ze_moved_closure_history_end_t* : ze_closure_history_end_drop, \
         ze_moved_closure_miss_t* : ze_closure_miss_drop, \
         ze_moved_publication_cache_t* : ze_publication_cache_drop, \
         ze_moved_querying_subscriber_t* : ze_querying_subscriber_drop, \
@@ -204,6 +259,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_alloc_layout_t : z_alloc_layout_move, \
         z_owned_bytes_t : z_bytes_move, \
         z_owned_bytes_writer_t : z_bytes_writer_move, \
+        z_owned_cancellation_token_t : z_cancellation_token_move, \
         z_owned_chunk_alloc_result_t : z_chunk_alloc_result_move, \
         z_owned_closure_hello_t : z_closure_hello_move, \
         z_owned_closure_query_t : z_closure_query_move, \
@@ -243,13 +299,31 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_string_t : z_string_move, \
         z_owned_subscriber_t : z_subscriber_move, \
         z_owned_task_t : z_task_move, \
+        zc_owned_closure_key_stats_t : zc_closure_key_stats_move, \
+        zc_owned_closure_keyexpr_interceptor_t : zc_closure_keyexpr_interceptor_move, \
         zc_owned_closure_log_t : zc_closure_log_move, \
+        zc_owned_closure_log_record_t : zc_closure_log_record_move, \
         zc_owned_closure_matching_status_t : zc_closure_matching_status_move, \
+        zc_owned_closure_metrics_t : zc_closure_metrics_move, \
+        zc_owned_closure_payload_transform_t : zc_closure_payload_transform_move, \
+        zc_owned_closure_query_diagnostics_t : zc_closure_query_diagnostics_move, \
+        zc_owned_closure_reply_entry_t : zc_closure_reply_entry_move, \
+        zc_owned_closure_table_get_t : zc_closure_table_get_move, \
+        zc_owned_closure_table_list_t : zc_closure_table_list_move, \
+        zc_owned_closure_watch_t : zc_closure_watch_move, \
+        zc_owned_closure_watermark_t : zc_closure_watermark_move, \
         zc_owned_concurrent_close_handle_t : zc_concurrent_close_handle_move, \
+        zc_owned_fifo_handler_matching_status_t : zc_fifo_handler_matching_status_move, \
         zc_owned_matching_listener_t : zc_matching_listener_move, \
+        zc_owned_metrics_subscriber_t : zc_metrics_subscriber_move, \
+        zc_owned_ring_handler_matching_status_t : zc_ring_handler_matching_status_move, \
         zc_owned_shm_client_list_t : zc_shm_client_list_move, \
+        zc_owned_stream_t : zc_stream_move, \
+        zc_owned_stream_listener_t : zc_stream_listener_move, \
+        zc_owned_watch_t : zc_watch_move, \
         ze_owned_advanced_publisher_t : ze_advanced_publisher_move, \
         ze_owned_advanced_subscriber_t : ze_advanced_subscriber_move, \
+        ze_owned_closure_history_end_t : ze_closure_history_end_move, \
         ze_owned_closure_miss_t : ze_closure_miss_move, \
         ze_owned_publication_cache_t : ze_publication_cache_move, \
         ze_owned_querying_subscriber_t : ze_querying_subscriber_move, \
@@ -262,6 +336,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_alloc_layout_t* : z_internal_alloc_layout_null, \
         z_owned_bytes_t* : z_internal_bytes_null, \
         z_owned_bytes_writer_t* : z_internal_bytes_writer_null, \
+        z_owned_cancellation_token_t* : z_internal_cancellation_token_null, \
         z_owned_chunk_alloc_result_t* : z_internal_chunk_alloc_result_null, \
         z_owned_closure_hello_t* : z_internal_closure_hello_null, \
         z_owned_closure_query_t* : z_internal_closure_query_null, \
@@ -301,13 +376,31 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_string_t* : z_internal_string_null, \
         z_owned_subscriber_t* : z_internal_subscriber_null, \
         z_owned_task_t* : z_internal_task_null, \
+        zc_owned_closure_key_stats_t* : zc_internal_closure_key_stats_null, \
+        zc_owned_closure_keyexpr_interceptor_t* : zc_internal_closure_keyexpr_interceptor_null, \
         zc_owned_closure_log_t* : zc_internal_closure_log_null, \
+        zc_owned_closure_log_record_t* : zc_internal_closure_log_record_null, \
         zc_owned_closure_matching_status_t* : zc_internal_closure_matching_status_null, \
+        zc_owned_closure_metrics_t* : zc_internal_closure_metrics_null, \
+        zc_owned_closure_payload_transform_t* : zc_internal_closure_payload_transform_null, \
+        zc_owned_closure_query_diagnostics_t* : zc_internal_closure_query_diagnostics_null, \
+        zc_owned_closure_reply_entry_t* : zc_internal_closure_reply_entry_null, \
+        zc_owned_closure_table_get_t* : zc_internal_closure_table_get_null, \
+        zc_owned_closure_table_list_t* : zc_internal_closure_table_list_null, \
+        zc_owned_closure_watch_t* : zc_internal_closure_watch_null, \
+        zc_owned_closure_watermark_t* : zc_internal_closure_watermark_null, \
         zc_owned_concurrent_close_handle_t* : zc_internal_concurrent_close_handle_null, \
+        zc_owned_fifo_handler_matching_status_t* : zc_internal_fifo_handler_matching_status_null, \
         zc_owned_matching_listener_t* : zc_internal_matching_listener_null, \
+        zc_owned_metrics_subscriber_t* : zc_internal_metrics_subscriber_null, \
+        zc_owned_ring_handler_matching_status_t* : zc_internal_ring_handler_matching_status_null, \
         zc_owned_shm_client_list_t* : zc_internal_shm_client_list_null, \
+        zc_owned_stream_listener_t* : zc_internal_stream_listener_null, \
+        zc_owned_stream_t* : zc_internal_stream_null, \
+        zc_owned_watch_t* : zc_internal_watch_null, \
         ze_owned_advanced_publisher_t* : ze_internal_advanced_publisher_null, \
         ze_owned_advanced_subscriber_t* : ze_internal_advanced_subscriber_null, \
+        ze_owned_closure_history_end_t* : ze_internal_closure_history_end_null, \
         ze_owned_closure_miss_t* : ze_internal_closure_miss_null, \
         ze_owned_publication_cache_t* : ze_internal_publication_cache_null, \
         ze_owned_querying_subscriber_t* : ze_internal_querying_subscriber_null, \
@@ -318,6 +411,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
 static inline void z_alloc_layout_take(z_owned_alloc_layout_t* this_, z_moved_alloc_layout_t* x) { *this_ = x->_this; z_internal_alloc_layout_null(&x->_this); }
 static inline void z_bytes_take(z_owned_bytes_t* this_, z_moved_bytes_t* x) { *this_ = x->_this; z_internal_bytes_null(&x->_this); }
 static inline void z_bytes_writer_take(z_owned_bytes_writer_t* this_, z_moved_bytes_writer_t* x) { *this_ = x->_this; z_internal_bytes_writer_null(&x->_this); }
+static inline void z_cancellation_token_take(z_owned_cancellation_token_t* this_, z_moved_cancellation_token_t* x) { *this_ = x->_this; z_internal_cancellation_token_null(&x->_this); }
 static inline void z_chunk_alloc_result_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_result_t* x) { *this_ = x->_this; z_internal_chunk_alloc_result_null(&x->_this); }
 static inline void z_closure_hello_take(z_owned_closure_hello_t* this_, z_moved_closure_hello_t* x) { *this_ = x->_this; z_internal_closure_hello_null(&x->_this); }
 static inline void z_closure_query_take(z_owned_closure_query_t* closure_, z_moved_closure_query_t* x) { *closure_ = x->_this; z_internal_closure_query_null(&x->_this); }
@@ -357,13 +451,31 @@ static inline void z_string_array_take(z_owned_string_array_t* this_, z_moved_st
 static inline void z_string_take(z_owned_string_t* this_, z_moved_string_t* x) { *this_ = x->_this; z_internal_string_null(&x->_this); }
 static inline void z_subscriber_take(z_owned_subscriber_t* this_, z_moved_subscriber_t* x) { *this_ = x->_this; z_internal_subscriber_null(&x->_this); }
 static inline void z_task_take(z_owned_task_t* this_, z_moved_task_t* x) { *this_ = x->_this; z_internal_task_null(&x->_this); }
+static inline void zc_closure_key_stats_take(zc_owned_closure_key_stats_t* closure_, zc_moved_closure_key_stats_t* x) { *closure_ = x->_this; zc_internal_closure_key_stats_null(&x->_this); }
+static inline void zc_closure_keyexpr_interceptor_take(zc_owned_closure_keyexpr_interceptor_t* closure_, zc_moved_closure_keyexpr_interceptor_t* x) { *closure_ = x->_this; zc_internal_closure_keyexpr_interceptor_null(&x->_this); }
 static inline void zc_closure_log_take(zc_owned_closure_log_t* closure_, zc_moved_closure_log_t* x) { *closure_ = x->_this; zc_internal_closure_log_null(&x->_this); }
+static inline void zc_closure_log_record_take(zc_owned_closure_log_record_t* closure_, zc_moved_closure_log_record_t* x) { *closure_ = x->_this; zc_internal_closure_log_record_null(&x->_this); }
 static inline void zc_closure_matching_status_take(zc_owned_closure_matching_status_t* closure_, zc_moved_closure_matching_status_t* x) { *closure_ = x->_this; zc_internal_closure_matching_status_null(&x->_this); }
+static inline void zc_closure_metrics_take(zc_owned_closure_metrics_t* closure_, zc_moved_closure_metrics_t* x) { *closure_ = x->_this; zc_internal_closure_metrics_null(&x->_this); }
+static inline void zc_closure_payload_transform_take(zc_owned_closure_payload_transform_t* closure_, zc_moved_closure_payload_transform_t* x) { *closure_ = x->_this; zc_internal_closure_payload_transform_null(&x->_this); }
+static inline void zc_closure_query_diagnostics_take(zc_owned_closure_query_diagnostics_t* closure_, zc_moved_closure_query_diagnostics_t* x) { *closure_ = x->_this; zc_internal_closure_query_diagnostics_null(&x->_this); }
+static inline void zc_closure_reply_entry_take(zc_owned_closure_reply_entry_t* closure_, zc_moved_closure_reply_entry_t* x) { *closure_ = x->_this; zc_internal_closure_reply_entry_null(&x->_this); }
+static inline void zc_closure_table_get_take(zc_owned_closure_table_get_t* closure_, zc_moved_closure_table_get_t* x) { *closure_ = x->_this; zc_internal_closure_table_get_null(&x->_this); }
+static inline void zc_closure_table_list_take(zc_owned_closure_table_list_t* closure_, zc_moved_closure_table_list_t* x) { *closure_ = x->_this; zc_internal_closure_table_list_null(&x->_this); }
+static inline void zc_closure_watch_take(zc_owned_closure_watch_t* closure_, zc_moved_closure_watch_t* x) { *closure_ = x->_this; zc_internal_closure_watch_null(&x->_this); }
+static inline void zc_closure_watermark_take(zc_owned_closure_watermark_t* closure_, zc_moved_closure_watermark_t* x) { *closure_ = x->_this; zc_internal_closure_watermark_null(&x->_this); }
 static inline void zc_concurrent_close_handle_take(zc_owned_concurrent_close_handle_t* this_, zc_moved_concurrent_close_handle_t* x) { *this_ = x->_this; zc_internal_concurrent_close_handle_null(&x->_this); }
+static inline void zc_fifo_handler_matching_status_take(zc_owned_fifo_handler_matching_status_t* this_, zc_moved_fifo_handler_matching_status_t* x) { *this_ = x->_this; zc_internal_fifo_handler_matching_status_null(&x->_this); }
 static inline void zc_matching_listener_take(zc_owned_matching_listener_t* this_, zc_moved_matching_listener_t* x) { *this_ = x->_this; zc_internal_matching_listener_null(&x->_this); }
+static inline void zc_metrics_subscriber_take(zc_owned_metrics_subscriber_t* this_, zc_moved_metrics_subscriber_t* x) { *this_ = x->_this; zc_internal_metrics_subscriber_null(&x->_this); }
+static inline void zc_ring_handler_matching_status_take(zc_owned_ring_handler_matching_status_t* this_, zc_moved_ring_handler_matching_status_t* x) { *this_ = x->_this; zc_internal_ring_handler_matching_status_null(&x->_this); }
 static inline void zc_shm_client_list_take(zc_owned_shm_client_list_t* this_, zc_moved_shm_client_list_t* x) { *this_ = x->_this; zc_internal_shm_client_list_null(&x->_this); }
+static inline void zc_stream_take(zc_owned_stream_t* this_, zc_moved_stream_t* x) { *this_ = x->_this; zc_internal_stream_null(&x->_this); }
+static inline void zc_stream_listener_take(zc_owned_stream_listener_t* this_, zc_moved_stream_listener_t* x) { *this_ = x->_this; zc_internal_stream_listener_null(&x->_this); }
+static inline void zc_watch_take(zc_owned_watch_t* this_, zc_moved_watch_t* x) { *this_ = x->_this; zc_internal_watch_null(&x->_this); }
 static inline void ze_advanced_publisher_take(ze_owned_advanced_publisher_t* this_, ze_moved_advanced_publisher_t* x) { *this_ = x->_this; ze_internal_advanced_publisher_null(&x->_this); }
 static inline void ze_advanced_subscriber_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subscriber_t* x) { *this_ = x->_this; ze_internal_advanced_subscriber_null(&x->_this); }
+static inline void ze_closure_history_end_take(ze_owned_closure_history_end_t* closure_, ze_moved_closure_history_end_t* x) { *closure_ = x->_this; ze_internal_closure_history_end_null(&x->_this); }
 static inline void ze_closure_miss_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) { *closure_ = x->_this; ze_internal_closure_miss_null(&x->_this); }
 static inline void ze_publication_cache_take(ze_owned_publication_cache_t* this_, ze_moved_publication_cache_t* x) { *this_ = x->_this; ze_internal_publication_cache_null(&x->_this); }
 static inline void ze_querying_subscriber_take(ze_owned_querying_subscriber_t* this_, ze_moved_querying_subscriber_t* x) { *this_ = x->_this; ze_internal_querying_subscriber_null(&x->_this); }
@@ -376,6 +488,7 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_alloc_layout_t* : z_alloc_layout_take, \
         z_owned_bytes_t* : z_bytes_take, \
         z_owned_bytes_writer_t* : z_bytes_writer_take, \
+        z_owned_cancellation_token_t* : z_cancellation_token_take, \
         z_owned_chunk_alloc_result_t* : z_chunk_alloc_result_take, \
         z_owned_closure_hello_t* : z_closure_hello_take, \
         z_owned_closure_query_t* : z_closure_query_take, \
@@ -415,13 +528,31 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_string_t* : z_string_take, \
         z_owned_subscriber_t* : z_subscriber_take, \
         z_owned_task_t* : z_task_take, \
+        zc_owned_closure_key_stats_t* : zc_closure_key_stats_take, \
+        zc_owned_closure_keyexpr_interceptor_t* : zc_closure_keyexpr_interceptor_take, \
         zc_owned_closure_log_t* : zc_closure_log_take, \
+        zc_owned_closure_log_record_t* : zc_closure_log_record_take, \
         zc_owned_closure_matching_status_t* : zc_closure_matching_status_take, \
+        zc_owned_closure_metrics_t* : zc_closure_metrics_take, \
+        zc_owned_closure_payload_transform_t* : zc_closure_payload_transform_take, \
+        zc_owned_closure_query_diagnostics_t* : zc_closure_query_diagnostics_take, \
+        zc_owned_closure_reply_entry_t* : zc_closure_reply_entry_take, \
+        zc_owned_closure_table_get_t* : zc_closure_table_get_take, \
+        zc_owned_closure_table_list_t* : zc_closure_table_list_take, \
+        zc_owned_closure_watch_t* : zc_closure_watch_take, \
+        zc_owned_closure_watermark_t* : zc_closure_watermark_take, \
         zc_owned_concurrent_close_handle_t* : zc_concurrent_close_handle_take, \
+        zc_owned_fifo_handler_matching_status_t* : zc_fifo_handler_matching_status_take, \
         zc_owned_matching_listener_t* : zc_matching_listener_take, \
+        zc_owned_metrics_subscriber_t* : zc_metrics_subscriber_take, \
+        zc_owned_ring_handler_matching_status_t* : zc_ring_handler_matching_status_take, \
         zc_owned_shm_client_list_t* : zc_shm_client_list_take, \
+        zc_owned_stream_t* : zc_stream_take, \
+        zc_owned_stream_listener_t* : zc_stream_listener_take, \
+        zc_owned_watch_t* : zc_watch_take, \
         ze_owned_advanced_publisher_t* : ze_advanced_publisher_take, \
         ze_owned_advanced_subscriber_t* : ze_advanced_subscriber_take, \
+        ze_owned_closure_history_end_t* : ze_closure_history_end_take, \
         ze_owned_closure_miss_t* : ze_closure_miss_take, \
         ze_owned_publication_cache_t* : ze_publication_cache_take, \
         ze_owned_querying_subscriber_t* : ze_querying_subscriber_take, \
@@ -434,6 +565,7 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_alloc_layout_t : z_internal_alloc_layout_check, \
         z_owned_bytes_t : z_internal_bytes_check, \
         z_owned_bytes_writer_t : z_internal_bytes_writer_check, \
+        z_owned_cancellation_token_t : z_internal_cancellation_token_check, \
         z_owned_chunk_alloc_result_t : z_internal_chunk_alloc_result_check, \
         z_owned_closure_hello_t : z_internal_closure_hello_check, \
         z_owned_closure_query_t : z_internal_closure_query_check, \
@@ -473,13 +605,31 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_string_t : z_internal_string_check, \
         z_owned_subscriber_t : z_internal_subscriber_check, \
         z_owned_task_t : z_internal_task_check, \
+        zc_owned_closure_key_stats_t : zc_internal_closure_key_stats_check, \
+        zc_owned_closure_keyexpr_interceptor_t : zc_internal_closure_keyexpr_interceptor_check, \
         zc_owned_closure_log_t : zc_internal_closure_log_check, \
+        zc_owned_closure_log_record_t : zc_internal_closure_log_record_check, \
         zc_owned_closure_matching_status_t : zc_internal_closure_matching_status_check, \
+        zc_owned_closure_metrics_t : zc_internal_closure_metrics_check, \
+        zc_owned_closure_payload_transform_t : zc_internal_closure_payload_transform_check, \
+        zc_owned_closure_query_diagnostics_t : zc_internal_closure_query_diagnostics_check, \
+        zc_owned_closure_reply_entry_t : zc_internal_closure_reply_entry_check, \
+        zc_owned_closure_table_get_t : zc_internal_closure_table_get_check, \
+        zc_owned_closure_table_list_t : zc_internal_closure_table_list_check, \
+        zc_owned_closure_watch_t : zc_internal_closure_watch_check, \
+        zc_owned_closure_watermark_t : zc_internal_closure_watermark_check, \
         zc_owned_concurrent_close_handle_t : zc_internal_concurrent_close_handle_check, \
+        zc_owned_fifo_handler_matching_status_t : zc_internal_fifo_handler_matching_status_check, \
         zc_owned_matching_listener_t : zc_internal_matching_listener_check, \
+        zc_owned_metrics_subscriber_t : zc_internal_metrics_subscriber_check, \
+        zc_owned_ring_handler_matching_status_t : zc_internal_ring_handler_matching_status_check, \
         zc_owned_shm_client_list_t : zc_internal_shm_client_list_check, \
+        zc_owned_stream_t : zc_internal_stream_check, \
+        zc_owned_stream_listener_t : zc_internal_stream_listener_check, \
+        zc_owned_watch_t : zc_internal_watch_check, \
         ze_owned_advanced_publisher_t : ze_internal_advanced_publisher_check, \
         ze_owned_advanced_subscriber_t : ze_internal_advanced_subscriber_check, \
+        ze_owned_closure_history_end_t : ze_internal_closure_history_end_check, \
         ze_owned_closure_miss_t : ze_internal_closure_miss_check, \
         ze_owned_publication_cache_t : ze_internal_publication_cache_check, \
         ze_owned_querying_subscriber_t : ze_internal_querying_subscriber_check, \
@@ -495,6 +645,9 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         const z_loaned_closure_sample_t* : z_closure_sample_call, \
         const z_loaned_closure_zid_t* : z_closure_zid_call, \
         const zc_loaned_closure_matching_status_t* : zc_closure_matching_status_call, \
+        const zc_loaned_closure_metrics_t* : zc_closure_metrics_call, \
+        const zc_loaned_closure_query_diagnostics_t* : zc_closure_query_diagnostics_call, \
+        const zc_loaned_closure_table_list_t* : zc_closure_table_list_call, \
         const ze_loaned_closure_miss_t* : ze_closure_miss_call \
     )(closure, hello)
 
@@ -504,8 +657,20 @@ typedef void(*z_closure_query_callback_t)(z_loaned_query_t *query, void *context
 typedef void(*z_closure_reply_callback_t)(z_loaned_reply_t *reply, void *context);
 typedef void(*z_closure_sample_callback_t)(z_loaned_sample_t *sample, void *context);
 typedef void(*z_closure_zid_callback_t)(const z_id_t *z_id, void *context);
+typedef void(*zc_closure_key_stats_callback_t)(const z_loaned_keyexpr_t *key_expr, uint64_t samples, void *context);
+typedef z_result_t(*zc_closure_keyexpr_interceptor_callback_t)(const z_loaned_keyexpr_t *key_expr, z_owned_keyexpr_t *rewritten, void *context);
 typedef void(*zc_closure_log_callback_t)(zc_log_severity_t severity, const z_loaned_string_t *msg, void *context);
+typedef void(*zc_closure_log_record_callback_t)(zc_log_severity_t severity, const z_loaned_string_t *target, const z_loaned_string_t *msg, void *context);
 typedef void(*zc_closure_matching_status_callback_t)(const zc_matching_status_t *matching_status, void *context);
+typedef void(*zc_closure_metrics_callback_t)(const zc_metrics_t *metrics, void *context);
+typedef void(*zc_closure_payload_transform_callback_t)(const z_loaned_bytes_t *payload, z_owned_bytes_t *transformed, void *context);
+typedef void(*zc_closure_query_diagnostics_callback_t)(const zc_query_diagnostics_t *diagnostics, void *context);
+typedef bool(*zc_closure_reply_entry_callback_t)(z_owned_keyexpr_t *key_expr, z_owned_bytes_t *payload, void *context);
+typedef bool(*zc_closure_table_get_callback_t)(const z_loaned_string_t *key, z_owned_bytes_t *value, void *context);
+typedef void(*zc_closure_table_list_callback_t)(z_loaned_string_array_t *keys, void *context);
+typedef void(*zc_closure_watch_callback_t)(z_loaned_sample_t *sample, bool is_initial, void *context);
+typedef void(*zc_closure_watermark_callback_t)(zc_watermark_t watermark, size_t occupancy, void *context);
+typedef void(*ze_closure_history_end_callback_t)(void *context);
 typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void *context);
 
 #define z_closure(this_, call, drop, context) \
@@ -515,8 +680,20 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
         z_owned_closure_reply_t* : z_closure_reply, \
         z_owned_closure_sample_t* : z_closure_sample, \
         z_owned_closure_zid_t* : z_closure_zid, \
+        zc_owned_closure_key_stats_t* : zc_closure_key_stats, \
+        zc_owned_closure_keyexpr_interceptor_t* : zc_closure_keyexpr_interceptor, \
         zc_owned_closure_log_t* : zc_closure_log, \
+        zc_owned_closure_log_record_t* : zc_closure_log_record, \
         zc_owned_closure_matching_status_t* : zc_closure_matching_status, \
+        zc_owned_closure_metrics_t* : zc_closure_metrics, \
+        zc_owned_closure_payload_transform_t* : zc_closure_payload_transform, \
+        zc_owned_closure_query_diagnostics_t* : zc_closure_query_diagnostics, \
+        zc_owned_closure_reply_entry_t* : zc_closure_reply_entry, \
+        zc_owned_closure_table_get_t* : zc_closure_table_get, \
+        zc_owned_closure_table_list_t* : zc_closure_table_list, \
+        zc_owned_closure_watch_t* : zc_closure_watch, \
+        zc_owned_closure_watermark_t* : zc_closure_watermark, \
+        ze_owned_closure_history_end_t* : ze_closure_history_end, \
         ze_owned_closure_miss_t* : ze_closure_miss \
     )(this_, call, drop, context)
 
@@ -527,7 +704,9 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
         const z_loaned_fifo_handler_sample_t* : z_fifo_handler_sample_try_recv, \
         const z_loaned_ring_handler_query_t* : z_ring_handler_query_try_recv, \
         const z_loaned_ring_handler_reply_t* : z_ring_handler_reply_try_recv, \
-        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_try_recv \
+        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_try_recv, \
+        const zc_loaned_fifo_handler_matching_status_t* : zc_fifo_handler_matching_status_try_recv, \
+        const zc_loaned_ring_handler_matching_status_t* : zc_ring_handler_matching_status_try_recv \
     )(this_, query)
 
 #define z_recv(this_, query) \
@@ -537,12 +716,15 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
         const z_loaned_fifo_handler_sample_t* : z_fifo_handler_sample_recv, \
         const z_loaned_ring_handler_query_t* : z_ring_handler_query_recv, \
         const z_loaned_ring_handler_reply_t* : z_ring_handler_reply_recv, \
-        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_recv \
+        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_recv, \
+        const zc_loaned_fifo_handler_matching_status_t* : zc_fifo_handler_matching_status_recv, \
+        const zc_loaned_ring_handler_matching_status_t* : zc_ring_handler_matching_status_recv \
     )(this_, query)
 
 #define z_clone(dst, this_) \
     _Generic((dst), \
         z_owned_bytes_t* : z_bytes_clone, \
+        z_owned_cancellation_token_t* : z_cancellation_token_clone, \
         z_owned_config_t* : z_config_clone, \
         z_owned_encoding_t* : z_encoding_clone, \
         z_owned_hello_t* : z_hello_clone, \
@@ -563,6 +745,7 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
 static inline z_moved_alloc_layout_t* z_alloc_layout_move(z_owned_alloc_layout_t* x) { return reinterpret_cast<z_moved_alloc_layout_t*>(x); }
 static inline z_moved_bytes_t* z_bytes_move(z_owned_bytes_t* x) { return reinterpret_cast<z_moved_bytes_t*>(x); }
 static inline z_moved_bytes_writer_t* z_bytes_writer_move(z_owned_bytes_writer_t* x) { return reinterpret_cast<z_moved_bytes_writer_t*>(x); }
+static inline z_moved_cancellation_token_t* z_cancellation_token_move(z_owned_cancellation_token_t* x) { return reinterpret_cast<z_moved_cancellation_token_t*>(x); }
 static inline z_moved_chunk_alloc_result_t* z_chunk_alloc_result_move(z_owned_chunk_alloc_result_t* x) { return reinterpret_cast<z_moved_chunk_alloc_result_t*>(x); }
 static inline z_moved_closure_hello_t* z_closure_hello_move(z_owned_closure_hello_t* x) { return reinterpret_cast<z_moved_closure_hello_t*>(x); }
 static inline z_moved_closure_query_t* z_closure_query_move(z_owned_closure_query_t* x) { return reinterpret_cast<z_moved_closure_query_t*>(x); }
@@ -602,13 +785,31 @@ static inline z_moved_string_array_t* z_string_array_move(z_owned_string_array_t
 static inline z_moved_string_t* z_string_move(z_owned_string_t* x) { return reinterpret_cast<z_moved_string_t*>(x); }
 static inline z_moved_subscriber_t* z_subscriber_move(z_owned_subscriber_t* x) { return reinterpret_cast<z_moved_subscriber_t*>(x); }
 static inline z_moved_task_t* z_task_move(z_owned_task_t* x) { return reinterpret_cast<z_moved_task_t*>(x); }
+static inline zc_moved_closure_key_stats_t* zc_closure_key_stats_move(zc_owned_closure_key_stats_t* x) { return reinterpret_cast<zc_moved_closure_key_stats_t*>(x); }
+static inline zc_moved_closure_keyexpr_interceptor_t* zc_closure_keyexpr_interceptor_move(zc_owned_closure_keyexpr_interceptor_t* x) { return reinterpret_cast<zc_moved_closure_keyexpr_interceptor_t*>(x); }
 static inline zc_moved_closure_log_t* zc_closure_log_move(zc_owned_closure_log_t* x) { return reinterpret_cast<zc_moved_closure_log_t*>(x); }
+static inline zc_moved_closure_log_record_t* zc_closure_log_record_move(zc_owned_closure_log_record_t* x) { return reinterpret_cast<zc_moved_closure_log_record_t*>(x); }
 static inline zc_moved_closure_matching_status_t* zc_closure_matching_status_move(zc_owned_closure_matching_status_t* x) { return reinterpret_cast<zc_moved_closure_matching_status_t*>(x); }
+static inline zc_moved_closure_metrics_t* zc_closure_metrics_move(zc_owned_closure_metrics_t* x) { return reinterpret_cast<zc_moved_closure_metrics_t*>(x); }
+static inline zc_moved_closure_payload_transform_t* zc_closure_payload_transform_move(zc_owned_closure_payload_transform_t* x) { return reinterpret_cast<zc_moved_closure_payload_transform_t*>(x); }
+static inline zc_moved_closure_query_diagnostics_t* zc_closure_query_diagnostics_move(zc_owned_closure_query_diagnostics_t* x) { return reinterpret_cast<zc_moved_closure_query_diagnostics_t*>(x); }
+static inline zc_moved_closure_reply_entry_t* zc_closure_reply_entry_move(zc_owned_closure_reply_entry_t* x) { return reinterpret_cast<zc_moved_closure_reply_entry_t*>(x); }
+static inline zc_moved_closure_table_get_t* zc_closure_table_get_move(zc_owned_closure_table_get_t* x) { return reinterpret_cast<zc_moved_closure_table_get_t*>(x); }
+static inline zc_moved_closure_table_list_t* zc_closure_table_list_move(zc_owned_closure_table_list_t* x) { return reinterpret_cast<zc_moved_closure_table_list_t*>(x); }
+static inline zc_moved_closure_watch_t* zc_closure_watch_move(zc_owned_closure_watch_t* x) { return reinterpret_cast<zc_moved_closure_watch_t*>(x); }
+static inline zc_moved_closure_watermark_t* zc_closure_watermark_move(zc_owned_closure_watermark_t* x) { return reinterpret_cast<zc_moved_closure_watermark_t*>(x); }
 static inline zc_moved_concurrent_close_handle_t* zc_concurrent_close_handle_move(zc_owned_concurrent_close_handle_t* x) { return reinterpret_cast<zc_moved_concurrent_close_handle_t*>(x); }
+static inline zc_moved_fifo_handler_matching_status_t* zc_fifo_handler_matching_status_move(zc_owned_fifo_handler_matching_status_t* x) { return reinterpret_cast<zc_moved_fifo_handler_matching_status_t*>(x); }
 static inline zc_moved_matching_listener_t* zc_matching_listener_move(zc_owned_matching_listener_t* x) { return reinterpret_cast<zc_moved_matching_listener_t*>(x); }
+static inline zc_moved_metrics_subscriber_t* zc_metrics_subscriber_move(zc_owned_metrics_subscriber_t* x) { return reinterpret_cast<zc_moved_metrics_subscriber_t*>(x); }
+static inline zc_moved_ring_handler_matching_status_t* zc_ring_handler_matching_status_move(zc_owned_ring_handler_matching_status_t* x) { return reinterpret_cast<zc_moved_ring_handler_matching_status_t*>(x); }
 static inline zc_moved_shm_client_list_t* zc_shm_client_list_move(zc_owned_shm_client_list_t* x) { return reinterpret_cast<zc_moved_shm_client_list_t*>(x); }
+static inline zc_moved_stream_t* zc_stream_move(zc_owned_stream_t* x) { return reinterpret_cast<zc_moved_stream_t*>(x); }
+static inline zc_moved_stream_listener_t* zc_stream_listener_move(zc_owned_stream_listener_t* x) { return reinterpret_cast<zc_moved_stream_listener_t*>(x); }
+static inline zc_moved_watch_t* zc_watch_move(zc_owned_watch_t* x) { return reinterpret_cast<zc_moved_watch_t*>(x); }
 static inline ze_moved_advanced_publisher_t* ze_advanced_publisher_move(ze_owned_advanced_publisher_t* x) { return reinterpret_cast<ze_moved_advanced_publisher_t*>(x); }
 static inline ze_moved_advanced_subscriber_t* ze_advanced_subscriber_move(ze_owned_advanced_subscriber_t* x) { return reinterpret_cast<ze_moved_advanced_subscriber_t*>(x); }
+static inline ze_moved_closure_history_end_t* ze_closure_history_end_move(ze_owned_closure_history_end_t* x) { return reinterpret_cast<ze_moved_closure_history_end_t*>(x); }
 static inline ze_moved_closure_miss_t* ze_closure_miss_move(ze_owned_closure_miss_t* x) { return reinterpret_cast<ze_moved_closure_miss_t*>(x); }
 static inline ze_moved_publication_cache_t* ze_publication_cache_move(ze_owned_publication_cache_t* x) { return reinterpret_cast<ze_moved_publication_cache_t*>(x); }
 static inline ze_moved_querying_subscriber_t* ze_querying_subscriber_move(ze_owned_querying_subscriber_t* x) { return reinterpret_cast<ze_moved_querying_subscriber_t*>(x); }
@@ -620,6 +821,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
 inline const z_loaned_alloc_layout_t* z_loan(const z_owned_alloc_layout_t& this_) { return z_alloc_layout_loan(&this_); };
 inline const z_loaned_bytes_t* z_loan(const z_owned_bytes_t& this_) { return z_bytes_loan(&this_); };
 inline const z_loaned_bytes_writer_t* z_loan(const z_owned_bytes_writer_t& this_) { return z_bytes_writer_loan(&this_); };
+inline const z_loaned_cancellation_token_t* z_loan(const z_owned_cancellation_token_t& this_) { return z_cancellation_token_loan(&this_); };
 inline const z_loaned_closure_hello_t* z_loan(const z_owned_closure_hello_t& closure) { return z_closure_hello_loan(&closure); };
 inline const z_loaned_closure_query_t* z_loan(const z_owned_closure_query_t& closure) { return z_closure_query_loan(&closure); };
 inline const z_loaned_closure_reply_t* z_loan(const z_owned_closure_reply_t& closure) { return z_closure_reply_loan(&closure); };
@@ -658,11 +860,27 @@ inline const z_loaned_subscriber_t* z_loan(const z_owned_subscriber_t& this_) {
 inline const z_loaned_keyexpr_t* z_loan(const z_view_keyexpr_t& this_) { return z_view_keyexpr_loan(&this_); };
 inline const z_loaned_slice_t* z_loan(const z_view_slice_t& this_) { return z_view_slice_loan(&this_); };
 inline const z_loaned_string_t* z_loan(const z_view_string_t& this_) { return z_view_string_loan(&this_); };
+inline const zc_loaned_closure_key_stats_t* z_loan(const zc_owned_closure_key_stats_t& closure) { return zc_closure_key_stats_loan(&closure); };
+inline const zc_loaned_closure_keyexpr_interceptor_t* z_loan(const zc_owned_closure_keyexpr_interceptor_t& closure) { return zc_closure_keyexpr_interceptor_loan(&closure); };
 inline const zc_loaned_closure_log_t* z_loan(const zc_owned_closure_log_t& closure) { return zc_closure_log_loan(&closure); };
+inline const zc_loaned_closure_log_record_t* z_loan(const zc_owned_closure_log_record_t& closure) { return zc_closure_log_record_loan(&closure); };
 inline const zc_loaned_closure_matching_status_t* z_loan(const zc_owned_closure_matching_status_t& closure) { return zc_closure_matching_status_loan(&closure); };
+inline const zc_loaned_closure_metrics_t* z_loan(const zc_owned_closure_metrics_t& closure) { return zc_closure_metrics_loan(&closure); };
+inline const zc_loaned_closure_payload_transform_t* z_loan(const zc_owned_closure_payload_transform_t& closure) { return zc_closure_payload_transform_loan(&closure); };
+inline const zc_loaned_closure_query_diagnostics_t* z_loan(const zc_owned_closure_query_diagnostics_t& closure) { return zc_closure_query_diagnostics_loan(&closure); };
+inline const zc_loaned_closure_reply_entry_t* z_loan(const zc_owned_closure_reply_entry_t& closure) { return zc_closure_reply_entry_loan(&closure); };
+inline const zc_loaned_closure_table_get_t* z_loan(const zc_owned_closure_table_get_t& closure) { return zc_closure_table_get_loan(&closure); };
+inline const zc_loaned_closure_table_list_t* z_loan(const zc_owned_closure_table_list_t& closure) { return zc_closure_table_list_loan(&closure); };
+inline const zc_loaned_closure_watch_t* z_loan(const zc_owned_closure_watch_t& closure) { return zc_closure_watch_loan(&closure); };
+inline const zc_loaned_closure_watermark_t* z_loan(const zc_owned_closure_watermark_t& closure) { return zc_closure_watermark_loan(&closure); };
+inline const zc_loaned_fifo_handler_matching_status_t* z_loan(const zc_owned_fifo_handler_matching_status_t& this_) { return zc_fifo_handler_matching_status_loan(&this_); };
+inline const zc_loaned_ring_handler_matching_status_t* z_loan(const zc_owned_ring_handler_matching_status_t& this_) { return zc_ring_handler_matching_status_loan(&this_); };
 inline const zc_loaned_shm_client_list_t* z_loan(const zc_owned_shm_client_list_t& this_) { return zc_shm_client_list_loan(&this_); };
+inline const zc_loaned_stream_listener_t* z_loan(const zc_owned_stream_listener_t& this_) { return zc_stream_listener_loan(&this_); };
+inline const zc_loaned_stream_t* z_loan(const zc_owned_stream_t& this_) { return zc_stream_loan(&this_); };
 inline const ze_loaned_advanced_publisher_t* z_loan(const ze_owned_advanced_publisher_t& this_) { return ze_advanced_publisher_loan(&this_); };
 inline const ze_loaned_advanced_subscriber_t* z_loan(const ze_owned_advanced_subscriber_t& this_) { return ze_advanced_subscriber_loan(&this_); };
+inline const ze_loaned_closure_history_end_t* z_loan(const ze_owned_closure_history_end_t& closure) { return ze_closure_history_end_loan(&closure); };
 inline const ze_loaned_closure_miss_t* z_loan(const ze_owned_closure_miss_t& closure) { return ze_closure_miss_loan(&closure); };
 inline const ze_loaned_publication_cache_t* z_loan(const ze_owned_publication_cache_t& this_) { return ze_publication_cache_loan(&this_); };
 inline const ze_loaned_querying_subscriber_t* z_loan(const ze_owned_querying_subscriber_t& this_) { return ze_querying_subscriber_loan(&this_); };
@@ -697,6 +915,7 @@ inline ze_loaned_serializer_t* z_loan_mut(ze_owned_serializer_t& this_) { return
 inline void z_drop(z_moved_alloc_layout_t* this_) { z_alloc_layout_drop(this_); };
 inline void z_drop(z_moved_bytes_t* this_) { z_bytes_drop(this_); };
 inline void z_drop(z_moved_bytes_writer_t* this_) { z_bytes_writer_drop(this_); };
+inline void z_drop(z_moved_cancellation_token_t* this_) { z_cancellation_token_drop(this_); };
 inline void z_drop(z_moved_chunk_alloc_result_t* this_) { z_chunk_alloc_result_drop(this_); };
 inline void z_drop(z_moved_closure_hello_t* this_) { z_closure_hello_drop(this_); };
 inline void z_drop(z_moved_closure_query_t* closure_) { z_closure_query_drop(closure_); };
@@ -736,13 +955,31 @@ inline void z_drop(z_moved_string_array_t* this_) { z_string_array_drop(this_);
 inline void z_drop(z_moved_string_t* this_) { z_string_drop(this_); };
 inline void z_drop(z_moved_subscriber_t* this_) { z_subscriber_drop(this_); };
 inline void z_drop(z_moved_task_t* this_) { z_task_drop(this_); };
+inline void z_drop(zc_moved_closure_key_stats_t* closure_) { zc_closure_key_stats_drop(closure_); };
+inline void z_drop(zc_moved_closure_keyexpr_interceptor_t* closure_) { zc_closure_keyexpr_interceptor_drop(closure_); };
 inline void z_drop(zc_moved_closure_log_t* closure_) { zc_closure_log_drop(closure_); };
+inline void z_drop(zc_moved_closure_log_record_t* closure_) { zc_closure_log_record_drop(closure_); };
 inline void z_drop(zc_moved_closure_matching_status_t* closure_) { zc_closure_matching_status_drop(closure_); };
+inline void z_drop(zc_moved_closure_metrics_t* closure_) { zc_closure_metrics_drop(closure_); };
+inline void z_drop(zc_moved_closure_payload_transform_t* closure_) { zc_closure_payload_transform_drop(closure_); };
+inline void z_drop(zc_moved_closure_query_diagnostics_t* closure_) { zc_closure_query_diagnostics_drop(closure_); };
+inline void z_drop(zc_moved_closure_reply_entry_t* closure_) { zc_closure_reply_entry_drop(closure_); };
+inline void z_drop(zc_moved_closure_table_get_t* closure_) { zc_closure_table_get_drop(closure_); };
+inline void z_drop(zc_moved_closure_table_list_t* closure_) { zc_closure_table_list_drop(closure_); };
+inline void z_drop(zc_moved_closure_watch_t* closure_) { zc_closure_watch_drop(closure_); };
+inline void z_drop(zc_moved_closure_watermark_t* closure_) { zc_closure_watermark_drop(closure_); };
 inline void z_drop(zc_moved_concurrent_close_handle_t* this_) { zc_concurrent_close_handle_drop(this_); };
+inline void z_drop(zc_moved_fifo_handler_matching_status_t* this_) { zc_fifo_handler_matching_status_drop(this_); };
 inline void z_drop(zc_moved_matching_listener_t* this_) { zc_matching_listener_drop(this_); };
+inline void z_drop(zc_moved_metrics_subscriber_t* this_) { zc_metrics_subscriber_drop(this_); };
+inline void z_drop(zc_moved_ring_handler_matching_status_t* this_) { zc_ring_handler_matching_status_drop(this_); };
 inline void z_drop(zc_moved_shm_client_list_t* this_) { zc_shm_client_list_drop(this_); };
+inline void z_drop(zc_moved_stream_t* this_) { zc_stream_drop(this_); };
+inline void z_drop(zc_moved_stream_listener_t* this_) { zc_stream_listener_drop(this_); };
+inline void z_drop(zc_moved_watch_t* this_) { zc_watch_drop(this_); };
 inline void z_drop(ze_moved_advanced_publisher_t* this_) { ze_advanced_publisher_drop(this_); };
 inline void z_drop(ze_moved_advanced_subscriber_t* this_) { ze_advanced_subscriber_drop(this_); };
+inline void z_drop(ze_moved_closure_history_end_t* closure_) { ze_closure_history_end_drop(closure_); };
 inline void z_drop(ze_moved_closure_miss_t* closure_) { ze_closure_miss_drop(closure_); };
 inline void z_drop(ze_moved_publication_cache_t* this_) { ze_publication_cache_drop(this_); };
 inline void z_drop(ze_moved_querying_subscriber_t* this_) { ze_querying_subscriber_drop(this_); };
@@ -753,6 +990,7 @@ inline void z_drop(ze_moved_serializer_t* this_) { ze_serializer_drop(this_); };
 inline z_moved_alloc_layout_t* z_move(z_owned_alloc_layout_t& this_) { return z_alloc_layout_move(&this_); };
 inline z_moved_bytes_t* z_move(z_owned_bytes_t& this_) { return z_bytes_move(&this_); };
 inline z_moved_bytes_writer_t* z_move(z_owned_bytes_writer_t& this_) { return z_bytes_writer_move(&this_); };
+inline z_moved_cancellation_token_t* z_move(z_owned_cancellation_token_t& this_) { return z_cancellation_token_move(&this_); };
 inline z_moved_chunk_alloc_result_t* z_move(z_owned_chunk_alloc_result_t& this_) { return z_chunk_alloc_result_move(&this_); };
 inline z_moved_closure_hello_t* z_move(z_owned_closure_hello_t& this_) { return z_closure_hello_move(&this_); };
 inline z_moved_closure_query_t* z_move(z_owned_closure_query_t& closure_) { return z_closure_query_move(&closure_); };
@@ -792,13 +1030,31 @@ inline z_moved_string_array_t* z_move(z_owned_string_array_t& this_) { return z_
 inline z_moved_string_t* z_move(z_owned_string_t& this_) { return z_string_move(&this_); };
 inline z_moved_subscriber_t* z_move(z_owned_subscriber_t& this_) { return z_subscriber_move(&this_); };
 inline z_moved_task_t* z_move(z_owned_task_t& this_) { return z_task_move(&this_); };
+inline zc_moved_closure_key_stats_t* z_move(zc_owned_closure_key_stats_t& closure_) { return zc_closure_key_stats_move(&closure_); };
+inline zc_moved_closure_keyexpr_interceptor_t* z_move(zc_owned_closure_keyexpr_interceptor_t& closure_) { return zc_closure_keyexpr_interceptor_move(&closure_); };
 inline zc_moved_closure_log_t* z_move(zc_owned_closure_log_t& closure_) { return zc_closure_log_move(&closure_); };
+inline zc_moved_closure_log_record_t* z_move(zc_owned_closure_log_record_t& closure_) { return zc_closure_log_record_move(&closure_); };
 inline zc_moved_closure_matching_status_t* z_move(zc_owned_closure_matching_status_t& closure_) { return zc_closure_matching_status_move(&closure_); };
+inline zc_moved_closure_metrics_t* z_move(zc_owned_closure_metrics_t& closure_) { return zc_closure_metrics_move(&closure_); };
+inline zc_moved_closure_payload_transform_t* z_move(zc_owned_closure_payload_transform_t& closure_) { return zc_closure_payload_transform_move(&closure_); };
+inline zc_moved_closure_query_diagnostics_t* z_move(zc_owned_closure_query_diagnostics_t& closure_) { return zc_closure_query_diagnostics_move(&closure_); };
+inline zc_moved_closure_reply_entry_t* z_move(zc_owned_closure_reply_entry_t& closure_) { return zc_closure_reply_entry_move(&closure_); };
+inline zc_moved_closure_table_get_t* z_move(zc_owned_closure_table_get_t& closure_) { return zc_closure_table_get_move(&closure_); };
+inline zc_moved_closure_table_list_t* z_move(zc_owned_closure_table_list_t& closure_) { return zc_closure_table_list_move(&closure_); };
+inline zc_moved_closure_watch_t* z_move(zc_owned_closure_watch_t& closure_) { return zc_closure_watch_move(&closure_); };
+inline zc_moved_closure_watermark_t* z_move(zc_owned_closure_watermark_t& closure_) { return zc_closure_watermark_move(&closure_); };
 inline zc_moved_concurrent_close_handle_t* z_move(zc_owned_concurrent_close_handle_t& this_) { return zc_concurrent_close_handle_move(&this_); };
+inline zc_moved_fifo_handler_matching_status_t* z_move(zc_owned_fifo_handler_matching_status_t& this_) { return zc_fifo_handler_matching_status_move(&this_); };
 inline zc_moved_matching_listener_t* z_move(zc_owned_matching_listener_t& this_) { return zc_matching_listener_move(&this_); };
+inline zc_moved_metrics_subscriber_t* z_move(zc_owned_metrics_subscriber_t& this_) { return zc_metrics_subscriber_move(&this_); };
+inline zc_moved_ring_handler_matching_status_t* z_move(zc_owned_ring_handler_matching_status_t& this_) { return zc_ring_handler_matching_status_move(&this_); };
 inline zc_moved_shm_client_list_t* z_move(zc_owned_shm_client_list_t& this_) { return zc_shm_client_list_move(&this_); };
+inline zc_moved_stream_t* z_move(zc_owned_stream_t& this_) { return zc_stream_move(&this_); };
+inline zc_moved_stream_listener_t* z_move(zc_owned_stream_listener_t& this_) { return zc_stream_listener_move(&this_); };
+inline zc_moved_watch_t* z_move(zc_owned_watch_t& this_) { return zc_watch_move(&this_); };
 inline ze_moved_advanced_publisher_t* z_move(ze_owned_advanced_publisher_t& this_) { return ze_advanced_publisher_move(&this_); };
 inline ze_moved_advanced_subscriber_t* z_move(ze_owned_advanced_subscriber_t& this_) { return ze_advanced_subscriber_move(&this_); };
+inline ze_moved_closure_history_end_t* z_move(ze_owned_closure_history_end_t& closure_) { return ze_closure_history_end_move(&closure_); };
 inline ze_moved_closure_miss_t* z_move(ze_owned_closure_miss_t& closure_) { return ze_closure_miss_move(&closure_); };
 inline ze_moved_publication_cache_t* z_move(ze_owned_publication_cache_t& this_) { return ze_publication_cache_move(&this_); };
 inline ze_moved_querying_subscriber_t* z_move(ze_owned_querying_subscriber_t& this_) { return ze_querying_subscriber_move(&this_); };
@@ -809,6 +1065,7 @@ inline ze_moved_serializer_t* z_move(ze_owned_serializer_t& this_) { return ze_s
 inline void z_internal_null(z_owned_alloc_layout_t* this_) { z_internal_alloc_layout_null(this_); };
 inline void z_internal_null(z_owned_bytes_t* this_) { z_internal_bytes_null(this_); };
 inline void z_internal_null(z_owned_bytes_writer_t* this_) { z_internal_bytes_writer_null(this_); };
+inline void z_internal_null(z_owned_cancellation_token_t* this_) { z_internal_cancellation_token_null(this_); };
 inline void z_internal_null(z_owned_chunk_alloc_result_t* this_) { z_internal_chunk_alloc_result_null(this_); };
 inline void z_internal_null(z_owned_closure_hello_t* this_) { z_internal_closure_hello_null(this_); };
 inline void z_internal_null(z_owned_closure_query_t* this_) { z_internal_closure_query_null(this_); };
@@ -848,13 +1105,31 @@ inline void z_internal_null(z_owned_string_array_t* this_) { z_internal_string_a
 inline void z_internal_null(z_owned_string_t* this_) { z_internal_string_null(this_); };
 inline void z_internal_null(z_owned_subscriber_t* this_) { z_internal_subscriber_null(this_); };
 inline void z_internal_null(z_owned_task_t* this_) { z_internal_task_null(this_); };
+inline void z_internal_null(zc_owned_closure_key_stats_t* this_) { zc_internal_closure_key_stats_null(this_); };
+inline void z_internal_null(zc_owned_closure_keyexpr_interceptor_t* this_) { zc_internal_closure_keyexpr_interceptor_null(this_); };
 inline void z_internal_null(zc_owned_closure_log_t* this_) { zc_internal_closure_log_null(this_); };
+inline void z_internal_null(zc_owned_closure_log_record_t* this_) { zc_internal_closure_log_record_null(this_); };
 inline void z_internal_null(zc_owned_closure_matching_status_t* this_) { zc_internal_closure_matching_status_null(this_); };
+inline void z_internal_null(zc_owned_closure_metrics_t* this_) { zc_internal_closure_metrics_null(this_); };
+inline void z_internal_null(zc_owned_closure_payload_transform_t* this_) { zc_internal_closure_payload_transform_null(this_); };
+inline void z_internal_null(zc_owned_closure_query_diagnostics_t* this_) { zc_internal_closure_query_diagnostics_null(this_); };
+inline void z_internal_null(zc_owned_closure_reply_entry_t* this_) { zc_internal_closure_reply_entry_null(this_); };
+inline void z_internal_null(zc_owned_closure_table_get_t* this_) { zc_internal_closure_table_get_null(this_); };
+inline void z_internal_null(zc_owned_closure_table_list_t* this_) { zc_internal_closure_table_list_null(this_); };
+inline void z_internal_null(zc_owned_closure_watch_t* this_) { zc_internal_closure_watch_null(this_); };
+inline void z_internal_null(zc_owned_closure_watermark_t* this_) { zc_internal_closure_watermark_null(this_); };
 inline void z_internal_null(zc_owned_concurrent_close_handle_t* this_) { zc_internal_concurrent_close_handle_null(this_); };
+inline void z_internal_null(zc_owned_fifo_handler_matching_status_t* this_) { zc_internal_fifo_handler_matching_status_null(this_); };
 inline void z_internal_null(zc_owned_matching_listener_t* this_) { zc_internal_matching_listener_null(this_); };
+inline void z_internal_null(zc_owned_metrics_subscriber_t* this_) { zc_internal_metrics_subscriber_null(this_); };
+inline void z_internal_null(zc_owned_ring_handler_matching_status_t* this_) { zc_internal_ring_handler_matching_status_null(this_); };
 inline void z_internal_null(zc_owned_shm_client_list_t* this_) { zc_internal_shm_client_list_null(this_); };
+inline void z_internal_null(zc_owned_stream_listener_t* this_) { zc_internal_stream_listener_null(this_); };
+inline void z_internal_null(zc_owned_stream_t* this_) { zc_internal_stream_null(this_); };
+inline void z_internal_null(zc_owned_watch_t* this_) { zc_internal_watch_null(this_); };
 inline void z_internal_null(ze_owned_advanced_publisher_t* this_) { ze_internal_advanced_publisher_null(this_); };
 inline void z_internal_null(ze_owned_advanced_subscriber_t* this_) { ze_internal_advanced_subscriber_null(this_); };
+inline void z_internal_null(ze_owned_closure_history_end_t* this_) { ze_internal_closure_history_end_null(this_); };
 inline void z_internal_null(ze_owned_closure_miss_t* this_) { ze_internal_closure_miss_null(this_); };
 inline void z_internal_null(ze_owned_publication_cache_t* this_) { ze_internal_publication_cache_null(this_); };
 inline void z_internal_null(ze_owned_querying_subscriber_t* this_) { ze_internal_querying_subscriber_null(this_); };
@@ -864,6 +1139,7 @@ inline void z_internal_null(ze_owned_serializer_t* this_) { ze_internal_serializ
 static inline void z_alloc_layout_take(z_owned_alloc_layout_t* this_, z_moved_alloc_layout_t* x) { *this_ = x->_this; z_internal_alloc_layout_null(&x->_this); }
 static inline void z_bytes_take(z_owned_bytes_t* this_, z_moved_bytes_t* x) { *this_ = x->_this; z_internal_bytes_null(&x->_this); }
 static inline void z_bytes_writer_take(z_owned_bytes_writer_t* this_, z_moved_bytes_writer_t* x) { *this_ = x->_this; z_internal_bytes_writer_null(&x->_this); }
+static inline void z_cancellation_token_take(z_owned_cancellation_token_t* this_, z_moved_cancellation_token_t* x) { *this_ = x->_this; z_internal_cancellation_token_null(&x->_this); }
 static inline void z_chunk_alloc_result_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_result_t* x) { *this_ = x->_this; z_internal_chunk_alloc_result_null(&x->_this); }
 static inline void z_closure_hello_take(z_owned_closure_hello_t* this_, z_moved_closure_hello_t* x) { *this_ = x->_this; z_internal_closure_hello_null(&x->_this); }
 static inline void z_closure_query_take(z_owned_closure_query_t* closure_, z_moved_closure_query_t* x) { *closure_ = x->_this; z_internal_closure_query_null(&x->_this); }
@@ -903,13 +1179,31 @@ static inline void z_string_array_take(z_owned_string_array_t* this_, z_moved_st
 static inline void z_string_take(z_owned_string_t* this_, z_moved_string_t* x) { *this_ = x->_this; z_internal_string_null(&x->_this); }
 static inline void z_subscriber_take(z_owned_subscriber_t* this_, z_moved_subscriber_t* x) { *this_ = x->_this; z_internal_subscriber_null(&x->_this); }
 static inline void z_task_take(z_owned_task_t* this_, z_moved_task_t* x) { *this_ = x->_this; z_internal_task_null(&x->_this); }
+static inline void zc_closure_key_stats_take(zc_owned_closure_key_stats_t* closure_, zc_moved_closure_key_stats_t* x) { *closure_ = x->_this; zc_internal_closure_key_stats_null(&x->_this); }
+static inline void zc_closure_keyexpr_interceptor_take(zc_owned_closure_keyexpr_interceptor_t* closure_, zc_moved_closure_keyexpr_interceptor_t* x) { *closure_ = x->_this; zc_internal_closure_keyexpr_interceptor_null(&x->_this); }
 static inline void zc_closure_log_take(zc_owned_closure_log_t* closure_, zc_moved_closure_log_t* x) { *closure_ = x->_this; zc_internal_closure_log_null(&x->_this); }
+static inline void zc_closure_log_record_take(zc_owned_closure_log_record_t* closure_, zc_moved_closure_log_record_t* x) { *closure_ = x->_this; zc_internal_closure_log_record_null(&x->_this); }
 static inline void zc_closure_matching_status_take(zc_owned_closure_matching_status_t* closure_, zc_moved_closure_matching_status_t* x) { *closure_ = x->_this; zc_internal_closure_matching_status_null(&x->_this); }
+static inline void zc_closure_metrics_take(zc_owned_closure_metrics_t* closure_, zc_moved_closure_metrics_t* x) { *closure_ = x->_this; zc_internal_closure_metrics_null(&x->_this); }
+static inline void zc_closure_payload_transform_take(zc_owned_closure_payload_transform_t* closure_, zc_moved_closure_payload_transform_t* x) { *closure_ = x->_this; zc_internal_closure_payload_transform_null(&x->_this); }
+static inline void zc_closure_query_diagnostics_take(zc_owned_closure_query_diagnostics_t* closure_, zc_moved_closure_query_diagnostics_t* x) { *closure_ = x->_this; zc_internal_closure_query_diagnostics_null(&x->_this); }
+static inline void zc_closure_reply_entry_take(zc_owned_closure_reply_entry_t* closure_, zc_moved_closure_reply_entry_t* x) { *closure_ = x->_this; zc_internal_closure_reply_entry_null(&x->_this); }
+static inline void zc_closure_table_get_take(zc_owned_closure_table_get_t* closure_, zc_moved_closure_table_get_t* x) { *closure_ = x->_this; zc_internal_closure_table_get_null(&x->_this); }
+static inline void zc_closure_table_list_take(zc_owned_closure_table_list_t* closure_, zc_moved_closure_table_list_t* x) { *closure_ = x->_this; zc_internal_closure_table_list_null(&x->_this); }
+static inline void zc_closure_watch_take(zc_owned_closure_watch_t* closure_, zc_moved_closure_watch_t* x) { *closure_ = x->_this; zc_internal_closure_watch_null(&x->_this); }
+static inline void zc_closure_watermark_take(zc_owned_closure_watermark_t* closure_, zc_moved_closure_watermark_t* x) { *closure_ = x->_this; zc_internal_closure_watermark_null(&x->_this); }
 static inline void zc_concurrent_close_handle_take(zc_owned_concurrent_close_handle_t* this_, zc_moved_concurrent_close_handle_t* x) { *this_ = x->_this; zc_internal_concurrent_close_handle_null(&x->_this); }
+static inline void zc_fifo_handler_matching_status_take(zc_owned_fifo_handler_matching_status_t* this_, zc_moved_fifo_handler_matching_status_t* x) { *this_ = x->_this; zc_internal_fifo_handler_matching_status_null(&x->_this); }
 static inline void zc_matching_listener_take(zc_owned_matching_listener_t* this_, zc_moved_matching_listener_t* x) { *this_ = x->_this; zc_internal_matching_listener_null(&x->_this); }
+static inline void zc_metrics_subscriber_take(zc_owned_metrics_subscriber_t* this_, zc_moved_metrics_subscriber_t* x) { *this_ = x->_this; zc_internal_metrics_subscriber_null(&x->_this); }
+static inline void zc_ring_handler_matching_status_take(zc_owned_ring_handler_matching_status_t* this_, zc_moved_ring_handler_matching_status_t* x) { *this_ = x->_this; zc_internal_ring_handler_matching_status_null(&x->_this); }
 static inline void zc_shm_client_list_take(zc_owned_shm_client_list_t* this_, zc_moved_shm_client_list_t* x) { *this_ = x->_this; zc_internal_shm_client_list_null(&x->_this); }
+static inline void zc_stream_take(zc_owned_stream_t* this_, zc_moved_stream_t* x) { *this_ = x->_this; zc_internal_stream_null(&x->_this); }
+static inline void zc_stream_listener_take(zc_owned_stream_listener_t* this_, zc_moved_stream_listener_t* x) { *this_ = x->_this; zc_internal_stream_listener_null(&x->_this); }
+static inline void zc_watch_take(zc_owned_watch_t* this_, zc_moved_watch_t* x) { *this_ = x->_this; zc_internal_watch_null(&x->_this); }
 static inline void ze_advanced_publisher_take(ze_owned_advanced_publisher_t* this_, ze_moved_advanced_publisher_t* x) { *this_ = x->_this; ze_internal_advanced_publisher_null(&x->_this); }
 static inline void ze_advanced_subscriber_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subscriber_t* x) { *this_ = x->_this; ze_internal_advanced_subscriber_null(&x->_this); }
+static inline void ze_closure_history_end_take(ze_owned_closure_history_end_t* closure_, ze_moved_closure_history_end_t* x) { *closure_ = x->_this; ze_internal_closure_history_end_null(&x->_this); }
 static inline void ze_closure_miss_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) { *closure_ = x->_this; ze_internal_closure_miss_null(&x->_this); }
 static inline void ze_publication_cache_take(ze_owned_publication_cache_t* this_, ze_moved_publication_cache_t* x) { *this_ = x->_this; ze_internal_publication_cache_null(&x->_this); }
 static inline void ze_querying_subscriber_take(ze_owned_querying_subscriber_t* this_, ze_moved_querying_subscriber_t* x) { *this_ = x->_this; ze_internal_querying_subscriber_null(&x->_this); }
@@ -927,6 +1221,9 @@ inline void z_take(z_owned_bytes_t* this_, z_moved_bytes_t* x) {
 inline void z_take(z_owned_bytes_writer_t* this_, z_moved_bytes_writer_t* x) {
     z_bytes_writer_take(this_, x);
 };
+inline void z_take(z_owned_cancellation_token_t* this_, z_moved_cancellation_token_t* x) {
+    z_cancellation_token_take(this_, x);
+};
 inline void z_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_result_t* x) {
     z_chunk_alloc_result_take(this_, x);
 };
@@ -1044,27 +1341,81 @@ inline void z_take(z_owned_subscriber_t* this_, z_moved_subscriber_t* x) {
 inline void z_take(z_owned_task_t* this_, z_moved_task_t* x) {
     z_task_take(this_, x);
 };
+inline void z_take(zc_owned_closure_key_stats_t* closure_, zc_moved_closure_key_stats_t* x) {
+    zc_closure_key_stats_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_keyexpr_interceptor_t* closure_, zc_moved_closure_keyexpr_interceptor_t* x) {
+    zc_closure_keyexpr_interceptor_take(closure_, x);
+};
 inline void z_take(zc_owned_closure_log_t* closure_, zc_moved_closure_log_t* x) {
     zc_closure_log_take(closure_, x);
 };
+inline void z_take(zc_owned_closure_log_record_t* closure_, zc_moved_closure_log_record_t* x) {
+    zc_closure_log_record_take(closure_, x);
+};
 inline void z_take(zc_owned_closure_matching_status_t* closure_, zc_moved_closure_matching_status_t* x) {
     zc_closure_matching_status_take(closure_, x);
 };
+inline void z_take(zc_owned_closure_metrics_t* closure_, zc_moved_closure_metrics_t* x) {
+    zc_closure_metrics_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_payload_transform_t* closure_, zc_moved_closure_payload_transform_t* x) {
+    zc_closure_payload_transform_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_query_diagnostics_t* closure_, zc_moved_closure_query_diagnostics_t* x) {
+    zc_closure_query_diagnostics_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_reply_entry_t* closure_, zc_moved_closure_reply_entry_t* x) {
+    zc_closure_reply_entry_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_table_get_t* closure_, zc_moved_closure_table_get_t* x) {
+    zc_closure_table_get_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_table_list_t* closure_, zc_moved_closure_table_list_t* x) {
+    zc_closure_table_list_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_watch_t* closure_, zc_moved_closure_watch_t* x) {
+    zc_closure_watch_take(closure_, x);
+};
+inline void z_take(zc_owned_closure_watermark_t* closure_, zc_moved_closure_watermark_t* x) {
+    zc_closure_watermark_take(closure_, x);
+};
 inline void z_take(zc_owned_concurrent_close_handle_t* this_, zc_moved_concurrent_close_handle_t* x) {
     zc_concurrent_close_handle_take(this_, x);
 };
+inline void z_take(zc_owned_fifo_handler_matching_status_t* this_, zc_moved_fifo_handler_matching_status_t* x) {
+    zc_fifo_handler_matching_status_take(this_, x);
+};
 inline void z_take(zc_owned_matching_listener_t* this_, zc_moved_matching_listener_t* x) {
     zc_matching_listener_take(this_, x);
 };
+inline void z_take(zc_owned_metrics_subscriber_t* this_, zc_moved_metrics_subscriber_t* x) {
+    zc_metrics_subscriber_take(this_, x);
+};
+inline void z_take(zc_owned_ring_handler_matching_status_t* this_, zc_moved_ring_handler_matching_status_t* x) {
+    zc_ring_handler_matching_status_take(this_, x);
+};
 inline void z_take(zc_owned_shm_client_list_t* this_, zc_moved_shm_client_list_t* x) {
     zc_shm_client_list_take(this_, x);
 };
+inline void z_take(zc_owned_stream_t* this_, zc_moved_stream_t* x) {
+    zc_stream_take(this_, x);
+};
+inline void z_take(zc_owned_stream_listener_t* this_, zc_moved_stream_listener_t* x) {
+    zc_stream_listener_take(this_, x);
+};
+inline void z_take(zc_owned_watch_t* this_, zc_moved_watch_t* x) {
+    zc_watch_take(this_, x);
+};
 inline void z_take(ze_owned_advanced_publisher_t* this_, ze_moved_advanced_publisher_t* x) {
     ze_advanced_publisher_take(this_, x);
 };
 inline void z_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subscriber_t* x) {
     ze_advanced_subscriber_take(this_, x);
 };
+inline void z_take(ze_owned_closure_history_end_t* closure_, ze_moved_closure_history_end_t* x) {
+    ze_closure_history_end_take(closure_, x);
+};
 inline void z_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) {
     ze_closure_miss_take(closure_, x);
 };
@@ -1085,6 +1436,7 @@ inline void z_take(ze_owned_serializer_t* this_, ze_moved_serializer_t* x) {
 inline bool z_internal_check(const z_owned_alloc_layout_t& this_) { return z_internal_alloc_layout_check(&this_); };
 inline bool z_internal_check(const z_owned_bytes_t& this_) { return z_internal_bytes_check(&this_); };
 inline bool z_internal_check(const z_owned_bytes_writer_t& this_) { return z_internal_bytes_writer_check(&this_); };
+inline bool z_internal_check(const z_owned_cancellation_token_t& this_) { return z_internal_cancellation_token_check(&this_); };
 inline bool z_internal_check(const z_owned_chunk_alloc_result_t& this_) { return z_internal_chunk_alloc_result_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_hello_t& this_) { return z_internal_closure_hello_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_query_t& this_) { return z_internal_closure_query_check(&this_); };
@@ -1124,13 +1476,31 @@ inline bool z_internal_check(const z_owned_string_array_t& this_) { return z_int
 inline bool z_internal_check(const z_owned_string_t& this_) { return z_internal_string_check(&this_); };
 inline bool z_internal_check(const z_owned_subscriber_t& this_) { return z_internal_subscriber_check(&this_); };
 inline bool z_internal_check(const z_owned_task_t& this_) { return z_internal_task_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_key_stats_t& this_) { return zc_internal_closure_key_stats_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_keyexpr_interceptor_t& this_) { return zc_internal_closure_keyexpr_interceptor_check(&this_); };
 inline bool z_internal_check(const zc_owned_closure_log_t& this_) { return zc_internal_closure_log_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_log_record_t& this_) { return zc_internal_closure_log_record_check(&this_); };
 inline bool z_internal_check(const zc_owned_closure_matching_status_t& this_) { return zc_internal_closure_matching_status_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_metrics_t& this_) { return zc_internal_closure_metrics_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_payload_transform_t& this_) { return zc_internal_closure_payload_transform_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_query_diagnostics_t& this_) { return zc_internal_closure_query_diagnostics_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_reply_entry_t& this_) { return zc_internal_closure_reply_entry_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_table_get_t& this_) { return zc_internal_closure_table_get_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_table_list_t& this_) { return zc_internal_closure_table_list_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_watch_t& this_) { return zc_internal_closure_watch_check(&this_); };
+inline bool z_internal_check(const zc_owned_closure_watermark_t& this_) { return zc_internal_closure_watermark_check(&this_); };
 inline bool z_internal_check(const zc_owned_concurrent_close_handle_t& this_) { return zc_internal_concurrent_close_handle_check(&this_); };
+inline bool z_internal_check(const zc_owned_fifo_handler_matching_status_t& this_) { return zc_internal_fifo_handler_matching_status_check(&this_); };
 inline bool z_internal_check(const zc_owned_matching_listener_t& this_) { return zc_internal_matching_listener_check(&this_); };
+inline bool z_internal_check(const zc_owned_metrics_subscriber_t& this_) { return zc_internal_metrics_subscriber_check(&this_); };
+inline bool z_internal_check(const zc_owned_ring_handler_matching_status_t& this_) { return zc_internal_ring_handler_matching_status_check(&this_); };
 inline bool z_internal_check(const zc_owned_shm_client_list_t& this_) { return zc_internal_shm_client_list_check(&this_); };
+inline bool z_internal_check(const zc_owned_stream_t& this_) { return zc_internal_stream_check(&this_); };
+inline bool z_internal_check(const zc_owned_stream_listener_t& this_) { return zc_internal_stream_listener_check(&this_); };
+inline bool z_internal_check(const zc_owned_watch_t& this_) { return zc_internal_watch_check(&this_); };
 inline bool z_internal_check(const ze_owned_advanced_publisher_t& this_) { return ze_internal_advanced_publisher_check(&this_); };
 inline bool z_internal_check(const ze_owned_advanced_subscriber_t& this_) { return ze_internal_advanced_subscriber_check(&this_); };
+inline bool z_internal_check(const ze_owned_closure_history_end_t& this_) { return ze_internal_closure_history_end_check(&this_); };
 inline bool z_internal_check(const ze_owned_closure_miss_t& this_) { return ze_internal_closure_miss_check(&this_); };
 inline bool z_internal_check(const ze_owned_publication_cache_t& this_) { return ze_internal_publication_cache_check(&this_); };
 inline bool z_internal_check(const ze_owned_querying_subscriber_t& this_) { return ze_internal_querying_subscriber_check(&this_); };
@@ -1156,6 +1526,15 @@ inline void z_call(const z_loaned_closure_zid_t* closure, const z_id_t* z_id) {
 inline void z_call(const zc_loaned_closure_matching_status_t* closure, const zc_matching_status_t* mathing_status) {
     zc_closure_matching_status_call(closure, mathing_status);
 };
+inline void z_call(const zc_loaned_closure_metrics_t* closure, const zc_metrics_t* metrics) {
+    zc_closure_metrics_call(closure, metrics);
+};
+inline void z_call(const zc_loaned_closure_query_diagnostics_t* closure, const zc_query_diagnostics_t* diagnostics) {
+    zc_closure_query_diagnostics_call(closure, diagnostics);
+};
+inline void z_call(const zc_loaned_closure_table_list_t* closure, z_loaned_string_array_t* keys) {
+    zc_closure_table_list_call(closure, keys);
+};
 inline void z_call(const ze_loaned_closure_miss_t* closure, const ze_miss_t* mathing_status) {
     ze_closure_miss_call(closure, mathing_status);
 };
@@ -1166,8 +1545,20 @@ extern "C" using z_closure_query_callback_t = void(z_loaned_query_t *query, void
 extern "C" using z_closure_reply_callback_t = void(z_loaned_reply_t *reply, void *context);
 extern "C" using z_closure_sample_callback_t = void(z_loaned_sample_t *sample, void *context);
 extern "C" using z_closure_zid_callback_t = void(const z_id_t *z_id, void *context);
+extern "C" using zc_closure_key_stats_callback_t = void(const z_loaned_keyexpr_t *key_expr, uint64_t samples, void *context);
+extern "C" using zc_closure_keyexpr_interceptor_callback_t = z_result_t(const z_loaned_keyexpr_t *key_expr, z_owned_keyexpr_t *rewritten, void *context);
 extern "C" using zc_closure_log_callback_t = void(zc_log_severity_t severity, const z_loaned_string_t *msg, void *context);
+extern "C" using zc_closure_log_record_callback_t = void(zc_log_severity_t severity, const z_loaned_string_t *target, const z_loaned_string_t *msg, void *context);
 extern "C" using zc_closure_matching_status_callback_t = void(const zc_matching_status_t *matching_status, void *context);
+extern "C" using zc_closure_metrics_callback_t = void(const zc_metrics_t *metrics, void *context);
+extern "C" using zc_closure_payload_transform_callback_t = void(const z_loaned_bytes_t *payload, z_owned_bytes_t *transformed, void *context);
+extern "C" using zc_closure_query_diagnostics_callback_t = void(const zc_query_diagnostics_t *diagnostics, void *context);
+extern "C" using zc_closure_reply_entry_callback_t = bool(z_owned_keyexpr_t *key_expr, z_owned_bytes_t *payload, void *context);
+extern "C" using zc_closure_table_get_callback_t = bool(const z_loaned_string_t *key, z_owned_bytes_t *value, void *context);
+extern "C" using zc_closure_table_list_callback_t = void(z_loaned_string_array_t *keys, void *context);
+extern "C" using zc_closure_watch_callback_t = void(z_loaned_sample_t *sample, bool is_initial, void *context);
+extern "C" using zc_closure_watermark_callback_t = void(zc_watermark_t watermark, size_t occupancy, void *context);
+extern "C" using ze_closure_history_end_callback_t = void(void *context);
 extern "C" using ze_closure_miss_callback_t = void(const ze_miss_t *matching_status, void *context);
 
 inline void z_closure(z_owned_closure_hello_t* this_, z_closure_hello_callback_t* call,
@@ -1190,14 +1581,62 @@ inline void z_closure(z_owned_closure_zid_t* this_, z_closure_zid_callback_t* ca
     z_closure_drop_callback_t* drop, void* context) {
     z_closure_zid(this_, call, drop, context);
 };
+inline void z_closure(zc_owned_closure_key_stats_t* this_, zc_closure_key_stats_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_key_stats(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_keyexpr_interceptor_t* this_, zc_closure_keyexpr_interceptor_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_keyexpr_interceptor(this_, call, drop, context);
+};
 inline void z_closure(zc_owned_closure_log_t* this_, zc_closure_log_callback_t* call,
     z_closure_drop_callback_t* drop, void* context) {
     zc_closure_log(this_, call, drop, context);
 };
+inline void z_closure(zc_owned_closure_log_record_t* this_, zc_closure_log_record_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_log_record(this_, call, drop, context);
+};
 inline void z_closure(zc_owned_closure_matching_status_t* this_, zc_closure_matching_status_callback_t* call,
     z_closure_drop_callback_t* drop, void* context) {
     zc_closure_matching_status(this_, call, drop, context);
 };
+inline void z_closure(zc_owned_closure_metrics_t* this_, zc_closure_metrics_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_metrics(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_payload_transform_t* this_, zc_closure_payload_transform_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_payload_transform(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_query_diagnostics_t* this_, zc_closure_query_diagnostics_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_query_diagnostics(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_reply_entry_t* this_, zc_closure_reply_entry_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_reply_entry(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_table_get_t* this_, zc_closure_table_get_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_table_get(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_table_list_t* this_, zc_closure_table_list_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_table_list(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_watch_t* this_, zc_closure_watch_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_watch(this_, call, drop, context);
+};
+inline void z_closure(zc_owned_closure_watermark_t* this_, zc_closure_watermark_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    zc_closure_watermark(this_, call, drop, context);
+};
+inline void z_closure(ze_owned_closure_history_end_t* this_, ze_closure_history_end_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    ze_closure_history_end(this_, call, drop, context);
+};
 inline void z_closure(ze_owned_closure_miss_t* this_, ze_closure_miss_callback_t* call,
     z_closure_drop_callback_t* drop, void* context) {
     ze_closure_miss(this_, call, drop, context);
@@ -1222,6 +1661,12 @@ inline z_result_t z_try_recv(const z_loaned_ring_handler_reply_t* this_, z_owned
 inline z_result_t z_try_recv(const z_loaned_ring_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_ring_handler_sample_try_recv(this_, sample);
 };
+inline z_result_t z_try_recv(const zc_loaned_fifo_handler_matching_status_t* this_, zc_matching_status_t* matching_status) {
+    return zc_fifo_handler_matching_status_try_recv(this_, matching_status);
+};
+inline z_result_t z_try_recv(const zc_loaned_ring_handler_matching_status_t* this_, zc_matching_status_t* matching_status) {
+    return zc_ring_handler_matching_status_try_recv(this_, matching_status);
+};
 
 
 inline z_result_t z_recv(const z_loaned_fifo_handler_query_t* this_, z_owned_query_t* query) {
@@ -1242,11 +1687,20 @@ inline z_result_t z_recv(const z_loaned_ring_handler_reply_t* this_, z_owned_rep
 inline z_result_t z_recv(const z_loaned_ring_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_ring_handler_sample_recv(this_, sample);
 };
+inline z_result_t z_recv(const zc_loaned_fifo_handler_matching_status_t* this_, zc_matching_status_t* matching_status) {
+    return zc_fifo_handler_matching_status_recv(this_, matching_status);
+};
+inline z_result_t z_recv(const zc_loaned_ring_handler_matching_status_t* this_, zc_matching_status_t* matching_status) {
+    return zc_ring_handler_matching_status_recv(this_, matching_status);
+};
 
 
 inline void z_clone(z_owned_bytes_t* dst, z_loaned_bytes_t* this_) {
     z_bytes_clone(dst, this_);
 };
+inline void z_clone(z_owned_cancellation_token_t* dst, z_loaned_cancellation_token_t* this_) {
+    z_cancellation_token_clone(dst, this_);
+};
 inline void z_clone(z_owned_config_t* dst, z_loaned_config_t* this_) {
     z_config_clone(dst, this_);
 };
@@ -1295,6 +1749,8 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_bytes_t> { typedef z_owned_b
 template<> struct z_owned_to_loaned_type_t<z_owned_bytes_t> { typedef z_loaned_bytes_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_bytes_writer_t> { typedef z_owned_bytes_writer_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_bytes_writer_t> { typedef z_loaned_bytes_writer_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_cancellation_token_t> { typedef z_owned_cancellation_token_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_cancellation_token_t> { typedef z_loaned_cancellation_token_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_hello_t> { typedef z_owned_closure_hello_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_hello_t> { typedef z_loaned_closure_hello_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_query_t> { typedef z_owned_closure_query_t type; };
@@ -1365,16 +1821,48 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_string_t> { typedef z_owned_
 template<> struct z_owned_to_loaned_type_t<z_owned_string_t> { typedef z_loaned_string_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_subscriber_t> { typedef z_owned_subscriber_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_subscriber_t> { typedef z_loaned_subscriber_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_key_stats_t> { typedef zc_owned_closure_key_stats_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_key_stats_t> { typedef zc_loaned_closure_key_stats_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_keyexpr_interceptor_t> { typedef zc_owned_closure_keyexpr_interceptor_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_keyexpr_interceptor_t> { typedef zc_loaned_closure_keyexpr_interceptor_t type; };
 template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_log_t> { typedef zc_owned_closure_log_t type; };
 template<> struct z_owned_to_loaned_type_t<zc_owned_closure_log_t> { typedef zc_loaned_closure_log_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_log_record_t> { typedef zc_owned_closure_log_record_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_log_record_t> { typedef zc_loaned_closure_log_record_t type; };
 template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_matching_status_t> { typedef zc_owned_closure_matching_status_t type; };
 template<> struct z_owned_to_loaned_type_t<zc_owned_closure_matching_status_t> { typedef zc_loaned_closure_matching_status_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_metrics_t> { typedef zc_owned_closure_metrics_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_metrics_t> { typedef zc_loaned_closure_metrics_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_payload_transform_t> { typedef zc_owned_closure_payload_transform_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_payload_transform_t> { typedef zc_loaned_closure_payload_transform_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_query_diagnostics_t> { typedef zc_owned_closure_query_diagnostics_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_query_diagnostics_t> { typedef zc_loaned_closure_query_diagnostics_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_reply_entry_t> { typedef zc_owned_closure_reply_entry_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_reply_entry_t> { typedef zc_loaned_closure_reply_entry_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_table_get_t> { typedef zc_owned_closure_table_get_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_table_get_t> { typedef zc_loaned_closure_table_get_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_table_list_t> { typedef zc_owned_closure_table_list_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_table_list_t> { typedef zc_loaned_closure_table_list_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_watch_t> { typedef zc_owned_closure_watch_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_watch_t> { typedef zc_loaned_closure_watch_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_closure_watermark_t> { typedef zc_owned_closure_watermark_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_closure_watermark_t> { typedef zc_loaned_closure_watermark_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_fifo_handler_matching_status_t> { typedef zc_owned_fifo_handler_matching_status_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_fifo_handler_matching_status_t> { typedef zc_loaned_fifo_handler_matching_status_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_ring_handler_matching_status_t> { typedef zc_owned_ring_handler_matching_status_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_ring_handler_matching_status_t> { typedef zc_loaned_ring_handler_matching_status_t type; };
 template<> struct z_loaned_to_owned_type_t<zc_loaned_shm_client_list_t> { typedef zc_owned_shm_client_list_t type; };
 template<> struct z_owned_to_loaned_type_t<zc_owned_shm_client_list_t> { typedef zc_loaned_shm_client_list_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_stream_listener_t> { typedef zc_owned_stream_listener_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_stream_listener_t> { typedef zc_loaned_stream_listener_t type; };
+template<> struct z_loaned_to_owned_type_t<zc_loaned_stream_t> { typedef zc_owned_stream_t type; };
+template<> struct z_owned_to_loaned_type_t<zc_owned_stream_t> { typedef zc_loaned_stream_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_advanced_publisher_t> { typedef ze_owned_advanced_publisher_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_advanced_publisher_t> { typedef ze_loaned_advanced_publisher_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_advanced_subscriber_t> { typedef ze_owned_advanced_subscriber_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_advanced_subscriber_t> { typedef ze_loaned_advanced_subscriber_t type; };
+template<> struct z_loaned_to_owned_type_t<ze_loaned_closure_history_end_t> { typedef ze_owned_closure_history_end_t type; };
+template<> struct z_owned_to_loaned_type_t<ze_owned_closure_history_end_t> { typedef ze_loaned_closure_history_end_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_closure_miss_t> { typedef ze_owned_closure_miss_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_closure_miss_t> { typedef ze_loaned_closure_miss_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_publication_cache_t> { typedef ze_owned_publication_cache_t type; };
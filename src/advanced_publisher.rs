@@ -12,7 +12,7 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, sync::Arc};
 
 use zenoh::{
     handlers::Callback,
@@ -24,13 +24,22 @@ use zenoh::{
 use zenoh_ext::{AdvancedPublisherBuilderExt, CacheConfig};
 
 use crate::{
-    _apply_pubisher_delete_options, _apply_pubisher_put_options, _declare_publisher_inner,
+    _apply_payload_transform, _apply_pubisher_delete_options, _apply_pubisher_put_options,
+    _declare_publisher_inner, _matching_status_callback, _release_publisher_options,
+    _release_publisher_put_options, _take_payload_transform, _take_put_attachment,
+    entity_limits::{EntityKind, EntitySlot},
+    keyexpr_interceptor,
+    matching::DependentListeners,
+    payload_limits,
     result::{self},
-    transmute::{IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{
+        moved_or_null, IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_congestion_control_t, z_entity_global_id_t, z_loaned_keyexpr_t, z_loaned_session_t,
     z_moved_bytes_t, z_priority_t, z_publisher_delete_options_t, z_publisher_options_t,
-    z_publisher_put_options_t, zc_closure_matching_status_call, zc_closure_matching_status_loan,
-    zc_matching_status_t, zc_moved_closure_matching_status_t, zc_owned_matching_listener_t,
+    z_publisher_put_options_t, zc_matching_status_t, zc_moved_closure_matching_status_t,
+    zc_owned_closure_payload_transform_t, zc_owned_matching_listener_t, MatchingListenerState,
+    Stateful,
 };
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -123,8 +132,20 @@ pub extern "C" fn ze_advanced_publisher_options_default(
 pub use crate::opaque_types::{
     ze_loaned_advanced_publisher_t, ze_moved_advanced_publisher_t, ze_owned_advanced_publisher_t,
 };
+
+/// The state kept by zenoh-c for an advanced publisher.
+pub(crate) struct AdvancedPublisherState {
+    _slot: EntitySlot,
+    payload_transform: Option<zc_owned_closure_payload_transform_t>,
+    /// The maximum size of the publications, resolved from the session limits when the publisher is declared.
+    max_payload_size: usize,
+    listeners: DependentListeners,
+}
+
+pub(crate) type CAdvancedPublisher =
+    Stateful<zenoh_ext::AdvancedPublisher<'static>, AdvancedPublisherState>;
 decl_c_type!(
-    owned(ze_owned_advanced_publisher_t, option zenoh_ext::AdvancedPublisher<'static>),
+    owned(ze_owned_advanced_publisher_t, option CAdvancedPublisher),
     loaned(ze_loaned_advanced_publisher_t),
 );
 
@@ -149,11 +170,37 @@ pub extern "C" fn ze_declare_advanced_publisher(
     mut options: Option<&'static mut ze_advanced_publisher_options_t>,
 ) -> result::z_result_t {
     let this = publisher.as_rust_type_mut_uninit();
-    let p = _declare_publisher_inner(
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Publisher,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            _release_publisher_options(options.map(|o| &mut o.publisher_options));
+            this.write(None);
+            return e;
+        }
+    };
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    let payload_transform =
+        _take_payload_transform(options.as_mut().map(|o| &mut o.publisher_options));
+    let allow_fragmentation = options
+        .as_ref()
+        .map_or(true, |o| o.publisher_options.allow_fragmentation);
+    let p = match _declare_publisher_inner(
         session,
         key_expr,
         options.as_mut().map(|o| &mut o.publisher_options),
-    );
+    ) {
+        Ok(p) => p,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     let mut p = p.advanced();
     if let Some(options) = options {
         if options.publisher_detection {
@@ -176,7 +223,17 @@ pub extern "C" fn ze_declare_advanced_publisher(
             result::Z_EGENERIC
         }
         Ok(publisher) => {
-            this.write(Some(publisher));
+            let state = AdvancedPublisherState {
+                _slot: slot,
+                payload_transform,
+                max_payload_size: session
+                    .as_rust_type_ref()
+                    .state()
+                    .payload_limits
+                    .max_payload_size(publisher.key_expr(), allow_fragmentation),
+                listeners: DependentListeners::for_entity(session.as_rust_type_ref()),
+            };
+            this.write(Some(CAdvancedPublisher::new(publisher, state)));
             result::Z_OK
         }
     }
@@ -258,17 +315,38 @@ pub extern "C" fn ze_advanced_publisher_put_options_default(
 /// @param payload: The data to publish. Will be consumed.
 /// @param options: The advanced publisher put options. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, negative error values in case of failure.
+/// @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, `Z_EFRAGMENT` if the publication exceeds the maximum
+/// payload size of the publisher (see `zc_publisher_max_payload_size()`), negative error values in case of failure.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn ze_advanced_publisher_put(
     this: &ze_loaned_advanced_publisher_t,
-    payload: &mut z_moved_bytes_t,
-    options: Option<&mut ze_advanced_publisher_put_options_t>,
+    payload: Option<&mut z_moved_bytes_t>,
+    mut options: Option<&mut ze_advanced_publisher_put_options_t>,
 ) -> result::z_result_t {
+    let Some(payload) = moved_or_null(payload, "ze_advanced_publisher_put") else {
+        _release_publisher_put_options(options.map(|o| &mut o.put_options));
+        return result::Z_EINVAL;
+    };
     let publisher = this.as_rust_type_ref();
-    let payload = payload.take_rust_type();
+    let payload = _apply_payload_transform(
+        publisher.state().payload_transform.as_ref(),
+        payload.take_rust_type(),
+    );
+    let attachment = _take_put_attachment(options.as_mut().map(|o| &mut o.put_options));
+    let res = payload_limits::check_publication_size(
+        publisher.state().max_payload_size,
+        &payload,
+        attachment.as_ref(),
+    );
+    if res != result::Z_OK {
+        _release_publisher_put_options(options.map(|o| &mut o.put_options));
+        return res;
+    }
     let mut put = publisher.put(payload);
+    if let Some(attachment) = attachment {
+        put = put.attachment(attachment);
+    }
     if let Some(options) = options {
         put = _apply_pubisher_put_options(put, &mut options.put_options);
     }
@@ -351,17 +429,13 @@ pub extern "C" fn ze_advanced_publisher_keyexpr(
 fn _advanced_publisher_matching_listener_declare_inner<'a>(
     publisher: &'a ze_loaned_advanced_publisher_t,
     callback: &mut zc_moved_closure_matching_status_t,
+    state: Arc<MatchingListenerState>,
 ) -> zenoh::matching::MatchingListenerBuilder<'a, Callback<MatchingStatus>> {
     let publisher = publisher.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let listener = publisher
         .matching_listener()
-        .callback_mut(move |matching_status| {
-            let status = zc_matching_status_t {
-                matching: matching_status.matching(),
-            };
-            zc_closure_matching_status_call(zc_closure_matching_status_loan(&callback), &status);
-        });
+        .callback_mut(_matching_status_callback(callback, state));
     listener
 }
 
@@ -373,18 +447,30 @@ fn _advanced_publisher_matching_listener_declare_inner<'a>(
 /// @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the publisher is dropped.
 /// @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_publisher_declare_matching_listener(
     publisher: &'static ze_loaned_advanced_publisher_t,
     matching_listener: &mut MaybeUninit<zc_owned_matching_listener_t>,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
     let this = matching_listener.as_rust_type_mut_uninit();
-    let listener = _advanced_publisher_matching_listener_declare_inner(publisher, callback);
+    let Some(callback) = moved_or_null(callback, "ze_advanced_publisher_declare_matching_listener")
+    else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let state = Arc::<MatchingListenerState>::default();
+    let listener =
+        _advanced_publisher_matching_listener_declare_inner(publisher, callback, state.clone());
     match listener.wait() {
         Ok(listener) => {
-            this.write(Some(listener));
+            publisher
+                .as_rust_type_ref()
+                .state()
+                .listeners
+                .register(&state);
+            this.write(Some((listener, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -403,13 +489,20 @@ pub extern "C" fn ze_advanced_publisher_declare_matching_listener(
 /// @param publisher: An advanced publisher to associate with matching listener.
 /// @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_publisher_declare_background_matching_listener(
     publisher: &'static ze_loaned_advanced_publisher_t,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
-    let listener = _advanced_publisher_matching_listener_declare_inner(publisher, callback);
+    let Some(callback) = moved_or_null(
+        callback,
+        "ze_advanced_publisher_declare_background_matching_listener",
+    ) else {
+        return result::Z_EINVAL;
+    };
+    let listener =
+        _advanced_publisher_matching_listener_declare_inner(publisher, callback, Arc::default());
     match listener.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -449,8 +542,15 @@ pub extern "C" fn ze_advanced_publisher_get_matching_status(
 /// This is equivalent to calling `z_undeclare_publisher()` and discarding its return value.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn ze_advanced_publisher_drop(this: &mut ze_moved_advanced_publisher_t) {
-    std::mem::drop(this.take_rust_type())
+pub extern "C" fn ze_advanced_publisher_drop(this: Option<&mut ze_moved_advanced_publisher_t>) {
+    let Some(this) = moved_or_null(this, "ze_advanced_publisher_drop") else {
+        return;
+    };
+    let publisher = this.take_rust_type();
+    if let Some(p) = &publisher {
+        p.state().listeners.orphan();
+    }
+    std::mem::drop(publisher)
 }
 
 #[no_mangle]
@@ -459,9 +559,15 @@ pub extern "C" fn ze_advanced_publisher_drop(this: &mut ze_moved_advanced_publis
 ///
 /// @return 0 in case of success, negative error code otherwise.
 pub extern "C" fn ze_undeclare_advanced_publisher(
-    this_: &mut ze_moved_advanced_publisher_t,
+    this_: Option<&mut ze_moved_advanced_publisher_t>,
 ) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "ze_undeclare_advanced_publisher") else {
+        return result::Z_OK;
+    };
     if let Some(p) = this_.take_rust_type() {
+        p.state().listeners.orphan();
+        // the state of the publisher is dropped once it is undeclared
+        let (p, _state) = p.into_parts();
         if let Err(e) = p.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_ENETWORK;
@@ -14,18 +14,26 @@
 
 use std::{mem::MaybeUninit, time::Duration};
 
-use zenoh::{handlers::Callback, liveliness::LivelinessSubscriberBuilder, sample::Sample, Wait};
+use zenoh::{
+    handlers::Callback, key_expr::KeyExpr, liveliness::LivelinessSubscriberBuilder, sample::Sample,
+    Wait,
+};
 use zenoh_ext::{AdvancedSubscriberBuilderExt, HistoryConfig, RecoveryConfig, SampleMissListener};
 
 use crate::{
-    _declare_subscriber_inner, result,
-    transmute::{IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    _declare_subscriber_inner, _release_subscriber_options,
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor, result,
+    transmute::{
+        moved_or_null, IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_closure_sample_call, z_closure_sample_loan, z_entity_global_id_t,
     z_liveliness_subscriber_options_t, z_loaned_keyexpr_t, z_loaned_session_t,
-    z_moved_closure_sample_t, z_owned_subscriber_t, z_subscriber_options_t, ze_closure_miss_call,
-    ze_closure_miss_loan, ze_loaned_advanced_subscriber_t, ze_moved_advanced_subscriber_t,
-    ze_moved_closure_miss_t, ze_moved_sample_miss_listener_t, ze_owned_advanced_subscriber_t,
-    ze_owned_sample_miss_listener_t,
+    z_moved_closure_sample_t, z_owned_closure_sample_t, z_owned_subscriber_t,
+    z_subscriber_options_t, ze_closure_miss_call, ze_closure_miss_loan,
+    ze_loaned_advanced_subscriber_t, ze_moved_advanced_subscriber_t, ze_moved_closure_miss_t,
+    ze_moved_sample_miss_listener_t, ze_owned_advanced_subscriber_t,
+    ze_owned_sample_miss_listener_t, CSubscriber, SubscriberState,
 };
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -168,18 +176,48 @@ pub extern "C" fn ze_advanced_subscriber_options_default(
     });
 }
 
-fn _declare_advanced_subscriber_inner(
-    session: &'static z_loaned_session_t,
-    key_expr: &'static z_loaned_keyexpr_t,
+/// Admits the declaration of an advanced subscriber on the session.
+///
+/// Returns the key expression rewritten by the interceptor of the session, if any, along with the callback holding
+/// the slot of the subscriber.
+fn _admit_advanced_subscriber(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_sample_t,
+    options: Option<&mut ze_advanced_subscriber_options_t>,
+) -> Result<(Option<KeyExpr<'static>>, z_owned_closure_sample_t), result::z_result_t> {
+    let callback = callback.take_rust_type();
+    match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Subscriber,
+    ) {
+        Ok((intercepted, slot)) => Ok((
+            intercepted,
+            entity_limits::hold_in_sample_callback(slot, callback),
+        )),
+        Err(e) => {
+            _release_subscriber_options(options.map(|o| &mut o.subscriber_options));
+            Err(e)
+        }
+    }
+}
+
+fn _declare_advanced_subscriber_inner<'b>(
+    session: &'static z_loaned_session_t,
+    key_expr: &'b z_loaned_keyexpr_t,
+    callback: z_owned_closure_sample_t,
     mut options: Option<&'static mut ze_advanced_subscriber_options_t>,
-) -> zenoh_ext::AdvancedSubscriberBuilder<'static, 'static, 'static, Callback<Sample>> {
+) -> Result<
+    zenoh_ext::AdvancedSubscriberBuilder<'static, 'b, 'static, Callback<Sample>>,
+    result::z_result_t,
+> {
     let sub = _declare_subscriber_inner(
         session,
         key_expr,
         callback,
         options.as_mut().map(|o| &mut o.subscriber_options),
-    );
+    )?;
     let mut sub = sub.advanced();
     if let Some(options) = options {
         if options.query_timeout_ms > 0 {
@@ -198,7 +236,7 @@ fn _declare_advanced_subscriber_inner(
             sub = sub.recovery((&options.recovery).into());
         }
     }
-    sub
+    Ok(sub)
 }
 
 decl_c_type!(
@@ -230,7 +268,10 @@ pub unsafe extern "C" fn ze_advanced_subscriber_loan(
 /// Undeclares advanced subscriber callback and resets it to its gravestone state.
 /// This is equivalent to calling `ze_undeclare_advanced_subscriber()` and discarding its return value.
 #[no_mangle]
-pub extern "C" fn ze_advanced_subscriber_drop(this_: &mut ze_moved_advanced_subscriber_t) {
+pub extern "C" fn ze_advanced_subscriber_drop(this_: Option<&mut ze_moved_advanced_subscriber_t>) {
+    let Some(this_) = moved_or_null(this_, "ze_advanced_subscriber_drop") else {
+        return;
+    };
     std::mem::drop(this_.take_rust_type())
 }
 
@@ -250,17 +291,39 @@ pub extern "C" fn ze_internal_advanced_subscriber_check(
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: The options to be passed to the subscriber declaration.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case subscriber will be in its gravestone state).
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case subscriber will be in its gravestone state).
 #[no_mangle]
 pub extern "C" fn ze_declare_advanced_subscriber(
     session: &'static z_loaned_session_t,
     subscriber: &'static mut MaybeUninit<ze_owned_advanced_subscriber_t>,
     key_expr: &'static z_loaned_keyexpr_t,
-    callback: &'static mut z_moved_closure_sample_t,
-    options: Option<&'static mut ze_advanced_subscriber_options_t>,
+    callback: Option<&'static mut z_moved_closure_sample_t>,
+    mut options: Option<&'static mut ze_advanced_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = subscriber.as_rust_type_mut_uninit();
-    let s = _declare_advanced_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "ze_declare_advanced_subscriber") else {
+        _release_subscriber_options(options.map(|o| &mut o.subscriber_options));
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let (intercepted, callback) =
+        match _admit_advanced_subscriber(session, key_expr, callback, options.as_deref_mut()) {
+            Ok(admitted) => admitted,
+            Err(e) => {
+                this.write(None);
+                return e;
+            }
+        };
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    let s = match _declare_advanced_subscriber_inner(session, key_expr, callback, options) {
+        Ok(s) => s,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     match s.wait() {
         Ok(sub) => {
             this.write(Some(sub));
@@ -282,15 +345,32 @@ pub extern "C" fn ze_declare_advanced_subscriber(
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: The options to be passed to the subscriber declaration.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_declare_background_advanced_subscriber(
     session: &'static z_loaned_session_t,
     key_expr: &'static z_loaned_keyexpr_t,
-    callback: &'static mut z_moved_closure_sample_t,
-    options: Option<&'static mut ze_advanced_subscriber_options_t>,
+    callback: Option<&'static mut z_moved_closure_sample_t>,
+    mut options: Option<&'static mut ze_advanced_subscriber_options_t>,
 ) -> result::z_result_t {
-    let subscriber = _declare_advanced_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "ze_declare_background_advanced_subscriber")
+    else {
+        _release_subscriber_options(options.map(|o| &mut o.subscriber_options));
+        return result::Z_EINVAL;
+    };
+    let (intercepted, callback) =
+        match _admit_advanced_subscriber(session, key_expr, callback, options.as_deref_mut()) {
+            Ok(admitted) => admitted,
+            Err(e) => return e,
+        };
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    let subscriber = match _declare_advanced_subscriber_inner(session, key_expr, callback, options)
+    {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
     match subscriber.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -306,8 +386,11 @@ pub extern "C" fn ze_declare_background_advanced_subscriber(
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_undeclare_advanced_subscriber(
-    this_: &mut ze_moved_advanced_subscriber_t,
+    this_: Option<&mut ze_moved_advanced_subscriber_t>,
 ) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "ze_undeclare_advanced_subscriber") else {
+        return result::Z_OK;
+    };
     if let Some(s) = this_.take_rust_type() {
         if let Err(e) = s.undeclare().wait() {
             tracing::error!("{}", e);
@@ -353,7 +436,10 @@ pub extern "C" fn ze_internal_sample_miss_listener_check(
 /// @brief Undeclares the given sample miss listener, droping and invalidating it.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn ze_sample_miss_listener_drop(this: &mut ze_moved_sample_miss_listener_t) {
+pub extern "C" fn ze_sample_miss_listener_drop(this: Option<&mut ze_moved_sample_miss_listener_t>) {
+    let Some(this) = moved_or_null(this, "ze_sample_miss_listener_drop") else {
+        return;
+    };
     std::mem::drop(this.take_rust_type())
 }
 
@@ -363,8 +449,11 @@ pub extern "C" fn ze_sample_miss_listener_drop(this: &mut ze_moved_sample_miss_l
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub extern "C" fn ze_undeclare_sample_miss_listener(
-    this: &mut ze_moved_sample_miss_listener_t,
+    this: Option<&mut ze_moved_sample_miss_listener_t>,
 ) -> result::z_result_t {
+    let Some(this) = moved_or_null(this, "ze_undeclare_sample_miss_listener") else {
+        return result::Z_OK;
+    };
     if let Some(m) = this.take_rust_type() {
         if let Err(e) = m.undeclare().wait() {
             tracing::error!("{}", e);
@@ -397,14 +486,21 @@ fn _advanced_subscriber_sample_miss_listener_declare_inner<'a>(
 /// @param sample_miss_listener: An uninitialized memory location where sample miss listener will be constructed. The sample miss listener's callback will be automatically dropped when the subscriber is dropped.
 /// @param callback: A closure that will be called every time the sample miss is detected.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_subscriber_declare_sample_miss_listener(
     subscriber: &'static ze_loaned_advanced_subscriber_t,
     sample_miss_listener: &mut MaybeUninit<ze_owned_sample_miss_listener_t>,
-    callback: &mut ze_moved_closure_miss_t,
+    callback: Option<&mut ze_moved_closure_miss_t>,
 ) -> result::z_result_t {
     let this = sample_miss_listener.as_rust_type_mut_uninit();
+    let Some(callback) = moved_or_null(
+        callback,
+        "ze_advanced_subscriber_declare_sample_miss_listener",
+    ) else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
     let listener = _advanced_subscriber_sample_miss_listener_declare_inner(subscriber, callback);
     match listener.wait() {
         Ok(listener) => {
@@ -426,12 +522,18 @@ pub extern "C" fn ze_advanced_subscriber_declare_sample_miss_listener(
 /// @param subscriber: A subscriber to associate with sample miss listener.
 /// @param callback: A closure that will be called every time the sample miss is detected.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_subscriber_declare_background_sample_miss_listener(
     subscriber: &'static ze_loaned_advanced_subscriber_t,
-    callback: &mut ze_moved_closure_miss_t,
+    callback: Option<&mut ze_moved_closure_miss_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(
+        callback,
+        "ze_advanced_subscriber_declare_background_sample_miss_listener",
+    ) else {
+        return result::Z_EINVAL;
+    };
     let listener = _advanced_subscriber_sample_miss_listener_declare_inner(subscriber, callback);
     match listener.background().wait() {
         Ok(_) => result::Z_OK,
@@ -472,19 +574,23 @@ fn _advanced_subscriber_detect_publishers_inner(
 /// @param callback: The callback function that will be called each time a liveliness token status is changed.
 /// @param options: The options to be passed to the liveliness subscriber declaration.
 ///
-/// @return 0 in case of success, negative error values otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_subscriber_detect_publishers(
     subscriber: &'static ze_loaned_advanced_subscriber_t,
     liveliness_subscriber: &mut MaybeUninit<z_owned_subscriber_t>,
-    callback: &'static mut z_moved_closure_sample_t,
+    callback: Option<&'static mut z_moved_closure_sample_t>,
     options: Option<&'static mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
     let liveliness_subscriber = liveliness_subscriber.as_rust_type_mut_uninit();
+    let Some(callback) = moved_or_null(callback, "ze_advanced_subscriber_detect_publishers") else {
+        liveliness_subscriber.write(None);
+        return result::Z_EINVAL;
+    };
     let builder = _advanced_subscriber_detect_publishers_inner(subscriber, callback, options);
     match builder.wait() {
         Ok(s) => {
-            liveliness_subscriber.write(Some(s));
+            liveliness_subscriber.write(Some(CSubscriber::new(s, SubscriberState::default())));
             result::Z_OK
         }
         Err(e) => {
@@ -502,13 +608,19 @@ pub extern "C" fn ze_advanced_subscriber_detect_publishers(
 /// @param callback: The callback function that will be called each time a liveliness token status is changed.
 /// @param options: The options to be passed to the liveliness subscriber declaration.
 ///
-/// @return 0 in case of success, negative error values otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
 #[no_mangle]
 pub extern "C" fn ze_advanced_subscriber_detect_publishers_background(
     subscriber: &'static ze_loaned_advanced_subscriber_t,
-    callback: &'static mut z_moved_closure_sample_t,
+    callback: Option<&'static mut z_moved_closure_sample_t>,
     options: Option<&'static mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(
+        callback,
+        "ze_advanced_subscriber_detect_publishers_background",
+    ) else {
+        return result::Z_EINVAL;
+    };
     let builder = _advanced_subscriber_detect_publishers_inner(subscriber, callback, options);
     match builder.background().wait() {
         Ok(_) => result::Z_OK,
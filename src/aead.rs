@@ -0,0 +1,186 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{mem::MaybeUninit, slice::from_raw_parts, str::FromStr};
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305};
+use unwrap_infallible::UnwrapInfallible;
+use zenoh::bytes::{Encoding, ZBytes};
+
+use crate::{
+    result::{self, z_result_t},
+    transmute::{RustTypeRef, RustTypeRefUninit},
+    z_loaned_bytes_t, z_loaned_encoding_t, z_owned_bytes_t,
+};
+
+/// Suffix appended to the encoding of the payloads encrypted with `zc_bytes_encrypt()`.
+const ENCRYPTED_ENCODING_SUFFIX: &str = ";aead=chacha20-poly1305";
+
+unsafe fn aead_key_and_nonce(
+    key: *const u8,
+    key_len: usize,
+    nonce: *const u8,
+    nonce_len: usize,
+) -> Result<(LessSafeKey, Nonce), z_result_t> {
+    if key.is_null() || nonce.is_null() {
+        tracing::error!("Encryption key and nonce should not be null");
+        return Err(result::Z_EINVAL);
+    }
+    let key = UnboundKey::new(&CHACHA20_POLY1305, from_raw_parts(key, key_len)).map_err(|_| {
+        tracing::error!(
+            "Invalid encryption key length {}, expected {}",
+            key_len,
+            CHACHA20_POLY1305.key_len()
+        );
+        result::Z_EINVAL
+    })?;
+    let nonce =
+        Nonce::try_assume_unique_for_key(from_raw_parts(nonce, nonce_len)).map_err(|_| {
+            tracing::error!(
+                "Invalid encryption nonce length {}, expected {}",
+                nonce_len,
+                CHACHA20_POLY1305.nonce_len()
+            );
+            result::Z_EINVAL
+        })?;
+    Ok((LessSafeKey::new(key), nonce))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Encrypts data with the ChaCha20-Poly1305 AEAD cipher.
+///
+/// The authentication tag is appended to the encrypted data. The same nonce must never be used twice with the same key.
+/// Encrypted payloads should be published with an encoding marked by `zc_encoding_set_encrypted()`.
+///
+/// @param this_: Data to encrypt.
+/// @param key: A pointer to the 32 bytes encryption key.
+/// @param key_len: Length of the key.
+/// @param nonce: A pointer to the 12 bytes nonce.
+/// @param nonce_len: Length of the nonce.
+/// @param dst: An uninitialized memory location where the encrypted data will be constructed.
+/// @return 0 in case of success, `Z_EINVAL` if key or nonce are invalid, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_bytes_encrypt(
+    this: &z_loaned_bytes_t,
+    key: *const u8,
+    key_len: usize,
+    nonce: *const u8,
+    nonce_len: usize,
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+) -> z_result_t {
+    let dst = dst.as_rust_type_mut_uninit();
+    let (key, nonce) = match aead_key_and_nonce(key, key_len, nonce, nonce_len) {
+        Ok(v) => v,
+        Err(e) => {
+            dst.write(ZBytes::default());
+            return e;
+        }
+    };
+    let mut data = this.as_rust_type_ref().to_bytes().into_owned();
+    match key.seal_in_place_append_tag(nonce, Aad::empty(), &mut data) {
+        Ok(()) => {
+            dst.write(ZBytes::from(data));
+            result::Z_OK
+        }
+        Err(_) => {
+            tracing::error!("Failed to encrypt data");
+            dst.write(ZBytes::default());
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Decrypts and authenticates data encrypted by `zc_bytes_encrypt()`.
+///
+/// @param this_: Data to decrypt.
+/// @param key: A pointer to the 32 bytes encryption key.
+/// @param key_len: Length of the key.
+/// @param nonce: A pointer to the 12 bytes nonce used for encryption.
+/// @param nonce_len: Length of the nonce.
+/// @param dst: An uninitialized memory location where the decrypted data will be constructed.
+/// @return 0 in case of success, `Z_EINVAL` if key or nonce are invalid, `Z_EDESERIALIZE` if the data could not be authenticated.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_bytes_decrypt(
+    this: &z_loaned_bytes_t,
+    key: *const u8,
+    key_len: usize,
+    nonce: *const u8,
+    nonce_len: usize,
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+) -> z_result_t {
+    let dst = dst.as_rust_type_mut_uninit();
+    let (key, nonce) = match aead_key_and_nonce(key, key_len, nonce, nonce_len) {
+        Ok(v) => v,
+        Err(e) => {
+            dst.write(ZBytes::default());
+            return e;
+        }
+    };
+    let mut data = this.as_rust_type_ref().to_bytes().into_owned();
+    match key.open_in_place(nonce, Aad::empty(), &mut data) {
+        Ok(plain) => {
+            let len = plain.len();
+            data.truncate(len);
+            dst.write(ZBytes::from(data));
+            result::Z_OK
+        }
+        Err(_) => {
+            tracing::error!("Failed to decrypt data: authentication failed");
+            dst.write(ZBytes::default());
+            result::Z_EDESERIALIZE
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Marks the encoding as the one of an encrypted payload, by appending `;aead=chacha20-poly1305` suffix to it.
+/// Marking an already marked encoding is a no-op.
+#[no_mangle]
+pub extern "C" fn zc_encoding_set_encrypted(this_: &mut z_loaned_encoding_t) {
+    let encoding = this_.as_rust_type_mut();
+    let s = encoding.to_string();
+    if !s.ends_with(ENCRYPTED_ENCODING_SUFFIX) {
+        *encoding = Encoding::from_str(&(s + ENCRYPTED_ENCODING_SUFFIX)).unwrap_infallible();
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Removes the encrypted payload mark set by `zc_encoding_set_encrypted()` from the encoding, restoring the original one.
+///
+/// @return ``true`` if the encoding was marked as encrypted, ``false`` otherwise (the encoding is not modified in this case).
+#[no_mangle]
+pub extern "C" fn zc_encoding_unset_encrypted(this_: &mut z_loaned_encoding_t) -> bool {
+    let encoding = this_.as_rust_type_mut();
+    let s = encoding.to_string();
+    match s.strip_suffix(ENCRYPTED_ENCODING_SUFFIX) {
+        Some(original) => {
+            *encoding = Encoding::from_str(original).unwrap_infallible();
+            true
+        }
+        None => false,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the encoding is marked as the one of an encrypted payload, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn zc_encoding_is_encrypted(this_: &z_loaned_encoding_t) -> bool {
+    this_
+        .as_rust_type_ref()
+        .to_string()
+        .ends_with(ENCRYPTED_ENCODING_SUFFIX)
+}
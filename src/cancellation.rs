@@ -0,0 +1,205 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
+
+pub use crate::opaque_types::{
+    z_loaned_cancellation_token_t, z_moved_cancellation_token_t, z_owned_cancellation_token_t,
+};
+use crate::{
+    result,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_closure_reply_call, z_closure_reply_loan, z_loaned_reply_t, z_owned_closure_reply_t,
+};
+
+/// The reply callback of a query, taken out on cancellation.
+type ReplyCallbackSlot = Mutex<Option<Arc<z_owned_closure_reply_t>>>;
+
+/// The state shared by all the copies of a cancellation token.
+#[derive(Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+    callbacks: Mutex<Vec<Weak<ReplyCallbackSlot>>>,
+}
+
+impl CancellationToken {
+    /// Registers the reply callback to drop on cancellation, returns `false` if the token is already cancelled.
+    fn register(&self, slot: &Arc<ReplyCallbackSlot>) -> bool {
+        let Ok(mut callbacks) = self.callbacks.lock() else {
+            return false;
+        };
+        if self.cancelled.load(Ordering::SeqCst) {
+            return false;
+        }
+        // forget the callbacks of the completed queries
+        callbacks.retain(|c| c.strong_count() > 0);
+        callbacks.push(Arc::downgrade(slot));
+        true
+    }
+
+    fn cancel(&self) -> Result<(), result::z_result_t> {
+        let callbacks = match self.callbacks.lock() {
+            Ok(mut callbacks) => {
+                self.cancelled.store(true, Ordering::SeqCst);
+                std::mem::take(&mut *callbacks)
+            }
+            Err(_) => return Err(result::Z_EGENERIC),
+        };
+        for slot in callbacks.iter().filter_map(Weak::upgrade) {
+            let callback = slot.lock().ok().and_then(|mut c| c.take());
+            // the callback is dropped once the replies being processed are done with it
+            std::mem::drop(callback);
+        }
+        Ok(())
+    }
+}
+
+/// A reply callback which stops receiving replies, and is dropped, when its cancellation token is cancelled.
+pub(crate) struct CancellableReplyCallback {
+    slot: Arc<ReplyCallbackSlot>,
+}
+
+impl CancellableReplyCallback {
+    pub(crate) fn new(callback: z_owned_closure_reply_t, token: &CancellationToken) -> Self {
+        let slot = Arc::new(Mutex::new(Some(Arc::new(callback))));
+        if !token.register(&slot) {
+            if let Ok(mut c) = slot.lock() {
+                c.take();
+            }
+        }
+        CancellableReplyCallback { slot }
+    }
+
+    pub(crate) fn call(&self, reply: &mut z_loaned_reply_t) {
+        // the lock is not held while running the callback, so that it can cancel the query
+        let callback = self.slot.lock().ok().and_then(|c| c.clone());
+        if let Some(callback) = callback {
+            z_closure_reply_call(z_closure_reply_loan(&callback), reply);
+        }
+    }
+}
+
+impl Drop for CancellableReplyCallback {
+    fn drop(&mut self) {
+        if let Ok(mut c) = self.slot.lock() {
+            c.take();
+        }
+    }
+}
+
+decl_c_type!(
+    owned(z_owned_cancellation_token_t, option Arc<CancellationToken>),
+    loaned(z_loaned_cancellation_token_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a new cancellation token, which can be passed to `z_get()` to cancel the query.
+#[no_mangle]
+pub extern "C" fn z_cancellation_token_new(
+    this_: &mut MaybeUninit<z_owned_cancellation_token_t>,
+) -> result::z_result_t {
+    this_
+        .as_rust_type_mut_uninit()
+        .write(Some(Arc::new(CancellationToken::default())));
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a shallow copy of the cancellation token: cancelling any copy cancels all of them.
+#[no_mangle]
+pub extern "C" fn z_cancellation_token_clone(
+    dst: &mut MaybeUninit<z_owned_cancellation_token_t>,
+    this_: &z_loaned_cancellation_token_t,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(Some(this_.as_rust_type_ref().clone()));
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Cancels the queries the token was passed to.
+///
+/// Their reply callbacks will not be called anymore and are dropped, once the replies currently being processed
+/// are done with them. The token stays cancelled: the queries it is later passed to are cancelled right away.
+///
+/// Cancellation only suppresses the delivery of the replies, on the querying side: zenoh provides no way to withdraw
+/// a query, so it is still routed to the matching queryables, which keep processing it and replying until it is
+/// finalized or times out. These replies are discarded on reception.
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_cancellation_token_cancel(
+    this_: &z_loaned_cancellation_token_t,
+) -> result::z_result_t {
+    match this_.as_rust_type_ref().cancel() {
+        Ok(()) => result::Z_OK,
+        Err(e) => {
+            tracing::error!("Failed to cancel the cancellation token");
+            e
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the cancellation token was cancelled, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_cancellation_token_is_cancelled(this_: &z_loaned_cancellation_token_t) -> bool {
+    this_.as_rust_type_ref().cancelled.load(Ordering::SeqCst)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a cancellation token in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_cancellation_token_null(
+    this_: &mut MaybeUninit<z_owned_cancellation_token_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the cancellation token is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_cancellation_token_check(
+    this_: &z_owned_cancellation_token_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows the cancellation token.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_cancellation_token_loan(
+    this_: &z_owned_cancellation_token_t,
+) -> &z_loaned_cancellation_token_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the cancellation token, resetting it to its gravestone state. The queries it was passed to are not cancelled.
+#[no_mangle]
+pub extern "C" fn z_cancellation_token_drop(this_: Option<&mut z_moved_cancellation_token_t>) {
+    let Some(this_) = moved_or_null(this_, "z_cancellation_token_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
@@ -19,8 +19,8 @@ use zenoh_runtime::ZRuntime;
 #[cfg(feature = "unstable")]
 use crate::opaque_types::zc_owned_concurrent_close_handle_t;
 use crate::{
-    result::{z_result_t, Z_EIO, Z_OK},
-    transmute::{RustTypeRef, RustTypeRefUninit, TakeRustType},
+    result::{z_result_t, Z_EINVAL, Z_EIO, Z_OK},
+    transmute::{moved_or_null, RustTypeRef, RustTypeRefUninit, TakeRustType},
     zc_moved_concurrent_close_handle_t,
 };
 
@@ -29,13 +29,21 @@ decl_c_type!(
     owned(zc_owned_concurrent_close_handle_t, option tokio::task::JoinHandle<zenoh::Result<()>>),
 );
 
-/// @brief Blocking wait on close handle to complete. Returns `Z_EIO` if close finishes with error.
+/// @brief Blocking wait on close handle to complete. Returns `Z_EIO` if close finishes with error,
+/// `Z_EINVAL` if `handle` is NULL or in its gravestone state.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn zc_concurrent_close_handle_wait(
-    handle: &mut zc_moved_concurrent_close_handle_t,
+    handle: Option<&mut zc_moved_concurrent_close_handle_t>,
 ) -> z_result_t {
-    match ZRuntime::Application.block_on(handle.take_rust_type().unwrap_unchecked()) {
+    let Some(handle) = moved_or_null(handle, "zc_concurrent_close_handle_wait") else {
+        return Z_EINVAL;
+    };
+    let Some(handle) = handle.take_rust_type() else {
+        tracing::error!("Close handle is in its gravestone state");
+        return Z_EINVAL;
+    };
+    match ZRuntime::Application.block_on(handle) {
         Ok(_) => Z_OK,
         Err(e) => {
             tracing::error!("Close error: {}", e);
@@ -46,7 +54,12 @@ pub unsafe extern "C" fn zc_concurrent_close_handle_wait(
 
 /// @brief Drops the close handle. The concurrent close task will not be interrupted.
 #[no_mangle]
-pub extern "C" fn zc_concurrent_close_handle_drop(this_: &mut zc_moved_concurrent_close_handle_t) {
+pub extern "C" fn zc_concurrent_close_handle_drop(
+    this_: Option<&mut zc_moved_concurrent_close_handle_t>,
+) {
+    let Some(this_) = moved_or_null(this_, "zc_concurrent_close_handle_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
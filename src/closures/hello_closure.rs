@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_loaned_hello_t,
 };
 /// @brief A hello message-processing closure.
@@ -95,7 +95,10 @@ pub extern "C" fn z_closure_hello_call(
 }
 /// Drops the closure. Droping an uninitialized closure is a no-op.
 #[no_mangle]
-pub extern "C" fn z_closure_hello_drop(this_: &mut z_moved_closure_hello_t) {
+pub extern "C" fn z_closure_hello_drop(this_: Option<&mut z_moved_closure_hello_t>) {
+    let Some(this_) = moved_or_null(this_, "z_closure_hello_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_loaned_string_t,
 };
 
@@ -161,7 +161,10 @@ pub extern "C" fn zc_closure_log_call(
 }
 /// Drops the closure. Droping an uninitialized closure is a no-op.
 #[no_mangle]
-pub extern "C" fn zc_closure_log_drop(closure_: &mut zc_moved_closure_log_t) {
+pub extern "C" fn zc_closure_log_drop(closure_: Option<&mut zc_moved_closure_log_t>) {
+    let Some(closure_) = moved_or_null(closure_, "zc_closure_log_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
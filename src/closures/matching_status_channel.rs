@@ -0,0 +1,272 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{mem::MaybeUninit, sync::Arc};
+
+use libc::c_void;
+use zenoh::handlers::{self, FifoChannelHandler, IntoHandler, RingChannelHandler};
+
+pub use crate::opaque_types::{
+    zc_loaned_fifo_handler_matching_status_t, zc_loaned_ring_handler_matching_status_t,
+    zc_moved_fifo_handler_matching_status_t, zc_moved_ring_handler_matching_status_t,
+    zc_owned_fifo_handler_matching_status_t, zc_owned_ring_handler_matching_status_t,
+};
+use crate::{
+    result::{self, z_result_t},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    zc_closure_matching_status, zc_matching_status_t, zc_owned_closure_matching_status_t,
+};
+decl_c_type!(
+    owned(
+        zc_owned_fifo_handler_matching_status_t,
+        option FifoChannelHandler<zc_matching_status_t>,
+    ),
+    loaned(zc_loaned_fifo_handler_matching_status_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_fifo_handler_matching_status_drop(
+    this_: Option<&mut zc_moved_fifo_handler_matching_status_t>,
+) {
+    let Some(this_) = moved_or_null(this_, "zc_fifo_handler_matching_status_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_fifo_handler_matching_status_null(
+    this: &mut MaybeUninit<zc_owned_fifo_handler_matching_status_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_fifo_handler_matching_status_check(
+    this_: &zc_owned_fifo_handler_matching_status_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+extern "C" fn __zc_handler_matching_status_send(
+    matching_status: &zc_matching_status_t,
+    context: *mut c_void,
+) {
+    unsafe {
+        let f = (context as *mut std::sync::Arc<dyn Fn(zc_matching_status_t) + Send + Sync>)
+            .as_mut()
+            .unwrap_unchecked();
+        (f)(*matching_status);
+    }
+}
+
+extern "C" fn __zc_handler_matching_status_drop(context: *mut c_void) {
+    unsafe {
+        let f = Box::from_raw(context as *mut Arc<dyn Fn(zc_matching_status_t) + Send + Sync>);
+        std::mem::drop(f);
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the fifo channel.
+///
+/// The callback end can be passed to any function declaring a matching listener,
+/// matching status changes can then be polled from the handler end.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_fifo_channel_matching_status_new(
+    callback: &mut MaybeUninit<zc_owned_closure_matching_status_t>,
+    handler: &mut MaybeUninit<zc_owned_fifo_handler_matching_status_t>,
+    capacity: usize,
+) {
+    let fifo = handlers::FifoChannel::new(capacity);
+    let (cb, h) = fifo.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    zc_closure_matching_status(
+        callback,
+        Some(__zc_handler_matching_status_send),
+        Some(__zc_handler_matching_status_drop),
+        cb_ptr,
+    );
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_fifo_handler_matching_status_loan(
+    this: &zc_owned_fifo_handler_matching_status_t,
+) -> &zc_loaned_fifo_handler_matching_status_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the fifo buffer. If there are no more pending statuses will block until next status is received, or until
+/// the channel is dropped (normally when the matching listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (`matching_status` is not updated in this case).
+#[no_mangle]
+pub extern "C" fn zc_fifo_handler_matching_status_recv(
+    this: &zc_loaned_fifo_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<zc_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(s) => {
+            matching_status.write(s);
+            result::Z_OK
+        }
+        Err(_) => result::Z_CHANNEL_DISCONNECTED,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the fifo buffer.
+/// If there are no more pending statuses will return immediately.
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped,
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (`matching_status` is not updated in both cases).
+#[no_mangle]
+pub extern "C" fn zc_fifo_handler_matching_status_try_recv(
+    this: &zc_loaned_fifo_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<zc_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(Some(s)) => {
+            matching_status.write(s);
+            result::Z_OK
+        }
+        Ok(None) => result::Z_CHANNEL_NODATA,
+        Err(_) => result::Z_CHANNEL_DISCONNECTED,
+    }
+}
+
+decl_c_type!(
+    owned(
+        zc_owned_ring_handler_matching_status_t,
+        option RingChannelHandler<zc_matching_status_t>,
+    ),
+    loaned(zc_loaned_ring_handler_matching_status_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_ring_handler_matching_status_drop(
+    this_: Option<&mut zc_moved_ring_handler_matching_status_t>,
+) {
+    let Some(this_) = moved_or_null(this_, "zc_ring_handler_matching_status_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_ring_handler_matching_status_null(
+    this: &mut MaybeUninit<zc_owned_ring_handler_matching_status_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_ring_handler_matching_status_check(
+    this_: &zc_owned_ring_handler_matching_status_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the ring channel.
+///
+/// The callback end can be passed to any function declaring a matching listener,
+/// the most recent matching status changes can then be polled from the handler end.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_ring_channel_matching_status_new(
+    callback: &mut MaybeUninit<zc_owned_closure_matching_status_t>,
+    handler: &mut MaybeUninit<zc_owned_ring_handler_matching_status_t>,
+    capacity: usize,
+) {
+    let ring = handlers::RingChannel::new(capacity);
+    let (cb, h) = ring.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    zc_closure_matching_status(
+        callback,
+        Some(__zc_handler_matching_status_send),
+        Some(__zc_handler_matching_status_drop),
+        cb_ptr,
+    );
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_ring_handler_matching_status_loan(
+    this: &zc_owned_ring_handler_matching_status_t,
+) -> &zc_loaned_ring_handler_matching_status_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the ring buffer. If there are no more pending statuses will block until next status is received, or until
+/// the channel is dropped (normally when the matching listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (`matching_status` is not updated in this case).
+#[no_mangle]
+pub extern "C" fn zc_ring_handler_matching_status_recv(
+    this: &zc_loaned_ring_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<zc_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(s) => {
+            matching_status.write(s);
+            result::Z_OK
+        }
+        Err(_) => result::Z_CHANNEL_DISCONNECTED,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the ring buffer. If there are no more pending statuses will return immediately.
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped,
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (`matching_status` is not updated in both cases).
+#[no_mangle]
+pub extern "C" fn zc_ring_handler_matching_status_try_recv(
+    this: &zc_loaned_ring_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<zc_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(Some(s)) => {
+            matching_status.write(s);
+            result::Z_OK
+        }
+        Ok(None) => result::Z_CHANNEL_NODATA,
+        Err(_) => result::Z_CHANNEL_DISCONNECTED,
+    }
+}
@@ -16,7 +16,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     zc_matching_status_t,
 };
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -113,8 +113,11 @@ pub extern "C" fn zc_closure_matching_status_call(
 /// @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
 #[no_mangle]
 pub extern "C" fn zc_closure_matching_status_drop(
-    closure_: &mut zc_moved_closure_matching_status_t,
+    closure_: Option<&mut zc_moved_closure_matching_status_t>,
 ) {
+    let Some(closure_) = moved_or_null(closure_, "zc_closure_matching_status_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
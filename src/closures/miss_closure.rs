@@ -16,7 +16,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     ze_miss_t,
 };
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -110,7 +110,10 @@ pub extern "C" fn ze_closure_miss_call(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
 #[no_mangle]
-pub extern "C" fn ze_closure_miss_drop(closure_: &mut ze_moved_closure_miss_t) {
+pub extern "C" fn ze_closure_miss_drop(closure_: Option<&mut ze_moved_closure_miss_t>) {
+    let Some(closure_) = moved_or_null(closure_, "ze_closure_miss_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
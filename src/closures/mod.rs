@@ -38,12 +38,77 @@ mod hello_closure;
 pub use log_closure::*;
 mod log_closure;
 
+#[cfg(feature = "unstable")]
+pub use log_record_closure::*;
+#[cfg(feature = "unstable")]
+mod log_record_closure;
+
 #[cfg(feature = "unstable")]
 pub use matching_status_closure::*;
 #[cfg(feature = "unstable")]
 mod matching_status_closure;
 
+#[cfg(feature = "unstable")]
+pub use matching_status_channel::*;
+#[cfg(feature = "unstable")]
+mod matching_status_channel;
+
+#[cfg(feature = "unstable")]
+pub use payload_transform_closure::*;
+#[cfg(feature = "unstable")]
+mod payload_transform_closure;
+
 #[cfg(feature = "unstable")]
 pub use miss_closure::*;
 #[cfg(feature = "unstable")]
 mod miss_closure;
+
+#[cfg(feature = "unstable")]
+pub use query_diagnostics_closure::*;
+#[cfg(feature = "unstable")]
+mod query_diagnostics_closure;
+
+#[cfg(feature = "unstable")]
+pub use keyexpr_interceptor_closure::*;
+#[cfg(feature = "unstable")]
+mod keyexpr_interceptor_closure;
+
+#[cfg(feature = "unstable")]
+pub use table_get_closure::*;
+#[cfg(feature = "unstable")]
+mod table_get_closure;
+
+#[cfg(feature = "unstable")]
+pub use table_list_closure::*;
+#[cfg(feature = "unstable")]
+mod table_list_closure;
+
+#[cfg(feature = "unstable")]
+pub use reply_entry_closure::*;
+#[cfg(feature = "unstable")]
+mod reply_entry_closure;
+
+#[cfg(feature = "unstable")]
+pub use key_stats_closure::*;
+#[cfg(feature = "unstable")]
+mod key_stats_closure;
+
+#[cfg(feature = "unstable")]
+pub use watch_closure::*;
+#[cfg(feature = "unstable")]
+mod watch_closure;
+
+#[cfg(feature = "unstable")]
+pub use history_end_closure::*;
+#[cfg(feature = "unstable")]
+mod history_end_closure;
+
+#[cfg(feature = "unstable")]
+pub use metrics_closure::*;
+#[cfg(feature = "unstable")]
+mod metrics_closure;
+
+#[cfg(feature = "unstable")]
+pub use watermark_closure::*;
+#[cfg(feature = "unstable")]
+mod watermark_closure;
@@ -15,27 +15,29 @@
 use std::{mem::MaybeUninit, sync::Arc};
 
 use libc::c_void;
-use zenoh::{
-    handlers::{self, FifoChannelHandler, IntoHandler, RingChannelHandler},
-    query::Query,
-};
+use zenoh::handlers::{self, FifoChannelHandler, IntoHandler, RingChannelHandler};
 
 pub use crate::opaque_types::{
     z_loaned_fifo_handler_query_t, z_moved_fifo_handler_query_t, z_owned_fifo_handler_query_t,
 };
 use crate::{
     result::{self, z_result_t},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_loaned_query_t, z_owned_closure_query_t, z_owned_query_t,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_query_t, z_owned_closure_query_t, z_owned_query_t, CQuery,
 };
+#[cfg(feature = "unstable")]
+use crate::{watermarks_valid, with_watermarks, zc_moved_closure_watermark_t};
 decl_c_type!(
-    owned(z_owned_fifo_handler_query_t, option FifoChannelHandler<Query> ),
+    owned(z_owned_fifo_handler_query_t, option FifoChannelHandler<CQuery> ),
     loaned(z_loaned_fifo_handler_query_t),
 );
 
 /// Drops the handler and resets it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_fifo_handler_query_drop(this_: &mut z_moved_fifo_handler_query_t) {
+pub extern "C" fn z_fifo_handler_query_drop(this_: Option<&mut z_moved_fifo_handler_query_t>) {
+    let Some(this_) = moved_or_null(this_, "z_fifo_handler_query_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -57,17 +59,17 @@ pub extern "C" fn z_internal_fifo_handler_query_check(
 
 extern "C" fn __z_handler_query_send(query: &mut z_loaned_query_t, context: *mut c_void) {
     unsafe {
-        let f = (context as *mut std::sync::Arc<dyn Fn(Query) + Send + Sync>)
+        let f = (context as *mut std::sync::Arc<dyn Fn(CQuery) + Send + Sync>)
             .as_mut()
             .unwrap_unchecked();
-        let owned_ref: &mut Option<Query> = std::mem::transmute(query);
+        let owned_ref: &mut Option<CQuery> = std::mem::transmute(query);
         (f)(std::mem::take(owned_ref).unwrap_unchecked());
     }
 }
 
 extern "C" fn __z_handler_query_drop(context: *mut c_void) {
     unsafe {
-        let f = Box::from_raw(context as *mut Arc<dyn Fn(Query) + Send + Sync>);
+        let f = Box::from_raw(context as *mut Arc<dyn Fn(CQuery) + Send + Sync>);
         std::mem::drop(f);
     }
 }
@@ -91,6 +93,71 @@ pub unsafe extern "C" fn z_fifo_channel_query_new(
     });
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the fifo channel, notifying `watermark_callback` when the number of pending querys
+/// reaches `high` and when it goes back down to `low` afterwards.
+///
+/// This gives an early warning that the receiving end is falling behind, before the channel is full and the sending end blocks.
+/// The occupancy of the channel is evaluated each time a query is pushed into it.
+/// The watermark callback is dropped along with the send end of the channel.
+///
+/// @param callback: An uninitialized location in memory where the send end of the channel will be constructed.
+/// @param handler: An uninitialized location in memory where the receive end of the channel will be constructed.
+/// @param capacity: The capacity of the channel.
+/// @param high: The high watermark, should not exceed `capacity`.
+/// @param low: The low watermark, should be lower than `high`.
+/// @param watermark_callback: The closure called when the occupancy of the channel crosses a watermark.
+/// @return 0 in case of success, `Z_EINVAL` if `watermark_callback` is NULL or the watermarks are invalid (`callback` and `handler` are then in gravestone state).
+#[cfg(feature = "unstable")]
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_fifo_channel_query_new_with_watermarks(
+    callback: &mut MaybeUninit<z_owned_closure_query_t>,
+    handler: &mut MaybeUninit<z_owned_fifo_handler_query_t>,
+    capacity: usize,
+    high: usize,
+    low: usize,
+    watermark_callback: Option<&mut zc_moved_closure_watermark_t>,
+) -> z_result_t {
+    let Some(watermark_callback) = moved_or_null(
+        watermark_callback,
+        "zc_fifo_channel_query_new_with_watermarks",
+    ) else {
+        handler.as_rust_type_mut_uninit().write(None);
+        callback.write(z_owned_closure_query_t::default());
+        return result::Z_EINVAL;
+    };
+    let watermark_callback = watermark_callback.take_rust_type();
+    if !watermarks_valid(capacity, high, low) {
+        tracing::error!(
+            "Invalid watermarks (high: {}, low: {}) for a channel of capacity {}",
+            high,
+            low,
+            capacity
+        );
+        handler.as_rust_type_mut_uninit().write(None);
+        callback.write(z_owned_closure_query_t::default());
+        return result::Z_EINVAL;
+    }
+    let fifo = handlers::FifoChannel::new(capacity);
+    let (cb, h) = fifo.into_handler();
+    let cb: Arc<dyn Fn(CQuery) + Send + Sync> = Arc::new(with_watermarks(
+        cb,
+        h.clone(),
+        high,
+        low,
+        watermark_callback,
+    ));
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(z_owned_closure_query_t {
+        _call: Some(__z_handler_query_send),
+        _context: cb_ptr,
+        _drop: Some(__z_handler_query_drop),
+    });
+    result::Z_OK
+}
+
 /// Borrows handler.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
@@ -154,14 +221,17 @@ pub use crate::opaque_types::{
 decl_c_type!(
     owned(
         z_owned_ring_handler_query_t,
-        option RingChannelHandler<Query>,
+        option RingChannelHandler<CQuery>,
     ),
     loaned(z_loaned_ring_handler_query_t),
 );
 
 /// Drops the handler and resets it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_ring_handler_query_drop(this_: &mut z_moved_ring_handler_query_t) {
+pub extern "C" fn z_ring_handler_query_drop(this_: Option<&mut z_moved_ring_handler_query_t>) {
+    let Some(this_) = moved_or_null(this_, "z_ring_handler_query_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
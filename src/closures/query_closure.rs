@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_loaned_query_t,
 };
 /// @brief A query-processing closure.
@@ -101,7 +101,10 @@ pub extern "C" fn z_closure_query_call(
 }
 /// Drops the closure, resetting it to its gravestone state.
 #[no_mangle]
-pub extern "C" fn z_closure_query_drop(closure_: &mut z_moved_closure_query_t) {
+pub extern "C" fn z_closure_query_drop(closure_: Option<&mut z_moved_closure_query_t>) {
+    let Some(closure_) = moved_or_null(closure_, "z_closure_query_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_loaned_reply_t,
 };
 
@@ -105,7 +105,10 @@ pub extern "C" fn z_closure_reply_call(
 }
 /// Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
 #[no_mangle]
-pub extern "C" fn z_closure_reply_drop(closure_: &mut z_moved_closure_reply_t) {
+pub extern "C" fn z_closure_reply_drop(closure_: Option<&mut z_moved_closure_reply_t>) {
+    let Some(closure_) = moved_or_null(closure_, "z_closure_reply_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
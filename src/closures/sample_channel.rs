@@ -25,9 +25,11 @@ pub use crate::opaque_types::{
 };
 use crate::{
     result::{self, z_result_t},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_sample_t, z_owned_closure_sample_t, z_owned_sample_t,
 };
+#[cfg(feature = "unstable")]
+use crate::{watermarks_valid, with_watermarks, zc_moved_closure_watermark_t};
 decl_c_type!(
     owned(z_owned_fifo_handler_sample_t, option FifoChannelHandler<Sample>),
     loaned(z_loaned_fifo_handler_sample_t),
@@ -35,7 +37,10 @@ decl_c_type!(
 
 /// Drops the handler and resets it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_fifo_handler_sample_drop(this_: &mut z_moved_fifo_handler_sample_t) {
+pub extern "C" fn z_fifo_handler_sample_drop(this_: Option<&mut z_moved_fifo_handler_sample_t>) {
+    let Some(this_) = moved_or_null(this_, "z_fifo_handler_sample_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -91,6 +96,71 @@ pub unsafe extern "C" fn z_fifo_channel_sample_new(
     });
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the fifo channel, notifying `watermark_callback` when the number of pending samples
+/// reaches `high` and when it goes back down to `low` afterwards.
+///
+/// This gives an early warning that the receiving end is falling behind, before the channel is full and the sending end blocks.
+/// The occupancy of the channel is evaluated each time a sample is pushed into it.
+/// The watermark callback is dropped along with the send end of the channel.
+///
+/// @param callback: An uninitialized location in memory where the send end of the channel will be constructed.
+/// @param handler: An uninitialized location in memory where the receive end of the channel will be constructed.
+/// @param capacity: The capacity of the channel.
+/// @param high: The high watermark, should not exceed `capacity`.
+/// @param low: The low watermark, should be lower than `high`.
+/// @param watermark_callback: The closure called when the occupancy of the channel crosses a watermark.
+/// @return 0 in case of success, `Z_EINVAL` if `watermark_callback` is NULL or the watermarks are invalid (`callback` and `handler` are then in gravestone state).
+#[cfg(feature = "unstable")]
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_fifo_channel_sample_new_with_watermarks(
+    callback: &mut MaybeUninit<z_owned_closure_sample_t>,
+    handler: &mut MaybeUninit<z_owned_fifo_handler_sample_t>,
+    capacity: usize,
+    high: usize,
+    low: usize,
+    watermark_callback: Option<&mut zc_moved_closure_watermark_t>,
+) -> z_result_t {
+    let Some(watermark_callback) = moved_or_null(
+        watermark_callback,
+        "zc_fifo_channel_sample_new_with_watermarks",
+    ) else {
+        handler.as_rust_type_mut_uninit().write(None);
+        callback.write(z_owned_closure_sample_t::default());
+        return result::Z_EINVAL;
+    };
+    let watermark_callback = watermark_callback.take_rust_type();
+    if !watermarks_valid(capacity, high, low) {
+        tracing::error!(
+            "Invalid watermarks (high: {}, low: {}) for a channel of capacity {}",
+            high,
+            low,
+            capacity
+        );
+        handler.as_rust_type_mut_uninit().write(None);
+        callback.write(z_owned_closure_sample_t::default());
+        return result::Z_EINVAL;
+    }
+    let fifo = handlers::FifoChannel::new(capacity);
+    let (cb, h) = fifo.into_handler();
+    let cb: Arc<dyn Fn(Sample) + Send + Sync> = Arc::new(with_watermarks(
+        cb,
+        h.clone(),
+        high,
+        low,
+        watermark_callback,
+    ));
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(z_owned_closure_sample_t {
+        _call: Some(__z_handler_sample_send),
+        _context: cb_ptr,
+        _drop: Some(__z_handler_sample_drop),
+    });
+    result::Z_OK
+}
+
 /// Borrows handler.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
@@ -161,7 +231,10 @@ decl_c_type!(
 
 /// Drops the handler and resets it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_ring_handler_sample_drop(this_: &mut z_moved_ring_handler_sample_t) {
+pub extern "C" fn z_ring_handler_sample_drop(this_: Option<&mut z_moved_ring_handler_sample_t>) {
+    let Some(this_) = moved_or_null(this_, "z_ring_handler_sample_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
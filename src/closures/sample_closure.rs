@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_loaned_sample_t,
 };
 /// @brief A sample-processing closure.
@@ -103,7 +103,10 @@ pub extern "C" fn z_closure_sample_call(
 
 /// Drops the closure. Droping an uninitialized closure is a no-op.
 #[no_mangle]
-pub extern "C" fn z_closure_sample_drop(closure_: &mut z_moved_closure_sample_t) {
+pub extern "C" fn z_closure_sample_drop(closure_: Option<&mut z_moved_closure_sample_t>) {
+    let Some(closure_) = moved_or_null(closure_, "z_closure_sample_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
@@ -0,0 +1,237 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+use std::{
+    mem::MaybeUninit,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use libc::c_void;
+use zenoh::handlers::{Callback, FifoChannelHandler};
+
+use crate::transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The watermark crossed by the occupancy of a fifo channel.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum zc_watermark_t {
+    /// The occupancy of the channel reached the high watermark: the receiving end is falling behind.
+    HIGH = 0,
+    /// The occupancy of the channel went back down to the low watermark after having reached the high one.
+    LOW = 1,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A fifo channel watermark closure.
+///
+/// The closure is called with the crossed watermark and the occupancy of the channel,
+/// see `zc_fifo_channel_sample_new_with_watermarks()`.
+///
+/// A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+#[repr(C)]
+pub struct zc_owned_closure_watermark_t {
+    _context: *mut c_void,
+    _call: Option<extern "C" fn(watermark: zc_watermark_t, occupancy: usize, context: *mut c_void)>,
+    _drop: Option<extern "C" fn(context: *mut c_void)>,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Loaned closure.
+#[repr(C)]
+pub struct zc_loaned_closure_watermark_t {
+    _0: [usize; 3],
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Moved closure.
+#[repr(C)]
+pub struct zc_moved_closure_watermark_t {
+    _this: zc_owned_closure_watermark_t,
+}
+
+decl_c_type!(
+    owned(zc_owned_closure_watermark_t),
+    loaned(zc_loaned_closure_watermark_t),
+    moved(zc_moved_closure_watermark_t),
+);
+
+impl Default for zc_owned_closure_watermark_t {
+    fn default() -> Self {
+        zc_owned_closure_watermark_t {
+            _context: std::ptr::null_mut(),
+            _call: None,
+            _drop: None,
+        }
+    }
+}
+
+impl zc_owned_closure_watermark_t {
+    pub fn is_empty(&self) -> bool {
+        self._call.is_none() && self._drop.is_none() && self._context.is_null()
+    }
+}
+unsafe impl Send for zc_owned_closure_watermark_t {}
+unsafe impl Sync for zc_owned_closure_watermark_t {}
+impl Drop for zc_owned_closure_watermark_t {
+    fn drop(&mut self) {
+        if let Some(drop) = self._drop {
+            drop(self._context)
+        }
+    }
+}
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a null value of 'zc_owned_closure_watermark_t' type
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_internal_closure_watermark_null(
+    this: *mut MaybeUninit<zc_owned_closure_watermark_t>,
+) {
+    (*this).write(zc_owned_closure_watermark_t::default());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_closure_watermark_check(this: &zc_owned_closure_watermark_t) -> bool {
+    !this.is_empty()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Calls the closure. Calling an uninitialized closure is a no-op.
+#[no_mangle]
+pub extern "C" fn zc_closure_watermark_call(
+    closure: &zc_loaned_closure_watermark_t,
+    watermark: zc_watermark_t,
+    occupancy: usize,
+) {
+    let closure = closure.as_owned_c_type_ref();
+    match closure._call {
+        Some(call) => call(watermark, occupancy, closure._context),
+        None => tracing::error!("Attempted to call an uninitialized closure!"),
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized closure is a no-op.
+#[no_mangle]
+pub extern "C" fn zc_closure_watermark_drop(closure_: Option<&mut zc_moved_closure_watermark_t>) {
+    let Some(closure_) = moved_or_null(closure_, "zc_closure_watermark_drop") else {
+        return;
+    };
+    let _ = closure_.take_rust_type();
+}
+
+impl<F: Fn(zc_watermark_t, usize)> From<F> for zc_owned_closure_watermark_t {
+    fn from(f: F) -> Self {
+        let this = Box::into_raw(Box::new(f)) as _;
+        extern "C" fn call<F: Fn(zc_watermark_t, usize)>(
+            watermark: zc_watermark_t,
+            occupancy: usize,
+            this: *mut c_void,
+        ) {
+            let this = unsafe { &*(this as *const F) };
+            this(watermark, occupancy)
+        }
+        extern "C" fn drop<F>(this: *mut c_void) {
+            std::mem::drop(unsafe { Box::from_raw(this as *mut F) })
+        }
+        zc_owned_closure_watermark_t {
+            _context: this,
+            _call: Some(call::<F>),
+            _drop: Some(drop::<F>),
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows closure.
+#[no_mangle]
+pub extern "C" fn zc_closure_watermark_loan(
+    closure: &zc_owned_closure_watermark_t,
+) -> &zc_loaned_closure_watermark_t {
+    closure.as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+///
+/// Closures are not guaranteed not to be called concurrently.
+///
+/// It is guaranteed that:
+///   - `call` will never be called once `drop` has started.
+///   - `drop` will only be called **once**, and **after every** `call` has ended.
+///   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+/// @brief Constructs closure.
+/// @param this_: uninitialized memory location where new closure will be constructed.
+/// @param call: a closure body.
+/// @param drop: an optional function to be called once on closure drop.
+/// @param context: closure context.
+#[no_mangle]
+pub extern "C" fn zc_closure_watermark(
+    this: &mut MaybeUninit<zc_owned_closure_watermark_t>,
+    call: Option<extern "C" fn(watermark: zc_watermark_t, occupancy: usize, context: *mut c_void)>,
+    drop: Option<extern "C" fn(context: *mut c_void)>,
+    context: *mut c_void,
+) {
+    this.write(zc_owned_closure_watermark_t {
+        _context: context,
+        _call: call,
+        _drop: drop,
+    });
+}
+
+/// Returns ``true`` if the watermarks can be used with a fifo channel of the given capacity.
+pub(crate) fn watermarks_valid(capacity: usize, high: usize, low: usize) -> bool {
+    low < high && high <= capacity
+}
+
+/// Wraps the sending end of a fifo channel to notify the watermark closure when the occupancy
+/// of the channel crosses its watermarks.
+///
+/// Items sent once the receiving end is dropped are discarded, so that a full channel without
+/// receiver never blocks the sender.
+pub(crate) fn with_watermarks<T: Send + 'static>(
+    callback: Callback<T>,
+    handler: FifoChannelHandler<T>,
+    high: usize,
+    low: usize,
+    watermark_callback: zc_owned_closure_watermark_t,
+) -> impl Fn(T) + Send + Sync + 'static {
+    let above_high = AtomicBool::new(false);
+    let update = move |occupancy: usize| {
+        if occupancy >= high {
+            if !above_high.swap(true, Ordering::AcqRel) {
+                zc_closure_watermark_call(
+                    zc_closure_watermark_loan(&watermark_callback),
+                    zc_watermark_t::HIGH,
+                    occupancy,
+                );
+            }
+        } else if occupancy <= low && above_high.swap(false, Ordering::AcqRel) {
+            zc_closure_watermark_call(
+                zc_closure_watermark_loan(&watermark_callback),
+                zc_watermark_t::LOW,
+                occupancy,
+            );
+        }
+    };
+    move |item: T| {
+        // the handler kept here is one of the receivers
+        if handler.receiver_count() < 2 {
+            return;
+        }
+        update(handler.len());
+        callback.call(item);
+        update(handler.len());
+    }
+}
@@ -17,7 +17,7 @@ use std::mem::MaybeUninit;
 use libc::c_void;
 
 use crate::{
-    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
     z_id_t,
 };
 /// @brief A zenoh id-processing closure.
@@ -103,7 +103,10 @@ pub extern "C" fn z_closure_zid_call(closure: &z_loaned_closure_zid_t, z_id: &z_
 
 /// @brief Drops the closure, resetting it to its gravestone state. Droping an uninitialized (null) closure is a no-op.
 #[no_mangle]
-pub extern "C" fn z_closure_zid_drop(closure_: &mut z_moved_closure_zid_t) {
+pub extern "C" fn z_closure_zid_drop(closure_: Option<&mut z_moved_closure_zid_t>) {
+    let Some(closure_) = moved_or_null(closure_, "z_closure_zid_drop") else {
+        return;
+    };
     let _ = closure_.take_rust_type();
 }
 
@@ -25,7 +25,7 @@ use libc::strlen;
 
 use crate::{
     result::{self, z_result_t},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
 };
 
 pub struct CSlice {
@@ -329,7 +329,10 @@ pub extern "C" fn z_internal_slice_null(this_: &mut MaybeUninit<z_owned_slice_t>
 /// Frees the memory and invalidates the slice.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn z_slice_drop(this_: &mut z_moved_slice_t) {
+pub unsafe extern "C" fn z_slice_drop(this_: Option<&mut z_moved_slice_t>) {
+    let Some(this_) = moved_or_null(this_, "z_slice_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -536,7 +539,10 @@ decl_c_type!(
 /// Frees memory and invalidates `z_owned_string_t`, putting it in gravestone state.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn z_string_drop(this_: &mut z_moved_string_t) {
+pub unsafe extern "C" fn z_string_drop(this_: Option<&mut z_moved_string_t>) {
+    let Some(this_) = moved_or_null(this_, "z_string_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -761,7 +767,10 @@ pub extern "C" fn z_internal_string_array_check(this_: &z_owned_string_array_t)
 
 /// Destroys the string array, resetting it to its gravestone value.
 #[no_mangle]
-pub extern "C" fn z_string_array_drop(this_: &mut z_moved_string_array_t) {
+pub extern "C" fn z_string_array_drop(this_: Option<&mut z_moved_string_array_t>) {
+    let Some(this_) = moved_or_null(this_, "z_string_array_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
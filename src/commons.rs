@@ -12,6 +12,8 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
+#[cfg(feature = "unstable")]
+use std::num::NonZeroU128;
 use std::{mem::MaybeUninit, ptr::null};
 
 use libc::c_ulong;
@@ -21,6 +23,7 @@ use zenoh::{
     query::ReplyKeyExpr,
     sample::{Locality, SourceInfo},
     session::EntityGlobalId,
+    time::{TimestampId, NTP64},
 };
 use zenoh::{
     qos::{CongestionControl, Priority},
@@ -31,13 +34,15 @@ use zenoh::{
 
 #[cfg(feature = "unstable")]
 use crate::transmute::IntoCType;
-#[cfg(feature = "unstable")]
-use crate::z_moved_source_info_t;
 use crate::{
     result,
-    transmute::{CTypeRef, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{
+        moved_or_null, CTypeRef, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_id_t, z_loaned_bytes_t, z_loaned_encoding_t, z_loaned_keyexpr_t, z_loaned_session_t,
 };
+#[cfg(feature = "unstable")]
+use crate::{z_moved_source_info_t, z_owned_keyexpr_t, z_owned_slice_t};
 
 /// A zenoh unsigned integer
 #[allow(non_camel_case_types)]
@@ -96,6 +101,55 @@ pub extern "C" fn z_timestamp_id(this_: &z_timestamp_t) -> z_id_t {
     this_.as_rust_type_ref().get_id().to_le_bytes().into()
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the binary representation of the timestamp: its NTP64 time as 8 bytes in little-endian order,
+/// followed by the 16 bytes of its id in LSB-first order.
+///
+/// This representation is stable and can be parsed back with `z_timestamp_from_bytes()`.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_timestamp_to_bytes(
+    this_: &z_timestamp_t,
+    dst: &mut MaybeUninit<z_owned_slice_t>,
+) {
+    let timestamp = this_.as_rust_type_ref();
+    let mut bytes = Vec::with_capacity(24);
+    bytes.extend_from_slice(&timestamp.get_time().0.to_le_bytes());
+    bytes.extend_from_slice(&timestamp.get_id().to_le_bytes());
+    dst.as_rust_type_mut_uninit().write(bytes.into());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Parses a timestamp from its binary representation, as constructed by `z_timestamp_to_bytes()`.
+///
+/// @param dst: The location where the parsed timestamp will be written.
+/// @param data: A pointer to the binary representation.
+/// @param len: The length of the binary representation, which should be 24.
+/// @return 0 in case of success, `Z_EINVAL` if `len` is not 24 or if the id is all zeros.
+#[cfg(feature = "unstable")]
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn z_timestamp_from_bytes(
+    dst: &mut MaybeUninit<z_timestamp_t>,
+    data: *const u8,
+    len: usize,
+) -> result::z_result_t {
+    if data.is_null() || len != 24 {
+        tracing::error!("Invalid binary representation of z_timestamp_t");
+        return result::Z_EINVAL;
+    }
+    let bytes = std::slice::from_raw_parts(data, len);
+    let (time, id) = bytes.split_at(8);
+    let time = u64::from_le_bytes(time.try_into().unwrap_or_default());
+    let Some(id) = NonZeroU128::new(u128::from_le_bytes(id.try_into().unwrap_or_default())) else {
+        tracing::error!("Invalid binary representation of z_timestamp_t");
+        return result::Z_EINVAL;
+    };
+    dst.as_rust_type_mut_uninit()
+        .write(Timestamp::new(NTP64(time), TimestampId::from(id)));
+    result::Z_OK
+}
+
 use crate::opaque_types::z_loaned_sample_t;
 pub use crate::opaque_types::{z_moved_sample_t, z_owned_sample_t};
 decl_c_type!(
@@ -104,10 +158,26 @@ decl_c_type!(
 );
 
 /// Returns the key expression of the sample.
+/// The returned key expression is only valid as long as the sample is, use `z_sample_keyexpr_clone()` to keep it longer.
 #[no_mangle]
 pub extern "C" fn z_sample_keyexpr(this_: &z_loaned_sample_t) -> &z_loaned_keyexpr_t {
     this_.as_rust_type_ref().key_expr().as_loaned_c_type_ref()
 }
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an owned copy of the key expression of the sample.
+///
+/// This is equivalent to calling `z_keyexpr_clone()` on the result of `z_sample_keyexpr()`.
+/// @param this_: The sample.
+/// @param dst: An uninitialized memory location where the key expression will be constructed.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_sample_keyexpr_clone(
+    this_: &z_loaned_sample_t,
+    dst: &mut MaybeUninit<z_owned_keyexpr_t>,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(Some(this_.as_rust_type_ref().key_expr().clone()));
+}
 /// Returns the encoding associated with the sample data.
 #[no_mangle]
 pub extern "C" fn z_sample_encoding(this_: &z_loaned_sample_t) -> &z_loaned_encoding_t {
@@ -231,7 +301,10 @@ pub unsafe extern "C" fn z_sample_loan_mut(this_: &mut z_owned_sample_t) -> &mut
 
 /// Frees the memory and invalidates the sample, resetting it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_sample_drop(this_: &mut z_moved_sample_t) {
+pub extern "C" fn z_sample_drop(this_: Option<&mut z_moved_sample_t>) {
+    let Some(this_) = moved_or_null(this_, "z_sample_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -619,7 +692,10 @@ pub extern "C" fn z_source_info_loan(this_: &z_owned_source_info_t) -> &z_loaned
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Frees the memory and invalidates the source info, resetting it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_source_info_drop(this_: &mut z_moved_source_info_t) {
+pub extern "C" fn z_source_info_drop(this_: Option<&mut z_moved_source_info_t>) {
+    let Some(this_) = moved_or_null(this_, "z_source_info_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -14,12 +14,13 @@
 use std::{ffi::CStr, mem::MaybeUninit, slice::from_raw_parts, str::from_utf8};
 
 use libc::{c_char, c_uint};
+use serde_path_to_error::Segment;
 use zenoh::config::{Config, WhatAmI};
 
 use crate::{
     result::{self, Z_OK},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_internal_string_null, z_owned_string_t, z_string_copy_from_substr,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_internal_string_null, z_owned_string_t, z_string_copy_from_substr, CStringOwned,
 };
 
 #[no_mangle]
@@ -223,7 +224,10 @@ pub unsafe extern "C" fn zc_config_insert_json5_from_substr(
 
 /// Frees `config`, and resets it to its gravestone state.
 #[no_mangle]
-pub extern "C" fn z_config_drop(this_: &mut z_moved_config_t) {
+pub extern "C" fn z_config_drop(this_: Option<&mut z_moved_config_t>) {
+    let Some(this_) = moved_or_null(this_, "z_config_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -258,6 +262,113 @@ pub unsafe extern "C" fn zc_config_from_str(
     res
 }
 
+/// A report describing why a configuration failed to validate.
+#[repr(C)]
+pub struct zc_config_error_report_t {
+    /// One-based line of the error in the validated string, 0 if unknown.
+    pub line: usize,
+    /// One-based column of the error in the validated string, 0 if unknown.
+    pub column: usize,
+    /// Path of the offending configuration key, such as "connect/endpoints/0", empty if unknown.
+    pub key_path: z_owned_string_t,
+    /// Human-readable description of the error.
+    pub message: z_owned_string_t,
+}
+
+fn config_key_path(path: &serde_path_to_error::Path) -> String {
+    path.iter()
+        .filter_map(|s| match s {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(key.clone()),
+            Segment::Enum { variant } => Some(variant.clone()),
+            Segment::Unknown => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn owned_string_from(s: String) -> z_owned_string_t {
+    let mut out = MaybeUninit::<z_owned_string_t>::uninit();
+    out.as_rust_type_mut_uninit().write(CStringOwned::from(s));
+    unsafe { out.assume_init() }
+}
+
+fn null_string() -> z_owned_string_t {
+    let mut out = MaybeUninit::<z_owned_string_t>::uninit();
+    z_internal_string_null(&mut out);
+    unsafe { out.assume_init() }
+}
+
+fn write_config_error_report(
+    report: Option<&mut MaybeUninit<zc_config_error_report_t>>,
+    location: Option<json5::Location>,
+    key_path: String,
+    message: String,
+) {
+    let Some(report) = report else {
+        return;
+    };
+    let (line, column) = location.map_or((0, 0), |l| (l.line, l.column));
+    report.write(zc_config_error_report_t {
+        line,
+        column,
+        key_path: owned_string_from(key_path),
+        message: owned_string_from(message),
+    });
+}
+
+/// Parses and validates a JSON5-serialized configuration without constructing it or opening a session.
+///
+/// If `error_report` is not null, it is always initialized: on failure it describes the first error found,
+/// on success its strings are in gravestone state (see `z_internal_string_null()`) and its line and column are 0.
+/// The strings of the report should be dropped by the caller in both cases.
+///
+/// Returns 0 if the configuration is valid, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_config_validate(
+    s: *const c_char,
+    error_report: Option<&mut MaybeUninit<zc_config_error_report_t>>,
+) -> result::z_result_t {
+    if s.is_null() {
+        write_config_error_report(
+            error_report,
+            None,
+            String::new(),
+            "Config string is null".to_string(),
+        );
+        return result::Z_EINVAL;
+    }
+    let conf_str = CStr::from_ptr(s).to_string_lossy();
+    let res = json5::Deserializer::from_str(&conf_str)
+        .map_err(|e| (e, String::new()))
+        .and_then(|mut d| {
+            serde_path_to_error::deserialize::<_, Config>(&mut d)
+                .map(|_| ())
+                .map_err(|e| {
+                    let key_path = config_key_path(e.path());
+                    (e.into_inner(), key_path)
+                })
+        });
+    match res {
+        Ok(()) => {
+            if let Some(report) = error_report {
+                report.write(zc_config_error_report_t {
+                    line: 0,
+                    column: 0,
+                    key_path: null_string(),
+                    message: null_string(),
+                });
+            }
+            result::Z_OK
+        }
+        Err((json5::Error::Message { msg, location }, key_path)) => {
+            write_config_error_report(error_report, location, key_path, msg);
+            result::Z_EPARSE
+        }
+    }
+}
+
 /// Constructs a json string representation of the `config`, such as '{"mode":"client","connect":{"endpoints":["tcp/127.0.0.1:7447"]}}'.
 ///
 /// Returns 0 in case of success, negative error code otherwise.
@@ -266,9 +377,77 @@ pub unsafe extern "C" fn zc_config_from_str(
 pub unsafe extern "C" fn zc_config_to_string(
     config: &z_loaned_config_t,
     out_config_string: &mut MaybeUninit<z_owned_string_t>,
+) -> result::z_result_t {
+    zc_config_to_string_with_options(config, out_config_string, None)
+}
+
+/// Options passed to the `zc_config_to_string_with_options()` function.
+#[repr(C)]
+pub struct zc_config_to_string_options_t {
+    /// If set to ``true``, the credentials and private keys of the configuration are replaced with `"***"`,
+    /// so that the resulting string can be safely logged.
+    pub redact_secrets: bool,
+}
+
+/// Constructs the default value for `zc_config_to_string_options_t`.
+#[no_mangle]
+pub extern "C" fn zc_config_to_string_options_default(
+    this_: &mut MaybeUninit<zc_config_to_string_options_t>,
+) {
+    this_.write(zc_config_to_string_options_t {
+        redact_secrets: false,
+    });
+}
+
+/// The placeholder replacing the secrets of a redacted configuration.
+const REDACTED: &str = "***";
+
+/// Returns ``true`` if the configuration key holds a credential or a private key.
+fn is_secret_key(key: &str) -> bool {
+    matches!(key, "user" | "password") || key.contains("private_key")
+}
+
+fn redact_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_secret_key(key) && !value.is_null() {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_secrets(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
+fn config_to_string(config: &Config, redact: bool) -> Result<String, String> {
+    if !redact {
+        return json5::to_string(config).map_err(|e| e.to_string());
+    }
+    let mut value = serde_json::to_value(config).map_err(|e| e.to_string())?;
+    redact_secrets(&mut value);
+    serde_json::to_string(&value).map_err(|e| e.to_string())
+}
+
+/// Constructs a json string representation of the `config`, such as '{"mode":"client","connect":{"endpoints":["tcp/127.0.0.1:7447"]}}'.
+///
+/// With the `redact_secrets` option, the user names, passwords and private keys of the authentication and TLS
+/// settings are replaced with `"***"`.
+///
+/// Returns 0 in case of success, negative error code otherwise.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn zc_config_to_string_with_options(
+    config: &z_loaned_config_t,
+    out_config_string: &mut MaybeUninit<z_owned_string_t>,
+    options: Option<&mut zc_config_to_string_options_t>,
 ) -> result::z_result_t {
     let config = config.as_rust_type_ref();
-    match json5::to_string(config) {
+    let redact = options.is_some_and(|o| o.redact_secrets);
+    match config_to_string(config, redact) {
         Ok(s) => {
             unsafe {
                 z_string_copy_from_substr(
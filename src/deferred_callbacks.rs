@@ -0,0 +1,190 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    drop_counters::{DropCounters, DropReason},
+    transmute::{LoanedCTypeRef, RustTypeRef},
+    z_closure_query_call, z_closure_query_loan, z_closure_reply_call, z_closure_reply_loan,
+    z_closure_sample_call, z_closure_sample_loan, z_loaned_query_t, z_loaned_reply_t,
+    z_loaned_sample_t, z_loaned_session_t, z_owned_closure_query_t, z_owned_closure_reply_t,
+    z_owned_closure_sample_t, CSession,
+};
+
+type DeferredCallback = Box<dyn FnOnce() + Send>;
+
+/// The callbacks of a session opened in deferred dispatch mode, waiting to be executed by `zc_session_drain_callbacks()`.
+pub(crate) struct DeferredQueue {
+    callbacks: Mutex<VecDeque<DeferredCallback>>,
+    not_empty: Condvar,
+    /// The maximum number of pending callbacks, 0 means unbounded.
+    capacity: usize,
+    drops: Arc<DropCounters>,
+}
+
+impl DeferredQueue {
+    pub(crate) fn new(capacity: usize, drops: Arc<DropCounters>) -> Self {
+        DeferredQueue {
+            callbacks: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            capacity,
+            drops,
+        }
+    }
+
+    /// Queues the callback, or drops it if the queue is full so that the pending ones keep their order.
+    fn push(&self, callback: DeferredCallback) {
+        if let Ok(mut callbacks) = self.callbacks.lock() {
+            if self.capacity != 0 && callbacks.len() >= self.capacity {
+                std::mem::drop(callbacks);
+                tracing::warn!(
+                    "The deferred callbacks queue is full ({} pending), the message is dropped",
+                    self.capacity
+                );
+                self.drops.record(DropReason::FullBuffer);
+                return;
+            }
+            callbacks.push_back(callback);
+            self.not_empty.notify_one();
+        }
+    }
+
+    fn drain(&self, max_items: usize, timeout: Duration) -> usize {
+        let Ok(mut callbacks) = self.callbacks.lock() else {
+            return 0;
+        };
+        if callbacks.is_empty() && !timeout.is_zero() {
+            callbacks = match self
+                .not_empty
+                .wait_timeout_while(callbacks, timeout, |c| c.is_empty())
+            {
+                Ok((callbacks, _)) => callbacks,
+                Err(_) => return 0,
+            };
+        }
+        let n = match max_items {
+            0 => callbacks.len(),
+            max_items => max_items.min(callbacks.len()),
+        };
+        let batch: Vec<DeferredCallback> = callbacks.drain(..n).collect();
+        // the callbacks are executed without holding the lock, so that they can trigger new ones
+        std::mem::drop(callbacks);
+        for callback in batch {
+            callback();
+        }
+        n
+    }
+}
+
+/// Returns the callback queueing the samples for the subscriber callback, if the session is in deferred dispatch mode,
+/// or the subscriber callback itself otherwise.
+pub(crate) fn defer_sample_callback(
+    session: &CSession,
+    callback: z_owned_closure_sample_t,
+) -> z_owned_closure_sample_t {
+    let Some(queue) = session.state().deferred.clone() else {
+        return callback;
+    };
+    let callback = Arc::new(callback);
+    (move |sample: &mut z_loaned_sample_t| {
+        let callback = callback.clone();
+        let mut sample = sample.as_rust_type_ref().clone();
+        queue.push(Box::new(move || {
+            z_closure_sample_call(
+                z_closure_sample_loan(&callback),
+                sample.as_loaned_c_type_mut(),
+            )
+        }));
+    })
+    .into()
+}
+
+/// Returns the callback queueing the queries for the queryable callback, if the session is in deferred dispatch mode,
+/// or the queryable callback itself otherwise.
+pub(crate) fn defer_query_callback(
+    session: &CSession,
+    callback: z_owned_closure_query_t,
+) -> z_owned_closure_query_t {
+    let Some(queue) = session.state().deferred.clone() else {
+        return callback;
+    };
+    let callback = Arc::new(callback);
+    (move |query: &mut z_loaned_query_t| {
+        let callback = callback.clone();
+        let mut query = query.as_rust_type_ref().clone();
+        queue.push(Box::new(move || {
+            z_closure_query_call(
+                z_closure_query_loan(&callback),
+                query.as_loaned_c_type_mut(),
+            )
+        }));
+    })
+    .into()
+}
+
+/// Returns the callback queueing the replies for the get callback, if the session is in deferred dispatch mode,
+/// or the get callback itself otherwise.
+pub(crate) fn defer_reply_callback(
+    session: &CSession,
+    callback: z_owned_closure_reply_t,
+) -> z_owned_closure_reply_t {
+    let Some(queue) = session.state().deferred.clone() else {
+        return callback;
+    };
+    let callback = Arc::new(callback);
+    (move |reply: &mut z_loaned_reply_t| {
+        let callback = callback.clone();
+        let mut reply = reply.as_rust_type_ref().clone();
+        queue.push(Box::new(move || {
+            z_closure_reply_call(
+                z_closure_reply_loan(&callback),
+                reply.as_loaned_c_type_mut(),
+            )
+        }));
+    })
+    .into()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Executes on the calling thread the pending callbacks of a session opened in deferred dispatch mode
+/// (see `deferred_callbacks` field of `z_open_options_t`).
+///
+/// Waits up to `timeout_ms` milliseconds for a callback to become pending if there are none, then executes
+/// the pending callbacks in the order they were triggered.
+///
+/// Channel handlers are deferred as well, since they can not be told apart from other callbacks:
+/// their samples, queries or replies are only sent to the channel when this function executes the corresponding callbacks,
+/// so calling the blocking `z_recv()` on the thread draining the callbacks before draining them never returns.
+/// Use `z_try_recv()` after this function, or drain the callbacks on another thread.
+///
+/// @param session: The zenoh session.
+/// @param max_items: The maximum number of callbacks to execute, 0 means all pending callbacks.
+/// @param timeout_ms: The maximum time to wait for a pending callback in milliseconds, 0 means no waiting.
+/// @return The number of executed callbacks, always 0 if the session is not in deferred dispatch mode.
+#[no_mangle]
+pub extern "C" fn zc_session_drain_callbacks(
+    session: &z_loaned_session_t,
+    max_items: usize,
+    timeout_ms: u64,
+) -> usize {
+    match &session.as_rust_type_ref().state().deferred {
+        Some(queue) => queue.drain(max_items, Duration::from_millis(timeout_ms)),
+        None => 0,
+    }
+}
@@ -0,0 +1,96 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    mem::MaybeUninit,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::{transmute::RustTypeRef, z_loaned_session_t};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The numbers of incoming messages dropped by zenoh-c itself, before reaching the application, since the
+/// session was opened.
+///
+/// The messages lost on the network or discarded by the zenoh runtime are not counted.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct zc_session_drop_counters_t {
+    /// The number of messages discarded because the buffer of a pull mode subscriber or the deferred callbacks queue was full.
+    pub full_buffers: u64,
+    /// The number of messages whose delivery was interrupted by a panic.
+    pub panicked_callbacks: u64,
+    /// The number of messages that could not be decoded, e.g. stream messages without a valid sequence number.
+    pub decode_failures: u64,
+}
+
+/// The reasons for which an incoming message may be dropped by zenoh-c.
+#[derive(Clone, Copy)]
+pub(crate) enum DropReason {
+    FullBuffer,
+    PanickedCallback,
+    DecodeFailure,
+}
+
+/// The drop counters of a session, kept in its state and shared with the callbacks of its entities.
+#[derive(Default)]
+pub(crate) struct DropCounters {
+    full_buffers: AtomicU64,
+    panicked_callbacks: AtomicU64,
+    decode_failures: AtomicU64,
+}
+
+impl DropCounters {
+    pub(crate) fn record(&self, reason: DropReason) {
+        let counter = match reason {
+            DropReason::FullBuffer => &self.full_buffers,
+            DropReason::PanickedCallback => &self.panicked_callbacks,
+            DropReason::DecodeFailure => &self.decode_failures,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> zc_session_drop_counters_t {
+        zc_session_drop_counters_t {
+            full_buffers: self.full_buffers.load(Ordering::Relaxed),
+            panicked_callbacks: self.panicked_callbacks.load(Ordering::Relaxed),
+            decode_failures: self.decode_failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Runs the delivery of a message, counting it as dropped instead of unwinding further if it panics.
+pub(crate) fn catch_callback_panic(counters: &DropCounters, f: impl FnOnce()) {
+    if catch_unwind(AssertUnwindSafe(f)).is_err() {
+        tracing::error!("A callback panicked, the message it was processing is dropped");
+        counters.record(DropReason::PanickedCallback);
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the numbers of incoming messages dropped by zenoh-c itself since the session was opened.
+///
+/// These counters allow to tell the messages lost by the application side (full buffers, panics, undecodable
+/// messages) from the ones lost on the network.
+///
+/// @param session: The zenoh session.
+/// @param counters: An uninitialized location in memory where the counters will be written.
+#[no_mangle]
+pub extern "C" fn zc_session_drop_counters(
+    session: &z_loaned_session_t,
+    counters: &mut MaybeUninit<zc_session_drop_counters_t>,
+) {
+    counters.write(session.as_rust_type_ref().state().drops.snapshot());
+}
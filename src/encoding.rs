@@ -26,7 +26,7 @@ use zenoh::bytes::Encoding;
 pub use crate::opaque_types::{z_loaned_encoding_t, z_owned_encoding_t};
 use crate::{
     result::{self, z_result_t},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_moved_encoding_t, z_owned_string_t, z_string_copy_from_substr,
 };
 
@@ -144,7 +144,10 @@ pub extern "C" fn z_internal_encoding_null(this_: &mut MaybeUninit<z_owned_encod
 
 /// Frees the memory and resets the encoding it to its default value.
 #[no_mangle]
-pub extern "C" fn z_encoding_drop(this_: &mut z_moved_encoding_t) {
+pub extern "C" fn z_encoding_drop(this_: Option<&mut z_moved_encoding_t>) {
+    let Some(this_) = moved_or_null(this_, "z_encoding_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -0,0 +1,223 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    fmt,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use crate::{
+    result::{self, z_result_t},
+    transmute::RustTypeRef,
+    z_closure_query_call, z_closure_query_loan, z_closure_sample_call, z_closure_sample_loan,
+    z_loaned_query_t, z_loaned_sample_t, z_loaned_session_t, z_owned_closure_query_t,
+    z_owned_closure_sample_t, CSession,
+};
+
+#[derive(Clone, Copy)]
+pub(crate) enum EntityKind {
+    Publisher = 0,
+    Subscriber = 1,
+    Queryable = 2,
+    Querier = 3,
+}
+
+impl fmt::Display for EntityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityKind::Publisher => f.write_str("publishers"),
+            EntityKind::Subscriber => f.write_str("subscribers"),
+            EntityKind::Queryable => f.write_str("queryables"),
+            EntityKind::Querier => f.write_str("queriers"),
+        }
+    }
+}
+
+struct EntityCounter {
+    count: AtomicUsize,
+    limit: usize,
+}
+
+impl EntityCounter {
+    fn with_limit(limit: usize) -> Self {
+        EntityCounter {
+            count: AtomicUsize::new(0),
+            limit,
+        }
+    }
+
+    fn try_acquire(&self) -> bool {
+        self.count
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |c| {
+                (self.limit == 0 || c < self.limit).then_some(c + 1)
+            })
+            .is_ok()
+    }
+
+    fn release(&self) {
+        let _ = self
+            .count
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |c| c.checked_sub(1));
+    }
+
+    fn get(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+/// The entities declared on a session, indexed by `EntityKind`.
+pub(crate) struct SessionEntities([EntityCounter; 4]);
+
+impl SessionEntities {
+    /// A limit of 0 means that the number of entities of the corresponding kind is not limited.
+    pub(crate) fn with_limits(
+        max_publishers: usize,
+        max_subscribers: usize,
+        max_queryables: usize,
+        max_queriers: usize,
+    ) -> Self {
+        SessionEntities([
+            EntityCounter::with_limit(max_publishers),
+            EntityCounter::with_limit(max_subscribers),
+            EntityCounter::with_limit(max_queryables),
+            EntityCounter::with_limit(max_queriers),
+        ])
+    }
+}
+
+impl Default for SessionEntities {
+    fn default() -> Self {
+        SessionEntities::with_limits(0, 0, 0, 0)
+    }
+}
+
+/// The slot of a declared entity in the counters of its session, given back when dropped.
+///
+/// It is kept in the state of the entity, or in its callback for the entities which are only undeclared
+/// along with it (e.g. background subscribers and queryables).
+pub(crate) struct EntitySlot {
+    entities: Arc<SessionEntities>,
+    kind: EntityKind,
+}
+
+impl Drop for EntitySlot {
+    fn drop(&mut self) {
+        self.entities.0[self.kind as usize].release();
+    }
+}
+
+/// Reserves a slot for a new entity of the given kind.
+///
+/// Returns `Z_ELIMIT` if the session already reached its limit for this kind of entities.
+pub(crate) fn acquire_entity(
+    session: &CSession,
+    kind: EntityKind,
+) -> Result<EntitySlot, z_result_t> {
+    let entities = &session.state().entities;
+    let counter = &entities.0[kind as usize];
+    if !counter.try_acquire() {
+        tracing::error!(
+            "Maximum number of {} ({}) reached for session {}",
+            kind,
+            counter.limit,
+            session.zid()
+        );
+        return Err(result::Z_ELIMIT);
+    }
+    Ok(EntitySlot {
+        entities: entities.clone(),
+        kind,
+    })
+}
+
+/// A callback holding the slot of the entity it is declared for.
+struct SlotCallback<C> {
+    callback: C,
+    _slot: EntitySlot,
+}
+
+impl SlotCallback<z_owned_closure_sample_t> {
+    fn call(&self, sample: &mut z_loaned_sample_t) {
+        z_closure_sample_call(z_closure_sample_loan(&self.callback), sample)
+    }
+}
+
+impl SlotCallback<z_owned_closure_query_t> {
+    fn call(&self, query: &mut z_loaned_query_t) {
+        z_closure_query_call(z_closure_query_loan(&self.callback), query)
+    }
+}
+
+/// Returns the subscriber callback holding the slot, which is given back once the subscriber is undeclared.
+pub(crate) fn hold_in_sample_callback(
+    slot: EntitySlot,
+    callback: z_owned_closure_sample_t,
+) -> z_owned_closure_sample_t {
+    let callback = SlotCallback {
+        callback,
+        _slot: slot,
+    };
+    (move |sample: &mut z_loaned_sample_t| callback.call(sample)).into()
+}
+
+/// Returns the queryable callback holding the slot, which is given back once the queryable is undeclared.
+pub(crate) fn hold_in_query_callback(
+    slot: EntitySlot,
+    callback: z_owned_closure_query_t,
+) -> z_owned_closure_query_t {
+    let callback = SlotCallback {
+        callback,
+        _slot: slot,
+    };
+    (move |query: &mut z_loaned_query_t| callback.call(query)).into()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The number of entities currently declared on a session.
+#[repr(C)]
+#[derive(Default)]
+pub struct zc_session_entity_counts_t {
+    /// The number of declared publishers, including advanced ones.
+    pub publishers: usize,
+    /// The number of declared subscribers, including background, liveliness, querying and advanced ones.
+    pub subscribers: usize,
+    /// The number of declared queryables, including background ones.
+    pub queryables: usize,
+    /// The number of declared queriers.
+    pub queriers: usize,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the number of publishers, subscribers, queryables and queriers currently declared on the session.
+///
+/// Entities are counted from their declaration until they are undeclared or dropped, background entities
+/// are counted until the session is closed or dropped.
+/// The corresponding limits can be set with `z_open_options_t`.
+#[no_mangle]
+pub extern "C" fn zc_session_get_entity_counts(
+    session: &z_loaned_session_t,
+    counts: &mut MaybeUninit<zc_session_entity_counts_t>,
+) {
+    let entities = &session.as_rust_type_ref().state().entities.0;
+    counts.write(zc_session_entity_counts_t {
+        publishers: entities[EntityKind::Publisher as usize].get(),
+        subscribers: entities[EntityKind::Subscriber as usize].get(),
+        queryables: entities[EntityKind::Queryable as usize].get(),
+        queriers: entities[EntityKind::Querier as usize].get(),
+    });
+}
@@ -27,19 +27,22 @@ use zenoh::{
 };
 
 pub use crate::opaque_types::{z_loaned_reply_err_t, z_moved_reply_err_t, z_owned_reply_err_t};
+#[cfg(feature = "unstable")]
+use crate::{
+    deferred_callbacks, transmute::IntoCType, z_id_t, z_moved_cancellation_token_t,
+    z_moved_source_info_t, zc_closure_query_diagnostics_call, zc_closure_query_diagnostics_loan,
+    zc_locality_default, zc_locality_t, zc_moved_closure_query_diagnostics_t,
+    zc_owned_closure_query_diagnostics_t, zc_reply_keyexpr_default, zc_reply_keyexpr_t,
+    CancellableReplyCallback,
+};
 use crate::{
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_reply_call, z_closure_reply_loan, z_congestion_control_t, z_consolidation_mode_t,
     z_loaned_bytes_t, z_loaned_encoding_t, z_loaned_keyexpr_t, z_loaned_sample_t,
     z_loaned_session_t, z_moved_bytes_t, z_moved_closure_reply_t, z_moved_encoding_t, z_priority_t,
     z_query_target_t,
 };
-#[cfg(feature = "unstable")]
-use crate::{
-    transmute::IntoCType, z_id_t, z_moved_source_info_t, zc_locality_default, zc_locality_t,
-    zc_reply_keyexpr_default, zc_reply_keyexpr_t,
-};
 decl_c_type!(
     owned(z_owned_reply_err_t, ReplyError),
     loaned(z_loaned_reply_err_t, ReplyError),
@@ -97,7 +100,10 @@ pub extern "C" fn z_reply_err_loan_mut(
 
 /// Frees the memory and resets the reply error it to its default value.
 #[no_mangle]
-pub extern "C" fn z_reply_err_drop(this_: &mut z_moved_reply_err_t) {
+pub extern "C" fn z_reply_err_drop(this_: Option<&mut z_moved_reply_err_t>) {
+    let Some(this_) = moved_or_null(this_, "z_reply_err_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -195,6 +201,96 @@ pub extern "C" fn z_reply_clone(dst: &mut MaybeUninit<z_owned_reply_t>, this_: &
         .write(Some(this_.as_rust_type_ref().clone()));
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Routing diagnostics of a query, reported once all its replies have been received.
+///
+/// The number of hops to the repliers is not reported, since it is not exposed by the zenoh routing layer.
+#[cfg(feature = "unstable")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct zc_query_diagnostics_t {
+    /// The total number of replies delivered to the reply callback, including the error ones. The error reply notifying
+    /// the query timeout is not counted, and neither are the replies discarded by the `replier_id` filter.
+    pub replies: usize,
+    /// The number of error replies delivered to the reply callback.
+    pub errors: usize,
+    /// The number of distinct sessions whose queryables replied to the query.
+    pub repliers: usize,
+    /// The finalization status of the query: `Z_OK` if all the replies were received, `Z_QUERY_TIMED_OUT` if the query
+    /// timeout fired before. Like the `Z_CHANNEL_*` statuses, `Z_QUERY_TIMED_OUT` is positive, since it is not an error.
+    pub status: result::z_result_t,
+}
+
+/// Collects the diagnostics of a query and reports them to the user closure once the query is complete,
+/// i.e. once the reply callback holding it is dropped.
+#[cfg(feature = "unstable")]
+struct QueryDiagnostics {
+    diagnostics: zc_query_diagnostics_t,
+    repliers: std::collections::HashSet<zenoh::session::ZenohId>,
+    callback: zc_owned_closure_query_diagnostics_t,
+}
+
+#[cfg(feature = "unstable")]
+impl QueryDiagnostics {
+    fn new(callback: zc_owned_closure_query_diagnostics_t) -> Self {
+        QueryDiagnostics {
+            diagnostics: zc_query_diagnostics_t::default(),
+            repliers: std::collections::HashSet::new(),
+            callback,
+        }
+    }
+
+    fn record_timeout(&mut self) {
+        self.diagnostics.status = result::Z_QUERY_TIMED_OUT;
+    }
+
+    fn record(&mut self, reply: &Reply) {
+        self.diagnostics.replies += 1;
+        if reply.result().is_err() {
+            self.diagnostics.errors += 1;
+        }
+        if let Some(id) = reply.replier_id() {
+            self.repliers.insert(id);
+        }
+        self.diagnostics.repliers = self.repliers.len();
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Drop for QueryDiagnostics {
+    fn drop(&mut self) {
+        zc_closure_query_diagnostics_call(
+            zc_closure_query_diagnostics_loan(&self.callback),
+            &self.diagnostics,
+        );
+    }
+}
+
+/// Returns ``true`` if the reply is the error reply sent by the querying session `zid` when the query timeout fires.
+///
+/// zenoh does not flag this reply, so it is recognized by its content, a `"Timeout"` string error sent by the querying
+/// session itself, and by being received once the query `deadline` is passed. The deadline is computed from the very
+/// timeout passed to zenoh, right before the query is sent, so that it is reached at most a few instants before the
+/// zenoh timer fires, after which zenoh discards the replies of the queryables.
+/// An identical error reply sent by a queryable of the querying session within these instants would still be taken
+/// for the timeout one.
+#[cfg(feature = "unstable")]
+fn is_timeout_reply(
+    reply: &Reply,
+    zid: &zenoh::session::ZenohId,
+    deadline: std::time::Instant,
+) -> bool {
+    match reply.result() {
+        Err(err) => {
+            reply.replier_id() == Some(*zid)
+                && std::time::Instant::now() >= deadline
+                && *err.encoding() == zenoh::bytes::Encoding::ZENOH_STRING
+                && err.payload().to_bytes().as_ref() == b"Timeout"
+        }
+        Ok(_) => false,
+    }
+}
+
 /// Options passed to the `z_get()` function.
 #[repr(C)]
 pub struct z_get_options_t {
@@ -231,6 +327,38 @@ pub struct z_get_options_t {
     pub attachment: Option<&'static mut z_moved_bytes_t>,
     /// The timeout for the query in milliseconds. 0 means default query timeout from zenoh configuration.
     pub timeout_ms: u64,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// An optional closure called once the query is complete, with the routing diagnostics of the query.
+    /// It helps to debug queries that silently return no replies.
+    pub diagnostics: Option<&'static mut zc_moved_closure_query_diagnostics_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// An optional cancellation token, allowing to cancel the query with `z_cancellation_token_cancel()` called on a copy of it.
+    /// Once cancelled, the reply callback is not called anymore and is dropped, even if the query timeout is not reached.
+    /// Cancellation only stops the delivery of the replies: the query is not withdrawn from the network, so the queryables
+    /// still process it (see `z_cancellation_token_cancel()`).
+    pub cancellation_token: Option<&'static mut z_moved_cancellation_token_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, the error reply notifying that the query timeout fired is not passed to the reply callback.
+    /// The replies received before the timeout are still delivered, and the timeout is instead reported by the
+    /// `Z_QUERY_TIMED_OUT` status of the query diagnostics (see `diagnostics`), so that they can knowingly be used as partial results.
+    /// Since zenoh does not flag the timeout reply, it is recognized as a `"Timeout"` string error sent by the querying session
+    /// itself once the timeout is passed: an identical error reply sent by a local queryable at the very instant the timeout
+    /// fires would be taken for it.
+    pub suppress_timeout_error_reply: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If not NULL, only the replies sent by the zenoh instance with this id (e.g. obtained with `z_reply_replier_id()`
+    /// from a previous reply) are passed to the reply callback, allowing to stick to a given replica of a storage.
+    /// Since the query is still routed by key expression, it is then sent to all the matching queryables without
+    /// consolidating their replies, whatever the `target` and `consolidation` options.
+    pub replier_id: Option<&'static z_id_t>,
 }
 
 /// Constructs default `z_get_options_t`
@@ -252,9 +380,43 @@ pub extern "C" fn z_get_options_default(this_: &mut MaybeUninit<z_get_options_t>
         #[cfg(feature = "unstable")]
         source_info: None,
         attachment: None,
+        #[cfg(feature = "unstable")]
+        diagnostics: None,
+        #[cfg(feature = "unstable")]
+        cancellation_token: None,
+        #[cfg(feature = "unstable")]
+        suppress_timeout_error_reply: false,
+        #[cfg(feature = "unstable")]
+        replier_id: None,
     });
 }
 
+fn _release_get_options(options: Option<&mut z_get_options_t>) {
+    if let Some(options) = options {
+        if let Some(payload) = options.payload.take() {
+            let _ = payload.take_rust_type();
+        }
+        if let Some(encoding) = options.encoding.take() {
+            let _ = encoding.take_rust_type();
+        }
+        if let Some(attachment) = options.attachment.take() {
+            let _ = attachment.take_rust_type();
+        }
+        #[cfg(feature = "unstable")]
+        {
+            if let Some(source_info) = options.source_info.take() {
+                let _ = source_info.take_rust_type();
+            }
+            if let Some(diagnostics) = options.diagnostics.take() {
+                let _ = diagnostics.take_rust_type();
+            }
+            if let Some(token) = options.cancellation_token.take() {
+                let _ = token.take_rust_type();
+            }
+        }
+    }
+}
+
 /// Query data from the matching queryables in the system.
 /// Replies are provided through a callback function.
 ///
@@ -264,16 +426,20 @@ pub extern "C" fn z_get_options_default(this_: &mut MaybeUninit<z_get_options_t>
 /// @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
 /// @param options: Additional options for the get. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, a negative error value upon failure.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, a negative error value upon failure.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_get(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
     parameters: *const c_char,
-    callback: &mut z_moved_closure_reply_t,
-    options: Option<&mut z_get_options_t>,
+    callback: Option<&mut z_moved_closure_reply_t>,
+    mut options: Option<&mut z_get_options_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "z_get") else {
+        _release_get_options(options);
+        return result::Z_EINVAL;
+    };
     let callback = callback.take_rust_type();
     let p = if parameters.is_null() {
         ""
@@ -281,12 +447,46 @@ pub unsafe extern "C" fn z_get(
         CStr::from_ptr(parameters).to_str().unwrap()
     };
     let session = session.as_rust_type_ref();
+    #[cfg(feature = "unstable")]
+    let callback = match options
+        .as_mut()
+        .and_then(|o| o.cancellation_token.take())
+        .and_then(|t| t.take_rust_type())
+    {
+        Some(token) => {
+            let callback = CancellableReplyCallback::new(callback, &token);
+            (move |reply: &mut z_loaned_reply_t| callback.call(reply)).into()
+        }
+        None => callback,
+    };
+    #[cfg(feature = "unstable")]
+    let callback = deferred_callbacks::defer_reply_callback(session, callback);
     let key_expr = key_expr.as_rust_type_ref();
     let mut get = session.get(Selector::from((key_expr, p)));
+    #[cfg(feature = "unstable")]
+    let mut diagnostics = None;
+    #[cfg(feature = "unstable")]
+    let mut suppress_timeout_error_reply = false;
+    #[cfg(feature = "unstable")]
+    let mut timeout = session.state().queries_default_timeout;
+    #[cfg(feature = "unstable")]
+    let zid = session.zid();
+    #[cfg(feature = "unstable")]
+    let mut replier_id = None;
     if let Some(options) = options {
         if let Some(payload) = options.payload.take() {
             get = get.payload(payload.take_rust_type());
         }
+        #[cfg(feature = "unstable")]
+        if let Some(callback) = options.diagnostics.take() {
+            diagnostics = Some(std::sync::Mutex::new(QueryDiagnostics::new(
+                callback.take_rust_type(),
+            )));
+        }
+        #[cfg(feature = "unstable")]
+        {
+            suppress_timeout_error_reply = options.suppress_timeout_error_reply;
+        }
         if let Some(encoding) = options.encoding.take() {
             get = get.encoding(encoding.take_rust_type());
         }
@@ -309,14 +509,52 @@ pub unsafe extern "C" fn z_get(
             get = get
                 .allowed_destination(options.allowed_destination.into())
                 .accept_replies(options.accept_replies.into());
+            replier_id = options.replier_id.map(|id| *id.as_rust_type_ref());
+            if replier_id.is_some() {
+                get = get
+                    .target(QueryTarget::All)
+                    .consolidation(ConsolidationMode::None);
+            }
         }
 
         if options.timeout_ms != 0 {
             get = get.timeout(std::time::Duration::from_millis(options.timeout_ms));
+            #[cfg(feature = "unstable")]
+            {
+                timeout = std::time::Duration::from_millis(options.timeout_ms);
+            }
         }
     }
+    // The timeout is passed to zenoh even when it is the default one, so that the deadline telling the timeout reply
+    // apart is derived from the same value as the zenoh timer.
+    #[cfg(feature = "unstable")]
+    let deadline = {
+        get = get.timeout(timeout);
+        std::time::Instant::now() + timeout
+    };
     match get
         .callback(move |response| {
+            #[cfg(feature = "unstable")]
+            {
+                let diagnostics = diagnostics
+                    .as_ref()
+                    .map(|d| d.lock().unwrap_or_else(std::sync::PoisonError::into_inner));
+                if is_timeout_reply(&response, &zid, deadline) {
+                    if let Some(mut diagnostics) = diagnostics {
+                        diagnostics.record_timeout();
+                    }
+                    if suppress_timeout_error_reply {
+                        return;
+                    }
+                } else {
+                    if replier_id.is_some_and(|id| response.replier_id() != Some(id)) {
+                        return;
+                    }
+                    if let Some(mut diagnostics) = diagnostics {
+                        diagnostics.record(&response);
+                    }
+                }
+            }
             let mut owned_response = Some(response);
             z_closure_reply_call(
                 z_closure_reply_loan(&callback),
@@ -339,7 +577,10 @@ pub unsafe extern "C" fn z_get(
 
 /// Frees reply, resetting it to its gravestone state.
 #[no_mangle]
-pub extern "C" fn z_reply_drop(this_: &mut z_moved_reply_t) {
+pub extern "C" fn z_reply_drop(this_: Option<&mut z_moved_reply_t>) {
+    let Some(this_) = moved_or_null(this_, "z_reply_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -16,9 +16,11 @@ use std::mem::MaybeUninit;
 use zenoh::{session::ZenohId, Wait};
 
 pub use crate::opaque_types::z_id_t;
+#[cfg(feature = "unstable")]
+use crate::z_owned_slice_t;
 use crate::{
     result,
-    transmute::{CTypeRef, IntoCType, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, CTypeRef, IntoCType, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_zid_call, z_closure_zid_loan, z_loaned_session_t, z_moved_closure_zid_t,
     z_owned_string_t,
 };
@@ -37,6 +39,51 @@ pub extern "C" fn z_id_to_string(zid: &z_id_t, dst: &mut MaybeUninit<z_owned_str
     dst.as_rust_type_mut_uninit().write(zid.to_string().into());
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the binary representation of the `z_id_t`: its 16 bytes in LSB-first order.
+///
+/// This representation is stable and can be parsed back with `z_id_from_bytes()`.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_id_to_bytes(zid: &z_id_t, dst: &mut MaybeUninit<z_owned_slice_t>) {
+    dst.as_rust_type_mut_uninit().write(zid.id.to_vec().into());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Parses a `z_id_t` from its binary representation, as constructed by `z_id_to_bytes()`.
+///
+/// @param dst: The location where the parsed id will be written.
+/// @param data: A pointer to the binary representation.
+/// @param len: The length of the binary representation, which should be 16.
+/// @return 0 in case of success, `Z_EINVAL` if `len` is not 16 or if the id is all zeros.
+#[cfg(feature = "unstable")]
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn z_id_from_bytes(
+    dst: &mut MaybeUninit<z_id_t>,
+    data: *const u8,
+    len: usize,
+) -> result::z_result_t {
+    let Some(id) = id_from_bytes(data, len) else {
+        tracing::error!("Invalid binary representation of z_id_t");
+        return result::Z_EINVAL;
+    };
+    dst.write(id.into());
+    result::Z_OK
+}
+
+#[cfg(feature = "unstable")]
+unsafe fn id_from_bytes(data: *const u8, len: usize) -> Option<[u8; 16]> {
+    if data.is_null() || len != 16 {
+        return None;
+    }
+    let id: [u8; 16] = std::slice::from_raw_parts(data, len).try_into().ok()?;
+    match id.iter().any(|b| *b != 0) {
+        true => Some(id),
+        false => None,
+    }
+}
+
 /// @brief Returns the session's Zenoh ID.
 ///
 /// Unless the `session` is invalid, that ID is guaranteed to be non-zero.
@@ -54,13 +101,16 @@ pub unsafe extern "C" fn z_info_zid(session: &z_loaned_session_t) -> z_id_t {
 /// `callback` will be called once for each ID, is guaranteed to never be called concurrently,
 /// and is guaranteed to be dropped before this function exits.
 ///
-/// Retuns 0 on success, negative values on failure
+/// Retuns 0 on success, `Z_EINVAL` if `callback` is NULL, negative values on failure
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_info_peers_zid(
     session: &z_loaned_session_t,
-    callback: &mut z_moved_closure_zid_t,
+    callback: Option<&mut z_moved_closure_zid_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "z_info_peers_zid") else {
+        return result::Z_EINVAL;
+    };
     let session = session.as_rust_type_ref();
     let callback = callback.take_rust_type();
     for mut id in session.info().peers_zid().wait() {
@@ -74,13 +124,16 @@ pub unsafe extern "C" fn z_info_peers_zid(
 /// `callback` will be called once for each ID, is guaranteed to never be called concurrently,
 /// and is guaranteed to be dropped before this function exits.
 ///
-/// Retuns 0 on success, negative values on failure.
+/// Retuns 0 on success, `Z_EINVAL` if `callback` is NULL, negative values on failure.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_info_routers_zid(
     session: &z_loaned_session_t,
-    callback: &mut z_moved_closure_zid_t,
+    callback: Option<&mut z_moved_closure_zid_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "z_info_routers_zid") else {
+        return result::Z_EINVAL;
+    };
     let session = session.as_rust_type_ref();
     let callback = callback.take_rust_type();
     for mut id in session.info().routers_zid().wait() {
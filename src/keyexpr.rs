@@ -26,7 +26,7 @@ pub use crate::opaque_types::{
 };
 use crate::{
     result::{self, z_result_t, Z_OK},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_session_t, z_view_string_from_substr, z_view_string_t,
 };
 
@@ -147,7 +147,10 @@ pub unsafe extern "C" fn z_view_keyexpr_loan(this_: &z_view_keyexpr_t) -> &z_loa
 
 /// Frees key expression and resets it to its gravestone state.
 #[no_mangle]
-pub extern "C" fn z_keyexpr_drop(this_: &mut z_moved_keyexpr_t) {
+pub extern "C" fn z_keyexpr_drop(this_: Option<&mut z_moved_keyexpr_t>) {
+    let Some(this_) = moved_or_null(this_, "z_keyexpr_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -487,13 +490,16 @@ pub extern "C" fn z_declare_keyexpr(
 }
 
 /// Undeclares the key expression generated by a call to `z_declare_keyexpr()`.
-/// The key expression is consumed.
-/// @return 0 in case of success, negative error code otherwise.
+/// The key expression is consumed. Passing NULL is a no-op.
+/// @return 0 in case of success or if `key_expr` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn z_undeclare_keyexpr(
     session: &z_loaned_session_t,
-    key_expr: &mut z_moved_keyexpr_t,
+    key_expr: Option<&mut z_moved_keyexpr_t>,
 ) -> result::z_result_t {
+    let Some(key_expr) = moved_or_null(key_expr, "z_undeclare_keyexpr") else {
+        return result::Z_OK;
+    };
     let Some(kexpr) = key_expr.take_rust_type() else {
         tracing::debug!("Attempted to undeclare dropped keyexpr");
         return result::Z_EINVAL;
@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::sync::Arc;
+
+use zenoh::key_expr::KeyExpr;
+
+use crate::{
+    entity_limits::{self, EntityKind, EntitySlot},
+    result::{self, z_result_t},
+    transmute::{moved_or_null, LoanedCTypeRef, OwnedCTypeRef, RustTypeRef, TakeRustType},
+    z_loaned_session_t, zc_closure_keyexpr_interceptor_call, zc_closure_keyexpr_interceptor_loan,
+    zc_moved_closure_keyexpr_interceptor_t, zc_owned_closure_keyexpr_interceptor_t, CSession,
+};
+
+/// Passes the key expression of an entity being declared on the session to its interceptor, if any.
+///
+/// Returns the key expression rewritten by the interceptor, if any, or the error code returned by the interceptor
+/// if the declaration is rejected.
+fn intercept_keyexpr(
+    session: &CSession,
+    key_expr: &KeyExpr<'static>,
+) -> Result<Option<KeyExpr<'static>>, z_result_t> {
+    let Some(interceptor) = session
+        .state()
+        .keyexpr_interceptor
+        .read()
+        .ok()
+        .and_then(|interceptor| interceptor.clone())
+    else {
+        return Ok(None);
+    };
+    let mut rewritten: Option<KeyExpr<'static>> = None;
+    let res = zc_closure_keyexpr_interceptor_call(
+        zc_closure_keyexpr_interceptor_loan(&interceptor),
+        key_expr.as_loaned_c_type_ref(),
+        rewritten.as_owned_c_type_mut(),
+    );
+    if res != result::Z_OK {
+        tracing::error!(
+            "Declaration on {} was rejected by the key expression interceptor: {}",
+            key_expr,
+            res
+        );
+        return Err(res);
+    }
+    Ok(rewritten)
+}
+
+/// Admits the declaration of an entity of the given kind on the session.
+///
+/// The key expression is passed to the interceptor of the session, then a slot is reserved for the entity
+/// in the counters of the session. Returns the key expression rewritten by the interceptor, if any, along with the slot.
+pub(crate) fn admit_declaration(
+    session: &CSession,
+    key_expr: &KeyExpr<'static>,
+    kind: EntityKind,
+) -> Result<(Option<KeyExpr<'static>>, EntitySlot), z_result_t> {
+    let intercepted = intercept_keyexpr(session, key_expr)?;
+    let slot = entity_limits::acquire_entity(session, kind)?;
+    Ok((intercepted, slot))
+}
+
+/// Admits the declaration of several entities on the same key expression, e.g. the publishers and subscribers of a stream.
+///
+/// The interceptor of the session is called once, and the slots already reserved are given back if one of the entities
+/// is over its limit.
+pub(crate) fn admit_declarations(
+    session: &CSession,
+    key_expr: &KeyExpr<'static>,
+    kinds: &[EntityKind],
+) -> Result<(Option<KeyExpr<'static>>, Vec<EntitySlot>), z_result_t> {
+    let intercepted = intercept_keyexpr(session, key_expr)?;
+    let slots = kinds
+        .iter()
+        .map(|kind| entity_limits::acquire_entity(session, *kind))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((intercepted, slots))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sets the interceptor called with the key expression of each entity declared on the session.
+///
+/// It is called for the publishers, subscribers, queryables and queriers, including the advanced, liveliness and querying
+/// ones, and for the key expressions of the streams, stream listeners and watches. The key expressions of the accepted
+/// streams are derived from the one of the opening end, so they can be rejected but not rewritten.
+///
+/// The interceptor can rewrite the key expression (e.g. to add a tenant prefix) or reject the declaration, in which case
+/// the declaring function fails with the error code returned by the interceptor (usually `Z_EDENIED`).
+/// It replaces the previously set interceptor, if any. Passing NULL or a closure in its gravestone state removes the interceptor.
+///
+/// @param session: The zenoh session.
+/// @param interceptor: The interceptor closure.
+#[no_mangle]
+pub extern "C" fn zc_session_set_keyexpr_interceptor(
+    session: &z_loaned_session_t,
+    interceptor: Option<&mut zc_moved_closure_keyexpr_interceptor_t>,
+) {
+    let interceptor = moved_or_null(interceptor, "zc_session_set_keyexpr_interceptor")
+        .map(|i| i.take_rust_type())
+        .unwrap_or_default();
+    if let Ok(mut current) = session
+        .as_rust_type_ref()
+        .state()
+        .keyexpr_interceptor
+        .write()
+    {
+        *current = (!interceptor.is_empty()).then(|| Arc::new(interceptor));
+    }
+}
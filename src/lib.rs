@@ -14,11 +14,15 @@
 
 #![allow(non_camel_case_types)]
 
-use std::{cmp::min, slice};
+use std::{
+    cmp::min,
+    slice,
+    sync::atomic::{AtomicU8, Ordering},
+};
 
 use libc::c_void;
 
-use crate::transmute::{LoanedCTypeRef, TakeRustType};
+use crate::transmute::{moved_or_null, LoanedCTypeRef, TakeRustType};
 #[macro_use]
 mod transmute;
 pub mod opaque_types;
@@ -26,6 +30,8 @@ pub use crate::opaque_types::*;
 
 mod collections;
 pub mod result;
+mod stateful;
+pub(crate) use crate::stateful::Stateful;
 pub use crate::collections::*;
 mod config;
 pub use crate::config::*;
@@ -68,10 +74,50 @@ pub use platform::*;
 mod liveliness;
 pub use liveliness::*;
 #[cfg(feature = "unstable")]
+mod aead;
+#[cfg(feature = "unstable")]
+pub use aead::*;
+#[cfg(feature = "unstable")]
+mod cancellation;
+#[cfg(feature = "unstable")]
+pub use cancellation::*;
+#[cfg(feature = "unstable")]
+mod deferred_callbacks;
+#[cfg(feature = "unstable")]
+pub use deferred_callbacks::*;
+#[cfg(feature = "unstable")]
+mod drop_counters;
+#[cfg(feature = "unstable")]
+pub use drop_counters::*;
+#[cfg(feature = "unstable")]
+mod entity_limits;
+#[cfg(feature = "unstable")]
+pub use entity_limits::*;
+#[cfg(feature = "unstable")]
+mod keyexpr_interceptor;
+#[cfg(feature = "unstable")]
+pub use keyexpr_interceptor::*;
+#[cfg(feature = "unstable")]
+mod metrics;
+#[cfg(feature = "unstable")]
+pub use metrics::*;
+#[cfg(feature = "unstable")]
 mod matching;
 #[cfg(feature = "unstable")]
 pub use matching::*;
 #[cfg(feature = "unstable")]
+mod payload_limits;
+#[cfg(feature = "unstable")]
+pub use payload_limits::*;
+#[cfg(all(feature = "plugins", feature = "unstable"))]
+mod plugins;
+#[cfg(all(feature = "plugins", feature = "unstable"))]
+pub use plugins::*;
+#[cfg(all(feature = "rest-gateway", feature = "unstable"))]
+mod rest_gateway;
+#[cfg(all(feature = "rest-gateway", feature = "unstable"))]
+pub use rest_gateway::*;
+#[cfg(feature = "unstable")]
 mod publication_cache;
 #[cfg(feature = "unstable")]
 pub use publication_cache::*;
@@ -87,6 +133,22 @@ pub use advanced_subscriber::*;
 mod advanced_publisher;
 #[cfg(feature = "unstable")]
 pub use advanced_publisher::*;
+#[cfg(feature = "unstable")]
+mod time_range;
+#[cfg(feature = "unstable")]
+pub use time_range::*;
+#[cfg(feature = "unstable")]
+mod stream;
+#[cfg(feature = "unstable")]
+pub use stream::*;
+#[cfg(feature = "unstable")]
+mod selftest;
+#[cfg(feature = "unstable")]
+pub use selftest::*;
+#[cfg(feature = "unstable")]
+mod watch;
+#[cfg(feature = "unstable")]
+pub use watch::*;
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 pub mod context;
 
@@ -126,19 +188,32 @@ pub unsafe extern "C" fn zc_init_log_from_env_or(
     }
 }
 
+/// Minimum severity of the log messages passed to the callbacks registered with `zc_init_log_with_callback()`
+/// and `zc_init_log_with_record_callback()`.
+static LOG_MIN_SEVERITY: AtomicU8 = AtomicU8::new(zc_log_severity_t::TRACE as u8);
+
+fn log_severity_enabled(severity: zc_log_severity_t) -> bool {
+    severity as u8 >= LOG_MIN_SEVERITY.load(Ordering::Relaxed)
+}
+
 /// Initializes the zenoh runtime logger with custom callback.
 ///
 /// @param min_severity: Minimum severity level of log message to be be passed to the `callback`.
-/// Messages with lower severity levels will be ignored.
+/// Messages with lower severity levels will be ignored. It can be changed later with `zc_log_set_min_severity()`.
 /// @param callback: A closure that will be called with each log message severity level and content.
+/// If NULL, the logger is not initialized.
 #[no_mangle]
 pub extern "C" fn zc_init_log_with_callback(
     min_severity: zc_log_severity_t,
-    callback: &mut zc_moved_closure_log_t,
+    callback: Option<&mut zc_moved_closure_log_t>,
 ) {
+    let Some(callback) = moved_or_null(callback, "zc_init_log_with_callback") else {
+        return;
+    };
     let callback = callback.take_rust_type();
+    LOG_MIN_SEVERITY.store(min_severity as u8, Ordering::Relaxed);
     zenoh_util::log::init_log_with_callback(
-        move |meta| min_severity <= (*meta.level()).into(),
+        move |meta| log_severity_enabled((*meta.level()).into()),
         move |record| {
             if let Some(s) = record.message.as_ref() {
                 let c = CStringView::new_borrowed_from_slice(s.as_bytes());
@@ -152,6 +227,56 @@ pub extern "C" fn zc_init_log_with_callback(
     );
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Initializes the zenoh runtime logger with custom callback receiving the target of each log record.
+///
+/// The target is the module path of the code that emitted the record (e.g. `zenoh_transport::unicast`),
+/// allowing host loggers to map Zenoh modules to their own categories.
+///
+/// @param min_severity: Minimum severity level of log message to be be passed to the `callback`.
+/// Messages with lower severity levels will be ignored. It can be changed later with `zc_log_set_min_severity()`.
+/// @param callback: A closure that will be called with each log record severity level, target and message.
+/// If NULL, the logger is not initialized.
+#[no_mangle]
+pub extern "C" fn zc_init_log_with_record_callback(
+    min_severity: zc_log_severity_t,
+    callback: Option<&mut zc_moved_closure_log_record_t>,
+) {
+    let Some(callback) = moved_or_null(callback, "zc_init_log_with_record_callback") else {
+        return;
+    };
+    let callback = callback.take_rust_type();
+    LOG_MIN_SEVERITY.store(min_severity as u8, Ordering::Relaxed);
+    zenoh_util::log::init_log_with_callback(
+        move |meta| log_severity_enabled((*meta.level()).into()),
+        move |record| {
+            if let Some(s) = record.message.as_ref() {
+                let target = CStringView::new_borrowed_from_slice(record.target.as_bytes());
+                let msg = CStringView::new_borrowed_from_slice(s.as_bytes());
+                zc_closure_log_record_call(
+                    zc_closure_log_record_loan(&callback),
+                    record.level.into(),
+                    target.as_loaned_c_type_ref(),
+                    msg.as_loaned_c_type_ref(),
+                );
+            }
+        },
+    );
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Changes the minimum severity level of the log messages passed to the callback registered with
+/// `zc_init_log_with_callback()` or `zc_init_log_with_record_callback()`.
+///
+/// It has no effect on the loggers initialized from the environment.
+#[no_mangle]
+pub extern "C" fn zc_log_set_min_severity(min_severity: zc_log_severity_t) {
+    LOG_MIN_SEVERITY.store(min_severity as u8, Ordering::Relaxed);
+    tracing::callsite::rebuild_interest_cache();
+}
+
 // Test should be runned with `cargo test --no-default-features`
 #[test]
 #[cfg(not(feature = "default"))]
@@ -16,21 +16,29 @@ use std::mem::MaybeUninit;
 
 use zenoh::{
     handlers::Callback,
+    key_expr::KeyExpr,
     liveliness::{LivelinessSubscriberBuilder, LivelinessToken},
     sample::Sample,
     Wait,
 };
 
+#[cfg(feature = "unstable")]
+use crate::{
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor,
+};
 use crate::{
     opaque_types::{z_loaned_liveliness_token_t, z_owned_liveliness_token_t},
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_reply_call, z_closure_reply_loan, z_closure_sample_call, z_closure_sample_loan,
     z_loaned_keyexpr_t, z_loaned_session_t, z_moved_closure_reply_t, z_moved_closure_sample_t,
-    z_moved_liveliness_token_t, z_owned_subscriber_t,
+    z_moved_liveliness_token_t, z_owned_subscriber_t, CSubscriber, Stateful, SubscriberState,
 };
+/// A liveliness token, along with the key expression it was declared on, which is only kept in unstable builds.
+pub(crate) type CLivelinessToken = Stateful<LivelinessToken, KeyExpr<'static>>;
 decl_c_type!(
-    owned(z_owned_liveliness_token_t, option LivelinessToken),
+    owned(z_owned_liveliness_token_t, option CLivelinessToken),
     loaned(z_loaned_liveliness_token_t),
 );
 
@@ -50,10 +58,23 @@ pub extern "C" fn z_internal_liveliness_token_check(this_: &z_owned_liveliness_t
 
 /// @brief Undeclares liveliness token, frees memory and resets it to a gravestone state.
 #[no_mangle]
-pub extern "C" fn z_liveliness_token_drop(this_: &mut z_moved_liveliness_token_t) {
+pub extern "C" fn z_liveliness_token_drop(this_: Option<&mut z_moved_liveliness_token_t>) {
+    let Some(this_) = moved_or_null(this_, "z_liveliness_token_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the key expression of the liveliness token.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_liveliness_token_keyexpr(
+    this_: &z_loaned_liveliness_token_t,
+) -> &z_loaned_keyexpr_t {
+    this_.as_rust_type_ref().state().as_loaned_c_type_ref()
+}
+
 /// @brief The options for `z_liveliness_declare_token()`.
 #[repr(C)]
 pub struct z_liveliness_token_options_t {
@@ -101,7 +122,7 @@ pub extern "C" fn z_liveliness_declare_token(
     let key_expr = key_expr.as_rust_type_ref();
     match session.liveliness().declare_token(key_expr).wait() {
         Ok(token) => {
-            this.write(Some(token));
+            this.write(Some(CLivelinessToken::new(token, key_expr.clone())));
             result::Z_OK
         }
         Err(e) => {
@@ -115,9 +136,13 @@ pub extern "C" fn z_liveliness_declare_token(
 /// @brief Destroys a liveliness token, notifying subscribers of its destruction.
 #[no_mangle]
 pub extern "C" fn z_liveliness_undeclare_token(
-    this: &mut z_moved_liveliness_token_t,
+    this: Option<&mut z_moved_liveliness_token_t>,
 ) -> result::z_result_t {
+    let Some(this) = moved_or_null(this, "z_liveliness_undeclare_token") else {
+        return result::Z_OK;
+    };
     if let Some(token) = this.take_rust_type() {
+        let (token, _) = token.into_parts();
         if let Err(e) = token.undeclare().wait() {
             tracing::error!("Failed to undeclare token: {e}");
             return result::Z_EGENERIC;
@@ -146,10 +171,17 @@ fn _liveliness_declare_subscriber_inner<'a, 'b>(
     key_expr: &'b z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut z_liveliness_subscriber_options_t>,
-) -> LivelinessSubscriberBuilder<'a, 'b, Callback<Sample>> {
+) -> Result<LivelinessSubscriberBuilder<'a, 'b, Callback<Sample>>, result::z_result_t> {
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) =
+        keyexpr_interceptor::admit_declaration(session, key_expr, EntityKind::Subscriber)?;
+    #[cfg(feature = "unstable")]
+    let key_expr: KeyExpr<'b> = intercepted.unwrap_or_else(|| key_expr.clone());
+    #[cfg(feature = "unstable")]
+    let callback = entity_limits::hold_in_sample_callback(slot, callback);
     let sub = session
         .liveliness()
         .declare_subscriber(key_expr)
@@ -163,7 +195,7 @@ fn _liveliness_declare_subscriber_inner<'a, 'b>(
                     .as_loaned_c_type_mut()
             })
         });
-    sub
+    Ok(sub)
 }
 /// @brief Declares a subscriber on liveliness tokens that intersect `key_expr`.
 ///
@@ -173,20 +205,34 @@ fn _liveliness_declare_subscriber_inner<'a, 'b>(
 /// @param callback: The callback function that will be called each time a liveliness token status is changed.
 /// @param options: The options to be passed to the liveliness subscriber declaration.
 ///
-/// @return 0 in case of success, negative error values otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
 #[no_mangle]
 pub extern "C" fn z_liveliness_declare_subscriber(
     session: &z_loaned_session_t,
     subscriber: &mut MaybeUninit<z_owned_subscriber_t>,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = subscriber.as_rust_type_mut_uninit();
-    let subscriber = _liveliness_declare_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "z_liveliness_declare_subscriber") else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let subscriber =
+        match _liveliness_declare_subscriber_inner(session, key_expr, callback, options) {
+            Ok(s) => s,
+            Err(e) => {
+                this.write(None);
+                return e;
+            }
+        };
     match subscriber.wait() {
         Ok(subscriber) => {
-            this.write(Some(subscriber));
+            this.write(Some(CSubscriber::new(
+                subscriber,
+                SubscriberState::default(),
+            )));
             result::Z_OK
         }
         Err(e) => {
@@ -205,16 +251,24 @@ pub extern "C" fn z_liveliness_declare_subscriber(
 /// @param callback: The callback function that will be called each time a liveliness token status is changed.
 /// @param options: The options to be passed to the liveliness subscriber declaration.
 ///
-/// @return 0 in case of success, negative error values otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
 #[no_mangle]
 #[cfg(feature = "unstable")]
 pub extern "C" fn zc_liveliness_declare_background_subscriber(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
-    let subscriber = _liveliness_declare_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "zc_liveliness_declare_background_subscriber")
+    else {
+        return result::Z_EINVAL;
+    };
+    let subscriber =
+        match _liveliness_declare_subscriber_inner(session, key_expr, callback, options) {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
     match subscriber.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -245,13 +299,18 @@ pub extern "C" fn z_liveliness_get_options_default(
 /// @param key_expr: The key expression to query liveliness tokens for.
 /// @param callback: The callback function that will be called for each received reply.
 /// @param options: Additional options for the liveliness get operation.
+///
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error values otherwise.
 #[no_mangle]
 pub extern "C" fn z_liveliness_get(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_reply_t,
+    callback: Option<&mut z_moved_closure_reply_t>,
     options: Option<&mut z_liveliness_get_options_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "z_liveliness_get") else {
+        return result::Z_EINVAL;
+    };
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
@@ -12,19 +12,105 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+use std::{
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
 
-use zenoh::{matching::MatchingListener, Wait};
+use zenoh::{
+    matching::{MatchingListener, MatchingStatus},
+    Wait,
+};
 
 pub use crate::opaque_types::{zc_moved_matching_listener_t, zc_owned_matching_listener_t};
 use crate::{
     result,
-    transmute::{RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    zc_closure_matching_status_call, zc_closure_matching_status_loan,
+    zc_owned_closure_matching_status_t, CSession,
 };
+/// The state shared by a matching listener and its callback.
+#[derive(Default)]
+pub(crate) struct MatchingListenerState {
+    /// Set while the callback is paused.
+    paused: AtomicBool,
+    /// Set once the listener was undeclared along with the entity it was declared on.
+    orphaned: AtomicBool,
+}
+
+impl MatchingListenerState {
+    fn is_active(&self) -> bool {
+        !self.paused.load(Ordering::Relaxed) && !self.orphaned.load(Ordering::Relaxed)
+    }
+}
+
+/// A matching listener, with the state shared with its callback.
+pub(crate) type CMatchingListener = (MatchingListener<()>, Arc<MatchingListenerState>);
 decl_c_type!(
-    owned(zc_owned_matching_listener_t, option MatchingListener<()>),
+    owned(zc_owned_matching_listener_t, option CMatchingListener),
 );
 
+/// The matching listeners undeclared along with a publisher or querier, kept in its state, or with a session,
+/// kept in its state as well and shared with the entities declared on it.
+#[derive(Default)]
+pub(crate) struct DependentListeners {
+    listeners: Mutex<Vec<Weak<MatchingListenerState>>>,
+    /// The listeners of the session the entity is declared on.
+    session: Option<Arc<DependentListeners>>,
+}
+
+impl DependentListeners {
+    /// Constructs the listeners of an entity declared on the session.
+    pub(crate) fn for_entity(session: &CSession) -> Self {
+        DependentListeners {
+            listeners: Mutex::new(Vec::new()),
+            session: Some(session.state().listeners.clone()),
+        }
+    }
+
+    /// Records that the matching listener is undeclared along with the entity and its session.
+    pub(crate) fn register(&self, state: &Arc<MatchingListenerState>) {
+        if let Ok(mut listeners) = self.listeners.lock() {
+            listeners.retain(|l| l.strong_count() > 0);
+            listeners.push(Arc::downgrade(state));
+        }
+        if let Some(session) = &self.session {
+            session.register(state);
+        }
+    }
+
+    /// Marks the matching listeners as orphaned, as they are undeclared along with the entity or session.
+    pub(crate) fn orphan(&self) {
+        let listeners = match self.listeners.lock() {
+            Ok(mut listeners) => std::mem::take(&mut *listeners),
+            Err(_) => return,
+        };
+        for state in listeners.iter().filter_map(Weak::upgrade) {
+            state.orphaned.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Returns the matching listener callback passing the matching status to the closure, while the listener is
+/// neither paused nor orphaned.
+pub(crate) fn _matching_status_callback(
+    callback: zc_owned_closure_matching_status_t,
+    state: Arc<MatchingListenerState>,
+) -> impl FnMut(MatchingStatus) + Send + Sync + 'static {
+    move |matching_status| {
+        if !state.is_active() {
+            return;
+        }
+        let status = zc_matching_status_t {
+            matching: matching_status.matching(),
+        };
+        zc_closure_matching_status_call(zc_closure_matching_status_loan(&callback), &status);
+    }
+}
+
 #[no_mangle]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Constructs an empty matching listener.
@@ -56,19 +142,37 @@ pub struct zc_matching_status_t {
 /// @brief Undeclares the given matching listener, droping and invalidating it.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn zc_matching_listener_drop(this: &mut zc_moved_matching_listener_t) {
-    std::mem::drop(this.take_rust_type())
+pub extern "C" fn zc_matching_listener_drop(this: Option<&mut zc_moved_matching_listener_t>) {
+    let Some(this) = moved_or_null(this, "zc_matching_listener_drop") else {
+        return;
+    };
+    if let Some((m, state)) = this.take_rust_type() {
+        if state.orphaned.load(Ordering::Relaxed) {
+            // the listener is already undeclared, which dropping it would report as an error
+            let _ = m.undeclare().wait();
+        }
+    }
 }
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Undeclares the given matching listener, droping and invalidating it.
+///
+/// Matching listeners are undeclared along with the publisher or querier they were declared on, or when its session
+/// is closed: undeclaring them afterwards only drops them, and succeeds.
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub extern "C" fn zc_undeclare_matching_listener(
-    this: &mut zc_moved_matching_listener_t,
+    this: Option<&mut zc_moved_matching_listener_t>,
 ) -> result::z_result_t {
-    if let Some(m) = this.take_rust_type() {
+    let Some(this) = moved_or_null(this, "zc_undeclare_matching_listener") else {
+        return result::Z_OK;
+    };
+    if let Some((m, state)) = this.take_rust_type() {
+        if state.orphaned.load(Ordering::Relaxed) {
+            let _ = m.undeclare().wait();
+            return result::Z_OK;
+        }
         if let Err(e) = m.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_ENETWORK;
@@ -76,3 +180,50 @@ pub extern "C" fn zc_undeclare_matching_listener(
     }
     result::Z_OK
 }
+
+fn _set_matching_listener_paused(
+    this_: &zc_owned_matching_listener_t,
+    paused: bool,
+) -> result::z_result_t {
+    match this_.as_rust_type_ref() {
+        Some((_, state)) => {
+            state.paused.store(paused, Ordering::Relaxed);
+            result::Z_OK
+        }
+        None => result::Z_EINVAL,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Pauses the matching listener: its callback is not called on matching status changes until
+/// `zc_matching_listener_resume()` is called, while the listener stays declared.
+///
+/// The changes happening while the listener is paused are not replayed on resume, the current matching status
+/// can be retrieved with `zc_publisher_get_matching_status()` or `zc_querier_get_matching_status()`.
+/// @return 0 in case of success, `Z_EINVAL` if the matching listener is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_matching_listener_pause(
+    this_: &zc_owned_matching_listener_t,
+) -> result::z_result_t {
+    _set_matching_listener_paused(this_, true)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Resumes a matching listener paused with `zc_matching_listener_pause()`.
+/// @return 0 in case of success, `Z_EINVAL` if the matching listener is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_matching_listener_resume(
+    this_: &zc_owned_matching_listener_t,
+) -> result::z_result_t {
+    _set_matching_listener_paused(this_, false)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the matching listener is paused, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn zc_matching_listener_is_paused(this_: &zc_owned_matching_listener_t) -> bool {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .is_some_and(|(_, state)| state.paused.load(Ordering::Relaxed))
+}
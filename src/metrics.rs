@@ -0,0 +1,174 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{mem::MaybeUninit, time::Duration};
+
+use flume::RecvTimeoutError;
+use zenoh::{Session, Wait};
+
+pub use crate::opaque_types::{zc_moved_metrics_subscriber_t, zc_owned_metrics_subscriber_t};
+use crate::{
+    result,
+    transmute::{moved_or_null, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_session_t, zc_closure_metrics_call, zc_closure_metrics_loan,
+    zc_moved_closure_metrics_t,
+};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The metrics of the local zenoh node, as reported by its adminspace.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct zc_metrics_t {
+    /// The number of transport sessions with remote zenoh nodes.
+    pub sessions: usize,
+    /// The number of links of these transport sessions.
+    pub links: usize,
+    /// The number of subscriber routes known by the node.
+    pub subscribers: usize,
+    /// The number of queryable routes known by the node.
+    pub queryables: usize,
+}
+
+/// Stops the metrics polling thread when dropped.
+pub struct MetricsSubscriber {
+    _stop: flume::Sender<()>,
+}
+
+decl_c_type!(owned(zc_owned_metrics_subscriber_t, option MetricsSubscriber));
+
+fn count_replies(session: &Session, selector: String) -> zenoh::Result<usize> {
+    let replies = session.get(selector).wait()?;
+    Ok(replies.iter().filter(|r| r.result().is_ok()).count())
+}
+
+fn query_metrics(session: &Session) -> zenoh::Result<zc_metrics_t> {
+    let zid = session.zid();
+    let mut metrics = zc_metrics_t::default();
+    let replies = session.get(format!("@/{zid}/*")).wait()?;
+    for reply in replies.iter() {
+        let Ok(sample) = reply.result() else {
+            continue;
+        };
+        let Ok(payload) = sample.payload().try_to_string() else {
+            continue;
+        };
+        let Ok(info) = serde_json::from_str::<serde_json::Value>(&payload) else {
+            continue;
+        };
+        if let Some(sessions) = info.get("sessions").and_then(|s| s.as_array()) {
+            metrics.sessions += sessions.len();
+            metrics.links += sessions
+                .iter()
+                .filter_map(|s| s.get("links")?.as_array())
+                .map(Vec::len)
+                .sum::<usize>();
+        }
+    }
+    metrics.subscribers = count_replies(session, format!("@/{zid}/*/subscriber/**"))?;
+    metrics.queryables = count_replies(session, format!("@/{zid}/*/queryable/**"))?;
+    Ok(metrics)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Periodically queries the adminspace of the local zenoh node, and calls the callback with its parsed metrics.
+///
+/// The adminspace must be enabled, with read permissions, in the session configuration (`adminspace/enabled` and
+/// `adminspace/permissions/read` keys). The callback is called from a dedicated thread, first right away and then
+/// every `period_ms` milliseconds, until the subscriber is dropped or the session is closed. The subscriber keeps
+/// the session alive: it should be dropped before the session, unless the session is closed with `z_close()`.
+///
+/// @param session: The zenoh session.
+/// @param subscriber: An uninitialized location in memory where the metrics subscriber will be constructed.
+/// @param callback: The callback function that will be called with the metrics.
+/// @param period_ms: The polling period in milliseconds, must be non-zero.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL or `period_ms` is zero, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_metrics_subscribe(
+    session: &z_loaned_session_t,
+    subscriber: &mut MaybeUninit<zc_owned_metrics_subscriber_t>,
+    callback: Option<&mut zc_moved_closure_metrics_t>,
+    period_ms: u64,
+) -> result::z_result_t {
+    let subscriber = subscriber.as_rust_type_mut_uninit();
+    let Some(callback) = moved_or_null(callback, "zc_metrics_subscribe") else {
+        subscriber.write(None);
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    if period_ms == 0 {
+        tracing::error!("The metrics polling period must be non-zero");
+        subscriber.write(None);
+        return result::Z_EINVAL;
+    }
+    let session = session.as_rust_type_ref().clone();
+    let (stop, stopped) = flume::bounded::<()>(1);
+    let res = std::thread::Builder::new()
+        .name("zc-metrics".to_string())
+        .spawn(move || loop {
+            match query_metrics(&session) {
+                Ok(metrics) => {
+                    zc_closure_metrics_call(zc_closure_metrics_loan(&callback), &metrics)
+                }
+                Err(e) => tracing::error!("Failed to query the adminspace metrics: {}", e),
+            }
+            if session.is_closed() {
+                break;
+            }
+            match stopped.recv_timeout(Duration::from_millis(period_ms)) {
+                Err(RecvTimeoutError::Timeout) => continue,
+                _ => break,
+            }
+        });
+    match res {
+        Ok(_) => {
+            subscriber.write(Some(MetricsSubscriber { _stop: stop }));
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("Failed to start the metrics polling thread: {}", e);
+            subscriber.write(None);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a metrics subscriber in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_metrics_subscriber_null(
+    this_: &mut MaybeUninit<zc_owned_metrics_subscriber_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the metrics subscriber is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_metrics_subscriber_check(
+    this_: &zc_owned_metrics_subscriber_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Stops the metrics polling and resets the subscriber to its gravestone state.
+///
+/// The callback is dropped once its ongoing call, if any, returns.
+#[no_mangle]
+pub extern "C" fn zc_metrics_subscriber_drop(this_: Option<&mut zc_moved_metrics_subscriber_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_metrics_subscriber_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
@@ -0,0 +1,101 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use zenoh::{bytes::ZBytes, config::Config, key_expr::KeyExpr};
+
+use crate::{result, transmute::RustTypeRef, z_loaned_publisher_t};
+
+const BATCH_SIZE_KEY: &str = "transport/link/tx/batch_size";
+const MAX_MESSAGE_SIZE_KEY: &str = "transport/link/rx/max_message_size";
+const DEFAULT_BATCH_SIZE: usize = u16::MAX as usize;
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 1 << 30;
+/// A conservative estimate of the size of the frame and message headers sent along with a payload.
+const MESSAGE_OVERHEAD: usize = 64;
+
+/// The sizes of the messages a session can send, as configured when it was opened.
+/// They are kept in the session state, and resolved for each publisher when it is declared.
+#[derive(Clone, Copy)]
+pub(crate) struct PayloadLimits {
+    batch_size: usize,
+    max_message_size: usize,
+}
+
+impl Default for PayloadLimits {
+    fn default() -> Self {
+        PayloadLimits {
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl PayloadLimits {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        let get = |key: &str, default: usize| {
+            config
+                .get_json(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(default)
+        };
+        PayloadLimits {
+            batch_size: get(BATCH_SIZE_KEY, DEFAULT_BATCH_SIZE),
+            max_message_size: get(MAX_MESSAGE_SIZE_KEY, DEFAULT_MAX_MESSAGE_SIZE),
+        }
+    }
+
+    /// Returns the maximum size of the payloads published on `key_expr`, with or without fragmentation.
+    pub(crate) fn max_payload_size(&self, key_expr: &KeyExpr, allow_fragmentation: bool) -> usize {
+        match allow_fragmentation {
+            true => self.max_message_size,
+            false => self
+                .batch_size
+                .saturating_sub(MESSAGE_OVERHEAD + key_expr.as_str().len()),
+        }
+    }
+}
+
+/// Returns `Z_EFRAGMENT` if the payload and the attachment of a publication exceed `max_payload_size` bytes together.
+pub(crate) fn check_publication_size(
+    max_payload_size: usize,
+    payload: &ZBytes,
+    attachment: Option<&ZBytes>,
+) -> result::z_result_t {
+    let size = payload.len() + attachment.map_or(0, ZBytes::len);
+    if size > max_payload_size {
+        tracing::error!(
+            "Publication of {} bytes exceeds the maximum payload size ({} bytes)",
+            size,
+            max_payload_size
+        );
+        return result::Z_EFRAGMENT;
+    }
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the maximum size in bytes of the payload and attachment of a publication made with `z_publisher_put()`
+/// or `zc_forward()`.
+///
+/// If the publisher was declared with fragmentation disallowed (see `allow_fragmentation` field of `z_publisher_options_t`),
+/// this is a conservative estimate of the largest publication that fits in a single batch of the session links
+/// (`transport/link/tx/batch_size` configuration key), so that the accepted publications are never fragmented.
+/// Otherwise, this is the maximum size of the messages that can be reassembled from fragments
+/// (`transport/link/rx/max_message_size` configuration key), assuming the remote nodes use the same configuration.
+/// Larger publications are rejected with `Z_EFRAGMENT`, so file-transfer applications can use this size to split their
+/// data in chunks. The same limits apply to `ze_advanced_publisher_put()`, and the fragmented one to `z_put()`.
+#[no_mangle]
+pub extern "C" fn zc_publisher_max_payload_size(publisher: &z_loaned_publisher_t) -> usize {
+    publisher.as_rust_type_ref().state().max_payload_size
+}
@@ -9,7 +9,7 @@ use libc::c_void;
 pub use crate::opaque_types::{z_loaned_mutex_t, z_moved_mutex_t, z_owned_mutex_t};
 use crate::{
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
 };
 
 decl_c_type!(
@@ -30,7 +30,10 @@ pub extern "C" fn z_mutex_init(this_: &mut MaybeUninit<z_owned_mutex_t>) -> resu
 
 /// Drops mutex and resets it to its gravestone state.
 #[no_mangle]
-pub extern "C" fn z_mutex_drop(this_: &mut z_moved_mutex_t) {
+pub extern "C" fn z_mutex_drop(this_: Option<&mut z_moved_mutex_t>) {
+    let Some(this_) = moved_or_null(this_, "z_mutex_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -128,7 +131,10 @@ pub extern "C" fn z_internal_condvar_null(this_: &mut MaybeUninit<z_owned_condva
 
 /// Drops conditional variable.
 #[no_mangle]
-pub extern "C" fn z_condvar_drop(this_: &mut z_moved_condvar_t) {
+pub extern "C" fn z_condvar_drop(this_: Option<&mut z_moved_condvar_t>) {
+    let Some(this_) = moved_or_null(this_, "z_condvar_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -213,13 +219,19 @@ pub extern "C" fn z_internal_task_null(this_: &mut MaybeUninit<z_owned_task_t>)
 
 /// Detaches the task and releases all allocated resources.
 #[no_mangle]
-pub extern "C" fn z_task_detach(this_: &mut z_moved_task_t) {
+pub extern "C" fn z_task_detach(this_: Option<&mut z_moved_task_t>) {
+    let Some(this_) = moved_or_null(this_, "z_task_detach") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
 /// Joins the task and releases all allocated resources
 #[no_mangle]
-pub extern "C" fn z_task_join(this_: &mut z_moved_task_t) -> result::z_result_t {
+pub extern "C" fn z_task_join(this_: Option<&mut z_moved_task_t>) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "z_task_join") else {
+        return result::Z_OK;
+    };
     let Some(task) = this_.take_rust_type() else {
         return result::Z_OK;
     };
@@ -231,7 +243,10 @@ pub extern "C" fn z_task_join(this_: &mut z_moved_task_t) -> result::z_result_t
 
 /// Drop the task. Same as `z_task_detach`. Use `z_task_join` to wait for the task completion.
 #[no_mangle]
-pub extern "C" fn z_task_drop(this_: &mut z_moved_task_t) {
+pub extern "C" fn z_task_drop(this_: Option<&mut z_moved_task_t>) {
+    let Some(this_) = moved_or_null(this_, "z_task_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
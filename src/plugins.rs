@@ -0,0 +1,69 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::ffi::CStr;
+
+use libc::c_char;
+
+use crate::{result, transmute::RustTypeRef, z_loaned_session_t};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Loads and starts a plugin (e.g. `rest` or `storage_manager`) in the session, as zenohd would do.
+///
+/// The plugin is loaded from the `zenoh_plugin_<name>` dynamic library, looked up in the `plugins_search_dirs`
+/// of the session configuration, which must also have `plugins_loading/enabled` set to `true`.
+/// The plugin is started asynchronously, and is stopped when the session is closed.
+///
+/// @param session: The zenoh session.
+/// @param name: The name of the plugin.
+/// @param config_json: The JSON5-serialized configuration of the plugin, `"{}"` if NULL.
+///
+/// @return 0 in case of success, `Z_EINVAL` if the name or the configuration are invalid, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_session_load_plugin(
+    session: &z_loaned_session_t,
+    name: *const c_char,
+    config_json: *const c_char,
+) -> result::z_result_t {
+    let name = match CStr::from_ptr(name).to_str() {
+        Ok(name) if !name.is_empty() && !name.contains('/') => name,
+        _ => {
+            tracing::error!("Invalid plugin name");
+            return result::Z_EINVAL;
+        }
+    };
+    let config_json = if config_json.is_null() {
+        "{}"
+    } else {
+        match CStr::from_ptr(config_json).to_str() {
+            Ok(config_json) => config_json,
+            Err(e) => {
+                tracing::error!("Plugin configuration is not a valid utf-8 string: {}", e);
+                return result::Z_EINVAL;
+            }
+        }
+    };
+    let session = session.as_rust_type_ref();
+    match session
+        .config()
+        .insert_json5(&format!("plugins/{name}"), config_json)
+    {
+        Ok(_) => result::Z_OK,
+        Err(e) => {
+            tracing::error!("Failed to load plugin '{}': {}", name, e);
+            result::Z_EINVAL
+        }
+    }
+}
@@ -20,7 +20,7 @@ use zenoh_ext::{PublicationCacheBuilder, SessionExt};
 
 use crate::{
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_keyexpr_t, z_loaned_session_t,
 };
 #[cfg(feature = "unstable")]
@@ -176,7 +176,10 @@ pub extern "C" fn ze_internal_publication_cache_check(
 /// This is equivalent to calling `ze_undeclare_publication_cache()` and discarding its return value.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn ze_publication_cache_drop(this: &mut ze_moved_publication_cache_t) {
+pub extern "C" fn ze_publication_cache_drop(this: Option<&mut ze_moved_publication_cache_t>) {
+    let Some(this) = moved_or_null(this, "ze_publication_cache_drop") else {
+        return;
+    };
     std::mem::drop(this.take_rust_type())
 }
 
@@ -208,8 +211,11 @@ pub unsafe extern "C" fn ze_publication_cache_loan(
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_undeclare_publication_cache(
-    this: &mut ze_moved_publication_cache_t,
+    this: Option<&mut ze_moved_publication_cache_t>,
 ) -> result::z_result_t {
+    let Some(this) = moved_or_null(this, "ze_undeclare_publication_cache") else {
+        return result::Z_OK;
+    };
     if let Some(p) = this.take_rust_type() {
         if let Err(e) = p.undeclare().wait() {
             tracing::error!("{}", e);
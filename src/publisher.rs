@@ -13,9 +13,11 @@
 //
 
 use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::sync::Arc;
 
 #[cfg(feature = "unstable")]
-use zenoh::{handlers::Callback, matching::MatchingStatus};
+use zenoh::{bytes::ZBytes, handlers::Callback, matching::MatchingStatus, sample::SampleKind};
 use zenoh::{
     internal::traits::{EncodingBuilderTrait, SampleBuilderTrait, TimestampBuilderTrait},
     pubsub::{Publisher, PublisherBuilder},
@@ -26,20 +28,28 @@ use zenoh::{
 
 #[cfg(feature = "unstable")]
 use crate::zc_moved_closure_matching_status_t;
+#[cfg(feature = "unstable")]
 use crate::{
-    result::{self},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_congestion_control_t, z_loaned_keyexpr_t, z_loaned_session_t, z_moved_bytes_t,
-    z_moved_encoding_t, z_priority_t, z_timestamp_t,
+    _matching_status_callback, transmute::IntoCType, z_entity_global_id_t, z_reliability_default,
+    z_reliability_t, zc_locality_default, zc_locality_t, MatchingListenerState,
 };
 #[cfg(feature = "unstable")]
 use crate::{
-    transmute::IntoCType, z_entity_global_id_t, z_reliability_default, z_reliability_t,
-    zc_closure_matching_status_call, zc_closure_matching_status_loan, zc_locality_default,
-    zc_locality_t,
+    entity_limits::{EntityKind, EntitySlot},
+    keyexpr_interceptor,
+    matching::DependentListeners,
+    payload_limits,
+    transmute::OwnedCTypeRef,
+    z_loaned_sample_t, z_moved_source_info_t, zc_closure_payload_transform_call,
+    zc_matching_status_t, zc_moved_closure_payload_transform_t,
+    zc_owned_closure_payload_transform_t, zc_owned_matching_listener_t,
+};
+use crate::{
+    result::{self},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_congestion_control_t, z_loaned_keyexpr_t, z_loaned_session_t, z_moved_bytes_t,
+    z_moved_encoding_t, z_priority_t, z_timestamp_t, Stateful,
 };
-#[cfg(feature = "unstable")]
-use crate::{z_moved_source_info_t, zc_matching_status_t, zc_owned_matching_listener_t};
 /// Options passed to the `z_declare_publisher()` function.
 #[repr(C)]
 pub struct z_publisher_options_t {
@@ -61,6 +71,27 @@ pub struct z_publisher_options_t {
     ///
     /// The allowed destination for this publisher.
     pub allowed_destination: zc_locality_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``false``, publications will not be delivered to the subscribers declared on the publisher's own session,
+    /// while remote subscribers will still receive them. Disabling self delivery is not compatible with `ZC_LOCALITY_SESSION_LOCAL`
+    /// allowed destination.
+    pub self_delivery: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// An optional closure applied to the payload of each publication made with `z_publisher_put()` or `zc_forward()`
+    /// before it is sent (e.g. to compress or encrypt it). For advanced publishers it applies to `ze_advanced_publisher_put()`. Subscribers can revert it with the `payload_transform` field of `z_subscriber_options_t`.
+    /// The closure is dropped once the publisher is undeclared or dropped.
+    pub payload_transform: Option<&'static mut zc_moved_closure_payload_transform_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``false``, the publications whose payload and attachment do not fit in a single batch of the session
+    /// links are rejected with `Z_EFRAGMENT` instead of being fragmented. If set to ``true``, they are fragmented, and
+    /// only rejected beyond the size the remote nodes can reassemble. See `zc_publisher_max_payload_size()`.
+    pub allow_fragmentation: bool,
 }
 
 impl Default for z_publisher_options_t {
@@ -74,6 +105,12 @@ impl Default for z_publisher_options_t {
             reliability: z_reliability_default(),
             #[cfg(feature = "unstable")]
             allowed_destination: zc_locality_default(),
+            #[cfg(feature = "unstable")]
+            self_delivery: true,
+            #[cfg(feature = "unstable")]
+            payload_transform: None,
+            #[cfg(feature = "unstable")]
+            allow_fragmentation: true,
         }
     }
 }
@@ -85,35 +122,73 @@ pub extern "C" fn z_publisher_options_default(this_: &mut MaybeUninit<z_publishe
 }
 
 pub use crate::opaque_types::{z_loaned_publisher_t, z_moved_publisher_t, z_owned_publisher_t};
+
+/// The state kept by zenoh-c for a publisher.
+pub(crate) struct PublisherState {
+    #[cfg(feature = "unstable")]
+    _slot: EntitySlot,
+    #[cfg(feature = "unstable")]
+    payload_transform: Option<zc_owned_closure_payload_transform_t>,
+    /// The maximum size of the publications, resolved from the session limits when the publisher is declared.
+    #[cfg(feature = "unstable")]
+    pub(crate) max_payload_size: usize,
+    #[cfg(feature = "unstable")]
+    listeners: DependentListeners,
+}
+
+pub(crate) type CPublisher = Stateful<Publisher<'static>, PublisherState>;
 decl_c_type!(
-    owned(z_owned_publisher_t, option Publisher<'static>),
+    owned(z_owned_publisher_t, option CPublisher),
     loaned(z_loaned_publisher_t),
 );
 
 pub(crate) fn _declare_publisher_inner(
     session: &'static z_loaned_session_t,
-    key_expr: &'static z_loaned_keyexpr_t,
+    key_expr: &z_loaned_keyexpr_t,
     options: Option<&mut z_publisher_options_t>,
-) -> PublisherBuilder<'static, 'static> {
+) -> Result<PublisherBuilder<'static, 'static>, result::z_result_t> {
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref().clone().into_owned();
     let mut p = session.declare_publisher(key_expr);
     if let Some(options) = options {
+        if let Some(encoding) = options.encoding.take() {
+            p = p.encoding(encoding.take_rust_type());
+        }
         p = p
             .congestion_control(options.congestion_control.into())
             .priority(options.priority.into())
             .express(options.is_express);
         #[cfg(feature = "unstable")]
         {
+            let allowed_destination = match (options.allowed_destination, options.self_delivery) {
+                (destination, true) => destination,
+                (zc_locality_t::SESSION_LOCAL, false) => {
+                    tracing::error!(
+                        "Self delivery can not be disabled for a publisher with session local destination"
+                    );
+                    return Err(result::Z_EINVAL);
+                }
+                (_, false) => zc_locality_t::REMOTE,
+            };
             p = p
                 .reliability(options.reliability.into())
-                .allowed_destination(options.allowed_destination.into());
+                .allowed_destination(allowed_destination.into());
         }
+    }
+    Ok(p)
+}
+
+/// Drops the moved fields of the publisher options, when the declaration fails before they are taken.
+#[cfg(feature = "unstable")]
+pub(crate) fn _release_publisher_options(options: Option<&mut z_publisher_options_t>) {
+    if let Some(options) = options {
         if let Some(encoding) = options.encoding.take() {
-            p = p.encoding(encoding.take_rust_type());
+            let _ = encoding.take_rust_type();
+        }
+        if let Some(transform) = options.payload_transform.take() {
+            let _ = transform.take_rust_type();
         }
     }
-    p
 }
 
 /// Constructs and declares a publisher for the given key expression.
@@ -128,14 +203,42 @@ pub(crate) fn _declare_publisher_inner(
 ///
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
+#[allow(unused_mut)]
 pub extern "C" fn z_declare_publisher(
     session: &'static z_loaned_session_t,
     publisher: &'static mut MaybeUninit<z_owned_publisher_t>,
     key_expr: &'static z_loaned_keyexpr_t,
-    options: Option<&'static mut z_publisher_options_t>,
+    mut options: Option<&'static mut z_publisher_options_t>,
 ) -> result::z_result_t {
     let this = publisher.as_rust_type_mut_uninit();
-    let p = _declare_publisher_inner(session, key_expr, options);
+    #[cfg(feature = "unstable")]
+    let allow_fragmentation = options.as_ref().map_or(true, |o| o.allow_fragmentation);
+    #[cfg(feature = "unstable")]
+    let payload_transform = _take_payload_transform(options.as_deref_mut());
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Publisher,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            _release_publisher_options(options);
+            this.write(None);
+            return e;
+        }
+    };
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    let p = match _declare_publisher_inner(session, key_expr, options) {
+        Ok(p) => p,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     match p.wait() {
         Err(e) => {
             tracing::error!("{}", e);
@@ -143,12 +246,61 @@ pub extern "C" fn z_declare_publisher(
             result::Z_EGENERIC
         }
         Ok(publisher) => {
-            this.write(Some(publisher));
+            let state = PublisherState {
+                #[cfg(feature = "unstable")]
+                _slot: slot,
+                #[cfg(feature = "unstable")]
+                payload_transform,
+                #[cfg(feature = "unstable")]
+                max_payload_size: session
+                    .as_rust_type_ref()
+                    .state()
+                    .payload_limits
+                    .max_payload_size(publisher.key_expr(), allow_fragmentation),
+                #[cfg(feature = "unstable")]
+                listeners: DependentListeners::for_entity(session.as_rust_type_ref()),
+            };
+            this.write(Some(CPublisher::new(publisher, state)));
             result::Z_OK
         }
     }
 }
 
+#[cfg(feature = "unstable")]
+pub(crate) fn _take_payload_transform(
+    options: Option<&mut z_publisher_options_t>,
+) -> Option<zc_owned_closure_payload_transform_t> {
+    options
+        .and_then(|o| o.payload_transform.take())
+        .map(|t| t.take_rust_type())
+}
+
+/// Applies the payload transform of a publisher, if it has one.
+#[cfg(feature = "unstable")]
+pub(crate) fn _apply_payload_transform(
+    transform: Option<&zc_owned_closure_payload_transform_t>,
+    payload: ZBytes,
+) -> ZBytes {
+    match transform {
+        Some(transform) => _transform_payload(transform, payload),
+        None => payload,
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) fn _transform_payload(
+    transform: &zc_owned_closure_payload_transform_t,
+    payload: ZBytes,
+) -> ZBytes {
+    let mut transformed = ZBytes::default();
+    zc_closure_payload_transform_call(
+        transform.as_loaned_c_type_ref(),
+        payload.as_loaned_c_type_ref(),
+        transformed.as_owned_c_type_mut(),
+    );
+    transformed
+}
+
 /// Constructs a publisher in a gravestone state.
 #[no_mangle]
 pub extern "C" fn z_internal_publisher_null(this_: &mut MaybeUninit<z_owned_publisher_t>) {
@@ -198,6 +350,7 @@ pub struct z_publisher_put_options_t {
     /// The source info for the publication.
     pub source_info: Option<&'static mut z_moved_source_info_t>,
     /// The attachment to attach to the publication.
+    /// The attachment is consumed, to attach the same data to many publications pass a shallow copy made with `z_bytes_clone()`, which does not copy the underlying data.
     pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
@@ -216,6 +369,21 @@ pub extern "C" fn z_publisher_put_options_default(
     });
 }
 
+pub(crate) fn _release_publisher_put_options(options: Option<&mut z_publisher_put_options_t>) {
+    if let Some(options) = options {
+        if let Some(encoding) = options.encoding.take() {
+            let _ = encoding.take_rust_type();
+        }
+        #[cfg(feature = "unstable")]
+        if let Some(source_info) = options.source_info.take() {
+            let _ = source_info.take_rust_type();
+        }
+        if let Some(attachment) = options.attachment.take() {
+            let _ = attachment.take_rust_type();
+        }
+    }
+}
+
 pub(crate) fn _apply_pubisher_put_options<
     T: SampleBuilderTrait + TimestampBuilderTrait + EncodingBuilderTrait,
 >(
@@ -239,6 +407,14 @@ pub(crate) fn _apply_pubisher_put_options<
     builder
 }
 
+/// Takes the attachment out of the put options, so that the publication size can be checked before it is built.
+#[cfg(feature = "unstable")]
+pub(crate) fn _take_put_attachment(
+    options: Option<&mut z_publisher_put_options_t>,
+) -> Option<ZBytes> {
+    options?.attachment.take().map(|a| a.take_rust_type())
+}
+
 /// Sends a `PUT` message onto the publisher's key expression, transfering the payload ownership.
 ///
 ///
@@ -248,17 +424,41 @@ pub(crate) fn _apply_pubisher_put_options<
 /// @param payload: The data to publish. Will be consumed.
 /// @param options: The publisher put options. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, negative error values in case of failure.
+/// @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error values in case of failure.
 #[no_mangle]
-#[allow(clippy::missing_safety_doc)]
+#[allow(clippy::missing_safety_doc, unused_mut)]
 pub unsafe extern "C" fn z_publisher_put(
     this: &z_loaned_publisher_t,
-    payload: &mut z_moved_bytes_t,
-    options: Option<&mut z_publisher_put_options_t>,
+    payload: Option<&mut z_moved_bytes_t>,
+    mut options: Option<&mut z_publisher_put_options_t>,
 ) -> result::z_result_t {
+    let Some(payload) = moved_or_null(payload, "z_publisher_put") else {
+        _release_publisher_put_options(options);
+        return result::Z_EINVAL;
+    };
     let publisher = this.as_rust_type_ref();
     let payload = payload.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let payload = _apply_payload_transform(publisher.state().payload_transform.as_ref(), payload);
+    #[cfg(feature = "unstable")]
+    let attachment = _take_put_attachment(options.as_deref_mut());
+    #[cfg(feature = "unstable")]
+    {
+        let res = payload_limits::check_publication_size(
+            publisher.state().max_payload_size,
+            &payload,
+            attachment.as_ref(),
+        );
+        if res != result::Z_OK {
+            _release_publisher_put_options(options);
+            return res;
+        }
+    }
     let mut put = publisher.put(payload);
+    #[cfg(feature = "unstable")]
+    if let Some(attachment) = attachment {
+        put = put.attachment(attachment);
+    }
     if let Some(options) = options {
         put = _apply_pubisher_put_options(put, options);
     }
@@ -275,11 +475,22 @@ pub unsafe extern "C" fn z_publisher_put(
 
 /// Represents the set of options that can be applied to the delete operation by a previously declared publisher,
 /// whenever issued via `z_publisher_delete()`.
+///
+/// The priority, congestion control and express flag of the delete operation are the ones of the publisher, since they can not be
+/// overridden per operation. Use `z_delete()` with `z_delete_options_t` to issue a delete operation with a different QoS,
+/// e.g. to prioritize tombstones above the bulk data sent by the publisher.
 #[repr(C)]
 #[derive(Default)]
 pub struct z_publisher_delete_options_t {
     /// The timestamp of this message.
     pub timestamp: Option<&'static z_timestamp_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The source info for the delete operation.
+    pub source_info: Option<&'static mut z_moved_source_info_t>,
+    /// The attachment to attach to the delete operation.
+    pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
 /// Constructs the default values for the delete operation via a publisher entity.
@@ -291,11 +502,18 @@ pub extern "C" fn z_publisher_delete_options_default(
     this.write(z_publisher_delete_options_t::default());
 }
 
-pub(crate) fn _apply_pubisher_delete_options<T: TimestampBuilderTrait>(
+pub(crate) fn _apply_pubisher_delete_options<T: SampleBuilderTrait + TimestampBuilderTrait>(
     builder: T,
     options: &mut z_publisher_delete_options_t,
 ) -> T {
     let mut builder = builder;
+    #[cfg(feature = "unstable")]
+    if let Some(source_info) = options.source_info.take() {
+        builder = builder.source_info(source_info.take_rust_type());
+    };
+    if let Some(attachment) = options.attachment.take() {
+        builder = builder.attachment(attachment.take_rust_type());
+    }
     if let Some(timestamp) = options.timestamp {
         builder = builder.timestamp(Some(*timestamp.as_rust_type_ref()));
     }
@@ -304,6 +522,8 @@ pub(crate) fn _apply_pubisher_delete_options<T: TimestampBuilderTrait>(
 
 /// Sends a `DELETE` message onto the publisher's key expression.
 ///
+/// All owned options fields are consumed upon function return.
+///
 /// @return 0 in case of success, negative error code in case of failure.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
@@ -323,6 +543,86 @@ pub extern "C" fn z_publisher_delete(
         result::Z_OK
     }
 }
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Republishes a received sample onto the publisher's key expression.
+///
+/// The payload of the sample is shared with the publication rather than copied, which makes this function
+/// the building block of bridges and relays. `PUT` samples are republished with `z_publisher_put()` semantics,
+/// `DELETE` samples with `z_publisher_delete()` semantics. The encoding, timestamp, source info and attachment
+/// of the sample are preserved, unless overridden by `options`, while the QoS are the ones of the publisher.
+///
+/// @param sample: The sample to republish.
+/// @param publisher: The publisher.
+/// @param options: The options overriding the metadata of the sample. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error code in case of failure.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_forward(
+    sample: &z_loaned_sample_t,
+    publisher: &z_loaned_publisher_t,
+    mut options: Option<&mut z_publisher_put_options_t>,
+) -> result::z_result_t {
+    let sample = sample.as_rust_type_ref();
+    let publisher = publisher.as_rust_type_ref();
+    let res = match sample.kind() {
+        SampleKind::Put => {
+            let payload = _apply_payload_transform(
+                publisher.state().payload_transform.as_ref(),
+                sample.payload().clone(),
+            );
+            let attachment = _take_put_attachment(options.as_deref_mut())
+                .or_else(|| sample.attachment().cloned());
+            let res = payload_limits::check_publication_size(
+                publisher.state().max_payload_size,
+                &payload,
+                attachment.as_ref(),
+            );
+            if res != result::Z_OK {
+                _release_publisher_put_options(options);
+                return res;
+            }
+            let mut put = publisher
+                .put(payload)
+                .encoding(sample.encoding().clone())
+                .timestamp(sample.timestamp().cloned())
+                .source_info(sample.source_info().clone())
+                .attachment(attachment);
+            if let Some(options) = options {
+                put = _apply_pubisher_put_options(put, options);
+            }
+            put.wait()
+        }
+        SampleKind::Delete => {
+            let mut del = publisher
+                .delete()
+                .timestamp(sample.timestamp().cloned())
+                .source_info(sample.source_info().clone())
+                .attachment(sample.attachment().cloned());
+            if let Some(options) = options {
+                // a delete has no payload, hence no encoding
+                std::mem::drop(options.encoding.take().map(|e| e.take_rust_type()));
+                let mut delete_options = z_publisher_delete_options_t {
+                    timestamp: options.timestamp,
+                    source_info: options.source_info.take(),
+                    attachment: options.attachment.take(),
+                };
+                del = _apply_pubisher_delete_options(del, &mut delete_options);
+            }
+            del.wait()
+        }
+    };
+    match res {
+        Ok(_) => result::Z_OK,
+        Err(e) if e.downcast_ref::<SessionClosedError>().is_some() => result::Z_ESESSION_CLOSED,
+        Err(e) => {
+            tracing::error!("{}", e);
+            result::Z_EGENERIC
+        }
+    }
+}
+
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Returns the ID of the publisher.
@@ -344,17 +644,13 @@ pub extern "C" fn z_publisher_keyexpr(publisher: &z_loaned_publisher_t) -> &z_lo
 fn _publisher_matching_listener_declare_inner<'a>(
     publisher: &'a z_loaned_publisher_t,
     callback: &mut zc_moved_closure_matching_status_t,
+    state: Arc<MatchingListenerState>,
 ) -> zenoh::matching::MatchingListenerBuilder<'a, Callback<MatchingStatus>> {
     let publisher = publisher.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let listener = publisher
         .matching_listener()
-        .callback_mut(move |matching_status| {
-            let status = zc_matching_status_t {
-                matching: matching_status.matching(),
-            };
-            zc_closure_matching_status_call(zc_closure_matching_status_loan(&callback), &status);
-        });
+        .callback_mut(_matching_status_callback(callback, state));
     listener
 }
 
@@ -366,18 +662,28 @@ fn _publisher_matching_listener_declare_inner<'a>(
 /// @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the publisher is dropped.
 /// @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn zc_publisher_declare_matching_listener(
     publisher: &'static z_loaned_publisher_t,
     matching_listener: &mut MaybeUninit<zc_owned_matching_listener_t>,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
     let this = matching_listener.as_rust_type_mut_uninit();
-    let listener = _publisher_matching_listener_declare_inner(publisher, callback);
+    let Some(callback) = moved_or_null(callback, "zc_publisher_declare_matching_listener") else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let state = Arc::<MatchingListenerState>::default();
+    let listener = _publisher_matching_listener_declare_inner(publisher, callback, state.clone());
     match listener.wait() {
         Ok(listener) => {
-            this.write(Some(listener));
+            publisher
+                .as_rust_type_ref()
+                .state()
+                .listeners
+                .register(&state);
+            this.write(Some((listener, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -396,13 +702,19 @@ pub extern "C" fn zc_publisher_declare_matching_listener(
 /// @param publisher: A publisher to associate with matching listener.
 /// @param callback: A closure that will be called every time the matching status of the publisher changes (If last subscriber disconnects or when the first subscriber connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn zc_publisher_declare_background_matching_listener(
     publisher: &'static z_loaned_publisher_t,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
-    let listener = _publisher_matching_listener_declare_inner(publisher, callback);
+    let Some(callback) = moved_or_null(
+        callback,
+        "zc_publisher_declare_background_matching_listener",
+    ) else {
+        return result::Z_EINVAL;
+    };
+    let listener = _publisher_matching_listener_declare_inner(publisher, callback, Arc::default());
     match listener.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -437,20 +749,91 @@ pub extern "C" fn zc_publisher_get_matching_status(
     }
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Waits until the publisher has matching subscribers.
+///
+/// A publication is only routed to the subscribers whose declarations were propagated to the publisher session.
+/// Calling this function after declaring the publisher ensures the first publication reaches the already declared
+/// subscribers, which is useful for benchmarks and latency-critical first messages. It does not wait for the
+/// declaration of the publisher itself to be acknowledged by the network, which Zenoh does not report, so it
+/// times out when no subscriber matches the publisher.
+///
+/// @param this_: The publisher.
+/// @param timeout_ms: The maximum time to wait in milliseconds.
+/// @return 0 if the publisher has matching subscribers, `Z_EUNAVAILABLE` if it still has none once the timeout elapsed,
+/// other negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_publisher_wait_matching(
+    this_: &z_loaned_publisher_t,
+    timeout_ms: u64,
+) -> result::z_result_t {
+    let publisher = this_.as_rust_type_ref();
+    let (matched, on_matched) = flume::bounded::<()>(1);
+    let listener = match publisher
+        .matching_listener()
+        .callback(move |s: MatchingStatus| {
+            if s.matching() {
+                let _ = matched.try_send(());
+            }
+        })
+        .wait()
+    {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::error!("{}", e);
+            return result::Z_ENETWORK;
+        }
+    };
+    // the listener is declared first, so that no change of the matching status is missed
+    let res = match publisher.matching_status().wait() {
+        Ok(s) if s.matching() => result::Z_OK,
+        Ok(_) => match on_matched.recv_timeout(std::time::Duration::from_millis(timeout_ms)) {
+            Ok(()) => result::Z_OK,
+            Err(_) => result::Z_EUNAVAILABLE,
+        },
+        Err(e) => {
+            tracing::error!("{}", e);
+            result::Z_ENETWORK
+        }
+    };
+    if let Err(e) = listener.undeclare().wait() {
+        tracing::error!("{}", e);
+    }
+    res
+}
+
 /// Frees memory and resets publisher to its gravestone state.
 /// This is equivalent to calling `z_undeclare_publisher()` and discarding its return value.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn z_publisher_drop(this: &mut z_moved_publisher_t) {
-    std::mem::drop(this.take_rust_type())
+pub extern "C" fn z_publisher_drop(this: Option<&mut z_moved_publisher_t>) {
+    let Some(this) = moved_or_null(this, "z_publisher_drop") else {
+        return;
+    };
+    let publisher = this.take_rust_type();
+    #[cfg(feature = "unstable")]
+    if let Some(p) = &publisher {
+        p.state().listeners.orphan();
+    }
+    std::mem::drop(publisher)
 }
 
 #[no_mangle]
 /// @brief Undeclares the given publisher.
 ///
 /// @return 0 in case of success, negative error code otherwise.
-pub extern "C" fn z_undeclare_publisher(this_: &mut z_moved_publisher_t) -> result::z_result_t {
+pub extern "C" fn z_undeclare_publisher(
+    this_: Option<&mut z_moved_publisher_t>,
+) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "z_undeclare_publisher") else {
+        return result::Z_OK;
+    };
     if let Some(p) = this_.take_rust_type() {
+        #[cfg(feature = "unstable")]
+        p.state().listeners.orphan();
+        // the state of the publisher is dropped once it is undeclared
+        let (p, _state) = p.into_parts();
         if let Err(e) = p.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_ENETWORK;
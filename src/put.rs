@@ -13,20 +13,23 @@
 //
 use std::mem::MaybeUninit;
 
+use libc::c_char;
 use zenoh::{
+    bytes::{Encoding, ZBytes},
     qos::{CongestionControl, Priority},
     session::SessionClosedError,
     Wait,
 };
 
-#[cfg(feature = "unstable")]
-use crate::z_moved_source_info_t;
 use crate::{
     commons::*,
     result,
-    transmute::{IntoRustType, RustTypeRef, TakeRustType},
+    transmute::{moved_or_null, IntoRustType, RustTypeRef, TakeRustType},
     z_loaned_keyexpr_t, z_loaned_session_t, z_moved_bytes_t, z_moved_encoding_t, z_timestamp_t,
+    CStringOwned,
 };
+#[cfg(feature = "unstable")]
+use crate::{payload_limits, z_moved_source_info_t};
 
 /// Options passed to the `z_put()` function.
 #[repr(C)]
@@ -58,6 +61,7 @@ pub struct z_put_options_t {
     /// The source info for the message.
     pub source_info: Option<&'static mut z_moved_source_info_t>,
     /// The attachment to this message.
+    /// The attachment is consumed, to attach the same data to many publications pass a shallow copy made with `z_bytes_clone()`, which does not copy the underlying data.
     pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
@@ -81,26 +85,45 @@ pub extern "C" fn z_put_options_default(this_: &mut MaybeUninit<z_put_options_t>
     });
 }
 
-/// Publishes data on specified key expression.
-///
-/// @param session: The Zenoh session.
-/// @param key_expr: The key expression to publish to.
-/// @param payload: The value to put (consumed upon function return).
-/// @param options: The put options (all owned values will be consumed upon function return).
-///
-/// @return 0 in case of success, negative error values in case of failure.
-#[no_mangle]
-#[allow(clippy::missing_safety_doc)]
-pub extern "C" fn z_put(
+#[allow(unused_mut)]
+fn _put_inner(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    payload: &mut z_moved_bytes_t,
-    options: Option<&mut z_put_options_t>,
+    payload: ZBytes,
+    default_encoding: Option<Encoding>,
+    mut options: Option<&mut z_put_options_t>,
 ) -> result::z_result_t {
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
-    let payload = payload.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let attachment = options
+        .as_mut()
+        .and_then(|o| o.attachment.take())
+        .map(|a| a.take_rust_type());
+    #[cfg(feature = "unstable")]
+    {
+        // a put is fragmented as needed, hence only limited by the size of the reassembled messages
+        let res = payload_limits::check_publication_size(
+            session
+                .state()
+                .payload_limits
+                .max_payload_size(key_expr, true),
+            &payload,
+            attachment.as_ref(),
+        );
+        if res != result::Z_OK {
+            _release_put_options(options);
+            return res;
+        }
+    }
     let mut put = session.put(key_expr, payload);
+    #[cfg(feature = "unstable")]
+    if let Some(attachment) = attachment {
+        put = put.attachment(attachment);
+    }
+    if let Some(encoding) = default_encoding {
+        put = put.encoding(encoding);
+    }
     if let Some(options) = options {
         if let Some(encoding) = options.encoding.take() {
             put = put.encoding(encoding.take_rust_type());
@@ -135,6 +158,110 @@ pub extern "C" fn z_put(
     }
 }
 
+/// Publishes data on specified key expression.
+///
+/// @param session: The Zenoh session.
+/// @param key_expr: The key expression to publish to.
+/// @param payload: The value to put (consumed upon function return).
+/// @param options: The put options (all owned values will be consumed upon function return).
+///
+/// @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error values in case of failure.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub extern "C" fn z_put(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    payload: Option<&mut z_moved_bytes_t>,
+    options: Option<&mut z_put_options_t>,
+) -> result::z_result_t {
+    let Some(payload) = moved_or_null(payload, "z_put") else {
+        _release_put_options(options);
+        return result::Z_EINVAL;
+    };
+    _put_inner(session, key_expr, payload.take_rust_type(), None, options)
+}
+
+fn _release_put_options(options: Option<&mut z_put_options_t>) {
+    if let Some(options) = options {
+        if let Some(encoding) = options.encoding.take() {
+            let _ = encoding.take_rust_type();
+        }
+        if let Some(attachment) = options.attachment.take() {
+            let _ = attachment.take_rust_type();
+        }
+        #[cfg(feature = "unstable")]
+        if let Some(source_info) = options.source_info.take() {
+            let _ = source_info.take_rust_type();
+        }
+    }
+}
+
+unsafe fn _put_str_inner(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    value: *const c_char,
+    encoding: Encoding,
+    options: Option<&mut z_put_options_t>,
+) -> result::z_result_t {
+    if value.is_null() {
+        tracing::error!("The string to publish is NULL");
+        _release_put_options(options);
+        return result::Z_EINVAL;
+    }
+    match CStringOwned::new(value, libc::strlen(value)) {
+        Ok(s) => _put_inner(session, key_expr, ZBytes::from(s), Some(encoding), options),
+        Err(e) => {
+            _release_put_options(options);
+            e
+        }
+    }
+}
+
+/// Publishes a null-terminated string on specified key expression.
+///
+/// The string is copied and published with `text/plain` encoding, unless another encoding is provided in `options`.
+///
+/// @param session: The Zenoh session.
+/// @param key_expr: The key expression to publish to.
+/// @param value: A pointer to the null-terminated string to publish. It is only read during the call, and must be
+///     either NULL or a valid null-terminated string.
+/// @param options: The put options (all owned values will be consumed upon function return).
+///
+/// @return 0 in case of success, `Z_EINVAL` if `value` is NULL, other negative error values in case of failure.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_put_string(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    value: *const c_char,
+    options: Option<&mut z_put_options_t>,
+) -> result::z_result_t {
+    _put_str_inner(session, key_expr, value, Encoding::TEXT_PLAIN, options)
+}
+
+/// Publishes a null-terminated JSON string on specified key expression.
+///
+/// The string is copied and published with `application/json` encoding, unless another encoding is provided in `options`.
+/// The content of the string is not validated.
+///
+/// @param session: The Zenoh session.
+/// @param key_expr: The key expression to publish to.
+/// @param json: A pointer to the null-terminated JSON string to publish. It is only read during the call, and must be
+///     either NULL or a valid null-terminated string.
+/// @param options: The put options (all owned values will be consumed upon function return).
+///
+/// @return 0 in case of success, `Z_EINVAL` if `json` is NULL, other negative error values in case of failure.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_put_json(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    json: *const c_char,
+    options: Option<&mut z_put_options_t>,
+) -> result::z_result_t {
+    _put_str_inner(session, key_expr, json, Encoding::APPLICATION_JSON, options)
+}
+
 /// Options passed to the `z_delete()` function.
 #[repr(C)]
 #[allow(non_camel_case_types)]
@@ -157,6 +284,13 @@ pub struct z_delete_options_t {
     ///
     /// The allowed destination of this message.
     pub allowed_destination: zc_locality_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The source info for the delete operation.
+    pub source_info: Option<&'static mut z_moved_source_info_t>,
+    /// The attachment to this delete message.
+    pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
 /// Constructs the default value for `z_delete_options_t`.
@@ -172,6 +306,9 @@ pub unsafe extern "C" fn z_delete_options_default(this_: &mut MaybeUninit<z_dele
         reliability: z_reliability_default(),
         #[cfg(feature = "unstable")]
         allowed_destination: zc_locality_default(),
+        #[cfg(feature = "unstable")]
+        source_info: None,
+        attachment: None,
     });
 }
 
@@ -179,7 +316,7 @@ pub unsafe extern "C" fn z_delete_options_default(this_: &mut MaybeUninit<z_dele
 ///
 /// @param session: The zenoh session.
 /// @param key_expr: The key expression to delete.
-/// @param options: The delete options.
+/// @param options: The delete options. All owned fields will be consumed.
 ///
 /// @return 0 in case of success, negative values in case of failure.
 #[no_mangle]
@@ -196,6 +333,9 @@ pub extern "C" fn z_delete(
         if let Some(timestamp) = options.timestamp.as_ref() {
             del = del.timestamp(Some(timestamp.into_rust_type()));
         }
+        if let Some(attachment) = options.attachment.take() {
+            del = del.attachment(attachment.take_rust_type());
+        }
         del = del
             .congestion_control(options.congestion_control.into())
             .priority(options.priority.into())
@@ -206,14 +346,18 @@ pub extern "C" fn z_delete(
             del = del
                 .reliability(options.reliability.into())
                 .allowed_destination(options.allowed_destination.into());
+            if let Some(source_info) = options.source_info.take() {
+                del = del.source_info(source_info.take_rust_type());
+            };
         }
     }
 
     match del.wait() {
+        Ok(()) => result::Z_OK,
+        Err(e) if e.downcast_ref::<SessionClosedError>().is_some() => result::Z_ESESSION_CLOSED,
         Err(e) => {
             tracing::error!("{}", e);
             result::Z_EGENERIC
         }
-        Ok(()) => result::Z_OK,
     }
 }
@@ -12,6 +12,8 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
+#[cfg(feature = "unstable")]
+use std::sync::Arc;
 use std::{ffi::CStr, mem::MaybeUninit};
 
 use libc::c_char;
@@ -24,20 +26,26 @@ use zenoh::{
     Wait,
 };
 
+#[cfg(feature = "unstable")]
+use crate::{
+    _matching_status_callback, transmute::IntoCType, z_entity_global_id_t, z_moved_source_info_t,
+    zc_locality_default, zc_locality_t, zc_matching_status_t, zc_moved_closure_matching_status_t,
+    zc_owned_matching_listener_t, zc_reply_keyexpr_default, zc_reply_keyexpr_t,
+    MatchingListenerState,
+};
+#[cfg(feature = "unstable")]
+use crate::{
+    entity_limits::{EntityKind, EntitySlot},
+    keyexpr_interceptor,
+    matching::DependentListeners,
+};
 use crate::{
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_reply_call, z_closure_reply_loan, z_congestion_control_t, z_loaned_keyexpr_t,
     z_loaned_querier_t, z_loaned_session_t, z_moved_bytes_t, z_moved_closure_reply_t,
     z_moved_encoding_t, z_moved_querier_t, z_owned_querier_t, z_priority_t,
-    z_query_consolidation_t, z_query_target_t,
-};
-#[cfg(feature = "unstable")]
-use crate::{
-    transmute::IntoCType, z_entity_global_id_t, z_moved_source_info_t,
-    zc_closure_matching_status_call, zc_closure_matching_status_loan, zc_locality_default,
-    zc_locality_t, zc_matching_status_t, zc_moved_closure_matching_status_t,
-    zc_owned_matching_listener_t, zc_reply_keyexpr_default, zc_reply_keyexpr_t,
+    z_query_consolidation_t, z_query_target_t, Stateful,
 };
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -86,8 +94,17 @@ pub extern "C" fn z_querier_options_default(this_: &mut MaybeUninit<z_querier_op
     });
 }
 
+/// The state kept by zenoh-c for a querier.
+pub(crate) struct QuerierState {
+    #[cfg(feature = "unstable")]
+    _slot: EntitySlot,
+    #[cfg(feature = "unstable")]
+    listeners: DependentListeners,
+}
+
+pub(crate) type CQuerier = Stateful<Querier<'static>, QuerierState>;
 decl_c_type!(
-    owned(z_owned_querier_t, option Querier<'static>),
+    owned(z_owned_querier_t, option CQuerier),
     loaned(z_loaned_querier_t),
 );
 
@@ -95,6 +112,8 @@ decl_c_type!(
 /// @brief Constructs and declares a querier on the given key expression.
 ///
 /// The queries can be send with the help of the `z_querier_get()` function.
+/// The key expression declaration and the query options are resolved once at querier declaration, so only the payload,
+/// encoding and attachment are provided per query. Queriers should thus be preferred over repeated `z_get()` calls for periodic queries.
 ///
 /// @param session: The Zenoh session.
 /// @param querier: An uninitialized location in memory where querier will be constructed.
@@ -112,7 +131,21 @@ pub extern "C" fn z_declare_querier(
 ) -> result::z_result_t {
     let this = querier.as_rust_type_mut_uninit();
     let session = session.as_rust_type_ref();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session,
+        key_expr.as_rust_type_ref(),
+        EntityKind::Querier,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     let key_expr = key_expr.as_rust_type_ref().clone().into_owned();
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted.unwrap_or(key_expr);
     let mut q = session.declare_querier(key_expr);
     if let Some(options) = options {
         q = q
@@ -138,7 +171,13 @@ pub extern "C" fn z_declare_querier(
             result::Z_EGENERIC
         }
         Ok(querier) => {
-            this.write(Some(querier));
+            let state = QuerierState {
+                #[cfg(feature = "unstable")]
+                _slot: slot,
+                #[cfg(feature = "unstable")]
+                listeners: DependentListeners::for_entity(session),
+            };
+            this.write(Some(CQuerier::new(querier, state)));
             result::Z_OK
         }
     }
@@ -225,15 +264,33 @@ pub extern "C" fn z_querier_get_options_default(this: &mut MaybeUninit<z_querier
 /// @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
 /// @param options: Additional options for the get. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, a negative error value upon failure.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, a negative error value upon failure.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_querier_get(
     querier: &z_loaned_querier_t,
     parameters: *const c_char,
-    callback: &mut z_moved_closure_reply_t,
+    callback: Option<&mut z_moved_closure_reply_t>,
     options: Option<&mut z_querier_get_options_t>,
 ) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "z_querier_get") else {
+        if let Some(options) = options {
+            if let Some(payload) = options.payload.take() {
+                let _ = payload.take_rust_type();
+            }
+            if let Some(encoding) = options.encoding.take() {
+                let _ = encoding.take_rust_type();
+            }
+            #[cfg(feature = "unstable")]
+            if let Some(source_info) = options.source_info.take() {
+                let _ = source_info.take_rust_type();
+            }
+            if let Some(attachment) = options.attachment.take() {
+                let _ = attachment.take_rust_type();
+            }
+        }
+        return result::Z_EINVAL;
+    };
     let querier = querier.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let mut get = querier.get();
@@ -296,17 +353,13 @@ pub extern "C" fn z_querier_keyexpr(querier: &z_loaned_querier_t) -> &z_loaned_k
 fn _querier_matching_listener_declare_inner<'a>(
     querier: &'a z_loaned_querier_t,
     callback: &mut zc_moved_closure_matching_status_t,
+    state: Arc<MatchingListenerState>,
 ) -> zenoh::matching::MatchingListenerBuilder<'a, Callback<MatchingStatus>> {
     let querier = querier.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let listener = querier
         .matching_listener()
-        .callback_mut(move |matching_status| {
-            let status = zc_matching_status_t {
-                matching: matching_status.matching(),
-            };
-            zc_closure_matching_status_call(zc_closure_matching_status_loan(&callback), &status);
-        });
+        .callback_mut(_matching_status_callback(callback, state));
     listener
 }
 
@@ -318,18 +371,28 @@ fn _querier_matching_listener_declare_inner<'a>(
 /// @param matching_listener: An uninitialized memory location where matching listener will be constructed. The matching listener's callback will be automatically dropped when the querier is dropped.
 /// @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn zc_querier_declare_matching_listener(
     querier: &'static z_loaned_querier_t,
     matching_listener: &mut MaybeUninit<zc_owned_matching_listener_t>,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
     let this = matching_listener.as_rust_type_mut_uninit();
-    let listener = _querier_matching_listener_declare_inner(querier, callback);
+    let Some(callback) = moved_or_null(callback, "zc_querier_declare_matching_listener") else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let state = Arc::<MatchingListenerState>::default();
+    let listener = _querier_matching_listener_declare_inner(querier, callback, state.clone());
     match listener.wait() {
         Ok(listener) => {
-            this.write(Some(listener));
+            querier
+                .as_rust_type_ref()
+                .state()
+                .listeners
+                .register(&state);
+            this.write(Some((listener, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -348,13 +411,17 @@ pub extern "C" fn zc_querier_declare_matching_listener(
 /// @param querier: A querier to associate with matching listener.
 /// @param callback: A closure that will be called every time the matching status of the querier changes (If last queryable disconnects or when the first queryable connects).
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn zc_querier_declare_background_matching_listener(
     querier: &'static z_loaned_querier_t,
-    callback: &mut zc_moved_closure_matching_status_t,
+    callback: Option<&mut zc_moved_closure_matching_status_t>,
 ) -> result::z_result_t {
-    let listener = _querier_matching_listener_declare_inner(querier, callback);
+    let Some(callback) = moved_or_null(callback, "zc_querier_declare_background_matching_listener")
+    else {
+        return result::Z_EINVAL;
+    };
+    let listener = _querier_matching_listener_declare_inner(querier, callback, Arc::default());
     match listener.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -393,8 +460,16 @@ pub extern "C" fn zc_querier_get_matching_status(
 /// This is equivalent to calling `z_undeclare_querier()` and discarding its return value.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub extern "C" fn z_querier_drop(this: &mut z_moved_querier_t) {
-    std::mem::drop(this.take_rust_type())
+pub extern "C" fn z_querier_drop(this: Option<&mut z_moved_querier_t>) {
+    let Some(this) = moved_or_null(this, "z_querier_drop") else {
+        return;
+    };
+    let querier = this.take_rust_type();
+    #[cfg(feature = "unstable")]
+    if let Some(q) = &querier {
+        q.state().listeners.orphan();
+    }
+    std::mem::drop(querier)
 }
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -402,8 +477,15 @@ pub extern "C" fn z_querier_drop(this: &mut z_moved_querier_t) {
 ///
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
-pub extern "C" fn z_undeclare_querier(this_: &mut z_moved_querier_t) -> result::z_result_t {
+pub extern "C" fn z_undeclare_querier(this_: Option<&mut z_moved_querier_t>) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "z_undeclare_querier") else {
+        return result::Z_OK;
+    };
     if let Some(q) = this_.take_rust_type() {
+        #[cfg(feature = "unstable")]
+        q.state().listeners.orphan();
+        // the state of the querier is dropped once it is undeclared
+        let (q, _state) = q.into_parts();
         if let Err(e) = q.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_ENETWORK;
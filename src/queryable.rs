@@ -11,7 +11,12 @@
 // Contributors:
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, sync::Arc};
+#[cfg(feature = "unstable")]
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
 
 use zenoh::{
     bytes::Encoding,
@@ -20,22 +25,46 @@ use zenoh::{
     query::{Query, Queryable, QueryableBuilder},
     Wait,
 };
+#[cfg(feature = "unstable")]
+use zenoh::{bytes::ZBytes, key_expr::KeyExpr, sample::SampleKind};
 
 pub use crate::opaque_types::{z_loaned_queryable_t, z_owned_queryable_t};
 #[cfg(feature = "unstable")]
 use crate::transmute::IntoCType;
+#[cfg(feature = "unstable")]
+use crate::{
+    deferred_callbacks,
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor,
+    transmute::OwnedCTypeRef,
+    z_entity_global_id_t, z_loaned_sample_t, z_moved_source_info_t, z_owned_bytes_t,
+    z_owned_keyexpr_t, zc_closure_reply_entry_call, zc_closure_reply_entry_loan,
+    zc_closure_table_get_call, zc_closure_table_get_loan, zc_closure_table_list_call,
+    zc_closure_table_list_loan, zc_locality_default, zc_locality_t, zc_moved_closure_reply_entry_t,
+    zc_moved_closure_table_get_t, zc_moved_closure_table_list_t, zc_owned_closure_table_get_t,
+    zc_owned_closure_table_list_t, ZVector,
+};
 use crate::{
     result,
-    transmute::{IntoRustType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{
+        moved_or_null, IntoRustType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_closure_query_call, z_closure_query_loan, z_congestion_control_t, z_loaned_bytes_t,
     z_loaned_encoding_t, z_loaned_keyexpr_t, z_loaned_session_t, z_moved_bytes_t,
-    z_moved_closure_query_t, z_moved_encoding_t, z_moved_queryable_t, z_priority_t, z_timestamp_t,
-    z_view_string_from_substr, z_view_string_t,
+    z_moved_closure_query_t, z_moved_encoding_t, z_moved_queryable_t, z_owned_closure_query_t,
+    z_priority_t, z_timestamp_t, z_view_string_from_substr, z_view_string_t, Stateful,
 };
-#[cfg(feature = "unstable")]
-use crate::{z_entity_global_id_t, z_moved_source_info_t};
+
+/// The state kept by zenoh-c for a queryable, shared with the queries it receives.
+#[derive(Default)]
+pub(crate) struct QueryableState {
+    #[cfg(feature = "unstable")]
+    stats: Option<Arc<QueryableStats>>,
+}
+
+pub(crate) type CQueryable = Stateful<Queryable<()>, QueryableState>;
 decl_c_type!(
-    owned(z_owned_queryable_t, option Queryable<()>),
+    owned(z_owned_queryable_t, option CQueryable),
     loaned(z_loaned_queryable_t),
 );
 
@@ -57,8 +86,9 @@ pub unsafe extern "C" fn z_queryable_loan(this_: &z_owned_queryable_t) -> &z_loa
 }
 
 pub use crate::opaque_types::{z_loaned_query_t, z_moved_query_t, z_owned_query_t};
+pub(crate) type CQuery = Stateful<Query, QueryableState>;
 decl_c_type!(
-    owned(z_owned_query_t, option Query),
+    owned(z_owned_query_t, option CQuery),
     loaned(z_loaned_query_t),
 );
 
@@ -96,7 +126,10 @@ pub unsafe extern "C" fn z_query_loan_mut(
 }
 /// Destroys the query resetting it to its gravestone value.
 #[no_mangle]
-pub extern "C" fn z_query_drop(this_: &mut z_moved_query_t) {
+pub extern "C" fn z_query_drop(this_: Option<&mut z_moved_query_t>) {
+    let Some(this_) = moved_or_null(this_, "z_query_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 /// Constructs a shallow copy of the query, allowing to keep it in an "open" state past the callback's return.
@@ -114,12 +147,22 @@ pub extern "C" fn z_query_clone(dst: &mut MaybeUninit<z_owned_query_t>, this_: &
 pub struct z_queryable_options_t {
     /// The completeness of the Queryable.
     pub complete: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// Restricts the queries that will be received by this Queryable to the ones
+    /// that have the compatible allowed_destination.
+    pub allowed_origin: zc_locality_t,
 }
 /// Constructs the default value for `z_query_reply_options_t`.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub extern "C" fn z_queryable_options_default(this_: &mut MaybeUninit<z_queryable_options_t>) {
-    this_.write(z_queryable_options_t { complete: false });
+    this_.write(z_queryable_options_t {
+        complete: false,
+        #[cfg(feature = "unstable")]
+        allowed_origin: zc_locality_default(),
+    });
 }
 
 /// Represents the set of options that can be applied to a query reply,
@@ -219,21 +262,60 @@ pub extern "C" fn z_query_reply_del_options_default(
     });
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Represents the set of options that can be applied to a reply forwarding a sample,
+/// sent via `z_query_reply_sample()`.
+///
+/// Unset fields keep the value of the forwarded sample.
+#[cfg(feature = "unstable")]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct z_query_reply_sample_options_t {
+    /// The key expression of the reply, instead of the key expression of the sample.
+    pub key_expr: Option<&'static z_loaned_keyexpr_t>,
+    /// The encoding of the reply payload, instead of the encoding of the sample.
+    pub encoding: Option<&'static mut z_moved_encoding_t>,
+    /// The timestamp of the reply, instead of the timestamp of the sample.
+    pub timestamp: Option<&'static mut z_timestamp_t>,
+    /// The attachment to this reply, instead of the attachment of the sample.
+    pub attachment: Option<&'static mut z_moved_bytes_t>,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_query_reply_sample_options_t`.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_query_reply_sample_options_default(
+    this_: &mut MaybeUninit<z_query_reply_sample_options_t>,
+) {
+    this_.write(z_query_reply_sample_options_t {
+        key_expr: None,
+        encoding: None,
+        timestamp: None,
+        attachment: None,
+    });
+}
+
 fn _declare_queryable_inner<'a, 'b>(
     session: &'a z_loaned_session_t,
     key_expr: &'b z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_query_t,
+    callback: z_owned_closure_query_t,
     options: Option<&mut z_queryable_options_t>,
+    state: &Arc<QueryableState>,
 ) -> QueryableBuilder<'a, 'b, Callback<Query>> {
     let session = session.as_rust_type_ref();
     let keyexpr = key_expr.as_rust_type_ref();
-    let callback = callback.take_rust_type();
     let mut builder = session.declare_queryable(keyexpr);
     if let Some(options) = options {
         builder = builder.complete(options.complete);
+        #[cfg(feature = "unstable")]
+        {
+            builder = builder.allowed_origin(options.allowed_origin.into());
+        }
     }
+    let state = state.clone();
     let queryable = builder.callback(move |query| {
-        let mut owned_query = Some(query);
+        let mut owned_query = Some(CQuery::with_state(query, &state));
         z_closure_query_call(z_closure_query_loan(&callback), unsafe {
             owned_query
                 .as_mut()
@@ -252,20 +334,51 @@ fn _declare_queryable_inner<'a, 'b>(
 /// @param callback: The callback function that will be called each time a matching query is received. Its ownership is passed to queryable.
 /// @param options: Options for the queryable.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case )
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case queryable will be in its gravestone state).
 #[no_mangle]
 pub extern "C" fn z_declare_queryable(
     session: &z_loaned_session_t,
     queryable: &mut MaybeUninit<z_owned_queryable_t>,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_query_t,
+    callback: Option<&mut z_moved_closure_query_t>,
     options: Option<&mut z_queryable_options_t>,
 ) -> result::z_result_t {
     let this = queryable.as_rust_type_mut_uninit();
-    let queryable = _declare_queryable_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "z_declare_queryable") else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Queryable,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    #[cfg(feature = "unstable")]
+    let callback = entity_limits::hold_in_query_callback(slot, callback);
+    #[cfg(feature = "unstable")]
+    let (callback, stats) = _stats_callback(callback);
+    #[cfg(feature = "unstable")]
+    let callback = deferred_callbacks::defer_query_callback(session.as_rust_type_ref(), callback);
+    let state = Arc::new(QueryableState {
+        #[cfg(feature = "unstable")]
+        stats: Some(stats),
+    });
+    let queryable = _declare_queryable_inner(session, key_expr, callback, options, &state);
     match queryable.wait() {
         Ok(q) => {
-            this.write(Some(q));
+            this.write(Some(CQueryable::with_state(q, &state)));
             result::Z_OK
         }
         Err(e) => {
@@ -284,15 +397,36 @@ pub extern "C" fn z_declare_queryable(
 /// @param callback: The callback function that will be called each time a matching query is received. Its ownership is passed to queryable.
 /// @param options: Options for the queryable.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case )
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn z_declare_background_queryable(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_query_t,
+    callback: Option<&mut z_moved_closure_query_t>,
     options: Option<&mut z_queryable_options_t>,
 ) -> result::z_result_t {
-    let queryable = _declare_queryable_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "z_declare_background_queryable") else {
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Queryable,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => return e,
+    };
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    #[cfg(feature = "unstable")]
+    let callback = entity_limits::hold_in_query_callback(slot, callback);
+    #[cfg(feature = "unstable")]
+    let callback = deferred_callbacks::defer_query_callback(session.as_rust_type_ref(), callback);
+    let queryable = _declare_queryable_inner(session, key_expr, callback, options, &Arc::default());
     match queryable.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -302,11 +436,127 @@ pub extern "C" fn z_declare_background_queryable(
     }
 }
 
+#[cfg(feature = "unstable")]
+fn _serve_table_query(
+    query: &Query,
+    prefix: &KeyExpr<'static>,
+    get: &zc_owned_closure_table_get_t,
+    list: &zc_owned_closure_table_list_t,
+) {
+    let mut keys = ZVector::new();
+    zc_closure_table_list_call(
+        zc_closure_table_list_loan(list),
+        keys.as_loaned_c_type_mut(),
+    );
+    for key in keys.iter() {
+        let Ok(suffix) = std::str::from_utf8(key.slice()) else {
+            tracing::error!("Table key is not a valid UTF-8 string");
+            continue;
+        };
+        let key_expr = match prefix.join(suffix) {
+            Ok(key_expr) => key_expr,
+            Err(e) => {
+                tracing::error!("Invalid table key {}: {}", suffix, e);
+                continue;
+            }
+        };
+        if !query.key_expr().intersects(&key_expr) {
+            continue;
+        }
+        let mut value = ZBytes::default();
+        if !zc_closure_table_get_call(
+            zc_closure_table_get_loan(get),
+            key.as_loaned_c_type_ref(),
+            value.as_owned_c_type_mut(),
+        ) {
+            continue;
+        }
+        if let Err(e) = query.reply(key_expr, value).wait() {
+            tracing::error!("{}", e);
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a Queryable serving the values of a user-provided key-value table.
+///
+/// The queryable is declared on `prefix` joined with `**`. For each received query, the `list` closure is called to enumerate the keys of the table,
+/// relative to `prefix`, and `get` is called for each key whose full key expression `prefix/key` intersects the query key expression.
+/// The found values are sent as replies, and the query is finalized once all keys have been processed.
+///
+/// @param session: A Zenoh session.
+/// @param queryable: An uninitialized memory location where queryable will be constructed.
+/// @param prefix: The key expression prefix of the table keys.
+/// @param get: The closure looking up the value of a key.
+/// @param list: The closure pushing all the keys of the table into the provided string array.
+///
+/// Both closures are dropped once the queryable is undeclared, or immediately if the declaration fails.
+/// They may be called concurrently.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_queryable_serve_table(
+    session: &z_loaned_session_t,
+    queryable: &mut MaybeUninit<z_owned_queryable_t>,
+    prefix: &z_loaned_keyexpr_t,
+    get: Option<&mut zc_moved_closure_table_get_t>,
+    list: Option<&mut zc_moved_closure_table_list_t>,
+) -> result::z_result_t {
+    let this = queryable.as_rust_type_mut_uninit();
+    let get = moved_or_null(get, "zc_queryable_serve_table").map(|g| g.take_rust_type());
+    let list = moved_or_null(list, "zc_queryable_serve_table").map(|l| l.take_rust_type());
+    let (Some(get), Some(list)) = (get, list) else {
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let session = session.as_rust_type_ref();
+    let prefix = prefix.as_rust_type_ref().clone().into_owned();
+    let (prefix, slot) =
+        match keyexpr_interceptor::admit_declaration(session, &prefix, EntityKind::Queryable) {
+            Ok((intercepted, slot)) => (intercepted.unwrap_or(prefix), slot),
+            Err(e) => {
+                this.write(None);
+                return e;
+            }
+        };
+    let key_expr = match prefix.join("**") {
+        Ok(key_expr) => key_expr,
+        Err(e) => {
+            tracing::error!("Invalid table prefix {}: {}", prefix, e);
+            this.write(None);
+            return result::Z_EINVAL;
+        }
+    };
+    match session
+        .declare_queryable(key_expr)
+        .callback(move |query| {
+            // the slot is given back once the callback is dropped along with the queryable
+            let _slot = &slot;
+            _serve_table_query(&query, &prefix, &get, &list)
+        })
+        .wait()
+    {
+        Ok(q) => {
+            this.write(Some(CQueryable::new(q, QueryableState::default())));
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("{}", e);
+            this.write(None);
+            result::Z_EGENERIC
+        }
+    }
+}
+
 /// Undeclares queryable callback and resets it to its gravestone state.
 /// This is equivalent to calling `z_undeclare_queryable()` and discarding its return value.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
-pub extern "C" fn z_queryable_drop(this_: &mut z_moved_queryable_t) {
+pub extern "C" fn z_queryable_drop(this_: Option<&mut z_moved_queryable_t>) {
+    let Some(this_) = moved_or_null(this_, "z_queryable_drop") else {
+        return;
+    };
     std::mem::drop(this_.take_rust_type())
 }
 
@@ -316,6 +566,15 @@ pub extern "C" fn z_internal_queryable_check(this_: &z_owned_queryable_t) -> boo
     this_.as_rust_type_ref().is_some()
 }
 
+/// Returns the key expression of the queryable.
+#[no_mangle]
+pub extern "C" fn z_queryable_keyexpr(queryable: &z_loaned_queryable_t) -> &z_loaned_keyexpr_t {
+    queryable
+        .as_rust_type_ref()
+        .key_expr()
+        .as_loaned_c_type_ref()
+}
+
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Returns the ID of the queryable.
@@ -324,6 +583,83 @@ pub extern "C" fn z_queryable_id(queryable: &z_loaned_queryable_t) -> z_entity_g
     queryable.as_rust_type_ref().id().into_c_type()
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Statistics of the queries handled by a queryable.
+#[cfg(feature = "unstable")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct zc_queryable_stats_t {
+    /// The number of queries received by the queryable.
+    pub queries: u64,
+    /// The number of replies (including delete replies) sent to the queries received by the queryable.
+    pub replies: u64,
+    /// The number of error replies sent to the queries received by the queryable.
+    pub errors: u64,
+    /// The average time spent in the queryable callback per query, in microseconds.
+    pub average_handling_time_us: u64,
+}
+
+#[cfg(feature = "unstable")]
+#[derive(Default)]
+struct QueryableStats {
+    queries: AtomicU64,
+    replies: AtomicU64,
+    errors: AtomicU64,
+    handling_time_us: AtomicU64,
+}
+
+/// Returns the callback counting the queries received by the queryable and the time spent handling them,
+/// and the corresponding statistics.
+#[cfg(feature = "unstable")]
+fn _stats_callback(
+    callback: z_owned_closure_query_t,
+) -> (z_owned_closure_query_t, Arc<QueryableStats>) {
+    let stats = Arc::new(QueryableStats::default());
+    let s = stats.clone();
+    let callback = (move |query: &mut z_loaned_query_t| {
+        s.queries.fetch_add(1, Ordering::Relaxed);
+        let start = Instant::now();
+        z_closure_query_call(z_closure_query_loan(&callback), query);
+        s.handling_time_us
+            .fetch_add(start.elapsed().as_micros() as u64, Ordering::Relaxed);
+    })
+    .into();
+    (callback, stats)
+}
+
+/// Counts a reply to a query in the statistics of the queryable that received it.
+#[cfg(feature = "unstable")]
+fn _record_reply(query: &CQuery, is_err: bool) {
+    if let Some(stats) = &query.state().stats {
+        stats.replies.fetch_add(1, Ordering::Relaxed);
+        if is_err {
+            stats.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the statistics of the queries handled by the queryable.
+///
+/// The replies are counted whenever they are sent, including from the queries cloned out of the callback or received
+/// through a channel, while the handling time only covers the time spent in the queryable callback.
+/// All statistics are 0 for the queryables not declared with `z_declare_queryable()`.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_queryable_stats(queryable: &z_loaned_queryable_t) -> zc_queryable_stats_t {
+    let Some(stats) = &queryable.as_rust_type_ref().state().stats else {
+        return zc_queryable_stats_t::default();
+    };
+    let queries = stats.queries.load(Ordering::Relaxed);
+    let handling_time_us = stats.handling_time_us.load(Ordering::Relaxed);
+    zc_queryable_stats_t {
+        queries,
+        replies: stats.replies.load(Ordering::Relaxed),
+        errors: stats.errors.load(Ordering::Relaxed),
+        average_handling_time_us: handling_time_us.checked_div(queries).unwrap_or(0),
+    }
+}
+
 /// Sends a reply to a query.
 ///
 /// This function must be called inside of a Queryable callback passing the
@@ -336,15 +672,30 @@ pub extern "C" fn z_queryable_id(queryable: &z_loaned_queryable_t) -> z_entity_g
 /// @param payload: The payload of this reply. Will be consumed.
 /// @param options: The options of this reply. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error code otherwise.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub extern "C" fn z_query_reply(
     this: &z_loaned_query_t,
     key_expr: &z_loaned_keyexpr_t,
-    payload: &mut z_moved_bytes_t,
+    payload: Option<&mut z_moved_bytes_t>,
     options: Option<&mut z_query_reply_options_t>,
 ) -> result::z_result_t {
+    let Some(payload) = moved_or_null(payload, "z_query_reply") else {
+        if let Some(options) = options {
+            if let Some(encoding) = options.encoding.take() {
+                let _ = encoding.take_rust_type();
+            }
+            #[cfg(feature = "unstable")]
+            if let Some(source_info) = options.source_info.take() {
+                let _ = source_info.take_rust_type();
+            }
+            if let Some(attachment) = options.attachment.take() {
+                let _ = attachment.take_rust_type();
+            }
+        }
+        return result::Z_EINVAL;
+    };
     let query = this.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let payload = payload.take_rust_type();
@@ -372,6 +723,8 @@ pub extern "C" fn z_query_reply(
         tracing::error!("{}", e);
         return result::Z_EGENERIC;
     }
+    #[cfg(feature = "unstable")]
+    _record_reply(query, false);
     result::Z_OK
 }
 
@@ -386,14 +739,20 @@ pub extern "C" fn z_query_reply(
 /// @param payload: The payload carrying error message. Will be consumed.
 /// @param options: The options of this reply. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `payload` is NULL, negative error code otherwise.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_query_reply_err(
     this: &z_loaned_query_t,
-    payload: &mut z_moved_bytes_t,
+    payload: Option<&mut z_moved_bytes_t>,
     options: Option<&mut z_query_reply_err_options_t>,
 ) -> result::z_result_t {
+    let Some(payload) = moved_or_null(payload, "z_query_reply_err") else {
+        if let Some(encoding) = options.and_then(|o| o.encoding.take()) {
+            let _ = encoding.take_rust_type();
+        }
+        return result::Z_EINVAL;
+    };
     let query = this.as_rust_type_ref();
     let payload = payload.take_rust_type();
     let reply = query.reply_err(payload).encoding(
@@ -407,6 +766,8 @@ pub unsafe extern "C" fn z_query_reply_err(
         tracing::error!("{}", e);
         return result::Z_EGENERIC;
     }
+    #[cfg(feature = "unstable")]
+    _record_reply(query, true);
     result::Z_OK
 }
 
@@ -453,6 +814,160 @@ pub unsafe extern "C" fn z_query_reply_del(
         tracing::error!("{}", e);
         return result::Z_EGENERIC;
     }
+    #[cfg(feature = "unstable")]
+    _record_reply(query, false);
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Replies to a query with the entries enumerated by a user-provided iterator, then finalizes the query.
+///
+/// `next` is called repeatedly until it returns ``false``. One reply is sent per entry whose key expression intersects the query key expression,
+/// the other entries (including the ones whose key expression was left in its gravestone state) are skipped and counted in `skipped`.
+/// This simplifies the storages answering queries on wildcard key expressions, which typically enumerate many keys.
+///
+/// The query is consumed, so that it is finalized once all entries have been sent, unless other copies of it are still alive.
+///
+/// @param query: The query to reply to. Will be consumed.
+/// @param next: The closure constructing the key expression and the payload of the next entry. Will be consumed and dropped
+/// once all entries have been enumerated.
+/// @param replied: If not NULL, will be set to the number of replies sent.
+/// @param skipped: If not NULL, will be set to the number of entries skipped for not matching the query key expression.
+///
+/// @return 0 in case of success, `Z_EINVAL` if `query` or `next` is NULL or in its gravestone state, negative error code otherwise.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_query_reply_entries(
+    query: Option<&mut z_moved_query_t>,
+    next: Option<&mut zc_moved_closure_reply_entry_t>,
+    replied: Option<&mut usize>,
+    skipped: Option<&mut usize>,
+) -> result::z_result_t {
+    let query = moved_or_null(query, "zc_query_reply_entries").map(|q| q.take_rust_type());
+    let next = moved_or_null(next, "zc_query_reply_entries").map(|n| n.take_rust_type());
+    let (Some(query), Some(next)) = (query, next) else {
+        return result::Z_EINVAL;
+    };
+    let Some(query) = query else {
+        tracing::error!("Query is in its gravestone state");
+        return result::Z_EINVAL;
+    };
+    if next.is_empty() {
+        tracing::error!("Entries closure is in its gravestone state");
+        return result::Z_EINVAL;
+    }
+    let mut count = 0;
+    let mut skipped_count = 0;
+    let mut res = result::Z_OK;
+    loop {
+        let mut key_expr: Option<KeyExpr<'static>> = None;
+        let mut payload = ZBytes::default();
+        if !zc_closure_reply_entry_call(
+            zc_closure_reply_entry_loan(&next),
+            key_expr.as_owned_c_type_mut(),
+            payload.as_owned_c_type_mut(),
+        ) {
+            break;
+        }
+        let Some(key_expr) = key_expr else {
+            tracing::error!("Entry key expression is in its gravestone state");
+            skipped_count += 1;
+            continue;
+        };
+        if !query.key_expr().intersects(&key_expr) {
+            tracing::error!(
+                "Entry {} does not match the query key expression {}",
+                key_expr,
+                query.key_expr()
+            );
+            skipped_count += 1;
+            continue;
+        }
+        if let Err(e) = query.reply(key_expr, payload).wait() {
+            tracing::error!("{}", e);
+            res = result::Z_EGENERIC;
+            break;
+        }
+        _record_reply(&query, false);
+        count += 1;
+    }
+    if let Some(replied) = replied {
+        *replied = count;
+    }
+    if let Some(skipped) = skipped {
+        *skipped = skipped_count;
+    }
+    res
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sends a previously received sample as a reply to a query.
+///
+/// The payload of the sample is shared with the reply rather than copied, and its kind, encoding, timestamp,
+/// attachment, source info and QoS are preserved, unless overridden by `options`. This allows caches and proxies
+/// to answer queries with the samples they hold without re-serializing them.
+///
+/// This function must be called inside of a Queryable callback passing the
+/// query received as parameters of the callback function.
+///
+/// @param this_: The query to reply to.
+/// @param sample: The sample to send as a reply.
+/// @param options: The options of this reply. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_query_reply_sample(
+    this_: &z_loaned_query_t,
+    sample: &z_loaned_sample_t,
+    options: Option<&mut z_query_reply_sample_options_t>,
+) -> result::z_result_t {
+    let query = this_.as_rust_type_ref();
+    let sample = sample.as_rust_type_ref();
+    let mut key_expr = sample.key_expr();
+    let mut encoding = sample.encoding().clone();
+    let mut timestamp = sample.timestamp().cloned();
+    let mut attachment = sample.attachment().cloned();
+    if let Some(options) = options {
+        if let Some(k) = options.key_expr {
+            key_expr = k.as_rust_type_ref();
+        }
+        if let Some(e) = options.encoding.take() {
+            encoding = e.take_rust_type();
+        }
+        if let Some(t) = options.timestamp.as_ref() {
+            timestamp = Some(t.into_rust_type());
+        }
+        if let Some(a) = options.attachment.take() {
+            attachment = Some(a.take_rust_type());
+        }
+    }
+    let res = match sample.kind() {
+        SampleKind::Put => query
+            .reply(key_expr, sample.payload().clone())
+            .encoding(encoding)
+            .timestamp(timestamp)
+            .attachment(attachment)
+            .source_info(sample.source_info().clone())
+            .priority(sample.priority())
+            .congestion_control(sample.congestion_control())
+            .express(sample.express())
+            .wait(),
+        SampleKind::Delete => query
+            .reply_del(key_expr)
+            .timestamp(timestamp)
+            .attachment(attachment)
+            .source_info(sample.source_info().clone())
+            .priority(sample.priority())
+            .congestion_control(sample.congestion_control())
+            .express(sample.express())
+            .wait(),
+    };
+    if let Err(e) = res {
+        tracing::error!("{}", e);
+        return result::Z_EGENERIC;
+    }
+    _record_reply(query, false);
     result::Z_OK
 }
 
@@ -537,8 +1052,15 @@ pub extern "C" fn z_query_attachment_mut(
 /// Undeclares a `z_owned_queryable_t`.
 /// Returns 0 in case of success, negative error code otherwise.
 #[no_mangle]
-pub extern "C" fn z_undeclare_queryable(this_: &mut z_moved_queryable_t) -> result::z_result_t {
+pub extern "C" fn z_undeclare_queryable(
+    this_: Option<&mut z_moved_queryable_t>,
+) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "z_undeclare_queryable") else {
+        return result::Z_OK;
+    };
     if let Some(qable) = this_.take_rust_type() {
+        // the state of the queryable is dropped once it is undeclared
+        let (qable, _state) = qable.into_parts();
         if let Err(e) = qable.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_EGENERIC;
@@ -15,13 +15,22 @@
 
 use std::mem::MaybeUninit;
 
-use zenoh::{handlers::Callback, sample::Sample, session::Session, Wait};
+use zenoh::{
+    handlers::Callback,
+    key_expr::KeyExpr,
+    query::{QueryConsolidation, QueryTarget, Reply},
+    sample::Sample,
+    session::Session,
+    Wait,
+};
 use zenoh_ext::*;
 
 use crate::{
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor,
     opaque_types::{ze_loaned_querying_subscriber_t, ze_owned_querying_subscriber_t},
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_sample_call, z_closure_sample_loan, z_get_options_t, z_loaned_keyexpr_t,
     z_loaned_session_t, z_moved_closure_sample_t, z_query_consolidation_none,
     z_query_consolidation_t, z_query_target_default, z_query_target_t,
@@ -29,7 +38,8 @@ use crate::{
 #[cfg(feature = "unstable")]
 use crate::{
     zc_locality_default, zc_locality_t, zc_reply_keyexpr_default, zc_reply_keyexpr_t,
-    ze_moved_querying_subscriber_t,
+    ze_closure_history_end_call, ze_closure_history_end_loan, ze_moved_closure_history_end_t,
+    ze_moved_querying_subscriber_t, ze_owned_closure_history_end_t,
 };
 decl_c_type!(
     owned(
@@ -69,6 +79,10 @@ pub struct ze_querying_subscriber_options_t {
     query_accept_replies: zc_reply_keyexpr_t,
     /// The timeout to be used for queries.
     query_timeout_ms: u64,
+    /// An optional closure called once all the samples returned by the initial query have been delivered
+    /// to the subscriber callback, including when the query fails or times out.
+    #[cfg(feature = "unstable")]
+    history_end: Option<&'static mut ze_moved_closure_history_end_t>,
 }
 
 /// @warning This API is deprecated. Please use ze_advanced_subscriber.
@@ -86,39 +100,119 @@ pub extern "C" fn ze_querying_subscriber_options_default(
         #[cfg(feature = "unstable")]
         query_accept_replies: zc_reply_keyexpr_default(),
         query_timeout_ms: 0,
+        #[cfg(feature = "unstable")]
+        history_end: None,
     });
 }
 
+/// Reply callback of the initial query of a querying subscriber calling its history end closure once dropped.
+///
+/// The fetch callback is dropped first, which delivers the replies merged with the live samples.
+#[cfg(feature = "unstable")]
+struct HistoryEnd {
+    callback: Option<Box<dyn Fn(Reply) + Send + Sync>>,
+    history_end: ze_owned_closure_history_end_t,
+}
+
+#[cfg(feature = "unstable")]
+impl HistoryEnd {
+    fn deliver(&self, reply: Reply) {
+        if let Some(callback) = &self.callback {
+            callback(reply);
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Drop for HistoryEnd {
+    fn drop(&mut self) {
+        std::mem::drop(self.callback.take());
+        ze_closure_history_end_call(ze_closure_history_end_loan(&self.history_end));
+    }
+}
+
+/// Drops the moved fields of the querying subscriber options, when the declaration fails before they are taken.
+fn _release_querying_subscriber_options(_options: Option<&mut ze_querying_subscriber_options_t>) {
+    #[cfg(feature = "unstable")]
+    if let Some(history_end) = _options.and_then(|o| o.history_end.take()) {
+        let _ = history_end.take_rust_type();
+    }
+}
+
+#[allow(clippy::type_complexity)]
 unsafe fn _declare_querying_subscriber_inner<'a, 'b>(
     session: &'a z_loaned_session_t,
     key_expr: &'b z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut ze_querying_subscriber_options_t>,
-) -> QueryingSubscriberBuilder<'a, 'b, UserSpace, Callback<Sample>> {
+) -> Result<
+    FetchingSubscriberBuilder<
+        'a,
+        'b,
+        UserSpace,
+        Callback<Sample>,
+        impl FnOnce(Box<dyn Fn(Reply) + Send + Sync>) -> zenoh::Result<()> + Send + Sync + 'a,
+        Reply,
+    >,
+    result::z_result_t,
+> {
     let session = session.as_rust_type_ref();
     let callback = callback.take_rust_type();
-    let mut sub = session
-        .declare_subscriber(key_expr.as_rust_type_ref())
-        .querying();
-    if let Some(options) = options {
-        sub = sub
-            .query_target(options.query_target.into())
-            .query_consolidation(options.query_consolidation);
-        #[cfg(feature = "unstable")]
-        {
-            sub = sub
-                .query_accept_replies(options.query_accept_replies.into())
-                .allowed_origin(options.allowed_origin.into());
-        }
-        if let Some(query_selector) = options.query_selector {
-            let query_selector = query_selector.as_rust_type_ref().clone();
-            sub = sub.query_selector(query_selector);
-        }
-        if options.query_timeout_ms != 0 {
-            sub = sub.query_timeout(std::time::Duration::from_millis(options.query_timeout_ms));
+    let mut default_options = MaybeUninit::uninit();
+    let options = match options {
+        Some(options) => options,
+        None => {
+            ze_querying_subscriber_options_default(&mut default_options);
+            default_options.assume_init_mut()
         }
-    }
-    let sub = sub.callback(move |sample| {
+    };
+    let query_selector = options
+        .query_selector
+        .map(|selector| selector.as_rust_type_ref().clone());
+    let target: QueryTarget = options.query_target.into();
+    let consolidation: QueryConsolidation = options.query_consolidation.into();
+    let timeout_ms = options.query_timeout_ms;
+    #[cfg(feature = "unstable")]
+    let accept_replies = options.query_accept_replies;
+    #[cfg(feature = "unstable")]
+    let history_end = options.history_end.take().map(|h| h.take_rust_type());
+    let (intercepted, slot) = keyexpr_interceptor::admit_declaration(
+        session,
+        key_expr.as_rust_type_ref(),
+        EntityKind::Subscriber,
+    )?;
+    let key_expr: KeyExpr<'static> =
+        intercepted.unwrap_or_else(|| key_expr.as_rust_type_ref().clone());
+    let selector = query_selector.unwrap_or_else(|| key_expr.clone());
+    let key_expr: KeyExpr<'b> = key_expr;
+    let callback = entity_limits::hold_in_sample_callback(slot, callback);
+    let sub = session.declare_subscriber(key_expr).fetching(
+        move |cb: Box<dyn Fn(Reply) + Send + Sync>| {
+            let mut get = session
+                .get(selector)
+                .target(target)
+                .consolidation(consolidation);
+            #[cfg(feature = "unstable")]
+            {
+                get = get.accept_replies(accept_replies.into());
+            }
+            if timeout_ms != 0 {
+                get = get.timeout(std::time::Duration::from_millis(timeout_ms));
+            }
+            #[cfg(feature = "unstable")]
+            if let Some(history_end) = history_end {
+                let history_end = HistoryEnd {
+                    callback: Some(cb),
+                    history_end,
+                };
+                return get.callback(move |reply| history_end.deliver(reply)).wait();
+            }
+            get.callback(cb).wait()
+        },
+    );
+    #[cfg(feature = "unstable")]
+    let sub = sub.allowed_origin(options.allowed_origin.into());
+    Ok(sub.callback(move |sample| {
         let mut owned_sample = Some(sample);
         z_closure_sample_call(
             z_closure_sample_loan(&callback),
@@ -127,33 +221,48 @@ unsafe fn _declare_querying_subscriber_inner<'a, 'b>(
                 .unwrap_unchecked()
                 .as_loaned_c_type_mut(),
         );
-    });
-    sub
+    }))
 }
 /// @warning This API is deprecated. Please use ze_advanced_subscriber.
 /// @brief Constructs and declares a querying subscriber for a given key expression.
 ///
+/// Samples received as replies to the initial query and live publications are delivered through the same callback.
+/// While the query is in progress, both are merged and delivered in timestamp order once it completes.
+/// Passing the closure of a FIFO or ring channel thus yields a single ordered stream that can be consumed with `z_recv()`.
+/// The optional `history_end` closure of the options is called once the samples of the initial query have been delivered.
+///
 /// @param session: A Zenoh session.
 /// @param querying_subscriber: An uninitialized memory location where querying subscriber will be constructed.
 /// @param key_expr: A key expression to subscribe to.
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: Additional options for the querying subscriber.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn ze_declare_querying_subscriber(
     session: &'static z_loaned_session_t,
     querying_subscriber: &mut MaybeUninit<ze_owned_querying_subscriber_t>,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut ze_querying_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = querying_subscriber.as_rust_type_mut_uninit();
-    let sub = _declare_querying_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "ze_declare_querying_subscriber") else {
+        _release_querying_subscriber_options(options);
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let sub = match _declare_querying_subscriber_inner(session, key_expr, callback, options) {
+        Ok(sub) => sub,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     match sub.wait() {
         Ok(sub) => {
-            let session = session.as_rust_type_ref();
+            let session: &'static Session = session.as_rust_type_ref();
             this.write(Some((sub, session)));
             result::Z_OK
         }
@@ -174,16 +283,24 @@ pub unsafe extern "C" fn ze_declare_querying_subscriber(
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: Additional options for the querying subscriber.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn ze_declare_background_querying_subscriber(
     session: &'static z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut ze_querying_subscriber_options_t>,
 ) -> result::z_result_t {
-    let sub = _declare_querying_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "ze_declare_background_querying_subscriber")
+    else {
+        _release_querying_subscriber_options(options);
+        return result::Z_EINVAL;
+    };
+    let sub = match _declare_querying_subscriber_inner(session, key_expr, callback, options) {
+        Ok(sub) => sub,
+        Err(e) => return e,
+    };
     match sub.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -262,7 +379,10 @@ pub unsafe extern "C" fn ze_querying_subscriber_get(
 /// @brief Undeclares querying subscriber callback and resets it to its gravestone state.
 /// This is equivalent to calling `ze_undeclare_querying_subscriber()` and discarding its return value.
 #[no_mangle]
-pub extern "C" fn ze_querying_subscriber_drop(this_: &mut ze_moved_querying_subscriber_t) {
+pub extern "C" fn ze_querying_subscriber_drop(this_: Option<&mut ze_moved_querying_subscriber_t>) {
+    let Some(this_) = moved_or_null(this_, "ze_querying_subscriber_drop") else {
+        return;
+    };
     std::mem::drop(this_.take_rust_type())
 }
 
@@ -294,8 +414,11 @@ pub unsafe extern "C" fn ze_querying_subscriber_loan(
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn ze_undeclare_querying_subscriber(
-    this_: &mut ze_moved_querying_subscriber_t,
+    this_: Option<&mut ze_moved_querying_subscriber_t>,
 ) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "ze_undeclare_querying_subscriber") else {
+        return result::Z_OK;
+    };
     if let Some(s) = this_.take_rust_type() {
         if let Err(e) = s.0.undeclare().wait() {
             tracing::error!("{}", e);
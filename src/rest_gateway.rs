@@ -0,0 +1,429 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    mem::MaybeUninit,
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, TrySendError},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use zenoh::{bytes::Encoding, key_expr::KeyExpr, query::Selector, Session, Wait};
+
+pub use crate::opaque_types::{
+    zc_loaned_rest_gateway_t, zc_moved_rest_gateway_t, zc_owned_rest_gateway_t,
+};
+use crate::{
+    result,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_session_t,
+};
+
+/// The maximum size of the request line and headers of a request.
+const MAX_HEADERS_SIZE: u64 = 64 * 1024;
+/// The maximum size of the body of a request, larger ones are rejected with `413 Payload Too Large`.
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+/// The time after which a stalled connection is closed.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+/// The number of threads serving the requests.
+const WORKERS: usize = 8;
+/// The number of connections waiting for a worker, more are rejected with `503 Service Unavailable`.
+const MAX_PENDING_CONNECTIONS: usize = 64;
+
+/// Stops the listening thread when dropped.
+pub struct RestGateway {
+    stop: Arc<AtomicBool>,
+    addr: SocketAddr,
+    thread: Option<JoinHandle<()>>,
+}
+
+decl_c_type!(
+    owned(zc_owned_rest_gateway_t, option RestGateway),
+    loaned(zc_loaned_rest_gateway_t),
+);
+
+impl Drop for RestGateway {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        // wakes the listening thread up, so that it notices it should stop
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn ok() -> Self {
+        Response {
+            status: "200 OK",
+            content_type: "text/plain",
+            body: Vec::new(),
+        }
+    }
+
+    fn error(status: &'static str, message: impl ToString) -> Self {
+        Response {
+            status,
+            content_type: "text/plain",
+            body: message.to_string().into_bytes(),
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            self.content_type,
+            self.body.len()
+        )?;
+        stream.write_all(&self.body)
+    }
+}
+
+fn get(session: &Session, key_expr: KeyExpr<'static>, parameters: &str) -> Response {
+    let replies = match session.get(Selector::from((key_expr, parameters))).wait() {
+        Ok(replies) => replies,
+        Err(e) => return Response::error("500 Internal Server Error", e),
+    };
+    let mut results = Vec::new();
+    while let Ok(reply) = replies.recv() {
+        let result = match reply.result() {
+            Ok(sample) => serde_json::json!({
+                "key": sample.key_expr().as_str(),
+                "value": String::from_utf8_lossy(&sample.payload().to_bytes()),
+                "encoding": sample.encoding().to_string(),
+                "timestamp": sample.timestamp().map(|t| t.to_string()),
+            }),
+            Err(err) => serde_json::json!({
+                "error": String::from_utf8_lossy(&err.payload().to_bytes()),
+                "encoding": err.encoding().to_string(),
+            }),
+        };
+        results.push(result);
+    }
+    Response {
+        status: "200 OK",
+        content_type: "application/json",
+        body: serde_json::Value::Array(results).to_string().into_bytes(),
+    }
+}
+
+/// Decodes the percent-encoded characters of a path, returns `None` if they are not valid UTF-8 once decoded.
+fn percent_decode(path: &str) -> Option<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .filter(|h| h.iter().all(u8::is_ascii_hexdigit))?;
+            decoded.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Decodes the names and values of the `;` or `&` separated query-string parameters, returns `None` if they are not
+/// validly encoded, or if they contain the separators of the selector parameters once decoded.
+fn decode_parameters(query: &str) -> Option<String> {
+    let mut parameters = Vec::new();
+    for parameter in query.split([';', '&']).filter(|p| !p.is_empty()) {
+        let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+        let name = percent_decode(name)?;
+        let value = percent_decode(value)?;
+        if name.is_empty() || name.contains([';', '=']) || value.contains(';') {
+            return None;
+        }
+        if value.is_empty() {
+            parameters.push(name);
+        } else {
+            parameters.push(format!("{name}={value}"));
+        }
+    }
+    Some(parameters.join(";"))
+}
+
+/// Returns ``true`` if the `Host` header names the gateway address, rejecting the requests sent to another host name
+/// resolving to the loopback address, e.g. by a web page through DNS rebinding.
+fn is_local_host(host: &str, addr: &SocketAddr) -> bool {
+    let (name, port) = match host.rsplit_once(':') {
+        Some((name, port)) => (name, port.parse().ok()),
+        None => (host, Some(80)),
+    };
+    port == Some(addr.port())
+        && (name.eq_ignore_ascii_case("localhost") || name == addr.ip().to_string())
+}
+
+fn handle_connection(
+    session: &Session,
+    addr: &SocketAddr,
+    mut stream: TcpStream,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?.take(MAX_HEADERS_SIZE));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut content_length = 0;
+    let mut content_type = None;
+    let mut host = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return match reader.get_ref().limit() {
+                0 => Response::error("431 Request Header Fields Too Large", "Headers too large"),
+                _ => Response::error("400 Bad Request", "Incomplete request"),
+            }
+            .write_to(&mut stream);
+        }
+        if line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                "content-type" => content_type = Some(value.trim().to_string()),
+                "host" => host = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+    if !host.is_some_and(|host| is_local_host(&host, addr)) {
+        return Response::error("403 Forbidden", "Invalid Host header").write_to(&mut stream);
+    }
+    if content_length > MAX_BODY_SIZE {
+        let message = format!("The request body exceeds {} bytes", MAX_BODY_SIZE);
+        return Response::error("413 Payload Too Large", message).write_to(&mut stream);
+    }
+    // the body is read past the headers size limit, up to its announced length
+    reader.get_mut().set_limit(content_length as u64);
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let mut request = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (request.next(), request.next()) else {
+        return Response::error("400 Bad Request", "Malformed request").write_to(&mut stream);
+    };
+    let selector = target.trim_start_matches('/');
+    let (key_expr, parameters) = selector.split_once('?').unwrap_or((selector, ""));
+    let Some(key_expr) = percent_decode(key_expr) else {
+        return Response::error("400 Bad Request", "Invalid percent-encoding in the path")
+            .write_to(&mut stream);
+    };
+    let Some(parameters) = decode_parameters(parameters) else {
+        return Response::error("400 Bad Request", "Invalid query-string parameters")
+            .write_to(&mut stream);
+    };
+    let key_expr = match KeyExpr::try_from(key_expr) {
+        Ok(key_expr) => key_expr,
+        Err(e) => return Response::error("400 Bad Request", e).write_to(&mut stream),
+    };
+    let response = match method {
+        "GET" => get(session, key_expr, &parameters),
+        "PUT" => {
+            let put = session.put(key_expr, body);
+            let put = match content_type {
+                Some(content_type) => put.encoding(Encoding::from(content_type)),
+                None => put,
+            };
+            match put.wait() {
+                Ok(_) => Response::ok(),
+                Err(e) => Response::error("500 Internal Server Error", e),
+            }
+        }
+        "DELETE" => match session.delete(key_expr).wait() {
+            Ok(_) => Response::ok(),
+            Err(e) => Response::error("500 Internal Server Error", e),
+        },
+        _ => Response::error("405 Method Not Allowed", "Unsupported method"),
+    };
+    response.write_to(&mut stream)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Starts a local HTTP gateway to the session, for debugging or interoperability purposes.
+///
+/// The gateway listens on `127.0.0.1:<port>`, and maps the HTTP requests on the `/<key_expr>[?<parameters>]` paths to:
+///   - `GET`: a `z_get()` on the selector, replying with a JSON array of the received replies (with `key`, `value`,
+///     `encoding` and `timestamp` fields for valid replies, and `error` and `encoding` fields for error replies).
+///   - `PUT`: a `z_put()` of the request body on the key expression, with the request content type as encoding.
+///   - `DELETE`: a `z_delete()` on the key expression.
+///
+/// The key expression of the path is percent-decoded, and must be a valid key expression. The names and values of the
+/// `;` or `&` separated query-string parameters are percent-decoded, and must not contain `;`, nor `=` for the names.
+/// Requests whose `Host` header is not `127.0.0.1:<port>` or `localhost:<port>` are rejected with `403 Forbidden`,
+/// so that web pages can't reach the gateway through DNS rebinding.
+/// Requests are served by a pool of 8 threads until the gateway is dropped, and rejected with `503 Service Unavailable`
+/// when more than 64 are waiting for a thread. Request bodies larger than 16 MiB are rejected with `413 Payload Too Large`,
+/// and connections stalled for more than 10 seconds are closed.
+///
+/// @param session: The zenoh session.
+/// @param gateway: An uninitialized location in memory where the gateway will be constructed.
+/// @param port: The port to listen on, an available port is picked if 0 (see `zc_rest_gateway_port()`).
+/// @return 0 in case of success, `Z_EIO` if the port can't be listened on, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_rest_gateway_start(
+    session: &z_loaned_session_t,
+    gateway: &mut MaybeUninit<zc_owned_rest_gateway_t>,
+    port: u16,
+) -> result::z_result_t {
+    let gateway = gateway.as_rust_type_mut_uninit();
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::error!("Failed to listen on port {}: {}", port, e);
+            gateway.write(None);
+            return result::Z_EIO;
+        }
+    };
+    let addr = match listener.local_addr() {
+        Ok(addr) => addr,
+        Err(e) => {
+            tracing::error!("Failed to get the gateway address: {}", e);
+            gateway.write(None);
+            return result::Z_EIO;
+        }
+    };
+    let session = session.as_rust_type_ref();
+    // the workers stop once the listening thread is stopped and the pending connections are served
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(MAX_PENDING_CONNECTIONS);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for i in 0..WORKERS {
+        let receiver = receiver.clone();
+        let session = session.clone();
+        let res = std::thread::Builder::new()
+            .name(format!("zc-rest-gateway-worker-{}", i))
+            .spawn(move || loop {
+                let stream = match receiver.lock() {
+                    Ok(receiver) => receiver.recv(),
+                    Err(_) => return,
+                };
+                let Ok(stream) = stream else {
+                    return;
+                };
+                if let Err(e) = handle_connection(&session, &addr, stream) {
+                    tracing::error!("Failed to serve the gateway request: {}", e);
+                }
+            });
+        if let Err(e) = res {
+            tracing::error!("Failed to start the gateway worker threads: {}", e);
+            gateway.write(None);
+            return result::Z_EGENERIC;
+        }
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    let stopped = stop.clone();
+    let res = std::thread::Builder::new()
+        .name("zc-rest-gateway".to_string())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::Acquire) {
+                    break;
+                }
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                match sender.try_send(stream) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(mut stream)) => {
+                        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+                        let _ = Response::error("503 Service Unavailable", "Too many requests")
+                            .write_to(&mut stream);
+                    }
+                    Err(TrySendError::Disconnected(_)) => break,
+                }
+            }
+        });
+    match res {
+        Ok(thread) => {
+            gateway.write(Some(RestGateway {
+                stop,
+                addr,
+                thread: Some(thread),
+            }));
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("Failed to start the gateway thread: {}", e);
+            gateway.write(None);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the port the gateway listens on.
+#[no_mangle]
+pub extern "C" fn zc_rest_gateway_port(this_: &zc_loaned_rest_gateway_t) -> u16 {
+    this_.as_rust_type_ref().addr.port()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows the gateway.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_rest_gateway_loan(
+    this_: &zc_owned_rest_gateway_t,
+) -> &zc_loaned_rest_gateway_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a gateway in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_rest_gateway_null(this_: &mut MaybeUninit<zc_owned_rest_gateway_t>) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the gateway is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_rest_gateway_check(this_: &zc_owned_rest_gateway_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Stops the gateway and resets it to its gravestone state.
+///
+/// The requests being served when the gateway is dropped are completed in the background.
+#[no_mangle]
+pub extern "C" fn zc_rest_gateway_drop(this_: Option<&mut zc_moved_rest_gateway_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_rest_gateway_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
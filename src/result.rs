@@ -17,6 +17,7 @@ pub type z_result_t = i8;
 
 pub const Z_CHANNEL_DISCONNECTED: z_result_t = 1;
 pub const Z_CHANNEL_NODATA: z_result_t = 2;
+pub const Z_QUERY_TIMED_OUT: z_result_t = 3;
 pub const Z_OK: z_result_t = 0;
 pub const Z_EINVAL: z_result_t = -1;
 pub const Z_EPARSE: z_result_t = -2;
@@ -32,4 +33,8 @@ pub const Z_EBUSY_MUTEX: z_result_t = -16;
 pub const Z_EINVAL_MUTEX: z_result_t = -22;
 pub const Z_EAGAIN_MUTEX: z_result_t = -11;
 pub const Z_EPOISON_MUTEX: z_result_t = -22; // same as Z_EINVAL_MUTEX
+// zenoh-c specific error codes, kept out of the range of the negated errno values
+pub const Z_ELIMIT: z_result_t = -100;
+pub const Z_EDENIED: z_result_t = -101;
+pub const Z_EFRAGMENT: z_result_t = -102;
 pub const Z_EGENERIC: z_result_t = i8::MIN;
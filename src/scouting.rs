@@ -22,7 +22,9 @@ use zenoh::{
 pub use crate::opaque_types::{z_loaned_hello_t, z_moved_hello_t, z_owned_hello_t};
 use crate::{
     result::{self, Z_OK},
-    transmute::{IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{
+        moved_or_null, IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_closure_hello_call, z_closure_hello_loan, z_id_t, z_moved_closure_hello_t, z_moved_config_t,
     z_owned_string_array_t, z_view_string_t, CString, CStringView, ZVector,
 };
@@ -34,7 +36,10 @@ decl_c_type!(
 /// Frees memory and resets hello message to its gravestone state.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn z_hello_drop(this_: &mut z_moved_hello_t) {
+pub unsafe extern "C" fn z_hello_drop(this_: Option<&mut z_moved_hello_t>) {
+    let Some(this_) = moved_or_null(this_, "z_hello_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -156,21 +161,25 @@ pub extern "C" fn z_scout_options_default(this_: &mut MaybeUninit<z_scout_option
 /// @param callback: A closure that will be called on each hello message received from discoverd Zenoh entities.
 /// @param options: A set of scouting options
 ///
-/// @return 0 if successful, negative error values upon failure.
+/// @return 0 if successful, `Z_EINVAL` if `config` or `callback` is NULL, negative error values upon failure.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub extern "C" fn z_scout(
-    config: &mut z_moved_config_t,
-    callback: &mut z_moved_closure_hello_t,
+    config: Option<&mut z_moved_config_t>,
+    callback: Option<&mut z_moved_closure_hello_t>,
     options: Option<&z_scout_options_t>,
 ) -> result::z_result_t {
+    let config = moved_or_null(config, "z_scout").map(|c| c.take_rust_type());
+    let Some(callback) = moved_or_null(callback, "z_scout") else {
+        return result::Z_EINVAL;
+    };
     let callback = callback.take_rust_type();
     let options = options.cloned().unwrap_or_default();
     let what =
         WhatAmIMatcher::try_from(options.what as u8).unwrap_or(WhatAmI::Router | WhatAmI::Peer);
     #[allow(clippy::unnecessary_cast)] // Required for multi-target
     let timeout = options.timeout_ms;
-    let Some(config) = config.take_rust_type() else {
+    let Some(config) = config.flatten() else {
         tracing::error!("Config not provided");
         return result::Z_EINVAL;
     };
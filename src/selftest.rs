@@ -0,0 +1,117 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use zenoh::{
+    sample::{Locality, Sample},
+    Wait,
+};
+
+use crate::{result, transmute::RustTypeRef, z_loaned_session_t};
+
+/// Makes the key expressions of the successive self-checks of a session unique.
+static SELFTEST_ID: AtomicU64 = AtomicU64::new(0);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Checks the local publication datapath of the session.
+///
+/// Declares a throwaway publisher and subscriber pair on a unique key expression (in the `@selftest/<zid>/**`
+/// verbatim space, so that no other subscriber receives it), publishes a message and waits for the subscriber
+/// to receive it. The publisher and subscriber are undeclared before returning.
+///
+/// The message is delivered within the session, and never sent on the network: this checks that the session is
+/// open and delivers the publications to its callbacks, but not the connectivity to the remote nodes, and the
+/// latency does not include any network transfer.
+///
+/// @param session: The zenoh session.
+/// @param timeout_ms: The maximum time to wait for the message in milliseconds.
+/// @param latency_us: If not NULL, set to the time elapsed between the publication and the reception of the message
+///     in microseconds in case of success.
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if the message is not received before the timeout,
+/// `Z_ESESSION_CLOSED` if the session is closed, other negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_selftest_pubsub_loopback(
+    session: &z_loaned_session_t,
+    timeout_ms: u64,
+    latency_us: Option<&mut u64>,
+) -> result::z_result_t {
+    let session = session.as_rust_type_ref();
+    if session.is_closed() {
+        return result::Z_ESESSION_CLOSED;
+    }
+    let id = SELFTEST_ID.fetch_add(1, Ordering::Relaxed);
+    let key_expr = format!("@selftest/{}/{}", session.zid(), id);
+    let payload = id.to_le_bytes();
+    let (received, on_received) = flume::bounded::<Instant>(1);
+    let subscriber = match session
+        .declare_subscriber(key_expr.as_str())
+        .allowed_origin(Locality::SessionLocal)
+        .callback(move |sample: Sample| {
+            if *sample.payload().to_bytes() == payload {
+                let _ = received.try_send(Instant::now());
+            }
+        })
+        .wait()
+    {
+        Ok(subscriber) => subscriber,
+        Err(e) => {
+            tracing::error!("Failed to declare the self-test subscriber: {}", e);
+            return result::Z_ENETWORK;
+        }
+    };
+    let publisher = match session
+        .declare_publisher(key_expr.as_str())
+        .allowed_destination(Locality::SessionLocal)
+        .wait()
+    {
+        Ok(publisher) => publisher,
+        Err(e) => {
+            tracing::error!("Failed to declare the self-test publisher: {}", e);
+            return result::Z_ENETWORK;
+        }
+    };
+    let sent = Instant::now();
+    let res = match publisher.put(payload.to_vec()).wait() {
+        Ok(()) => match on_received.recv_timeout(Duration::from_millis(timeout_ms)) {
+            Ok(received) => {
+                if let Some(latency_us) = latency_us {
+                    *latency_us = received.saturating_duration_since(sent).as_micros() as u64;
+                }
+                result::Z_OK
+            }
+            Err(_) => {
+                tracing::error!(
+                    "The self-test message was not received within {} ms",
+                    timeout_ms
+                );
+                result::Z_EUNAVAILABLE
+            }
+        },
+        Err(e) => {
+            tracing::error!("Failed to publish the self-test message: {}", e);
+            result::Z_ENETWORK
+        }
+    };
+    if let Err(e) = publisher.undeclare().wait() {
+        tracing::error!("{}", e);
+    }
+    if let Err(e) = subscriber.undeclare().wait() {
+        tracing::error!("{}", e);
+    }
+    res
+}
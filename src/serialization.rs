@@ -26,7 +26,7 @@ pub use crate::opaque_types::{
 };
 use crate::{
     result::{self, z_result_t},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_bytes_t, z_loaned_slice_t, z_loaned_string_t, z_owned_bytes_t, z_owned_slice_t,
     z_owned_string_t, CSliceOwned, CStringOwned,
 };
@@ -48,7 +48,10 @@ extern "C" fn ze_serializer_empty(this: &mut MaybeUninit<ze_owned_serializer_t>)
 
 /// @brief Drops `this_`, resetting it to gravestone value.
 #[no_mangle]
-extern "C" fn ze_serializer_drop(this_: &mut ze_moved_serializer_t) {
+extern "C" fn ze_serializer_drop(this_: Option<&mut ze_moved_serializer_t>) {
+    let Some(this_) = moved_or_null(this_, "ze_serializer_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -90,16 +93,20 @@ pub extern "C" fn ze_internal_serializer_null(this_: &mut MaybeUninit<ze_owned_s
 
 /// @brief Drop serializer and extract underlying `bytes` object it was writing to.
 /// @param this_: A serializer instance.
-/// @param bytes: An uninitialized memory location where `bytes` object` will be written to.
+/// @param bytes: An uninitialized memory location where `bytes` object` will be written to. If `this_` is NULL or in its
+/// gravestone state, empty bytes are written.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn ze_serializer_finish(
-    this: &mut ze_moved_serializer_t,
+    this: Option<&mut ze_moved_serializer_t>,
     bytes: &mut MaybeUninit<z_owned_bytes_t>,
 ) {
-    bytes
-        .as_rust_type_mut_uninit()
-        .write(this.take_rust_type().unwrap_unchecked().finish());
+    bytes.as_rust_type_mut_uninit().write(
+        moved_or_null(this, "ze_serializer_finish")
+            .and_then(|s| s.take_rust_type())
+            .map(|s| s.finish())
+            .unwrap_or_default(),
+    );
 }
 
 decl_c_type! {loaned(ze_deserializer_t, ZDeserializer<'static>)}
@@ -122,7 +129,7 @@ where
         }
         Err(e) => {
             tracing::error!("Failed to deserialize the payload: {:?}", e);
-            result::Z_EPARSE
+            result::Z_EDESERIALIZE
         }
     }
 }
@@ -238,77 +245,77 @@ pub extern "C" fn ze_serialize_bool(
 }
 
 /// @brief Deserializes into an unsigned integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_uint8(this: &z_loaned_bytes_t, dst: &mut u8) -> z_result_t {
     ze_deserialize_arithmetic::<u8>(this, dst)
 }
 
 /// @brief Deserializes into an unsigned integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_uint16(this: &z_loaned_bytes_t, dst: &mut u16) -> z_result_t {
     ze_deserialize_arithmetic::<u16>(this, dst)
 }
 
 /// @brief Deserializes into an unsigned integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_uint32(this: &z_loaned_bytes_t, dst: &mut u32) -> z_result_t {
     ze_deserialize_arithmetic::<u32>(this, dst)
 }
 
 /// @brief Deserializes into an unsigned integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_uint64(this: &z_loaned_bytes_t, dst: &mut u64) -> z_result_t {
     ze_deserialize_arithmetic::<u64>(this, dst)
 }
 
 /// @brief Deserializes into a signed integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_int8(this: &z_loaned_bytes_t, dst: &mut i8) -> z_result_t {
     ze_deserialize_arithmetic::<i8>(this, dst)
 }
 
 /// @brief Deserializes into a signed integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_int16(this: &z_loaned_bytes_t, dst: &mut i16) -> z_result_t {
     ze_deserialize_arithmetic::<i16>(this, dst)
 }
 
 /// @brief Deserializes into a signed integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_int32(this: &z_loaned_bytes_t, dst: &mut i32) -> z_result_t {
     ze_deserialize_arithmetic::<i32>(this, dst)
 }
 
 /// @brief Deserializes into a signed integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_int64(this: &z_loaned_bytes_t, dst: &mut i64) -> z_result_t {
     ze_deserialize_arithmetic::<i64>(this, dst)
 }
 
 /// @brief Deserializes into a float.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_float(this: &z_loaned_bytes_t, dst: &mut f32) -> z_result_t {
     ze_deserialize_arithmetic::<f32>(this, dst)
 }
 
-/// @brief Deserializes into a signed integer.
-/// @return 0 in case of success, negative error code otherwise.
+/// @brief Deserializes into a double.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_double(this: &z_loaned_bytes_t, dst: &mut f64) -> z_result_t {
     ze_deserialize_arithmetic::<f64>(this, dst)
 }
 
 /// @brief Deserializes into a bool.
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserialize_bool(this: &z_loaned_bytes_t, dst: &mut bool) -> z_result_t {
     ze_deserialize_arithmetic::<bool>(this, dst)
@@ -343,6 +350,7 @@ pub extern "C" fn ze_serialize_buf(
 }
 
 /// @brief Deserializes into a slice.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub extern "C" fn ze_deserialize_slice(
@@ -428,6 +436,7 @@ pub unsafe extern "C" fn ze_serialize_str(
 }
 
 /// @brief Deserializes into a UTF-8 string.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn ze_deserialize_string(
@@ -736,6 +745,7 @@ pub extern "C" fn ze_serializer_serialize_buf(
 }
 
 /// @brief Deserializes into a slice.
+/// @return 0 in case of success, `Z_EDESERIALIZE` if the payload does not contain a serialized value of the expected type.
 #[no_mangle]
 pub extern "C" fn ze_deserializer_deserialize_slice(
     this: &mut ze_deserializer_t,
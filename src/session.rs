@@ -13,21 +13,91 @@
 //
 
 use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use zenoh::{Session, Wait};
 
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 use crate::z_loaned_shm_client_storage_t;
 #[cfg(feature = "unstable")]
-use crate::zc_owned_concurrent_close_handle_t;
+use crate::{
+    deferred_callbacks::DeferredQueue, drop_counters::DropCounters, entity_limits::SessionEntities,
+    matching::DependentListeners, payload_limits::PayloadLimits,
+    zc_owned_closure_keyexpr_interceptor_t, zc_owned_concurrent_close_handle_t,
+};
 use crate::{
     opaque_types::{z_loaned_session_t, z_owned_session_t},
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_moved_config_t, z_moved_session_t,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_moved_config_t, z_moved_session_t, Stateful,
 };
+
+/// The state kept by zenoh-c for a session, shared with the entities declared on it.
+#[derive(Default)]
+pub(crate) struct SessionState {
+    #[cfg(feature = "unstable")]
+    pub(crate) entities: Arc<SessionEntities>,
+    #[cfg(feature = "unstable")]
+    pub(crate) keyexpr_interceptor: RwLock<Option<Arc<zc_owned_closure_keyexpr_interceptor_t>>>,
+    #[cfg(feature = "unstable")]
+    pub(crate) drops: Arc<DropCounters>,
+    #[cfg(feature = "unstable")]
+    pub(crate) deferred: Option<Arc<DeferredQueue>>,
+    #[cfg(feature = "unstable")]
+    pub(crate) payload_limits: PayloadLimits,
+    #[cfg(feature = "unstable")]
+    pub(crate) listeners: Arc<DependentListeners>,
+    /// The timeout of the queries made without an explicit one.
+    #[cfg(feature = "unstable")]
+    pub(crate) queries_default_timeout: Duration,
+}
+
+/// The default value of the `queries_default_timeout` configuration key.
+#[cfg(feature = "unstable")]
+const DEFAULT_QUERIES_TIMEOUT_MS: u64 = 10000;
+
+#[cfg(feature = "unstable")]
+impl SessionState {
+    /// Constructs the state of a session, resolving the configuration values it depends on before the session is opened.
+    fn new(options: Option<&z_open_options_t>, config: &zenoh::config::Config) -> Self {
+        let drops = Arc::new(DropCounters::default());
+        SessionState {
+            entities: Arc::new(options.map_or_else(SessionEntities::default, |o| {
+                SessionEntities::with_limits(
+                    o.max_publishers,
+                    o.max_subscribers,
+                    o.max_queryables,
+                    o.max_queriers,
+                )
+            })),
+            keyexpr_interceptor: RwLock::new(None),
+            deferred: options.filter(|o| o.deferred_callbacks).map(|o| {
+                Arc::new(DeferredQueue::new(
+                    o.deferred_callbacks_capacity,
+                    drops.clone(),
+                ))
+            }),
+            drops,
+            payload_limits: PayloadLimits::from_config(config),
+            listeners: Arc::default(),
+            queries_default_timeout: Duration::from_millis(
+                config
+                    .get_json("queries_default_timeout")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_QUERIES_TIMEOUT_MS),
+            ),
+        }
+    }
+}
+
+pub(crate) type CSession = Stateful<Session, SessionState>;
 decl_c_type!(
-    owned(z_owned_session_t, option Session),
+    owned(z_owned_session_t, option CSession),
     loaned(z_loaned_session_t),
 );
 
@@ -65,34 +135,129 @@ pub extern "C" fn z_internal_session_null(this_: &mut MaybeUninit<z_owned_sessio
 /// Options passed to the `z_open()` function.
 #[repr(C)]
 pub struct z_open_options_t {
+    #[cfg(feature = "unstable")]
+    /// The maximum number of publishers (including advanced ones) that can be declared on the session at the same time, 0 means unlimited.
+    /// Declaring more publishers fails with `Z_ELIMIT`.
+    pub max_publishers: usize,
+    #[cfg(feature = "unstable")]
+    /// The maximum number of subscribers (including background, liveliness, querying and advanced ones) that can be declared on the session at the same time, 0 means unlimited.
+    /// Declaring more subscribers fails with `Z_ELIMIT`.
+    pub max_subscribers: usize,
+    #[cfg(feature = "unstable")]
+    /// The maximum number of queryables (including background ones) that can be declared on the session at the same time, 0 means unlimited.
+    /// Declaring more queryables fails with `Z_ELIMIT`.
+    pub max_queryables: usize,
+    #[cfg(feature = "unstable")]
+    /// The maximum number of queriers that can be declared on the session at the same time, 0 means unlimited.
+    /// Declaring more queriers fails with `Z_ELIMIT`.
+    pub max_queriers: usize,
+    #[cfg(feature = "unstable")]
+    /// The number of additional attempts to open the session if the first one fails, 0 means no retry.
+    /// This allows applications started before their router to wait for it to become available.
+    pub retry_attempts: usize,
+    #[cfg(feature = "unstable")]
+    /// The delay in milliseconds before the first retry, it is doubled after each failed attempt.
+    pub retry_backoff_ms: u64,
+    #[cfg(feature = "unstable")]
+    /// If set to ``true``, the callbacks of the subscribers, queryables and gets of the session are not executed on the zenoh threads,
+    /// but queued until `zc_session_drain_callbacks()` executes them on the calling thread.
+    /// This gives applications with a main loop (e.g. GUI) control over the thread and the moment their callbacks are executed.
+    ///
+    /// Channel handlers are deferred as well, so `z_recv()` must not be called on the thread draining the callbacks
+    /// before draining them, or it never returns (see `zc_session_drain_callbacks()`).
+    pub deferred_callbacks: bool,
+    #[cfg(feature = "unstable")]
+    /// The maximum number of callbacks pending in deferred dispatch mode, 0 means unbounded.
+    /// The messages arriving while the queue is full are dropped and counted in the `full_buffers` drop counter.
+    pub deferred_callbacks_capacity: usize,
+    #[cfg(not(feature = "unstable"))]
     _dummy: u8,
 }
 
 /// Constructs the default value for `z_open_options_t`.
 #[no_mangle]
 pub extern "C" fn z_open_options_default(this_: &mut MaybeUninit<z_open_options_t>) {
-    this_.write(z_open_options_t { _dummy: 0 });
+    this_.write(z_open_options_t {
+        #[cfg(feature = "unstable")]
+        max_publishers: 0,
+        #[cfg(feature = "unstable")]
+        max_subscribers: 0,
+        #[cfg(feature = "unstable")]
+        max_queryables: 0,
+        #[cfg(feature = "unstable")]
+        max_queriers: 0,
+        #[cfg(feature = "unstable")]
+        retry_attempts: 0,
+        #[cfg(feature = "unstable")]
+        retry_backoff_ms: 1000,
+        #[cfg(feature = "unstable")]
+        deferred_callbacks: false,
+        #[cfg(feature = "unstable")]
+        deferred_callbacks_capacity: 1024,
+        #[cfg(not(feature = "unstable"))]
+        _dummy: 0,
+    });
+}
+
+#[cfg(feature = "unstable")]
+fn _open_with_retry(
+    config: zenoh::config::Config,
+    attempts: usize,
+    backoff_ms: u64,
+) -> zenoh::Result<Session> {
+    let mut backoff = Duration::from_millis(backoff_ms);
+    let mut res = zenoh::open(config.clone()).wait();
+    for attempt in 1..=attempts {
+        let Err(e) = &res else {
+            break;
+        };
+        tracing::warn!(
+            "Error opening session: {}, retrying in {} ms ({}/{})",
+            e,
+            backoff.as_millis(),
+            attempt,
+            attempts
+        );
+        std::thread::sleep(backoff);
+        backoff = backoff.saturating_mul(2);
+        res = zenoh::open(config.clone()).wait();
+    }
+    res
 }
 
 /// Constructs and opens a new Zenoh session.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case the session will be in its gravestone state).
+/// @return 0 in case of success, `Z_EINVAL` if `config` is NULL or in its gravestone state, negative error code otherwise
+/// (in this case the session will be in its gravestone state).
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub extern "C" fn z_open(
     this: &mut MaybeUninit<z_owned_session_t>,
-    config: &mut z_moved_config_t,
-    _options: Option<&z_open_options_t>,
+    config: Option<&mut z_moved_config_t>,
+    #[allow(unused)] options: Option<&z_open_options_t>,
 ) -> result::z_result_t {
     let this = this.as_rust_type_mut_uninit();
-    let Some(config) = config.take_rust_type().take() else {
+    let Some(config) = moved_or_null(config, "z_open").and_then(|c| c.take_rust_type()) else {
         tracing::error!("Config not provided");
         this.write(None);
         return result::Z_EINVAL;
     };
-    match zenoh::open(config).wait() {
+    #[cfg(feature = "unstable")]
+    let state = SessionState::new(options, &config);
+    #[cfg(not(feature = "unstable"))]
+    let state = SessionState::default();
+    #[cfg(feature = "unstable")]
+    let res = match options {
+        Some(o) if o.retry_attempts > 0 => {
+            _open_with_retry(config, o.retry_attempts, o.retry_backoff_ms)
+        }
+        _ => zenoh::open(config).wait(),
+    };
+    #[cfg(not(feature = "unstable"))]
+    let res = zenoh::open(config).wait();
+    match res {
         Ok(s) => {
-            this.write(Some(s));
+            this.write(Some(CSession::new(s, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -107,26 +272,30 @@ pub extern "C" fn z_open(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Constructs and opens a new Zenoh session with specified client storage.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case the session will be in its gravestone state).
+/// @return 0 in case of success, `Z_EINVAL` if `config` is NULL or in its gravestone state, negative error code otherwise
+/// (in this case the session will be in its gravestone state).
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub extern "C" fn z_open_with_custom_shm_clients(
     this: &mut MaybeUninit<z_owned_session_t>,
-    config: &mut z_moved_config_t,
+    config: Option<&mut z_moved_config_t>,
     shm_clients: &z_loaned_shm_client_storage_t,
 ) -> result::z_result_t {
     let this = this.as_rust_type_mut_uninit();
-    let Some(config) = config.take_rust_type() else {
+    let Some(config) =
+        moved_or_null(config, "z_open_with_custom_shm_clients").and_then(|c| c.take_rust_type())
+    else {
         tracing::error!("Config not provided");
         this.write(None);
         return result::Z_EINVAL;
     };
+    let state = SessionState::new(None, &config);
     match zenoh::open(config)
         .with_shm_clients(shm_clients.as_rust_type_ref().clone())
         .wait()
     {
         Ok(s) => {
-            this.write(Some(s));
+            this.write(Some(CSession::new(s, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -179,12 +348,19 @@ pub extern "C" fn z_close_options_default(this_: &mut MaybeUninit<z_close_option
 
 /// Closes zenoh session. This also drops all the closure callbacks remaining from dropped, but not undeclared subscribers.
 ///
+/// All the entities declared on the session (publishers, subscribers, queryables, queriers, liveliness tokens
+/// and matching listeners) are undeclared as part of the close, before the session transports are shut down,
+/// so there is no need to undeclare them beforehand in any particular order. Dropping them after the session is closed
+/// only releases their memory.
+///
 /// @return `0` in case of success, a negative value if an error occured while closing the session.
 #[no_mangle]
 pub extern "C" fn z_close(
     session: &mut z_loaned_session_t,
     #[allow(unused)] options: Option<&mut z_close_options_t>,
 ) -> result::z_result_t {
+    #[cfg(feature = "unstable")]
+    session.as_rust_type_ref().state().listeners.orphan();
     #[allow(unused_mut)]
     let mut close_builder = session.as_rust_type_mut().close();
 
@@ -223,6 +399,14 @@ pub extern "C" fn z_session_is_closed(session: &z_loaned_session_t) -> bool {
 
 /// Closes and invalidates the session.
 #[no_mangle]
-pub extern "C" fn z_session_drop(this_: &mut z_moved_session_t) {
-    let _ = this_.take_rust_type();
+pub extern "C" fn z_session_drop(this_: Option<&mut z_moved_session_t>) {
+    let Some(this_) = moved_or_null(this_, "z_session_drop") else {
+        return;
+    };
+    let session = this_.take_rust_type();
+    #[cfg(feature = "unstable")]
+    if let Some(s) = &session {
+        s.state().listeners.orphan();
+    }
+    std::mem::drop(session)
 }
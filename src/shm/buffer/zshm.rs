@@ -20,7 +20,7 @@ use std::{
 use zenoh::shm::{zshm, zshmmut, ZShm};
 
 use crate::{
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_shm_mut_t, z_loaned_shm_t, z_moved_shm_mut_t, z_moved_shm_t, z_owned_shm_t,
 };
 
@@ -30,13 +30,15 @@ decl_c_type!(
 );
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
-/// @brief Constructs ZShm slice from ZShmMut slice.
+/// @brief Constructs ZShm slice from ZShmMut slice. If `that` is NULL, `this_` is constructed in its gravestone state.
 #[no_mangle]
 pub extern "C" fn z_shm_from_mut(
     this_: &mut MaybeUninit<z_owned_shm_t>,
-    that: &mut z_moved_shm_mut_t,
+    that: Option<&mut z_moved_shm_mut_t>,
 ) {
-    let shm: Option<ZShm> = that.take_rust_type().take().map(|val| val.into());
+    let shm: Option<ZShm> = moved_or_null(that, "z_shm_from_mut")
+        .and_then(|that| that.take_rust_type())
+        .map(|val| val.into());
     this_.as_rust_type_mut_uninit().write(shm);
 }
 
@@ -91,11 +93,17 @@ pub unsafe extern "C" fn z_shm_loan_mut(this_: &mut z_owned_shm_t) -> &mut z_loa
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Mutably borrows ZShm slice as borrowed ZShmMut slice.
+/// @return NULL if the slice is not uniquely owned, or if `this_` is NULL or in its gravestone state.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn z_shm_try_mut(this_: &mut z_owned_shm_t) -> *mut z_loaned_shm_mut_t {
-    let this = this_.as_rust_type_mut();
-    let this: &mut ZShm = this.as_mut().unwrap_unchecked();
+pub unsafe extern "C" fn z_shm_try_mut(
+    this_: Option<&mut z_owned_shm_t>,
+) -> *mut z_loaned_shm_mut_t {
+    let Some(this) =
+        moved_or_null(this_, "z_shm_try_mut").and_then(|t| t.as_rust_type_mut().as_mut())
+    else {
+        return std::ptr::null_mut();
+    };
     let shm: &mut zshm = this.borrow_mut();
     match shm.try_into() {
         Ok(val) => {
@@ -109,7 +117,10 @@ pub unsafe extern "C" fn z_shm_try_mut(this_: &mut z_owned_shm_t) -> *mut z_loan
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes ZShm slice.
 #[no_mangle]
-pub extern "C" fn z_shm_drop(this_: &mut z_moved_shm_t) {
+pub extern "C" fn z_shm_drop(this_: Option<&mut z_moved_shm_t>) {
+    let Some(this_) = moved_or_null(this_, "z_shm_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
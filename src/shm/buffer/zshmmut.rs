@@ -21,7 +21,7 @@ use zenoh::shm::{zshmmut, ZShmMut};
 
 use crate::{
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_shm_mut_t, z_moved_shm_mut_t, z_moved_shm_t, z_owned_shm_mut_t, z_owned_shm_t,
 };
 
@@ -37,14 +37,16 @@ decl_c_type!(
 /// @param immut: immutable SHM buffer returned back to caller's side
 /// ONLY in case of Z_EUNAVAILABLE failure
 /// @return Z_OK in case of success, Z_EUNAVAILABLE in case of unsuccessful write access,
-/// Z_EINVAL if moved value is incorrect.
+/// Z_EINVAL if moved value is NULL or incorrect.
 #[no_mangle]
 pub extern "C" fn z_shm_mut_try_from_immut(
     this: &mut MaybeUninit<z_owned_shm_mut_t>,
-    that: &mut z_moved_shm_t,
+    that: Option<&mut z_moved_shm_t>,
     immut: &mut MaybeUninit<z_owned_shm_t>,
 ) -> result::z_result_t {
-    if let Some(shm) = that.take_rust_type() {
+    if let Some(shm) =
+        moved_or_null(that, "z_shm_mut_try_from_immut").and_then(|t| t.take_rust_type())
+    {
         return match ZShmMut::try_from(shm) {
             Ok(val) => {
                 this.as_rust_type_mut_uninit().write(Some(val));
@@ -104,7 +106,10 @@ pub unsafe extern "C" fn z_shm_mut_loan_mut(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes ZShmMut slice.
 #[no_mangle]
-pub extern "C" fn z_shm_mut_drop(this_: &mut z_moved_shm_mut_t) {
+pub extern "C" fn z_shm_mut_drop(this_: Option<&mut z_moved_shm_mut_t>) {
+    let Some(this_) = moved_or_null(this_, "z_shm_mut_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
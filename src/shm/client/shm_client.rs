@@ -25,7 +25,7 @@ pub use crate::opaque_types::{z_moved_shm_client_t, z_owned_shm_client_t};
 use crate::{
     context::{zc_threadsafe_context_t, DroppableContext, ThreadsafeContext},
     shm::common::types::z_segment_id_t,
-    transmute::{RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, RustTypeRef, RustTypeRefUninit, TakeRustType},
 };
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -98,6 +98,9 @@ pub extern "C" fn z_internal_shm_client_check(this_: &z_owned_shm_client_t) -> b
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes SHM Client.
 #[no_mangle]
-pub extern "C" fn z_shm_client_drop(this_: &mut z_moved_shm_client_t) {
+pub extern "C" fn z_shm_client_drop(this_: Option<&mut z_moved_shm_client_t>) {
+    let Some(this_) = moved_or_null(this_, "z_shm_client_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
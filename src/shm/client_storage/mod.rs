@@ -19,7 +19,7 @@ use zenoh::shm::{ProtocolID, ShmClient, ShmClientStorage, GLOBAL_CLIENT_STORAGE}
 use super::common::types::z_protocol_id_t;
 use crate::{
     result::{z_result_t, Z_EINVAL, Z_OK},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_shm_client_storage_t, z_moved_shm_client_storage_t, z_moved_shm_client_t,
     z_owned_shm_client_storage_t, zc_loaned_shm_client_list_t, zc_moved_shm_client_list_t,
     zc_owned_shm_client_list_t,
@@ -57,7 +57,10 @@ pub extern "C" fn zc_internal_shm_client_list_check(this_: &zc_owned_shm_client_
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes list of SHM Clients.
 #[no_mangle]
-pub extern "C" fn zc_shm_client_list_drop(this_: &mut zc_moved_shm_client_list_t) {
+pub extern "C" fn zc_shm_client_list_drop(this_: Option<&mut zc_moved_shm_client_list_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_shm_client_list_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -89,13 +92,16 @@ pub unsafe extern "C" fn zc_shm_client_list_loan_mut(
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Add client to the list.
+/// @return 0 in case of success, `Z_EINVAL` if `client` is NULL or in its gravestone state.
 #[no_mangle]
 pub extern "C" fn zc_shm_client_list_add_client(
     this: &mut zc_loaned_shm_client_list_t,
     id: z_protocol_id_t,
-    client: &mut z_moved_shm_client_t,
+    client: Option<&mut z_moved_shm_client_t>,
 ) -> z_result_t {
-    let Some(client) = client.take_rust_type() else {
+    let Some(client) =
+        moved_or_null(client, "zc_shm_client_list_add_client").and_then(|c| c.take_rust_type())
+    else {
         return Z_EINVAL;
     };
     this.as_rust_type_mut().push((id, client));
@@ -186,7 +192,10 @@ pub extern "C" fn z_internal_shm_client_storage_check(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Derefs SHM Client Storage.
 #[no_mangle]
-pub extern "C" fn z_shm_client_storage_drop(this_: &mut z_moved_shm_client_storage_t) {
+pub extern "C" fn z_shm_client_storage_drop(this_: Option<&mut z_moved_shm_client_storage_t>) {
+    let Some(this_) = moved_or_null(this_, "z_shm_client_storage_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -28,7 +28,7 @@ use crate::{
     context::{zc_threadsafe_context_t, Context, ThreadsafeContext},
     result::z_result_t,
     shm::protocol_implementations::posix::posix_shm_provider::PosixAllocLayout,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_alloc_layout_t, z_loaned_shm_provider_t, z_moved_alloc_layout_t,
     z_owned_alloc_layout_t,
 };
@@ -92,7 +92,10 @@ pub unsafe extern "C" fn z_alloc_layout_loan(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes Alloc Layout.
 #[no_mangle]
-pub extern "C" fn z_alloc_layout_drop(this_: &mut z_moved_alloc_layout_t) {
+pub extern "C" fn z_alloc_layout_drop(this_: Option<&mut z_moved_alloc_layout_t>) {
+    let Some(this_) = moved_or_null(this_, "z_alloc_layout_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -37,7 +37,7 @@ use crate::{
         protocol_implementations::posix::posix_shm_provider::PosixShmProvider,
         provider::types::z_buf_layout_alloc_result_t,
     },
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_shm_provider_t, z_moved_shm_provider_t, z_owned_shm_mut_t, z_owned_shm_provider_t,
 };
 
@@ -125,7 +125,10 @@ pub unsafe extern "C" fn z_shm_provider_loan(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes SHM Provider.
 #[no_mangle]
-pub extern "C" fn z_shm_provider_drop(this_: &mut z_moved_shm_provider_t) {
+pub extern "C" fn z_shm_provider_drop(this_: Option<&mut z_moved_shm_provider_t>) {
+    let Some(this_) = moved_or_null(this_, "z_shm_provider_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -23,7 +23,9 @@ use super::chunk::z_allocated_chunk_t;
 use crate::{
     result::{z_result_t, Z_EINVAL, Z_OK},
     shm::buffer::zshmmut::z_internal_shm_mut_null,
-    transmute::{IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{
+        moved_or_null, IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
     z_loaned_memory_layout_t, z_moved_chunk_alloc_result_t, z_moved_memory_layout_t,
     z_owned_chunk_alloc_result_t, z_owned_memory_layout_t, z_owned_shm_mut_t,
 };
@@ -171,7 +173,10 @@ pub unsafe extern "C" fn z_memory_layout_loan(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes Memory Layout.
 #[no_mangle]
-pub extern "C" fn z_memory_layout_drop(this_: &mut z_moved_memory_layout_t) {
+pub extern "C" fn z_memory_layout_drop(this_: Option<&mut z_moved_memory_layout_t>) {
+    let Some(this_) = moved_or_null(this_, "z_memory_layout_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -240,7 +245,10 @@ pub extern "C" fn z_internal_chunk_alloc_result_check(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Deletes Chunk Alloc Result.
 #[no_mangle]
-pub extern "C" fn z_chunk_alloc_result_drop(this_: &mut z_moved_chunk_alloc_result_t) {
+pub extern "C" fn z_chunk_alloc_result_drop(this_: Option<&mut z_moved_chunk_alloc_result_t>) {
+    let Some(this_) = moved_or_null(this_, "z_chunk_alloc_result_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
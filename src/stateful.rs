@@ -0,0 +1,111 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+#[cfg(not(feature = "unstable"))]
+use std::marker::PhantomData;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+/// A zenoh object along with the state kept by zenoh-c for it.
+///
+/// The state lives as long as the object, and can be shared with its callbacks and with the entities declared on it.
+/// Its opaque C type is declared as `Stateful<T>`, i.e. `(T, Arc<u8>)`.
+///
+/// Only the unstable API keeps some state: without the `unstable` feature the states are empty and are not stored,
+/// so that the stable types keep the layout of the zenoh object and no allocation is made for them.
+pub(crate) struct Stateful<T, S> {
+    inner: T,
+    #[cfg(feature = "unstable")]
+    state: Arc<S>,
+    #[cfg(not(feature = "unstable"))]
+    state: PhantomData<S>,
+}
+
+impl<T, S> Stateful<T, S> {
+    #[cfg(feature = "unstable")]
+    pub(crate) fn new(inner: T, state: S) -> Self {
+        Stateful {
+            inner,
+            state: Arc::new(state),
+        }
+    }
+
+    #[cfg(not(feature = "unstable"))]
+    pub(crate) fn new(inner: T, _state: S) -> Self {
+        Stateful {
+            inner,
+            state: PhantomData,
+        }
+    }
+
+    /// Constructs the object sharing the state of another one, e.g. a query sharing the state of the queryable that received it.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn with_state(inner: T, state: &Arc<S>) -> Self {
+        Stateful {
+            inner,
+            state: state.clone(),
+        }
+    }
+
+    /// Constructs the object sharing the state of another one, e.g. a query sharing the state of the queryable that received it.
+    #[cfg(not(feature = "unstable"))]
+    pub(crate) fn with_state(inner: T, _state: &Arc<S>) -> Self {
+        Stateful {
+            inner,
+            state: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "unstable")]
+    pub(crate) fn state(&self) -> &Arc<S> {
+        &self.state
+    }
+
+    /// Splits the object from its state, e.g. to undeclare it before the state is dropped.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn into_parts(self) -> (T, Arc<S>) {
+        (self.inner, self.state)
+    }
+
+    /// Splits the object from its state, e.g. to undeclare it before the state is dropped.
+    #[cfg(not(feature = "unstable"))]
+    pub(crate) fn into_parts(self) -> (T, PhantomData<S>) {
+        (self.inner, self.state)
+    }
+}
+
+impl<T: Clone, S> Clone for Stateful<T, S> {
+    fn clone(&self) -> Self {
+        Stateful {
+            inner: self.inner.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T, S> Deref for Stateful<T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T, S> DerefMut for Stateful<T, S> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,805 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    collections::VecDeque,
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use zenoh::{
+    bytes::ZBytes,
+    key_expr::KeyExpr,
+    pubsub::{Publisher, Subscriber},
+    qos::{CongestionControl, Reliability},
+    query::{ConsolidationMode, Query, Queryable, Selector},
+    sample::Sample,
+    Wait,
+};
+
+pub use crate::opaque_types::{
+    zc_loaned_stream_listener_t, zc_loaned_stream_t, zc_moved_stream_listener_t, zc_moved_stream_t,
+    zc_owned_stream_listener_t, zc_owned_stream_t,
+};
+use crate::{
+    drop_counters::{DropCounters, DropReason},
+    entity_limits::{EntityKind, EntitySlot},
+    keyexpr_interceptor, result,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_keyexpr_t, z_loaned_session_t, CSession,
+};
+
+/// The query parameter carrying the id of the stream being opened.
+const STREAM_ID_PARAMETER: &str = "_stream";
+/// The query parameter carrying the time in ms the opening end waits for the stream to be accepted.
+const STREAM_TIMEOUT_PARAMETER: &str = "_stream_timeout";
+
+static NEXT_STREAM_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The entities declared by each end of a stream.
+const STREAM_ENTITIES: [EntityKind; 4] = [
+    EntityKind::Publisher,
+    EntityKind::Publisher,
+    EntityKind::Subscriber,
+    EntityKind::Subscriber,
+];
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to `zc_stream_open()` and `zc_declare_stream_listener()`.
+#[repr(C)]
+pub struct zc_stream_options_t {
+    /// The maximum number of messages sent on the stream and not yet read by the peer.
+    pub window: usize,
+    /// The maximum size in bytes of the messages the written data is split into.
+    pub chunk_size: usize,
+    /// The time in ms to wait for the stream to be accepted. Ignored by the stream listeners.
+    pub open_timeout_ms: u64,
+    /// The maximum time in ms `zc_stream_write()` waits for the peer to read the data, after which the stream is failed.
+    /// 0 means no timeout.
+    pub write_timeout_ms: u64,
+}
+
+impl Default for zc_stream_options_t {
+    fn default() -> Self {
+        zc_stream_options_t {
+            window: 16,
+            chunk_size: 64 * 1024,
+            open_timeout_ms: 10000,
+            write_timeout_ms: 10000,
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `zc_stream_options_t`.
+#[no_mangle]
+pub extern "C" fn zc_stream_options_default(this_: &mut MaybeUninit<zc_stream_options_t>) {
+    this_.write(zc_stream_options_t::default());
+}
+
+#[derive(Default)]
+struct StreamBuffers {
+    /// The sequence number of the next sent message.
+    next_sn: u64,
+    /// The number of sent messages read by the peer.
+    acked: u64,
+    /// The sequence number of the next received message.
+    expected_sn: u64,
+    /// The delivered messages not yet fully read, `offset` bytes of the first one being already read.
+    received: VecDeque<Vec<u8>>,
+    offset: usize,
+    /// The number of received messages fully read.
+    consumed: u64,
+    peer_closed: bool,
+    /// Set once a message was lost, received beyond the window, or not read by the peer before the write timeout.
+    failed: bool,
+}
+
+/// The state shared by a stream and the callbacks of its subscribers.
+struct StreamState {
+    buffers: Mutex<StreamBuffers>,
+    cond: Condvar,
+    window: u64,
+    chunk_size: usize,
+    write_timeout: Option<Duration>,
+    drops: Arc<DropCounters>,
+    _slots: Vec<EntitySlot>,
+}
+
+fn decode_u64(bytes: &ZBytes) -> Option<u64> {
+    let bytes = bytes.to_bytes();
+    Some(u64::from_le_bytes(bytes.as_ref().try_into().ok()?))
+}
+
+impl StreamState {
+    fn on_data(&self, sample: Sample) {
+        let Some(sn) = sample.attachment().and_then(decode_u64) else {
+            tracing::error!(
+                "Received a stream message without sequence number on {}",
+                sample.key_expr()
+            );
+            self.drops.record(DropReason::DecodeFailure);
+            return;
+        };
+        let Ok(mut guard) = self.buffers.lock() else {
+            return;
+        };
+        let buffers = &mut *guard;
+        if sn < buffers.expected_sn || buffers.peer_closed || buffers.failed {
+            return;
+        }
+        // the peer never sends more than `window` messages ahead of the ones read, plus the one closing the stream
+        if sn > buffers.consumed + self.window {
+            tracing::error!(
+                "Received a stream message beyond the window on {}, the stream is failed",
+                sample.key_expr()
+            );
+            buffers.failed = true;
+            self.cond.notify_all();
+            return;
+        }
+        // the messages are published reliably, and are delivered in order, so a gap means that some were lost
+        if sn > buffers.expected_sn {
+            tracing::error!(
+                "Lost {} stream messages on {}, the stream is failed",
+                sn - buffers.expected_sn,
+                sample.key_expr()
+            );
+            buffers.failed = true;
+            self.cond.notify_all();
+            return;
+        }
+        buffers.expected_sn += 1;
+        let data = sample.payload().to_bytes().into_owned();
+        if data.is_empty() {
+            buffers.peer_closed = true;
+        } else {
+            buffers.received.push_back(data);
+        }
+        self.cond.notify_all();
+    }
+
+    fn on_ack(&self, sample: Sample) {
+        let Some(acked) = decode_u64(sample.payload()) else {
+            tracing::error!(
+                "Received an invalid stream acknowledgement on {}",
+                sample.key_expr()
+            );
+            self.drops.record(DropReason::DecodeFailure);
+            return;
+        };
+        if let Ok(mut buffers) = self.buffers.lock() {
+            buffers.acked = buffers.acked.max(acked);
+            self.cond.notify_all();
+        }
+    }
+}
+
+/// One end of a stream, sending its data on the `tx` key expression and receiving the peer data on the `rx` one.
+/// The number of messages read by each end is acknowledged on the `ack` chunk appended to the key expression of the data.
+pub struct Stream {
+    state: Arc<StreamState>,
+    data_publisher: Publisher<'static>,
+    ack_publisher: Publisher<'static>,
+    _data_subscriber: Subscriber<()>,
+    _ack_subscriber: Subscriber<()>,
+}
+
+decl_c_type!(
+    owned(zc_owned_stream_t, option Stream),
+    loaned(zc_loaned_stream_t),
+);
+
+impl Stream {
+    fn new(
+        session: &CSession,
+        tx: KeyExpr<'static>,
+        rx: KeyExpr<'static>,
+        options: &zc_stream_options_t,
+        slots: Vec<EntitySlot>,
+    ) -> zenoh::Result<Self> {
+        let state = Arc::new(StreamState {
+            buffers: Mutex::new(StreamBuffers::default()),
+            cond: Condvar::new(),
+            window: options.window.max(1) as u64,
+            chunk_size: options.chunk_size.max(1),
+            write_timeout: (options.write_timeout_ms != 0)
+                .then(|| Duration::from_millis(options.write_timeout_ms)),
+            drops: session.state().drops.clone(),
+            _slots: slots,
+        });
+        let data_publisher = session
+            .declare_publisher(tx.clone())
+            .congestion_control(CongestionControl::Block)
+            .reliability(Reliability::Reliable)
+            .wait()?;
+        let ack_publisher = session
+            .declare_publisher(rx.join("ack")?)
+            .congestion_control(CongestionControl::Block)
+            .reliability(Reliability::Reliable)
+            .wait()?;
+        let data_state = state.clone();
+        let data_subscriber = session
+            .declare_subscriber(rx)
+            .callback(move |sample| data_state.on_data(sample))
+            .wait()?;
+        let ack_state = state.clone();
+        let ack_subscriber = session
+            .declare_subscriber(tx.join("ack")?)
+            .callback(move |sample| ack_state.on_ack(sample))
+            .wait()?;
+        Ok(Stream {
+            state,
+            data_publisher,
+            ack_publisher,
+            _data_subscriber: data_subscriber,
+            _ack_subscriber: ack_subscriber,
+        })
+    }
+
+    fn send(&self, sn: u64, data: Vec<u8>) -> zenoh::Result<()> {
+        self.data_publisher
+            .put(data)
+            .attachment(sn.to_le_bytes().to_vec())
+            .wait()
+    }
+
+    fn write(&self, data: &[u8]) -> result::z_result_t {
+        for chunk in data.chunks(self.state.chunk_size) {
+            let Ok(guard) = self.state.buffers.lock() else {
+                return result::Z_EPOISON_MUTEX;
+            };
+            let window_full = |b: &mut StreamBuffers| {
+                !b.peer_closed && !b.failed && b.next_sn - b.acked >= self.state.window
+            };
+            let buffers = match self.state.write_timeout {
+                None => self.state.cond.wait_while(guard, window_full),
+                Some(timeout) => self
+                    .state
+                    .cond
+                    .wait_timeout_while(guard, timeout, window_full)
+                    .map(|(buffers, _)| buffers),
+            };
+            let Ok(mut buffers) = buffers else {
+                return result::Z_EPOISON_MUTEX;
+            };
+            if window_full(&mut buffers) {
+                tracing::error!("The stream peer did not read the data before the write timeout, the stream is failed");
+                buffers.failed = true;
+                self.state.cond.notify_all();
+            }
+            if buffers.failed {
+                return result::Z_EIO;
+            }
+            if buffers.peer_closed {
+                return result::Z_CHANNEL_DISCONNECTED;
+            }
+            let sn = buffers.next_sn;
+            buffers.next_sn += 1;
+            drop(buffers);
+            if let Err(e) = self.send(sn, chunk.to_vec()) {
+                tracing::error!("Failed to write on the stream: {}", e);
+                return result::Z_EGENERIC;
+            }
+        }
+        result::Z_OK
+    }
+
+    fn read(&self, buf: &mut [u8]) -> Result<usize, result::z_result_t> {
+        let guard = self
+            .state
+            .buffers
+            .lock()
+            .map_err(|_| result::Z_EPOISON_MUTEX)?;
+        let mut guard = self
+            .state
+            .cond
+            .wait_while(guard, |b| {
+                b.received.is_empty() && !b.peer_closed && !b.failed
+            })
+            .map_err(|_| result::Z_EPOISON_MUTEX)?;
+        let buffers = &mut *guard;
+        if buffers.failed {
+            return Err(result::Z_EIO);
+        }
+        if buffers.received.is_empty() {
+            return Err(result::Z_CHANNEL_DISCONNECTED);
+        }
+        let mut read = 0;
+        let consumed = buffers.consumed;
+        while read < buf.len() {
+            let Some(message) = buffers.received.front() else {
+                break;
+            };
+            let len = (message.len() - buffers.offset).min(buf.len() - read);
+            buf[read..read + len].copy_from_slice(&message[buffers.offset..buffers.offset + len]);
+            read += len;
+            buffers.offset += len;
+            if buffers.offset == message.len() {
+                buffers.received.pop_front();
+                buffers.offset = 0;
+                buffers.consumed += 1;
+            }
+        }
+        let acked = (buffers.consumed != consumed).then_some(buffers.consumed);
+        drop(guard);
+        if let Some(acked) = acked {
+            if let Err(e) = self.ack_publisher.put(acked.to_le_bytes().to_vec()).wait() {
+                tracing::error!("Failed to acknowledge the stream data: {}", e);
+            }
+        }
+        Ok(read)
+    }
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        let Ok(mut buffers) = self.state.buffers.lock() else {
+            return;
+        };
+        let sn = buffers.next_sn;
+        buffers.next_sn += 1;
+        drop(buffers);
+        // an empty message notifies the peer that the stream is closed
+        if let Err(e) = self.send(sn, Vec::new()) {
+            tracing::error!("Failed to close the stream: {}", e);
+        }
+    }
+}
+
+/// Returns ``true`` if the stream id is a single chunk without wildcards, so that it can't address other streams.
+fn is_valid_stream_id(id: &str) -> bool {
+    !id.is_empty() && !id.contains(['/', '*', '$', '?', '#'])
+}
+
+/// Returns the key expressions of the data sent by the opening and the accepting ends of a stream.
+fn stream_keys(
+    key_expr: &KeyExpr<'static>,
+    id: &str,
+) -> zenoh::Result<(KeyExpr<'static>, KeyExpr<'static>)> {
+    let prefix = key_expr.join(&format!("_stream/{id}"))?;
+    Ok((prefix.join("c2s")?, prefix.join("s2c")?))
+}
+
+/// A stream opening query, with the time it was queued at.
+struct PendingStream {
+    query: Query,
+    queued: Instant,
+}
+
+impl PendingStream {
+    /// Returns ``true`` if the opening end stopped waiting for the stream to be accepted.
+    fn is_stale(&self) -> bool {
+        self.query
+            .parameters()
+            .get(STREAM_TIMEOUT_PARAMETER)
+            .and_then(|timeout| timeout.parse().ok())
+            .is_some_and(|timeout| self.queued.elapsed() >= Duration::from_millis(timeout))
+    }
+}
+
+/// The state shared by a stream listener and the callback of its queryable.
+struct StreamListenerState {
+    queries: Mutex<VecDeque<PendingStream>>,
+    cond: Condvar,
+    window: usize,
+    chunk_size: usize,
+    write_timeout_ms: u64,
+    _slot: EntitySlot,
+}
+
+/// Queues the stream opening queries until they are accepted.
+pub struct StreamListener {
+    _queryable: Queryable<()>,
+    state: Arc<StreamListenerState>,
+    session: CSession,
+}
+
+decl_c_type!(
+    owned(zc_owned_stream_listener_t, option StreamListener),
+    loaned(zc_loaned_stream_listener_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Opens a stream to the stream listener declared on the key expression.
+///
+/// A stream provides an ordered and flow-controlled byte stream between two applications, on top of publications and queries.
+/// The written data is split into sequence-numbered messages, and each end acknowledges the messages it has read, so that
+/// no more than `window` messages are ever waiting to be read by the peer.
+///
+/// The messages are published reliably and never retransmitted by the stream itself: a lost message, a message received
+/// beyond the window, or data not read by the peer within `write_timeout_ms` fails the stream, in which case
+/// `zc_stream_read()` and `zc_stream_write()` return `Z_EIO`. Streams should not be used over lossy links.
+///
+/// @param session: The zenoh session.
+/// @param stream: An uninitialized memory location where the stream will be constructed.
+/// @param key_expr: The key expression the stream listener is declared on.
+/// @param options: The stream options, default options are used if NULL.
+///
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if the stream was not accepted before the timeout, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_stream_open(
+    session: &z_loaned_session_t,
+    stream: &mut MaybeUninit<zc_owned_stream_t>,
+    key_expr: &z_loaned_keyexpr_t,
+    options: Option<&zc_stream_options_t>,
+) -> result::z_result_t {
+    let stream = stream.as_rust_type_mut_uninit();
+    let session = session.as_rust_type_ref();
+    let key_expr = key_expr.as_rust_type_ref();
+    let default_options = zc_stream_options_t::default();
+    let options = options.unwrap_or(&default_options);
+    let (intercepted, slots) =
+        match keyexpr_interceptor::admit_declarations(session, key_expr, &STREAM_ENTITIES) {
+            Ok(admitted) => admitted,
+            Err(e) => {
+                stream.write(None);
+                return e;
+            }
+        };
+    let key_expr = intercepted.as_ref().unwrap_or(key_expr);
+    let id = format!(
+        "{}_{}",
+        session.zid(),
+        NEXT_STREAM_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    let s = match stream_keys(key_expr, &id)
+        .and_then(|(c2s, s2c)| Stream::new(session, c2s, s2c, options, slots))
+    {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!("Failed to open a stream on {}: {}", key_expr, e);
+            stream.write(None);
+            return result::Z_EGENERIC;
+        }
+    };
+    let parameters = format!(
+        "{STREAM_ID_PARAMETER}={id};{STREAM_TIMEOUT_PARAMETER}={}",
+        options.open_timeout_ms
+    );
+    let replies = match session
+        .get(Selector::from((key_expr.clone(), parameters.as_str())))
+        .consolidation(ConsolidationMode::None)
+        .timeout(Duration::from_millis(options.open_timeout_ms))
+        .wait()
+    {
+        Ok(replies) => replies,
+        Err(e) => {
+            tracing::error!("Failed to open a stream on {}: {}", key_expr, e);
+            stream.write(None);
+            return result::Z_EGENERIC;
+        }
+    };
+    match replies.recv() {
+        Ok(reply) if reply.result().is_ok() => {
+            stream.write(Some(s));
+            result::Z_OK
+        }
+        _ => {
+            tracing::error!("The stream on {} was not accepted", key_expr);
+            stream.write(None);
+            result::Z_EUNAVAILABLE
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Writes data on the stream.
+///
+/// Blocks until all the data is sent, i.e. until the peer has read enough data for the remaining one to fit in the window.
+/// If the peer does not read the data within the `write_timeout_ms` of the stream options, e.g. because it crashed, the
+/// stream is failed.
+///
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if the peer closed the stream, `Z_EIO` if the stream failed,
+/// negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_stream_write(
+    this_: &zc_loaned_stream_t,
+    data: *const u8,
+    len: usize,
+) -> result::z_result_t {
+    if len == 0 {
+        return result::Z_OK;
+    }
+    if data.is_null() {
+        return result::Z_EINVAL;
+    }
+    this_
+        .as_rust_type_ref()
+        .write(std::slice::from_raw_parts(data, len))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Reads data from the stream.
+///
+/// Blocks until some data is available, then reads up to `len` bytes of it, in the order it was written by the peer.
+///
+/// @param this_: The stream.
+/// @param buf: The buffer to read into.
+/// @param len: The size of the buffer.
+/// @param read: Will be set to the number of bytes read.
+///
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if the peer closed the stream and all its data was read,
+/// `Z_EIO` if the stream failed, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_stream_read(
+    this_: &zc_loaned_stream_t,
+    buf: *mut u8,
+    len: usize,
+    read: &mut usize,
+) -> result::z_result_t {
+    *read = 0;
+    if buf.is_null() || len == 0 {
+        return result::Z_EINVAL;
+    }
+    match this_
+        .as_rust_type_ref()
+        .read(std::slice::from_raw_parts_mut(buf, len))
+    {
+        Ok(n) => {
+            *read = n;
+            result::Z_OK
+        }
+        Err(e) => e,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows the stream.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_stream_loan(this_: &zc_owned_stream_t) -> &zc_loaned_stream_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a stream in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_stream_null(this_: &mut MaybeUninit<zc_owned_stream_t>) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the stream is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_stream_check(this_: &zc_owned_stream_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Closes the stream and resets it to its gravestone state.
+///
+/// The peer reads the data written before the stream was closed, then gets `Z_CHANNEL_DISCONNECTED`.
+#[no_mangle]
+pub extern "C" fn zc_stream_drop(this_: Option<&mut zc_moved_stream_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_stream_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Declares a stream listener, accepting the streams opened with `zc_stream_open()` on the key expression.
+///
+/// @param session: The zenoh session.
+/// @param listener: An uninitialized memory location where the listener will be constructed.
+/// @param key_expr: The key expression to accept the streams on.
+/// @param options: The options of the accepted streams, default options are used if NULL.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_declare_stream_listener(
+    session: &z_loaned_session_t,
+    listener: &mut MaybeUninit<zc_owned_stream_listener_t>,
+    key_expr: &z_loaned_keyexpr_t,
+    options: Option<&zc_stream_options_t>,
+) -> result::z_result_t {
+    let listener = listener.as_rust_type_mut_uninit();
+    let session = session.as_rust_type_ref();
+    let key_expr = key_expr.as_rust_type_ref();
+    let default_options = zc_stream_options_t::default();
+    let options = options.unwrap_or(&default_options);
+    let (intercepted, slot) =
+        match keyexpr_interceptor::admit_declaration(session, key_expr, EntityKind::Queryable) {
+            Ok(admitted) => admitted,
+            Err(e) => {
+                listener.write(None);
+                return e;
+            }
+        };
+    let key_expr = intercepted.as_ref().unwrap_or(key_expr);
+    let state = Arc::new(StreamListenerState {
+        queries: Mutex::new(VecDeque::new()),
+        cond: Condvar::new(),
+        window: options.window,
+        chunk_size: options.chunk_size,
+        write_timeout_ms: options.write_timeout_ms,
+        _slot: slot,
+    });
+    let queryable_state = state.clone();
+    let res = session
+        .declare_queryable(key_expr.clone())
+        .callback(move |query| {
+            let Some(id) = query.parameters().get(STREAM_ID_PARAMETER) else {
+                return;
+            };
+            if !is_valid_stream_id(id) {
+                tracing::error!(
+                    "Rejected a stream with an invalid id on {}",
+                    query.key_expr()
+                );
+                let _ = query.reply_err(ZBytes::from("Invalid stream id")).wait();
+                return;
+            }
+            if let Ok(mut queries) = queryable_state.queries.lock() {
+                queries.push_back(PendingStream {
+                    query,
+                    queued: Instant::now(),
+                });
+                queryable_state.cond.notify_one();
+            }
+        })
+        .wait();
+    match res {
+        Ok(queryable) => {
+            listener.write(Some(StreamListener {
+                _queryable: queryable,
+                state,
+                session: session.clone(),
+            }));
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("Failed to declare a stream listener on {}: {}", key_expr, e);
+            listener.write(None);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Accepts a stream opened on the key expression of the listener.
+///
+/// Blocks until a stream is opened or the timeout expires, the streams opened before being accepted in order.
+/// The streams whose opening end stopped waiting for them to be accepted are dropped.
+///
+/// @param listener: The stream listener.
+/// @param stream: An uninitialized memory location where the accepted stream will be constructed.
+/// @param timeout_ms: The maximum time to wait for a stream to be opened in milliseconds, 0 means no timeout.
+///
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if no stream was opened before the timeout, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_stream_accept(
+    listener: &zc_loaned_stream_listener_t,
+    stream: &mut MaybeUninit<zc_owned_stream_t>,
+    timeout_ms: u64,
+) -> result::z_result_t {
+    let listener = listener.as_rust_type_ref();
+    let stream = stream.as_rust_type_mut_uninit();
+    stream.write(None);
+    let state = &listener.state;
+    let Ok(guard) = state.queries.lock() else {
+        return result::Z_EPOISON_MUTEX;
+    };
+    let no_fresh_query = |q: &mut VecDeque<PendingStream>| {
+        q.retain(|pending| !pending.is_stale());
+        q.is_empty()
+    };
+    let queries = match timeout_ms {
+        0 => state.cond.wait_while(guard, no_fresh_query),
+        timeout_ms => state
+            .cond
+            .wait_timeout_while(guard, Duration::from_millis(timeout_ms), no_fresh_query)
+            .map(|(queries, _)| queries),
+    };
+    let Ok(mut queries) = queries else {
+        return result::Z_EPOISON_MUTEX;
+    };
+    let Some(PendingStream { query, .. }) = queries.pop_front() else {
+        return result::Z_EUNAVAILABLE;
+    };
+    drop(queries);
+    // the key expressions of the stream are derived from the one of the opening end, so they can't be rewritten
+    let slots = match keyexpr_interceptor::admit_declarations(
+        &listener.session,
+        query.key_expr(),
+        &STREAM_ENTITIES,
+    ) {
+        Ok((_, slots)) => slots,
+        Err(e) => {
+            let _ = query.reply_err(ZBytes::from("Stream rejected")).wait();
+            return e;
+        }
+    };
+    let id = query
+        .parameters()
+        .get(STREAM_ID_PARAMETER)
+        .unwrap_or_default();
+    let options = zc_stream_options_t {
+        window: state.window,
+        chunk_size: state.chunk_size,
+        write_timeout_ms: state.write_timeout_ms,
+        ..Default::default()
+    };
+    let res = stream_keys(query.key_expr(), id)
+        .and_then(|(c2s, s2c)| Stream::new(&listener.session, s2c, c2s, &options, slots))
+        .and_then(|s| {
+            query
+                .reply(query.key_expr().clone(), ZBytes::default())
+                .wait()?;
+            Ok(s)
+        });
+    match res {
+        Ok(s) => {
+            stream.write(Some(s));
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("Failed to accept a stream on {}: {}", query.key_expr(), e);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows the stream listener.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn zc_stream_listener_loan(
+    this_: &zc_owned_stream_listener_t,
+) -> &zc_loaned_stream_listener_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a stream listener in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_stream_listener_null(
+    this_: &mut MaybeUninit<zc_owned_stream_listener_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the stream listener is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_stream_listener_check(this_: &zc_owned_stream_listener_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares the stream listener and resets it to its gravestone state.
+///
+/// The streams opened and not yet accepted are rejected, the accepted ones are unaffected.
+#[no_mangle]
+pub extern "C" fn zc_stream_listener_drop(this_: Option<&mut zc_moved_stream_listener_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_stream_listener_drop") else {
+        return;
+    };
+    let _ = this_.take_rust_type();
+}
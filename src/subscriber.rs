@@ -13,7 +13,14 @@
 //
 
 use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
 
+#[cfg(feature = "unstable")]
+use zenoh::key_expr::KeyExpr;
 use zenoh::{
     handlers::Callback,
     pubsub::{Subscriber, SubscriberBuilder},
@@ -22,17 +29,52 @@ use zenoh::{
 };
 
 pub use crate::opaque_types::{z_loaned_subscriber_t, z_moved_subscriber_t, z_owned_subscriber_t};
+#[cfg(feature = "unstable")]
+use crate::{
+    deferred_callbacks,
+    drop_counters::{catch_callback_panic, DropCounters, DropReason},
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor,
+    publisher::_transform_payload,
+    transmute::IntoCType,
+    z_entity_global_id_t, z_loaned_sample_t, z_moved_string_array_t, zc_closure_key_stats_call,
+    zc_closure_key_stats_loan, zc_locality_default, zc_locality_t, zc_moved_closure_key_stats_t,
+    zc_moved_closure_payload_transform_t, ZVector,
+};
 use crate::{
     keyexpr::*,
     result,
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_sample_call, z_closure_sample_loan, z_loaned_session_t, z_moved_closure_sample_t,
+    z_owned_closure_sample_t, Stateful,
 };
-#[cfg(feature = "unstable")]
-use crate::{transmute::IntoCType, z_entity_global_id_t, zc_locality_default, zc_locality_t};
 
+/// The state kept by zenoh-c for a subscriber.
+pub(crate) struct SubscriberState {
+    #[cfg(feature = "unstable")]
+    pull: Option<Arc<PullBuffer>>,
+    #[cfg(feature = "unstable")]
+    key_stats: Option<Arc<KeyStats>>,
+    #[cfg(feature = "unstable")]
+    allowed_origin: zc_locality_t,
+}
+
+impl Default for SubscriberState {
+    fn default() -> Self {
+        SubscriberState {
+            #[cfg(feature = "unstable")]
+            pull: None,
+            #[cfg(feature = "unstable")]
+            key_stats: None,
+            #[cfg(feature = "unstable")]
+            allowed_origin: zc_locality_default(),
+        }
+    }
+}
+
+pub(crate) type CSubscriber = Stateful<Subscriber<()>, SubscriberState>;
 decl_c_type!(
-    owned(z_owned_subscriber_t, option Subscriber<()>),
+    owned(z_owned_subscriber_t, option CSubscriber),
     loaned(z_loaned_subscriber_t),
 );
 
@@ -64,6 +106,23 @@ pub struct z_subscriber_options_t {
     /// Restricts the matching publications that will be received by this Subscribers to the ones
     /// that have the compatible allowed_destination.
     pub allowed_origin: zc_locality_t,
+    #[cfg(feature = "unstable")]
+    /// An optional array of key expressions to exclude from the subscription. Samples whose key expression
+    /// intersects with any of them are discarded before reaching the callback.
+    pub excluded_key_exprs: Option<&'static mut z_moved_string_array_t>,
+    #[cfg(feature = "unstable")]
+    /// An optional closure applied to the payload of each received sample before it reaches the callback
+    /// (e.g. to decompress or decrypt it), usually reverting the `payload_transform` of the matching publishers.
+    pub payload_transform: Option<&'static mut zc_moved_closure_payload_transform_t>,
+    #[cfg(feature = "unstable")]
+    /// The maximum number of samples buffered by the subscriber in pull mode, 0 means push mode (the default).
+    /// In pull mode the received samples are buffered (dropping the oldest ones when the buffer is full) instead of being passed to the callback,
+    /// until `z_subscriber_pull()` is called. Only applies to subscribers declared with `z_declare_subscriber()`.
+    pub pull_capacity: usize,
+    #[cfg(feature = "unstable")]
+    /// If set to ``true``, the subscriber counts the samples received on each concrete key expression, which can then be
+    /// retrieved with `zc_subscriber_key_stats()`. Only applies to subscribers declared with `z_declare_subscriber()`.
+    pub key_stats: bool,
 }
 
 impl Default for z_subscriber_options_t {
@@ -73,6 +132,27 @@ impl Default for z_subscriber_options_t {
             _0: Default::default(),
             #[cfg(feature = "unstable")]
             allowed_origin: zc_locality_default(),
+            #[cfg(feature = "unstable")]
+            excluded_key_exprs: None,
+            #[cfg(feature = "unstable")]
+            payload_transform: None,
+            #[cfg(feature = "unstable")]
+            pull_capacity: 0,
+            #[cfg(feature = "unstable")]
+            key_stats: false,
+        }
+    }
+}
+
+/// Drops the moved fields of the subscriber options, when the declaration fails before they are taken.
+#[cfg(feature = "unstable")]
+pub(crate) fn _release_subscriber_options(options: Option<&mut z_subscriber_options_t>) {
+    if let Some(options) = options {
+        if let Some(key_exprs) = options.excluded_key_exprs.take() {
+            let _ = key_exprs.take_rust_type();
+        }
+        if let Some(transform) = options.payload_transform.take() {
+            let _ = transform.take_rust_type();
         }
     }
 }
@@ -83,32 +163,137 @@ pub extern "C" fn z_subscriber_options_default(this_: &mut MaybeUninit<z_subscri
     this_.write(z_subscriber_options_t::default());
 }
 
+#[cfg(feature = "unstable")]
+fn _excluded_key_exprs(key_exprs: ZVector) -> Result<Vec<KeyExpr<'static>>, result::z_result_t> {
+    key_exprs
+        .iter()
+        .map(|s| {
+            let s = std::str::from_utf8(s.slice()).map_err(|e| {
+                tracing::error!("Excluded key expression is not a valid UTF-8 string: {}", e);
+                result::Z_EUTF8
+            })?;
+            KeyExpr::try_from(s.to_owned()).map_err(|e| {
+                tracing::error!("Invalid excluded key expression {}: {}", s, e);
+                result::Z_EINVAL
+            })
+        })
+        .collect()
+}
+
 #[allow(unused_variables, unused_mut)]
 pub(crate) fn _declare_subscriber_inner<'a, 'b>(
     session: &'a z_loaned_session_t,
     key_expr: &'b z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
-    options: Option<&mut z_subscriber_options_t>,
-) -> SubscriberBuilder<'a, 'b, Callback<Sample>> {
+    callback: z_owned_closure_sample_t,
+    mut options: Option<&mut z_subscriber_options_t>,
+) -> Result<SubscriberBuilder<'a, 'b, Callback<Sample>>, result::z_result_t> {
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
-    let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let payload_transform = options
+        .as_mut()
+        .and_then(|o| o.payload_transform.take())
+        .map(|t| t.take_rust_type());
+    #[cfg(feature = "unstable")]
+    let excluded_key_exprs = match options.as_mut().and_then(|o| o.excluded_key_exprs.take()) {
+        Some(key_exprs) => _excluded_key_exprs(key_exprs.take_rust_type())?,
+        None => Vec::new(),
+    };
+    #[cfg(feature = "unstable")]
+    let drops = session.state().drops.clone();
+    let deliver = move |mut sample: Sample| {
+        #[cfg(feature = "unstable")]
+        if excluded_key_exprs
+            .iter()
+            .any(|ke| ke.intersects(sample.key_expr()))
+        {
+            return;
+        }
+        #[cfg(feature = "unstable")]
+        if let Some(transform) = &payload_transform {
+            let payload = std::mem::take(sample.payload_mut());
+            *sample.payload_mut() = _transform_payload(transform, payload);
+        }
+        let mut owned_sample = Some(sample);
+        z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
+            owned_sample
+                .as_mut()
+                .unwrap_unchecked()
+                .as_loaned_c_type_mut()
+        })
+    };
     let mut subscriber = session
         .declare_subscriber(key_expr)
         .callback(move |sample| {
-            let mut owned_sample = Some(sample);
-            z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
-                owned_sample
-                    .as_mut()
-                    .unwrap_unchecked()
-                    .as_loaned_c_type_mut()
-            })
+            #[cfg(feature = "unstable")]
+            catch_callback_panic(&drops, || deliver(sample));
+            #[cfg(not(feature = "unstable"))]
+            deliver(sample);
         });
     #[cfg(feature = "unstable")]
     if let Some(options) = options {
         subscriber = subscriber.allowed_origin(options.allowed_origin.into());
     }
-    subscriber
+    Ok(subscriber)
+}
+
+/// Samples buffered by a pull mode subscriber until they are passed to its callback by `z_subscriber_pull()`.
+#[cfg(feature = "unstable")]
+struct PullBuffer {
+    samples: Mutex<VecDeque<Sample>>,
+    capacity: usize,
+    callback: z_owned_closure_sample_t,
+    drops: Arc<DropCounters>,
+}
+
+#[cfg(feature = "unstable")]
+impl PullBuffer {
+    fn push(&self, sample: Sample) {
+        if let Ok(mut samples) = self.samples.lock() {
+            if samples.len() == self.capacity {
+                samples.pop_front();
+                self.drops.record(DropReason::FullBuffer);
+            }
+            samples.push_back(sample);
+        }
+    }
+
+    fn pull(&self) -> usize {
+        let samples = match self.samples.lock() {
+            Ok(mut samples) => std::mem::take(&mut *samples),
+            Err(_) => return 0,
+        };
+        let count = samples.len();
+        for mut sample in samples {
+            z_closure_sample_call(
+                z_closure_sample_loan(&self.callback),
+                sample.as_loaned_c_type_mut(),
+            );
+        }
+        count
+    }
+}
+
+/// Returns the callback buffering the samples for the pull mode subscribers, and the corresponding buffer.
+#[cfg(feature = "unstable")]
+fn _pull_mode_callback(
+    drops: Arc<DropCounters>,
+    callback: z_owned_closure_sample_t,
+    capacity: usize,
+) -> (z_owned_closure_sample_t, Option<Arc<PullBuffer>>) {
+    if capacity == 0 {
+        return (callback, None);
+    }
+    let buffer = Arc::new(PullBuffer {
+        samples: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity,
+        callback,
+        drops,
+    });
+    let b = buffer.clone();
+    let callback =
+        (move |sample: &mut z_loaned_sample_t| b.push(sample.as_rust_type_ref().clone())).into();
+    (callback, Some(buffer))
 }
 
 /// Constructs and declares a subscriber for a given key expression. Dropping subscriber undeclares its callback.
@@ -119,20 +304,75 @@ pub(crate) fn _declare_subscriber_inner<'a, 'b>(
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: The options to be passed to the subscriber declaration.
 ///
-/// @return 0 in case of success, negative error code otherwise (in this case subscriber will be in its gravestone state).
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise (in this case subscriber will be in its gravestone state).
 #[no_mangle]
 pub extern "C" fn z_declare_subscriber(
     session: &z_loaned_session_t,
     subscriber: &mut MaybeUninit<z_owned_subscriber_t>,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut z_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = subscriber.as_rust_type_mut_uninit();
-    let s = _declare_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "z_declare_subscriber") else {
+        #[cfg(feature = "unstable")]
+        _release_subscriber_options(options);
+        this.write(None);
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Subscriber,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            _release_subscriber_options(options);
+            this.write(None);
+            return e;
+        }
+    };
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    #[cfg(feature = "unstable")]
+    let callback = entity_limits::hold_in_sample_callback(slot, callback);
+    #[cfg(feature = "unstable")]
+    let callback = deferred_callbacks::defer_sample_callback(session.as_rust_type_ref(), callback);
+    #[cfg(feature = "unstable")]
+    let (callback, pull) = _pull_mode_callback(
+        session.as_rust_type_ref().state().drops.clone(),
+        callback,
+        options.as_ref().map(|o| o.pull_capacity).unwrap_or(0),
+    );
+    #[cfg(feature = "unstable")]
+    let (callback, key_stats) =
+        _key_stats_callback(callback, options.as_ref().is_some_and(|o| o.key_stats));
+    #[cfg(feature = "unstable")]
+    let allowed_origin = options
+        .as_ref()
+        .map_or_else(zc_locality_default, |o| o.allowed_origin);
+    let s = match _declare_subscriber_inner(session, key_expr, callback, options) {
+        Ok(s) => s,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
     match s.wait() {
         Ok(sub) => {
-            this.write(Some(sub));
+            let state = SubscriberState {
+                #[cfg(feature = "unstable")]
+                pull,
+                #[cfg(feature = "unstable")]
+                key_stats,
+                #[cfg(feature = "unstable")]
+                allowed_origin,
+            };
+            this.write(Some(CSubscriber::new(sub, state)));
             result::Z_OK
         }
         Err(e) => {
@@ -151,15 +391,46 @@ pub extern "C" fn z_declare_subscriber(
 /// @param callback: The callback function that will be called each time a data matching the subscribed expression is received.
 /// @param options: The options to be passed to the subscriber declaration.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
 #[no_mangle]
 pub extern "C" fn z_declare_background_subscriber(
     session: &z_loaned_session_t,
     key_expr: &z_loaned_keyexpr_t,
-    callback: &mut z_moved_closure_sample_t,
+    callback: Option<&mut z_moved_closure_sample_t>,
     options: Option<&mut z_subscriber_options_t>,
 ) -> result::z_result_t {
-    let subscriber = _declare_subscriber_inner(session, key_expr, callback, options);
+    let Some(callback) = moved_or_null(callback, "z_declare_background_subscriber") else {
+        #[cfg(feature = "unstable")]
+        _release_subscriber_options(options);
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Subscriber,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            _release_subscriber_options(options);
+            return e;
+        }
+    };
+    #[cfg(feature = "unstable")]
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    #[cfg(feature = "unstable")]
+    let callback = entity_limits::hold_in_sample_callback(slot, callback);
+    #[cfg(feature = "unstable")]
+    let callback = deferred_callbacks::defer_sample_callback(session.as_rust_type_ref(), callback);
+    let subscriber = match _declare_subscriber_inner(session, key_expr, callback, options) {
+        Ok(s) => s,
+        Err(e) => {
+            return e;
+        }
+    };
     match subscriber.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -182,7 +453,10 @@ pub extern "C" fn z_subscriber_keyexpr(subscriber: &z_loaned_subscriber_t) -> &z
 /// Undeclares subscriber callback and resets it to its gravestone state.
 /// This is equivalent to calling `z_undeclare_subscriber()` and discarding its return value.
 #[no_mangle]
-pub extern "C" fn z_subscriber_drop(this_: &mut z_moved_subscriber_t) {
+pub extern "C" fn z_subscriber_drop(this_: Option<&mut z_moved_subscriber_t>) {
+    let Some(this_) = moved_or_null(this_, "z_subscriber_drop") else {
+        return;
+    };
     std::mem::drop(this_.take_rust_type())
 }
 
@@ -196,8 +470,15 @@ pub extern "C" fn z_internal_subscriber_check(this_: &z_owned_subscriber_t) -> b
 ///
 /// @return 0 in case of success, negative error code otherwise.
 #[no_mangle]
-pub extern "C" fn z_undeclare_subscriber(this_: &mut z_moved_subscriber_t) -> result::z_result_t {
+pub extern "C" fn z_undeclare_subscriber(
+    this_: Option<&mut z_moved_subscriber_t>,
+) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "z_undeclare_subscriber") else {
+        return result::Z_OK;
+    };
     if let Some(s) = this_.take_rust_type() {
+        // the state of the subscriber is dropped once it is undeclared
+        let (s, _state) = s.into_parts();
         if let Err(e) = s.undeclare().wait() {
             tracing::error!("{}", e);
             return result::Z_EGENERIC;
@@ -213,3 +494,95 @@ pub extern "C" fn z_undeclare_subscriber(this_: &mut z_moved_subscriber_t) -> re
 pub extern "C" fn z_subscriber_id(subscriber: &z_loaned_subscriber_t) -> z_entity_global_id_t {
     subscriber.as_rust_type_ref().id().into_c_type()
 }
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Passes the samples buffered by a pull mode subscriber to its callback, in the calling thread.
+///
+/// See `z_subscriber_options_t.pull_capacity` for declaring a pull mode subscriber.
+/// @return The number of samples passed to the callback, 0 for subscribers in push mode.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_subscriber_pull(this_: &z_loaned_subscriber_t) -> usize {
+    match &this_.as_rust_type_ref().state().pull {
+        Some(buffer) => buffer.pull(),
+        None => 0,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the locality of the publications received by the subscriber, as set by the `allowed_origin` field
+/// of `z_subscriber_options_t` when it was declared.
+///
+/// Subscribers have no reliability setting: it is chosen by the publishers, see `z_sample_reliability()`.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_subscriber_allowed_origin(this_: &z_loaned_subscriber_t) -> zc_locality_t {
+    this_.as_rust_type_ref().state().allowed_origin
+}
+
+/// The number of samples received on each concrete key expression by a subscriber.
+#[cfg(feature = "unstable")]
+type KeyStats = Mutex<HashMap<KeyExpr<'static>, u64>>;
+
+/// Returns the callback counting the samples received on each key expression, if enabled, and the corresponding statistics.
+#[cfg(feature = "unstable")]
+fn _key_stats_callback(
+    callback: z_owned_closure_sample_t,
+    enabled: bool,
+) -> (z_owned_closure_sample_t, Option<Arc<KeyStats>>) {
+    if !enabled {
+        return (callback, None);
+    }
+    let stats = Arc::new(KeyStats::default());
+    let s = stats.clone();
+    let callback = (move |sample: &mut z_loaned_sample_t| {
+        if let Ok(mut map) = s.lock() {
+            let key_expr = sample.as_rust_type_ref().key_expr();
+            match map.get_mut(key_expr) {
+                Some(count) => *count += 1,
+                None => {
+                    map.insert(key_expr.clone().into_owned(), 1);
+                }
+            }
+        }
+        z_closure_sample_call(z_closure_sample_loan(&callback), sample)
+    })
+    .into();
+    (callback, Some(stats))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Calls the closure with each concrete key expression received by the subscriber, in an unspecified order,
+/// and the number of samples received on it, then drops the closure.
+///
+/// This allows to discover the key expressions actually published under a wildcard subscription.
+/// See `z_subscriber_options_t.key_stats` for enabling the statistics.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, `Z_EUNAVAILABLE` if the statistics are not enabled for the subscriber.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn zc_subscriber_key_stats(
+    this_: &z_loaned_subscriber_t,
+    callback: Option<&mut zc_moved_closure_key_stats_t>,
+) -> result::z_result_t {
+    let Some(callback) = moved_or_null(callback, "zc_subscriber_key_stats") else {
+        return result::Z_EINVAL;
+    };
+    let callback = callback.take_rust_type();
+    let Some(stats) = &this_.as_rust_type_ref().state().key_stats else {
+        tracing::error!("Key statistics are not enabled for the subscriber");
+        return result::Z_EUNAVAILABLE;
+    };
+    // the statistics are copied so that the closure is not called with the lock held
+    let stats: Vec<(KeyExpr<'static>, u64)> = match stats.lock() {
+        Ok(map) => map.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+        Err(_) => return result::Z_EGENERIC,
+    };
+    for (key_expr, samples) in stats {
+        zc_closure_key_stats_call(
+            zc_closure_key_stats_loan(&callback),
+            key_expr.as_loaned_c_type_ref(),
+            samples,
+        );
+    }
+    result::Z_OK
+}
@@ -0,0 +1,184 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    mem::MaybeUninit,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use zenoh_util::time_range::{TimeBound, TimeExpr, TimeRange};
+
+use crate::{
+    result, transmute::RustTypeRef, z_loaned_query_t, z_owned_string_t, z_string_copy_from_substr,
+    z_timestamp_t,
+};
+
+/// The selector parameter holding the time range of a query.
+const TIME_RANGE_KEY: &str = "_time";
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The kind of a time range bound.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum zc_time_bound_kind_t {
+    /// The range is not bounded on this side.
+    UNBOUNDED = 0,
+    /// The bound is included in the range.
+    INCLUSIVE = 1,
+    /// The bound is excluded from the range.
+    EXCLUSIVE = 2,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A bound of a time range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct zc_time_bound_t {
+    /// The kind of the bound.
+    pub kind: zc_time_bound_kind_t,
+    /// The bound, in milliseconds since UNIX epoch. Ignored for unbounded bounds.
+    pub epoch_ms: u64,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A time range, as carried by the `_time` parameter of a selector to query the samples stored in that range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct zc_time_range_t {
+    /// The start of the range.
+    pub start: zc_time_bound_t,
+    /// The end of the range.
+    pub end: zc_time_bound_t,
+}
+
+const UNBOUNDED: zc_time_bound_t = zc_time_bound_t {
+    kind: zc_time_bound_kind_t::UNBOUNDED,
+    epoch_ms: 0,
+};
+
+fn epoch_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+impl From<TimeBound<SystemTime>> for zc_time_bound_t {
+    fn from(bound: TimeBound<SystemTime>) -> Self {
+        match bound {
+            TimeBound::Inclusive(t) => zc_time_bound_t {
+                kind: zc_time_bound_kind_t::INCLUSIVE,
+                epoch_ms: epoch_ms(t),
+            },
+            TimeBound::Exclusive(t) => zc_time_bound_t {
+                kind: zc_time_bound_kind_t::EXCLUSIVE,
+                epoch_ms: epoch_ms(t),
+            },
+            TimeBound::Unbounded => UNBOUNDED,
+        }
+    }
+}
+
+impl From<&zc_time_bound_t> for TimeBound<TimeExpr> {
+    fn from(bound: &zc_time_bound_t) -> Self {
+        let time = || TimeExpr::Fixed(UNIX_EPOCH + Duration::from_millis(bound.epoch_ms));
+        match bound.kind {
+            zc_time_bound_kind_t::INCLUSIVE => TimeBound::Inclusive(time()),
+            zc_time_bound_kind_t::EXCLUSIVE => TimeBound::Exclusive(time()),
+            zc_time_bound_kind_t::UNBOUNDED => TimeBound::Unbounded,
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an unbounded time range.
+#[no_mangle]
+pub extern "C" fn zc_time_range_default(this_: &mut MaybeUninit<zc_time_range_t>) {
+    this_.write(zc_time_range_t {
+        start: UNBOUNDED,
+        end: UNBOUNDED,
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the time range including the times between two timestamps.
+///
+/// @param this_: An uninitialized location in memory where the time range will be constructed.
+/// @param start: The start of the range, the range is not bounded on this side if NULL.
+/// @param end: The end of the range, the range is not bounded on this side if NULL.
+#[no_mangle]
+pub extern "C" fn zc_time_range_from_timestamps(
+    this_: &mut MaybeUninit<zc_time_range_t>,
+    start: Option<&z_timestamp_t>,
+    end: Option<&z_timestamp_t>,
+) {
+    let bound = |ts: Option<&z_timestamp_t>| match ts {
+        Some(ts) => zc_time_bound_t {
+            kind: zc_time_bound_kind_t::INCLUSIVE,
+            epoch_ms: epoch_ms(ts.as_rust_type_ref().get_time().to_system_time()),
+        },
+        None => UNBOUNDED,
+    };
+    this_.write(zc_time_range_t {
+        start: bound(start),
+        end: bound(end),
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the `_time=[start..end]` selector parameter for the time range.
+///
+/// The parameter can be passed as, or appended with a `;` separator to, the parameters of `z_get()` or `z_querier_get()`.
+#[no_mangle]
+pub extern "C" fn zc_time_range_to_parameter(
+    this_: &zc_time_range_t,
+    dst: &mut MaybeUninit<z_owned_string_t>,
+) {
+    let range = TimeRange {
+        start: (&this_.start).into(),
+        end: (&this_.end).into(),
+    };
+    let parameter = format!("{TIME_RANGE_KEY}={range}");
+    unsafe { z_string_copy_from_substr(dst, parameter.as_ptr() as _, parameter.len()) };
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the time range of a query from the `_time` parameter of its selector.
+///
+/// Time expressions relative to the current time, such as `now(-1h)`, are resolved when calling this function.
+///
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if the query has no `_time` parameter, `Z_EPARSE` if it is malformed
+/// (`dst` is not updated in both cases).
+#[no_mangle]
+pub extern "C" fn zc_query_time_range(
+    this_: &z_loaned_query_t,
+    dst: &mut MaybeUninit<zc_time_range_t>,
+) -> result::z_result_t {
+    let query = this_.as_rust_type_ref();
+    let Some(value) = query.parameters().get(TIME_RANGE_KEY) else {
+        return result::Z_EUNAVAILABLE;
+    };
+    match value.parse::<TimeRange<TimeExpr>>() {
+        Ok(range) => {
+            let range = range.resolve_at(SystemTime::now());
+            dst.write(zc_time_range_t {
+                start: range.start.into(),
+                end: range.end.into(),
+            });
+            result::Z_OK
+        }
+        Err(e) => {
+            tracing::error!("Invalid time range '{}': {}", value, e);
+            result::Z_EPARSE
+        }
+    }
+}
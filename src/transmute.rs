@@ -81,6 +81,16 @@ where
     }
 }
 
+/// Unwraps the moved object passed to a destructor-like function (`*_drop()`, `*_undeclare*()`...),
+/// which are a no-op when called with NULL. This is most likely a mistake on the caller's side,
+/// so it is reported in debug builds.
+pub(crate) fn moved_or_null<'a, T>(this: Option<&'a mut T>, function: &str) -> Option<&'a mut T> {
+    if this.is_none() && cfg!(debug_assertions) {
+        tracing::debug!("{function} called with NULL, ignoring");
+    }
+    this
+}
+
 macro_rules! validate_equivalence {
     ($type_a:ty, $type_b:ty) => {
         const _: () = {
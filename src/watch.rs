@@ -0,0 +1,265 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    mem::MaybeUninit,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use zenoh::{pubsub::Subscriber, query::Reply, sample::Sample, Wait};
+
+pub use crate::opaque_types::{zc_moved_watch_t, zc_owned_watch_t};
+use crate::{
+    _declare_subscriber_inner, deferred_callbacks,
+    entity_limits::{self, EntityKind},
+    keyexpr_interceptor, result,
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_closure_reply_call, z_closure_reply_loan, z_loaned_keyexpr_t, z_loaned_reply_t,
+    z_loaned_sample_t, z_loaned_session_t, z_owned_closure_reply_t, z_owned_closure_sample_t,
+    zc_closure_watch_call, zc_closure_watch_loan, zc_moved_closure_watch_t,
+    zc_owned_closure_watch_t,
+};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to the `zc_declare_watch()` function.
+#[repr(C)]
+pub struct zc_watch_options_t {
+    /// The timeout for the initial query in milliseconds. 0 means default query timeout from zenoh configuration.
+    pub query_timeout_ms: u64,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `zc_watch_options_t`.
+#[no_mangle]
+pub extern "C" fn zc_watch_options_default(this_: &mut MaybeUninit<zc_watch_options_t>) {
+    this_.write(zc_watch_options_t {
+        query_timeout_ms: 0,
+    });
+}
+
+struct WatchState {
+    callback: zc_owned_closure_watch_t,
+    /// The live samples received while the initial query is in progress, `None` once it completed.
+    pending: Mutex<Option<Vec<Sample>>>,
+    undeclared: AtomicBool,
+}
+
+impl WatchState {
+    fn deliver(&self, mut sample: Sample, is_initial: bool) {
+        if self.undeclared.load(Ordering::Acquire) {
+            return;
+        }
+        zc_closure_watch_call(
+            zc_closure_watch_loan(&self.callback),
+            sample.as_loaned_c_type_mut(),
+            is_initial,
+        );
+    }
+
+    fn on_update(&self, sample: Sample) {
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+        match pending.as_mut() {
+            Some(samples) => samples.push(sample),
+            None => {
+                drop(pending);
+                self.deliver(sample, false);
+            }
+        }
+    }
+
+    fn on_initial_query_completed(&self) {
+        // the lock is held while flushing, so that the updates received meanwhile are delivered after
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+        for sample in pending.take().unwrap_or_default() {
+            self.deliver(sample, false);
+        }
+    }
+}
+
+/// Completes the initial query of the watch when dropped, i.e. once all its replies are received.
+struct InitialQuery(Arc<WatchState>);
+
+impl Drop for InitialQuery {
+    fn drop(&mut self) {
+        self.0.on_initial_query_completed();
+    }
+}
+
+pub struct Watch {
+    subscriber: Subscriber<()>,
+    state: Arc<WatchState>,
+}
+
+decl_c_type!(owned(zc_owned_watch_t, option Watch));
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs and declares a watch on a key expression.
+///
+/// A watch queries the current values of the key expression, and then keeps delivering their updates, through a single
+/// callback. It is called with each reply to the initial query with `is_initial` set to ``true``, and with each
+/// publication on the key expression with `is_initial` set to ``false``.
+///
+/// The subscriber is declared before the query is issued, so that no update is missed. The updates received while
+/// the query is in progress are delivered once it completes, so that they are always delivered after the initial
+/// values they supersede.
+///
+/// The subscriber of the watch is declared like the ones of `z_declare_subscriber()`: it is counted in the session
+/// limits, its key expression is passed to the key expression interceptor of the session, and the callback is deferred
+/// if the session is in deferred dispatch mode.
+///
+/// @param session: The zenoh session.
+/// @param watch: An uninitialized location in memory where the watch will be constructed.
+/// @param key_expr: The key expression to watch.
+/// @param callback: The callback function that will be called with the initial values and their updates.
+/// @param options: The options for the watch, pass NULL for default options.
+/// @return 0 in case of success, `Z_EINVAL` if `callback` is NULL, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_declare_watch(
+    session: &z_loaned_session_t,
+    watch: &mut MaybeUninit<zc_owned_watch_t>,
+    key_expr: &z_loaned_keyexpr_t,
+    callback: Option<&mut zc_moved_closure_watch_t>,
+    options: Option<&mut zc_watch_options_t>,
+) -> result::z_result_t {
+    let watch = watch.as_rust_type_mut_uninit();
+    let Some(callback) = moved_or_null(callback, "zc_declare_watch") else {
+        watch.write(None);
+        return result::Z_EINVAL;
+    };
+    let state = Arc::new(WatchState {
+        callback: callback.take_rust_type(),
+        pending: Mutex::new(Some(Vec::new())),
+        undeclared: AtomicBool::new(false),
+    });
+    let (intercepted, slot) = match keyexpr_interceptor::admit_declaration(
+        session.as_rust_type_ref(),
+        key_expr.as_rust_type_ref(),
+        EntityKind::Subscriber,
+    ) {
+        Ok(admitted) => admitted,
+        Err(e) => {
+            watch.write(None);
+            return e;
+        }
+    };
+    let key_expr = intercepted
+        .as_ref()
+        .map_or(key_expr, |k| k.as_loaned_c_type_ref());
+    let subscriber_state = state.clone();
+    let callback: z_owned_closure_sample_t = (move |sample: &mut z_loaned_sample_t| {
+        subscriber_state.on_update(sample.as_rust_type_ref().clone())
+    })
+    .into();
+    let callback = entity_limits::hold_in_sample_callback(slot, callback);
+    let callback = deferred_callbacks::defer_sample_callback(session.as_rust_type_ref(), callback);
+    let subscriber =
+        match _declare_subscriber_inner(session, key_expr, callback, None).and_then(|s| {
+            s.wait().map_err(|e| {
+                tracing::error!("Failed to declare the watch subscriber: {}", e);
+                result::Z_EGENERIC
+            })
+        }) {
+            Ok(subscriber) => subscriber,
+            Err(e) => {
+                watch.write(None);
+                return e;
+            }
+        };
+    let initial_query = InitialQuery(state.clone());
+    let reply_callback: z_owned_closure_reply_t =
+        (move |reply: &mut z_loaned_reply_t| match reply.as_rust_type_ref().result() {
+            Ok(sample) => initial_query.0.deliver(sample.clone(), true),
+            Err(e) => tracing::debug!("Ignoring an error reply to the watch query: {:?}", e),
+        })
+        .into();
+    let reply_callback =
+        deferred_callbacks::defer_reply_callback(session.as_rust_type_ref(), reply_callback);
+    let mut get = session
+        .as_rust_type_ref()
+        .get(key_expr.as_rust_type_ref().clone())
+        .callback(move |mut reply: Reply| {
+            z_closure_reply_call(
+                z_closure_reply_loan(&reply_callback),
+                reply.as_loaned_c_type_mut(),
+            )
+        });
+    if let Some(timeout_ms) = options.map(|o| o.query_timeout_ms).filter(|t| *t != 0) {
+        get = get.timeout(Duration::from_millis(timeout_ms));
+    }
+    if let Err(e) = get.wait() {
+        tracing::error!("Failed to query the watched values: {}", e);
+        watch.write(None);
+        return result::Z_EGENERIC;
+    }
+    watch.write(Some(Watch { subscriber, state }));
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a watch in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_watch_null(this_: &mut MaybeUninit<zc_owned_watch_t>) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the watch is valid, ``false`` if it is in its gravestone state.
+#[no_mangle]
+pub extern "C" fn zc_internal_watch_check(this_: &zc_owned_watch_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares the watch.
+///
+/// The callback is not called anymore once this function returns (except by the calls already in progress),
+/// but it is only dropped once the initial query, if still in progress, completes.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn zc_undeclare_watch(this_: Option<&mut zc_moved_watch_t>) -> result::z_result_t {
+    let Some(this_) = moved_or_null(this_, "zc_undeclare_watch") else {
+        return result::Z_OK;
+    };
+    if let Some(Watch { subscriber, state }) = this_.take_rust_type() {
+        state.undeclared.store(true, Ordering::Release);
+        if let Err(e) = subscriber.undeclare().wait() {
+            tracing::error!("{}", e);
+            return result::Z_EGENERIC;
+        }
+    }
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares the watch and resets it to its gravestone state.
+/// This is equivalent to calling `zc_undeclare_watch()` and discarding its return value.
+#[no_mangle]
+pub extern "C" fn zc_watch_drop(this_: Option<&mut zc_moved_watch_t>) {
+    let Some(this_) = moved_or_null(this_, "zc_watch_drop") else {
+        return;
+    };
+    if let Some(Watch { subscriber, state }) = this_.take_rust_type() {
+        state.undeclared.store(true, Ordering::Release);
+        std::mem::drop(subscriber);
+    }
+}
@@ -13,6 +13,8 @@
 //
 
 use core::fmt;
+#[cfg(feature = "unstable")]
+use std::hash::Hasher;
 use std::{
     any::Any,
     io::{Read, Seek, SeekFrom, Write},
@@ -32,7 +34,7 @@ pub use crate::opaque_types::{z_loaned_bytes_t, z_owned_bytes_t};
 use crate::result::Z_ENULL;
 use crate::{
     result::{self, z_result_t, Z_EINVAL, Z_EIO, Z_OK},
-    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    transmute::{moved_or_null, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_loaned_slice_t, z_loaned_string_t, z_moved_bytes_t, z_moved_slice_t, z_moved_string_t,
     z_owned_slice_t, z_owned_string_t, z_view_slice_t, CSlice, CSliceOwned, CSliceView, CString,
     CStringOwned,
@@ -59,7 +61,10 @@ extern "C" fn z_bytes_empty(this: &mut MaybeUninit<z_owned_bytes_t>) {
 /// Drops `this_`, resetting it to gravestone value. If there are any shallow copies
 /// created by `z_bytes_clone()`, they would still stay valid.
 #[no_mangle]
-extern "C" fn z_bytes_drop(this_: &mut z_moved_bytes_t) {
+extern "C" fn z_bytes_drop(this_: Option<&mut z_moved_bytes_t>) {
+    let Some(this_) = moved_or_null(this_, "z_bytes_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -100,6 +105,34 @@ extern "C" fn z_bytes_len(this: &z_loaned_bytes_t) -> usize {
     this.as_rust_type_ref().len()
 }
 
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the CRC-32 (IEEE) checksum of the payload.
+///
+/// The checksum is computed over the slices of the payload, without copying them to contiguous memory.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_bytes_crc32(this_: &z_loaned_bytes_t) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    for slice in this_.as_rust_type_ref().slices() {
+        hasher.update(slice);
+    }
+    hasher.finalize()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the XXH64 hash of the payload, for the given seed.
+///
+/// The hash is computed over the slices of the payload, without copying them to contiguous memory.
+#[cfg(feature = "unstable")]
+#[no_mangle]
+pub extern "C" fn z_bytes_xxhash64(this_: &z_loaned_bytes_t, seed: u64) -> u64 {
+    let mut hasher = twox_hash::XxHash64::with_seed(seed);
+    for slice in this_.as_rust_type_ref().slices() {
+        hasher.write(slice);
+    }
+    hasher.finish()
+}
+
 /// Converts data into an owned non-null-terminated string.
 ///
 /// @param this_: Data to convert.
@@ -126,6 +159,8 @@ pub unsafe extern "C" fn z_bytes_to_string(
 
 /// Converts data into an owned slice.
 ///
+/// The data is always copied into the slice. To access it without copying, use `z_bytes_get_slice_iterator()`.
+///
 /// @param this_: Data to convert.
 /// @param dst: An uninitialized memory location where to construct a slice.
 #[no_mangle]
@@ -265,15 +300,16 @@ impl From<CStringOwned> for ZBytes {
 }
 
 /// Converts a slice into `z_owned_bytes_t`.
-/// The slice is consumed upon function return.
+/// The slice is consumed upon function return. If `slice` is NULL, empty bytes are constructed.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_from_slice(
     this: &mut MaybeUninit<z_owned_bytes_t>,
-    slice: &mut z_moved_slice_t,
+    slice: Option<&mut z_moved_slice_t>,
 ) {
-    let slice = slice.take_rust_type();
-    let payload = ZBytes::from(slice);
+    let payload = moved_or_null(slice, "z_bytes_from_slice")
+        .map(|slice| ZBytes::from(slice.take_rust_type()))
+        .unwrap_or_default();
     this.as_rust_type_mut_uninit().write(payload);
 }
 
@@ -315,6 +351,8 @@ pub unsafe extern "C" fn z_bytes_from_buf(
 }
 
 /// Converts a statically allocated constant buffer into `z_owned_bytes_t`.
+///
+/// The data is aliased without copy and is never freed, which makes it the cheapest way to repeatedly publish a constant payload.
 /// @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
 /// @param data: A pointer to the statically allocated constant data.
 /// @param len: A length of the buffer.
@@ -323,7 +361,7 @@ pub unsafe extern "C" fn z_bytes_from_buf(
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_from_static_buf(
     this: &mut MaybeUninit<z_owned_bytes_t>,
-    data: *mut u8,
+    data: *const u8,
     len: usize,
 ) -> z_result_t {
     if let Ok(s) = CSliceOwned::wrap(data as _, len, None, null_mut()) {
@@ -357,16 +395,17 @@ pub unsafe extern "C" fn z_bytes_copy_from_buf(
 }
 
 /// Converts a string into `z_owned_bytes_t`.
-/// The string is consumed upon function return.
+/// The string is consumed upon function return. If `s` is NULL, empty bytes are constructed.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_from_string(
     this: &mut MaybeUninit<z_owned_bytes_t>,
-    s: &mut z_moved_string_t,
+    s: Option<&mut z_moved_string_t>,
 ) {
     // TODO: verify that string is a valid utf-8 string ?
-    let cs = s.take_rust_type();
-    let payload = ZBytes::from(cs);
+    let payload = moved_or_null(s, "z_bytes_from_string")
+        .map(|s| ZBytes::from(s.take_rust_type()))
+        .unwrap_or_default();
     this.as_rust_type_mut_uninit().write(payload);
 }
 
@@ -452,6 +491,9 @@ decl_c_type!(loaned(z_bytes_slice_iterator_t, ZBytesSliceIterator<'static>));
 /// then allows to access raw data directly without any attempt of deserializing it.
 /// Please note that no guarantee is provided on the internal memory layout.
 /// The only provided guarantee is on the bytes order that is preserved.
+///
+/// The slices returned by `z_bytes_slice_iterator_next()` are views on the memory of the data, no copy is made:
+/// they stay valid as long as the data is neither modified nor dropped.
 #[no_mangle]
 pub extern "C" fn z_bytes_get_slice_iterator(
     this: &'static z_loaned_bytes_t,
@@ -507,13 +549,14 @@ pub extern "C" fn z_bytes_get_contiguous_view(
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Converts from an immutable SHM buffer consuming it.
+/// @return 0 in case of success, `Z_ENULL` if `shm` is NULL or in its gravestone state (in this case empty bytes are constructed).
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_from_shm(
     this: &mut MaybeUninit<z_owned_bytes_t>,
-    shm: &mut z_moved_shm_t,
+    shm: Option<&mut z_moved_shm_t>,
 ) -> z_result_t {
-    let Some(shm) = shm.take_rust_type() else {
+    let Some(shm) = moved_or_null(shm, "z_bytes_from_shm").and_then(|s| s.take_rust_type()) else {
         this.as_rust_type_mut_uninit().write(ZBytes::default());
         return Z_ENULL;
     };
@@ -524,13 +567,15 @@ pub unsafe extern "C" fn z_bytes_from_shm(
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief Converts a mutable SHM buffer consuming it.
+/// @return 0 in case of success, `Z_ENULL` if `shm` is NULL or in its gravestone state (in this case empty bytes are constructed).
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_from_shm_mut(
     this: &mut MaybeUninit<z_owned_bytes_t>,
-    shm: &mut z_moved_shm_mut_t,
+    shm: Option<&mut z_moved_shm_mut_t>,
 ) -> z_result_t {
-    let Some(shm) = shm.take_rust_type() else {
+    let Some(shm) = moved_or_null(shm, "z_bytes_from_shm_mut").and_then(|s| s.take_rust_type())
+    else {
         this.as_rust_type_mut_uninit().write(ZBytes::default());
         return Z_ENULL;
     };
@@ -554,7 +599,7 @@ pub extern "C" fn z_bytes_get_reader(data: &'static z_loaned_bytes_t) -> z_bytes
 /// @param this_: Data reader to read from.
 /// @param dst: Buffer where the read data is written.
 /// @param len: Maximum number of bytes to read.
-/// @return number of bytes read. If return value is smaller than `len`, it means that  theend of the data was reached.
+/// @return number of bytes read. If return value is smaller than `len`, it means that the end of the data was reached.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_bytes_reader_read(
@@ -636,7 +681,10 @@ extern "C" fn z_bytes_writer_empty(this: &mut MaybeUninit<z_owned_bytes_writer_t
 
 /// Drops `this_`, resetting it to gravestone value.
 #[no_mangle]
-extern "C" fn z_bytes_writer_drop(this_: &mut z_moved_bytes_writer_t) {
+extern "C" fn z_bytes_writer_drop(this_: Option<&mut z_moved_bytes_writer_t>) {
+    let Some(this_) = moved_or_null(this_, "z_bytes_writer_drop") else {
+        return;
+    };
     let _ = this_.take_rust_type();
 }
 
@@ -678,16 +726,20 @@ pub extern "C" fn z_internal_bytes_writer_null(this_: &mut MaybeUninit<z_owned_b
 
 /// @brief Drop writer and extract underlying `bytes` object it was writing to.
 /// @param this_: A writer instance.
-/// @param bytes: An uninitialized memory location where `bytes` object` will be written to.
+/// @param bytes: An uninitialized memory location where `bytes` object` will be written to. If `this_` is NULL or in its
+/// gravestone state, empty bytes are written.
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn z_bytes_writer_finish(
-    this: &mut z_moved_bytes_writer_t,
+    this: Option<&mut z_moved_bytes_writer_t>,
     bytes: &mut MaybeUninit<z_owned_bytes_t>,
 ) {
-    bytes
-        .as_rust_type_mut_uninit()
-        .write(this.take_rust_type().unwrap_unchecked().finish());
+    bytes.as_rust_type_mut_uninit().write(
+        moved_or_null(this, "z_bytes_writer_finish")
+            .and_then(|w| w.take_rust_type())
+            .map(|w| w.finish())
+            .unwrap_or_default(),
+    );
 }
 
 /// Writes `len` bytes from `src` into underlying data.
@@ -710,12 +762,15 @@ unsafe extern "C" fn z_bytes_writer_write_all(
 /// This allows to compose a serialized data out of multiple `z_owned_bytes_t` that may point to different memory regions.
 /// Said in other terms, it allows to create a linear view on different memory regions without copy.
 ///
-/// @return 0 in case of success, negative error code otherwise.
+/// @return 0 in case of success, `Z_EINVAL` if `bytes` is NULL, negative error code otherwise.
 #[no_mangle]
 extern "C" fn z_bytes_writer_append(
     this: &mut z_loaned_bytes_writer_t,
-    bytes: &mut z_moved_bytes_t,
+    bytes: Option<&mut z_moved_bytes_t>,
 ) -> z_result_t {
+    let Some(bytes) = moved_or_null(bytes, "z_bytes_writer_append") else {
+        return result::Z_EINVAL;
+    };
     this.as_rust_type_mut().append(bytes.take_rust_type());
     result::Z_OK
 }
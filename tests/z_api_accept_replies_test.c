@@ -0,0 +1,92 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* query_keyexpr = "zenoh/test/accept_replies/query";
+const char* reply_keyexpr = "zenoh/test/accept_replies/other";
+
+// Replies to a query on a key expression which does not intersect with it, returning the number of received replies.
+size_t get_and_reply(const z_loaned_session_t* s, z_owned_fifo_handler_query_t* queries, z_get_options_t* opts,
+                     z_result_t expected_reply_result) {
+    z_view_keyexpr_t ke, reply_ke;
+    z_view_keyexpr_from_str(&ke, query_keyexpr);
+    z_view_keyexpr_from_str(&reply_ke, reply_keyexpr);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(callback), opts) == Z_OK);
+
+    z_owned_query_t query;
+    assert(z_recv(z_loan(*queries), &query) == Z_OK);
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "reply");
+    assert(z_query_reply(z_loan(query), z_loan(reply_ke), z_move(payload), NULL) == expected_reply_result);
+    z_drop(z_move(query));
+
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        assert(z_keyexpr_equals(z_sample_keyexpr(z_reply_ok(z_loan(reply))), z_loan(reply_ke)));
+        count++;
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+    return count;
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, query_keyexpr);
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t callback;
+    z_fifo_channel_query_new(&callback, &queries, 16);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    // by default, replies on non-intersecting key expressions are refused
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    assert(opts.accept_replies == ZC_REPLY_KEYEXPR_MATCHING_QUERY);
+    assert(get_and_reply(z_loan(s), &queries, &opts, Z_EGENERIC) == 0);
+
+    z_get_options_default(&opts);
+    opts.accept_replies = ZC_REPLY_KEYEXPR_ANY;
+    assert(get_and_reply(z_loan(s), &queries, &opts, Z_OK) == 1);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(queries));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
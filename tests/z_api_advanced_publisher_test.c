@@ -0,0 +1,109 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/advanced_publisher";
+const char* values[] = {"value_1", "value_2", "value_3", "value_4", "value_5", "value_6"};
+const size_t values_count = sizeof(values) / sizeof(values[0]);
+const size_t published_before = 4;
+const size_t max_samples = 2;
+
+void put_values(const ze_loaned_advanced_publisher_t* pub, size_t from, size_t to) {
+    for (size_t i = from; i < to; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_from_static_str(&payload, values[i]);
+        assert(ze_advanced_publisher_put(pub, z_move(payload), NULL) == Z_OK);
+    }
+}
+
+// Checks the received values, and that their sequence numbers follow each other.
+void check_values(const z_loaned_fifo_handler_sample_t* samples, size_t from, size_t to) {
+    uint32_t last_sn = 0;
+    for (size_t i = from; i < to; i++) {
+        z_owned_sample_t sample;
+        assert(z_recv(samples, &sample) == Z_OK);
+        z_owned_string_t value;
+        z_bytes_to_string(z_sample_payload(z_loan(sample)), &value);
+        assert(z_string_len(z_loan(value)) == strlen(values[i]));
+        assert(strncmp(z_string_data(z_loan(value)), values[i], strlen(values[i])) == 0);
+        z_drop(z_move(value));
+
+        const z_loaned_source_info_t* info = z_sample_source_info(z_loan(sample));
+        assert(info != NULL);
+        uint32_t sn = z_source_info_sn(info);
+        assert(i == from || sn == last_sn + 1);
+        last_sn = sn;
+        z_drop(z_move(sample));
+    }
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), Z_CONFIG_ADD_TIMESTAMP_KEY, "true") == Z_OK);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    ze_advanced_publisher_options_t pub_opts;
+    ze_advanced_publisher_options_default(&pub_opts);
+    ze_advanced_publisher_cache_options_default(&pub_opts.cache);
+    pub_opts.cache.max_samples = max_samples;
+    pub_opts.sample_miss_detection = true;
+    ze_owned_advanced_publisher_t pub;
+    assert(ze_declare_advanced_publisher(z_loan(s), &pub, z_loan(ke), &pub_opts) == Z_OK);
+    assert(z_internal_check(pub));
+
+    // only the most recent samples are kept in the history
+    put_values(z_loan(pub), 0, published_before);
+    z_sleep_ms(100);
+
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 16);
+    ze_advanced_subscriber_options_t sub_opts;
+    ze_advanced_subscriber_options_default(&sub_opts);
+    ze_advanced_subscriber_history_options_default(&sub_opts.history);
+    ze_owned_advanced_subscriber_t sub;
+    assert(ze_declare_advanced_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), &sub_opts) == Z_OK);
+    z_sleep_ms(500);
+
+    put_values(z_loan(pub), published_before, values_count);
+    check_values(z_loan(samples), published_before - max_samples, values_count);
+    z_sleep_ms(100);
+    z_owned_sample_t sample;
+    assert(z_try_recv(z_loan(samples), &sample) == Z_CHANNEL_NODATA);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    assert(ze_undeclare_advanced_publisher(z_move(pub)) == Z_OK);
+    assert(!z_internal_check(pub));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
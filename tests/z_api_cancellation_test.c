@@ -0,0 +1,113 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/cancellation";
+
+void test_cancel_pending_get(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    // the queries are kept in the channel and never replied to
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t callback;
+    z_fifo_channel_query_new(&callback, &queries, 16);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(s, &q, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_cancellation_token_t token, token_copy;
+    assert(z_cancellation_token_new(&token) == Z_OK);
+    z_cancellation_token_clone(&token_copy, z_loan(token));
+    assert(!z_cancellation_token_is_cancelled(z_loan(token)));
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.timeout_ms = 60000;
+    opts.cancellation_token = z_move(token_copy);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_reply_t reply;
+    assert(z_try_recv(z_loan(replies), &reply) == Z_CHANNEL_NODATA);
+    z_clock_t start = z_clock_now();
+    assert(z_cancellation_token_cancel(z_loan(token)) == Z_OK);
+    assert(z_cancellation_token_is_cancelled(z_loan(token)));
+    // the reply callback is dropped without waiting for the query timeout
+    assert(z_recv(z_loan(replies), &reply) == Z_CHANNEL_DISCONNECTED);
+    assert(z_clock_elapsed_ms(&start) < 5000);
+    z_drop(z_move(replies));
+
+    z_drop(z_move(token));
+    z_drop(z_move(q));
+    z_drop(z_move(queries));
+}
+
+void test_get_with_cancelled_token(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    z_owned_cancellation_token_t token, token_copy;
+    assert(z_cancellation_token_new(&token) == Z_OK);
+    assert(z_cancellation_token_cancel(z_loan(token)) == Z_OK);
+    z_cancellation_token_clone(&token_copy, z_loan(token));
+    assert(z_cancellation_token_is_cancelled(z_loan(token_copy)));
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.timeout_ms = 60000;
+    opts.cancellation_token = z_move(token_copy);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    z_clock_t start = z_clock_now();
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+    assert(!z_internal_check(token_copy));
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(replies), &reply) == Z_CHANNEL_DISCONNECTED);
+    assert(z_clock_elapsed_ms(&start) < 5000);
+    z_drop(z_move(replies));
+
+    z_drop(z_move(token));
+    assert(!z_internal_check(token));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_cancel_pending_get(z_loan(s));
+    test_get_with_cancelled_token(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,147 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/channel_watermarks";
+const size_t capacity = 8;
+const size_t high = 6;
+const size_t low = 2;
+
+typedef struct watermark_context_t {
+    int high_count;
+    int low_count;
+    size_t occupancy;
+    int dropped;
+} watermark_context_t;
+
+void on_watermark(zc_watermark_t watermark, size_t occupancy, void* context) {
+    watermark_context_t* ctx = (watermark_context_t*)context;
+    if (watermark == ZC_WATERMARK_HIGH) {
+        ctx->high_count++;
+    } else {
+        ctx->low_count++;
+    }
+    ctx->occupancy = occupancy;
+}
+
+void on_drop(void* context) {
+    watermark_context_t* ctx = (watermark_context_t*)context;
+    ctx->dropped++;
+}
+
+void put_values(const z_loaned_session_t* s, size_t count) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    for (size_t i = 0; i < count; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_copy_from_str(&payload, "value");
+        assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+    }
+    z_sleep_ms(100);
+}
+
+void recv_values(const z_loaned_fifo_handler_sample_t* samples, size_t count) {
+    for (size_t i = 0; i < count; i++) {
+        z_owned_sample_t sample;
+        assert(z_recv(samples, &sample) == Z_OK);
+        z_drop(z_move(sample));
+    }
+}
+
+void test_invalid_watermarks(void) {
+    watermark_context_t ctx = {0};
+    z_owned_closure_sample_t callback;
+    z_owned_fifo_handler_sample_t samples;
+    zc_owned_closure_watermark_t watermark_callback;
+
+    z_closure(&watermark_callback, on_watermark, on_drop, (void*)&ctx);
+    assert(zc_fifo_channel_sample_new_with_watermarks(&callback, &samples, capacity, capacity + 1, low,
+                                                      z_move(watermark_callback)) == Z_EINVAL);
+    assert(!z_internal_check(callback));
+    assert(!z_internal_check(samples));
+    assert(ctx.dropped == 1);
+
+    z_closure(&watermark_callback, on_watermark, on_drop, (void*)&ctx);
+    assert(zc_fifo_channel_sample_new_with_watermarks(&callback, &samples, capacity, low, low,
+                                                      z_move(watermark_callback)) == Z_EINVAL);
+    assert(!z_internal_check(callback));
+    assert(!z_internal_check(samples));
+    assert(ctx.dropped == 2);
+}
+
+void test_watermarks(const z_loaned_session_t* s) {
+    watermark_context_t ctx = {0};
+    z_owned_closure_sample_t callback;
+    z_owned_fifo_handler_sample_t samples;
+    zc_owned_closure_watermark_t watermark_callback;
+    z_closure(&watermark_callback, on_watermark, on_drop, (void*)&ctx);
+    assert(zc_fifo_channel_sample_new_with_watermarks(&callback, &samples, capacity, high, low,
+                                                      z_move(watermark_callback)) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    put_values(s, high - 1);
+    assert(ctx.high_count == 0);
+    put_values(s, 1);
+    assert(ctx.high_count == 1);
+    assert(ctx.occupancy == high);
+
+    // the high watermark is only notified once until the channel is drained
+    put_values(s, 1);
+    assert(ctx.high_count == 1);
+    assert(ctx.low_count == 0);
+
+    // the occupancy is evaluated when pushing into the channel
+    recv_values(z_loan(samples), high + 1 - low);
+    assert(ctx.low_count == 0);
+    put_values(s, 1);
+    assert(ctx.low_count == 1);
+    assert(ctx.occupancy == low);
+    assert(ctx.high_count == 1);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    assert(ctx.dropped == 1);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_invalid_watermarks();
+    test_watermarks(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,104 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* keyexpr = "zenoh/test/clone";
+const char* payload_value = "cloned data";
+
+void assert_bytes_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    assert(bytes != NULL);
+    z_owned_string_t s;
+    z_bytes_to_string(bytes, &s);
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+}
+
+// The samples and replies are cloned in the callbacks, running on zenoh threads, and used by the main thread
+// once the callbacks returned.
+void on_sample(z_loaned_sample_t* sample, void* context) { z_sample_clone((z_owned_sample_t*)context, sample); }
+
+void on_query(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, payload_value);
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void on_reply(z_loaned_reply_t* reply, void* context) { z_reply_clone((z_owned_reply_t*)context, reply); }
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    z_owned_sample_t sample;
+    z_internal_null(&sample);
+    z_owned_closure_sample_t sample_callback;
+    z_closure(&sample_callback, on_sample, NULL, (void*)&sample);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, NULL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, payload_value);
+    assert(z_put(z_loan(s), z_loan(ke), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(z_internal_check(sample));
+    assert(z_keyexpr_equals(z_sample_keyexpr(z_loan(sample)), z_loan(ke)));
+    assert_bytes_eq(z_sample_payload(z_loan(sample)), payload_value);
+
+    // clones share the payload but can be dropped independently
+    z_owned_sample_t sample_copy;
+    z_sample_clone(&sample_copy, z_loan(sample));
+    z_drop(z_move(sample));
+    assert_bytes_eq(z_sample_payload(z_loan(sample_copy)), payload_value);
+    z_drop(z_move(sample_copy));
+
+    z_owned_reply_t reply;
+    z_internal_null(&reply);
+    z_owned_closure_reply_t reply_callback;
+    z_closure(&reply_callback, on_reply, NULL, (void*)&reply);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(z_internal_check(reply));
+    assert(z_reply_is_ok(z_loan(reply)));
+    assert_bytes_eq(z_sample_payload(z_reply_ok(z_loan(reply))), payload_value);
+
+    z_owned_reply_t reply_copy;
+    z_reply_clone(&reply_copy, z_loan(reply));
+    z_drop(z_move(reply));
+    assert_bytes_eq(z_sample_payload(z_reply_ok(z_loan(reply_copy))), payload_value);
+    z_drop(z_move(reply_copy));
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+    return 0;
+}
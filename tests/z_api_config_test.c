@@ -36,7 +36,80 @@ void insert_get() {
     z_drop(z_move(config));
 }
 
+void validate() {
+    zc_config_error_report_t report;
+    assert(zc_config_validate("{mode: \"client\", connect: {endpoints: [\"tcp/127.0.0.1:7447\"]}}", &report) == Z_OK);
+    assert(!z_internal_check(report.message));
+    assert(!z_internal_check(report.key_path));
+    assert(z_string_len(z_loan(report.message)) == 0);
+    assert(z_string_len(z_loan(report.key_path)) == 0);
+    assert(report.line == 0 && report.column == 0);
+    z_drop(z_move(report.message));
+    z_drop(z_move(report.key_path));
+
+    assert(zc_config_validate("{mode: \"client\",\n connect: {endpoints: 42}}", &report) == Z_EPARSE);
+    assert(z_internal_check(report.message));
+    assert(z_string_len(z_loan(report.key_path)) == strlen("connect/endpoints"));
+    assert(strncmp(z_string_data(z_loan(report.key_path)), "connect/endpoints", strlen("connect/endpoints")) == 0);
+    assert(report.line == 2);
+    z_drop(z_move(report.message));
+    z_drop(z_move(report.key_path));
+
+    assert(zc_config_validate("{mode: \"client\",,}", &report) == Z_EPARSE);
+    assert(z_internal_check(report.message));
+    assert(!z_internal_check(report.key_path));
+    assert(report.line == 1 && report.column > 0);
+    z_drop(z_move(report.message));
+    z_drop(z_move(report.key_path));
+
+    assert(zc_config_validate("{mode: \"client\"}", NULL) == Z_OK);
+    assert(zc_config_validate("{mode: 12}", NULL) == Z_EPARSE);
+}
+
+bool string_contains(const z_loaned_string_t *s, const char *needle) {
+    size_t len = z_string_len(s), needle_len = strlen(needle);
+    const char *data = z_string_data(s);
+    for (size_t i = 0; i + needle_len <= len; i++) {
+        if (strncmp(data + i, needle, needle_len) == 0) {
+            return true;
+        }
+    }
+    return false;
+}
+
+void redact_secrets() {
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), Z_CONFIG_USER_KEY, "\"secret-user\"") == Z_OK);
+    assert(zc_config_insert_json5(z_loan_mut(config), Z_CONFIG_PASSWORD_KEY, "\"secret-password\"") == Z_OK);
+
+    z_owned_string_t s;
+    assert(zc_config_to_string(z_loan(config), &s) == Z_OK);
+    assert(string_contains(z_loan(s), "secret-user"));
+    assert(string_contains(z_loan(s), "secret-password"));
+    z_drop(z_move(s));
+
+    zc_config_to_string_options_t opts;
+    zc_config_to_string_options_default(&opts);
+    assert(!opts.redact_secrets);
+    assert(zc_config_to_string_with_options(z_loan(config), &s, &opts) == Z_OK);
+    assert(string_contains(z_loan(s), "secret-password"));
+    z_drop(z_move(s));
+
+    opts.redact_secrets = true;
+    assert(zc_config_to_string_with_options(z_loan(config), &s, &opts) == Z_OK);
+    assert(!string_contains(z_loan(s), "secret-user"));
+    assert(!string_contains(z_loan(s), "secret-password"));
+    assert(string_contains(z_loan(s), "\"***\""));
+    // the rest of the configuration is kept
+    assert(string_contains(z_loan(s), "\"mode\""));
+    z_drop(z_move(s));
+    z_drop(z_move(config));
+}
+
 int main(int argc, char **argv) {
     zc_try_init_log_from_env();
     insert_get();
+    validate();
+    redact_secrets();
 }
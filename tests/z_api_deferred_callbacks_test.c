@@ -0,0 +1,152 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/deferred";
+
+typedef struct callback_counts_t {
+    int samples;
+    int queries;
+    int replies;
+    int replies_dropped;
+} callback_counts_t;
+
+void on_sample(z_loaned_sample_t* sample, void* context) { ((callback_counts_t*)context)->samples++; }
+
+void on_query(z_loaned_query_t* query, void* context) {
+    ((callback_counts_t*)context)->queries++;
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "reply");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void on_reply(z_loaned_reply_t* reply, void* context) { ((callback_counts_t*)context)->replies++; }
+
+void on_reply_drop(void* context) { ((callback_counts_t*)context)->replies_dropped++; }
+
+void test_dispatch(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_open_options_t opts;
+    z_open_options_default(&opts);
+    assert(!opts.deferred_callbacks);
+    assert(opts.deferred_callbacks_capacity == 1024);
+    opts.deferred_callbacks = true;
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), &opts) == Z_OK);
+
+    callback_counts_t counts = {0, 0, 0, 0};
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    z_owned_closure_sample_t sample_callback;
+    z_closure(&sample_callback, on_sample, NULL, (void*)&counts);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, (void*)&counts);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    // nothing is pending
+    assert(zc_session_drain_callbacks(z_loan(s), 0, 0) == 0);
+
+    for (int i = 0; i < 3; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_from_static_str(&payload, "data");
+        assert(z_put(z_loan(s), z_loan(ke), z_move(payload), NULL) == Z_OK);
+    }
+    z_sleep_ms(100);
+    // callbacks are only executed when draining
+    assert(counts.samples == 0);
+    assert(zc_session_drain_callbacks(z_loan(s), 2, 1000) == 2);
+    assert(counts.samples == 2);
+    assert(zc_session_drain_callbacks(z_loan(s), 0, 1000) == 1);
+    assert(counts.samples == 3);
+
+    z_owned_closure_reply_t reply_callback;
+    z_closure(&reply_callback, on_reply, on_reply_drop, (void*)&counts);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    // the query callback, then the reply callback
+    assert(zc_session_drain_callbacks(z_loan(s), 1, 1000) == 1);
+    assert(counts.queries == 1);
+    assert(counts.replies == 0);
+    assert(zc_session_drain_callbacks(z_loan(s), 1, 1000) == 1);
+    assert(counts.replies == 1);
+    for (int i = 0; i < 50 && counts.replies_dropped == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(counts.replies_dropped == 1);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+}
+
+void test_capacity(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_open_options_t opts;
+    z_open_options_default(&opts);
+    opts.deferred_callbacks = true;
+    opts.deferred_callbacks_capacity = 2;
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), &opts) == Z_OK);
+
+    callback_counts_t counts = {0, 0, 0, 0};
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_sample_t sample_callback;
+    z_closure(&sample_callback, on_sample, NULL, (void*)&counts);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    for (int i = 0; i < 5; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_from_static_str(&payload, "data");
+        assert(z_put(z_loan(s), z_loan(ke), z_move(payload), NULL) == Z_OK);
+    }
+    z_sleep_ms(100);
+    // the samples arriving while the queue is full are dropped and counted
+    assert(zc_session_drain_callbacks(z_loan(s), 0, 1000) == 2);
+    assert(counts.samples == 2);
+    zc_session_drop_counters_t drops;
+    zc_session_drop_counters(z_loan(s), &drops);
+    assert(drops.full_buffers == 3);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    test_dispatch();
+    test_capacity();
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
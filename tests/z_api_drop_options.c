@@ -45,6 +45,83 @@ void put() {
     z_drop(z_move(s));
 }
 
+void put_shared_attachment() {
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), NULL) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test_put");
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&attachment, "abc");
+    for (int i = 0; i < 3; i++) {
+        z_put_options_t opts;
+        z_put_options_default(&opts);
+        z_owned_bytes_t payload, attachment_copy;
+        z_bytes_clone(&attachment_copy, z_loan(attachment));
+        opts.attachment = z_move(attachment_copy);
+        z_bytes_copy_from_str(&payload, "cde");
+        z_put(z_loan(s), z_loan(ke), z_move(payload), &opts);
+        assert(!z_internal_check(attachment_copy));
+    }
+    assert(z_internal_check(attachment));
+    assert(z_bytes_len(z_loan(attachment)) == 3);
+    z_drop(z_move(attachment));
+    z_drop(z_move(s));
+}
+
+void delete() {
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), NULL) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test_delete");
+    z_delete_options_t opts;
+    z_delete_options_default(&opts);
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&attachment, "abc");
+    opts.attachment = z_move(attachment);
+    z_delete(z_loan(s), z_loan(ke), &opts);
+    assert(!z_internal_check(attachment));
+    z_drop(z_move(s));
+}
+
+void publisher_delete() {
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), NULL) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test_publisher_delete");
+    z_owned_publisher_t pub;
+    z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL);
+    z_publisher_delete_options_t opts;
+    z_publisher_delete_options_default(&opts);
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&attachment, "abc");
+    opts.attachment = z_move(attachment);
+    z_publisher_delete(z_loan(pub), &opts);
+    assert(!z_internal_check(attachment));
+    z_drop(z_move(pub));
+    z_drop(z_move(s));
+}
+
 void get() {
     z_owned_config_t config;
     z_config_default(&config);
@@ -76,5 +153,8 @@ void get() {
 int main(int argc, char **argv) {
     zc_try_init_log_from_env();
     put();
+    put_shared_attachment();
+    delete();
+    publisher_delete();
     get();
 }
@@ -0,0 +1,120 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* in_keyexpr = "zenoh/test/forward/in";
+const char* out_keyexpr = "zenoh/test/forward/out";
+const char* payload_value = "forwarded data";
+const char* attachment_value = "forwarded attachment";
+const char* override_value = "override attachment";
+
+void assert_bytes_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    assert(bytes != NULL);
+    z_owned_string_t s;
+    z_bytes_to_string(bytes, &s);
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t in_ke, out_ke;
+    z_view_keyexpr_from_str(&in_ke, in_keyexpr);
+    z_view_keyexpr_from_str(&out_ke, out_keyexpr);
+
+    z_owned_fifo_handler_sample_t in_samples, out_samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &in_samples, 16);
+    z_owned_subscriber_t in_sub;
+    assert(z_declare_subscriber(z_loan(s), &in_sub, z_loan(in_ke), z_move(callback), NULL) == Z_OK);
+    z_fifo_channel_sample_new(&callback, &out_samples, 16);
+    z_owned_subscriber_t out_sub;
+    assert(z_declare_subscriber(z_loan(s), &out_sub, z_loan(out_ke), z_move(callback), NULL) == Z_OK);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(out_ke), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_bytes_t payload, attachment;
+    z_bytes_copy_from_str(&payload, payload_value);
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_owned_encoding_t encoding;
+    z_encoding_clone(&encoding, z_encoding_text_plain());
+    z_put_options_t put_opts;
+    z_put_options_default(&put_opts);
+    put_opts.encoding = z_move(encoding);
+    put_opts.attachment = z_move(attachment);
+    assert(z_put(z_loan(s), z_loan(in_ke), z_move(payload), &put_opts) == Z_OK);
+    z_owned_sample_t sample, forwarded;
+    assert(z_recv(z_loan(in_samples), &sample) == Z_OK);
+
+    // the metadata of the sample is preserved
+    assert(zc_forward(z_loan(sample), z_loan(pub), NULL) == Z_OK);
+    assert(z_recv(z_loan(out_samples), &forwarded) == Z_OK);
+    assert(z_sample_kind(z_loan(forwarded)) == Z_SAMPLE_KIND_PUT);
+    assert(z_keyexpr_equals(z_sample_keyexpr(z_loan(forwarded)), z_loan(out_ke)));
+    assert_bytes_eq(z_sample_payload(z_loan(forwarded)), payload_value);
+    assert_bytes_eq(z_sample_attachment(z_loan(forwarded)), attachment_value);
+    assert(z_encoding_equals(z_sample_encoding(z_loan(forwarded)), z_encoding_text_plain()));
+    z_drop(z_move(forwarded));
+
+    // or overridden by the options
+    z_bytes_copy_from_str(&attachment, override_value);
+    z_publisher_put_options_t opts;
+    z_publisher_put_options_default(&opts);
+    opts.attachment = z_move(attachment);
+    assert(zc_forward(z_loan(sample), z_loan(pub), &opts) == Z_OK);
+    assert(!z_internal_check(attachment));
+    assert(z_recv(z_loan(out_samples), &forwarded) == Z_OK);
+    assert_bytes_eq(z_sample_payload(z_loan(forwarded)), payload_value);
+    assert_bytes_eq(z_sample_attachment(z_loan(forwarded)), override_value);
+    z_drop(z_move(forwarded));
+    z_drop(z_move(sample));
+
+    // delete samples are forwarded as deletes
+    assert(z_delete(z_loan(s), z_loan(in_ke), NULL) == Z_OK);
+    assert(z_recv(z_loan(in_samples), &sample) == Z_OK);
+    assert(zc_forward(z_loan(sample), z_loan(pub), NULL) == Z_OK);
+    assert(z_recv(z_loan(out_samples), &forwarded) == Z_OK);
+    assert(z_sample_kind(z_loan(forwarded)) == Z_SAMPLE_KIND_DELETE);
+    assert(z_keyexpr_equals(z_sample_keyexpr(z_loan(forwarded)), z_loan(out_ke)));
+    z_drop(z_move(forwarded));
+    z_drop(z_move(sample));
+
+    z_drop(z_move(pub));
+    z_drop(z_move(in_sub));
+    z_drop(z_move(out_sub));
+    z_drop(z_move(in_samples));
+    z_drop(z_move(out_samples));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,108 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* replying_keyexpr = "zenoh/test/get_options/replying";
+const char* silent_keyexpr = "zenoh/test/get_options/silent";
+
+void on_query(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "reply");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+size_t count_replies(z_owned_fifo_handler_reply_t* replies) {
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(*replies), &reply) == Z_OK) {
+        count++;
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(*replies));
+    return count;
+}
+
+void test_target_and_consolidation(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, replying_keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t q1, q2;
+    assert(z_declare_queryable(s, &q1, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_closure(&callback, on_query, NULL, NULL);
+    assert(z_declare_queryable(s, &q2, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.target = Z_QUERY_TARGET_ALL;
+    opts.consolidation = z_query_consolidation_none();
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+    assert(count_replies(&replies) == 2);
+
+    z_drop(z_move(q1));
+    z_drop(z_move(q2));
+}
+
+void test_timeout(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, silent_keyexpr);
+    // the queries are kept in the channel and never replied to
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t callback;
+    z_fifo_channel_query_new(&callback, &queries, 16);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(s, &q, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.timeout_ms = 200;
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    z_clock_t start = z_clock_now();
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+    assert(count_replies(&replies) == 0);
+    uint64_t elapsed = z_clock_elapsed_ms(&start);
+    assert(elapsed >= 150);
+    assert(elapsed < 5000);
+
+    z_drop(z_move(q));
+    z_drop(z_move(queries));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_target_and_consolidation(z_loan(s));
+    test_timeout(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
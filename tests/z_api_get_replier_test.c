@@ -0,0 +1,106 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/get_replier";
+
+typedef struct replies_t {
+    int ok;
+    int err;
+    bool done;
+} replies_t;
+
+void on_query(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "value");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void on_reply(z_loaned_reply_t* reply, void* context) {
+    replies_t* replies = (replies_t*)context;
+    if (z_reply_is_ok(reply)) {
+        replies->ok++;
+    } else {
+        replies->err++;
+    }
+}
+
+void on_reply_drop(void* context) { ((replies_t*)context)->done = true; }
+
+void get_from(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, const z_id_t* replier_id,
+              replies_t* replies) {
+    z_owned_closure_reply_t callback;
+    z_closure(&callback, on_reply, on_reply_drop, (void*)replies);
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    assert(opts.replier_id == NULL);
+    opts.timeout_ms = 1000;
+    opts.replier_id = replier_id;
+    assert(z_get(s, ke, "", z_move(callback), &opts) == Z_OK);
+    for (int i = 0; i < 50 && !replies->done; i++) {
+        z_sleep_ms(100);
+    }
+    assert(replies->done);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    // the replies of the targeted replier are delivered
+    z_id_t zid = z_info_zid(z_loan(s));
+    replies_t replies = {0};
+    get_from(z_loan(s), z_loan(ke), &zid, &replies);
+    assert(replies.ok == 1);
+    assert(replies.err == 0);
+
+    // the ones of the other repliers are discarded
+    z_id_t other = zid;
+    other.id[0] ^= 0xff;
+    memset(&replies, 0, sizeof(replies));
+    get_from(z_loan(s), z_loan(ke), &other, &replies);
+    assert(replies.ok == 0);
+    assert(replies.err == 0);
+
+    memset(&replies, 0, sizeof(replies));
+    get_from(z_loan(s), z_loan(ke), NULL, &replies);
+    assert(replies.ok == 1);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,86 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+void test_id(const z_loaned_session_t* s) {
+    z_id_t zid = z_info_zid(s);
+    z_owned_slice_t bytes;
+    z_id_to_bytes(&zid, &bytes);
+    assert(z_slice_len(z_loan(bytes)) == 16);
+    // the bytes are in the same LSB-first order as the id
+    assert(memcmp(z_slice_data(z_loan(bytes)), zid.id, 16) == 0);
+
+    z_id_t parsed;
+    assert(z_id_from_bytes(&parsed, z_slice_data(z_loan(bytes)), z_slice_len(z_loan(bytes))) == Z_OK);
+    assert(memcmp(&parsed, &zid, sizeof(z_id_t)) == 0);
+    assert(z_id_from_bytes(&parsed, z_slice_data(z_loan(bytes)), 15) == Z_EINVAL);
+    z_drop(z_move(bytes));
+
+    uint8_t zeros[16] = {0};
+    assert(z_id_from_bytes(&parsed, zeros, sizeof(zeros)) == Z_EINVAL);
+}
+
+void test_timestamp(const z_loaned_session_t* s) {
+    z_timestamp_t ts;
+    assert(z_timestamp_new(&ts, s) == Z_OK);
+    z_owned_slice_t bytes;
+    z_timestamp_to_bytes(&ts, &bytes);
+    assert(z_slice_len(z_loan(bytes)) == 24);
+    const uint8_t* data = z_slice_data(z_loan(bytes));
+    uint64_t time = 0;
+    for (int i = 7; i >= 0; i--) {
+        time = (time << 8) | data[i];
+    }
+    assert(time == z_timestamp_ntp64_time(&ts));
+    z_id_t ts_id = z_timestamp_id(&ts);
+    assert(memcmp(data + 8, ts_id.id, 16) == 0);
+
+    z_timestamp_t parsed;
+    assert(z_timestamp_from_bytes(&parsed, data, 24) == Z_OK);
+    assert(z_timestamp_ntp64_time(&parsed) == z_timestamp_ntp64_time(&ts));
+    z_id_t parsed_id = z_timestamp_id(&parsed);
+    assert(memcmp(&parsed_id, &ts_id, sizeof(z_id_t)) == 0);
+    assert(z_timestamp_from_bytes(&parsed, data, 16) == Z_EINVAL);
+    z_drop(z_move(bytes));
+
+    uint8_t zero_id[24] = {1, 2, 3, 4, 5, 6, 7, 8};
+    assert(z_timestamp_from_bytes(&parsed, zero_id, sizeof(zero_id)) == Z_EINVAL);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_id(z_loan(s));
+    test_timestamp(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
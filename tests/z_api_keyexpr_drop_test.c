@@ -69,12 +69,36 @@ void test_subscriber() {
     z_drop(z_move(s));
 }
 
+void query_handler(z_loaned_query_t *query, void *arg) {}
+
+void test_queryable() {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    z_open(&s, z_move(config), NULL);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, query_handler, NULL, NULL);
+    char keyexpr[256];
+    strncpy(keyexpr, "foo/bar", 256);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_queryable_t queryable;
+    z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL);
+    strncpy(keyexpr, "baz/quax", 256);  // Update source string to ensure that the keyexpr is copied into the queryable
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    const z_loaned_keyexpr_t *queryable_ke = z_queryable_keyexpr(z_loan(queryable));
+    z_view_string_t queryable_keyexpr;
+    z_keyexpr_as_view_string(queryable_ke, &queryable_keyexpr);
+    assert(strncmp(z_string_data(z_loan(queryable_keyexpr)), "foo/bar", z_string_len(z_loan(queryable_keyexpr))) ==
+           0);  // Check that queryable keeps the correct keyexpr
+    z_drop(z_move(queryable));
+    z_drop(z_move(s));
+}
+
 int main(int argc, char **argv) {
     test_publisher();
     test_subscriber();
-    // TODO: Make same tests for pull subscriber and queryable when their `keyexpr` getters are implemented
-    // test_pull_subscriber();
-    // test_queryable();
+    test_queryable();
 
     return 0;
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* forbidden_prefix = "forbidden";
+const char* tenant = "tenant";
+
+z_result_t intercept(const z_loaned_keyexpr_t* key_expr, z_owned_keyexpr_t* rewritten, void* context) {
+    int* calls = (int*)context;
+    (*calls)++;
+    z_view_string_t ke_str;
+    z_keyexpr_as_view_string(key_expr, &ke_str);
+    if (z_string_len(z_loan(ke_str)) >= strlen(forbidden_prefix) &&
+        strncmp(z_string_data(z_loan(ke_str)), forbidden_prefix, strlen(forbidden_prefix)) == 0) {
+        return Z_EDENIED;
+    }
+    z_view_keyexpr_t prefix;
+    z_view_keyexpr_from_str(&prefix, tenant);
+    return z_keyexpr_join(rewritten, z_loan(prefix), key_expr);
+}
+
+void on_sample(z_loaned_sample_t* sample, void* context) {}
+
+void on_watch(z_loaned_sample_t* sample, bool is_initial, void* context) {}
+
+void assert_keyexpr_eq(const z_loaned_keyexpr_t* key_expr, const char* expected) {
+    z_view_string_t ke_str;
+    z_keyexpr_as_view_string(key_expr, &ke_str);
+    assert(z_string_len(z_loan(ke_str)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(ke_str)), expected, strlen(expected)) == 0);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    int calls = 0;
+    zc_owned_closure_keyexpr_interceptor_t interceptor;
+    z_closure(&interceptor, intercept, NULL, (void*)&calls);
+    zc_session_set_keyexpr_interceptor(z_loan(s), z_move(interceptor));
+
+    z_view_keyexpr_t allowed, forbidden;
+    z_view_keyexpr_from_str(&allowed, "zenoh/test/interceptor");
+    z_view_keyexpr_from_str(&forbidden, "forbidden/test/interceptor");
+
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(forbidden), NULL) == Z_EDENIED);
+    assert(!z_internal_check(pub));
+    assert(calls == 1);
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(allowed), NULL) == Z_OK);
+    assert(calls == 2);
+    assert_keyexpr_eq(z_publisher_keyexpr(z_loan(pub)), "tenant/zenoh/test/interceptor");
+
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(forbidden), z_move(callback), NULL) == Z_EDENIED);
+    assert(!z_internal_check(sub));
+    assert(!z_internal_check(callback));
+    assert(calls == 3);
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(allowed), z_move(callback), NULL) == Z_OK);
+    assert(calls == 4);
+    assert_keyexpr_eq(z_subscriber_keyexpr(z_loan(sub)), "tenant/zenoh/test/interceptor");
+    z_drop(z_move(sub));
+    z_drop(z_move(pub));
+
+    // the other kinds of entities are intercepted as well
+    z_owned_querier_t querier;
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(forbidden), NULL) == Z_EDENIED);
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(allowed), NULL) == Z_OK);
+    assert_keyexpr_eq(z_querier_keyexpr(z_loan(querier)), "tenant/zenoh/test/interceptor");
+    z_drop(z_move(querier));
+    assert(calls == 6);
+
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(z_liveliness_declare_subscriber(z_loan(s), &sub, z_loan(forbidden), z_move(callback), NULL) == Z_EDENIED);
+    assert(!z_internal_check(sub));
+    assert(calls == 7);
+
+    ze_owned_advanced_publisher_t adv_pub;
+    assert(ze_declare_advanced_publisher(z_loan(s), &adv_pub, z_loan(forbidden), NULL) == Z_EDENIED);
+    assert(ze_declare_advanced_publisher(z_loan(s), &adv_pub, z_loan(allowed), NULL) == Z_OK);
+    assert_keyexpr_eq(ze_advanced_publisher_keyexpr(z_loan(adv_pub)), "tenant/zenoh/test/interceptor");
+    z_drop(z_move(adv_pub));
+    assert(calls == 9);
+
+    ze_owned_advanced_subscriber_t adv_sub;
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(ze_declare_advanced_subscriber(z_loan(s), &adv_sub, z_loan(forbidden), z_move(callback), NULL) ==
+           Z_EDENIED);
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(ze_declare_advanced_subscriber(z_loan(s), &adv_sub, z_loan(allowed), z_move(callback), NULL) == Z_OK);
+    assert_keyexpr_eq(ze_advanced_subscriber_keyexpr(z_loan(adv_sub)), "tenant/zenoh/test/interceptor");
+    z_drop(z_move(adv_sub));
+    assert(calls == 11);
+
+    zc_owned_stream_listener_t listener;
+    assert(zc_declare_stream_listener(z_loan(s), &listener, z_loan(forbidden), NULL) == Z_EDENIED);
+    assert(!z_internal_check(listener));
+    zc_owned_stream_t stream;
+    assert(zc_stream_open(z_loan(s), &stream, z_loan(forbidden), NULL) == Z_EDENIED);
+    assert(!z_internal_check(stream));
+    assert(calls == 13);
+
+    zc_owned_closure_watch_t watch_callback;
+    z_closure(&watch_callback, on_watch, NULL, NULL);
+    zc_owned_watch_t watch;
+    assert(zc_declare_watch(z_loan(s), &watch, z_loan(forbidden), z_move(watch_callback), NULL) == Z_EDENIED);
+    assert(!z_internal_check(watch));
+    assert(calls == 14);
+
+    // removing the interceptor
+    z_internal_null(&interceptor);
+    zc_session_set_keyexpr_interceptor(z_loan(s), z_move(interceptor));
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(forbidden), NULL) == Z_OK);
+    assert(calls == 14);
+    assert_keyexpr_eq(z_publisher_keyexpr(z_loan(pub)), "forbidden/test/interceptor");
+    z_drop(z_move(pub));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -94,6 +94,97 @@ void test_liveliness_sub() {
     assert(context.token2_drop);
 }
 
+void test_liveliness_token_session_close() {
+    const char* expr = "zenoh/liveliness/test/*";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+
+    assert(z_open(&s1, z_move(c1), NULL) == Z_OK);
+    assert(z_open(&s2, z_move(c2), NULL) == Z_OK);
+
+    z_owned_closure_sample_t closure;
+    context_t context = {false, false, false, false};
+    z_closure(&closure, on_receive, NULL, (void*)(&context));
+
+    z_owned_subscriber_t sub;
+    assert(z_liveliness_declare_subscriber(z_loan(s2), &sub, z_loan(k), z_move(closure), NULL) == Z_OK);
+
+    z_sleep_s(1);
+    z_owned_liveliness_token_t t1;
+    assert(z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), NULL) == Z_OK);
+    assert(z_internal_check(t1));
+    z_sleep_s(1);
+    assert(context.token1_put);
+    assert(!context.token1_drop);
+
+    // the token is lost along with the session which declared it
+    assert(z_close(z_loan_mut(s1), NULL) == Z_OK);
+    z_sleep_s(1);
+    assert(context.token1_drop);
+
+    z_drop(z_move(t1));
+    z_drop(z_move(sub));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
+void test_liveliness_sub_history() {
+    const char* expr = "zenoh/liveliness/test/*";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+
+    assert(z_open(&s1, z_move(c1), NULL) == Z_OK);
+    assert(z_open(&s2, z_move(c2), NULL) == Z_OK);
+
+    z_sleep_s(1);
+    z_owned_liveliness_token_t t1;
+    assert(z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), NULL) == Z_OK);
+    z_sleep_s(1);
+
+    // the token declared before the subscribers is only notified to the one with history
+    z_liveliness_subscriber_options_t opts;
+    z_liveliness_subscriber_options_default(&opts);
+    assert(!opts.history);
+    z_owned_closure_sample_t closure;
+    context_t context = {false, false, false, false};
+    z_closure(&closure, on_receive, NULL, (void*)(&context));
+    z_owned_subscriber_t sub;
+    assert(z_liveliness_declare_subscriber(z_loan(s2), &sub, z_loan(k), z_move(closure), &opts) == Z_OK);
+
+    opts.history = true;
+    context_t history_context = {false, false, false, false};
+    z_closure(&closure, on_receive, NULL, (void*)(&history_context));
+    z_owned_subscriber_t history_sub;
+    assert(z_liveliness_declare_subscriber(z_loan(s2), &history_sub, z_loan(k), z_move(closure), &opts) == Z_OK);
+    z_sleep_s(1);
+
+    assert(!context.token1_put);
+    assert(history_context.token1_put);
+
+    // both subscribers are notified of the changes following their declaration
+    z_liveliness_undeclare_token(z_move(t1));
+    z_sleep_s(1);
+    assert(context.token1_drop);
+    assert(history_context.token1_drop);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(history_sub));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
 void test_liveliness_get() {
     const char* expr = "zenoh/liveliness/test/*";
 
@@ -142,7 +233,115 @@ void test_liveliness_get() {
     z_drop(z_move(s2));
 }
 
+void test_liveliness_get_snapshot() {
+    const char* expr = "zenoh/liveliness/test/*";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1, k2;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+    z_view_keyexpr_from_str(&k2, token2_expr);
+
+    assert(z_open(&s1, z_move(c1), NULL) == Z_OK);
+    assert(z_open(&s2, z_move(c2), NULL) == Z_OK);
+
+    z_sleep_s(1);
+    z_owned_liveliness_token_t t1, t2;
+    assert(z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), NULL) == Z_OK);
+    assert(z_liveliness_declare_token(z_loan(s2), &t2, z_loan(k2), NULL) == Z_OK);
+    z_sleep_s(1);
+
+    // the alive tokens of both the remote and the local session are returned
+    z_liveliness_get_options_t opts;
+    z_liveliness_get_options_default(&opts);
+    opts.timeout_ms = 1000;
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t cb;
+    z_fifo_channel_reply_new(&cb, &handler, 16);
+    assert(z_liveliness_get(z_loan(s2), z_loan(k), z_move(cb), &opts) == Z_OK);
+
+    context_t context = {false, false, false, false};
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(handler), &reply) == Z_OK) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        z_owned_sample_t sample;
+        z_sample_clone(&sample, z_reply_ok(z_loan(reply)));
+        on_receive(z_loan_mut(sample), (void*)&context);
+        z_drop(z_move(sample));
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(handler));
+    assert(context.token1_put);
+    assert(context.token2_put);
+
+    z_drop(z_move(t1));
+    z_drop(z_move(t2));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
+void check_change(const z_loaned_fifo_handler_sample_t* samples, const char* expr, z_sample_kind_t kind) {
+    z_owned_sample_t sample;
+    assert(z_recv(samples, &sample) == Z_OK);
+    assert(z_sample_kind(z_loan(sample)) == kind);
+    z_view_string_t ks;
+    z_keyexpr_as_view_string(z_sample_keyexpr(z_loan(sample)), &ks);
+    assert(z_string_len(z_loan(ks)) == strlen(expr));
+    assert(strncmp(expr, z_string_data(z_loan(ks)), strlen(expr)) == 0);
+    z_drop(z_move(sample));
+}
+
+void test_liveliness_sub_channel() {
+    const char* expr = "zenoh/liveliness/test/*";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+
+    assert(z_open(&s1, z_move(c1), NULL) == Z_OK);
+    assert(z_open(&s2, z_move(c2), NULL) == Z_OK);
+
+    // liveliness changes are delivered as samples, usable with the sample channels
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t closure;
+    z_fifo_channel_sample_new(&closure, &samples, 16);
+    z_owned_subscriber_t sub;
+    assert(z_liveliness_declare_subscriber(z_loan(s2), &sub, z_loan(k), z_move(closure), NULL) == Z_OK);
+    z_sleep_s(1);
+
+    z_owned_liveliness_token_t t1;
+    assert(z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), NULL) == Z_OK);
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_view_string_t token_ke;
+    z_keyexpr_as_view_string(zc_liveliness_token_keyexpr(z_loan(t1)), &token_ke);
+    assert(z_string_len(z_loan(token_ke)) == strlen(token1_expr));
+    assert(strncmp(token1_expr, z_string_data(z_loan(token_ke)), strlen(token1_expr)) == 0);
+#endif
+    check_change(z_loan(samples), token1_expr, Z_SAMPLE_KIND_PUT);
+
+    assert(z_liveliness_undeclare_token(z_move(t1)) == Z_OK);
+    check_change(z_loan(samples), token1_expr, Z_SAMPLE_KIND_DELETE);
+
+    z_drop(z_move(sub));
+    z_owned_sample_t sample;
+    assert(z_recv(z_loan(samples), &sample) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(samples));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
 int main(int argc, char** argv) {
     test_liveliness_sub();
+    test_liveliness_sub_channel();
+    test_liveliness_token_session_close();
+    test_liveliness_sub_history();
     test_liveliness_get();
+    test_liveliness_get_snapshot();
 }
@@ -0,0 +1,202 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/locality";
+
+void on_receive(z_loaned_sample_t* s, void* context) {
+    int* received = (int*)context;
+    (*received)++;
+}
+
+void test_publisher_allowed_destination(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, int* received) {
+    z_publisher_options_t opts;
+    z_publisher_options_default(&opts);
+    opts.allowed_destination = ZC_LOCALITY_REMOTE;
+    z_owned_publisher_t remote_pub;
+    assert(z_declare_publisher(s, &remote_pub, ke, &opts) == Z_OK);
+
+    z_publisher_options_default(&opts);
+    opts.allowed_destination = ZC_LOCALITY_SESSION_LOCAL;
+    z_owned_publisher_t local_pub;
+    assert(z_declare_publisher(s, &local_pub, ke, &opts) == Z_OK);
+
+    *received = 0;
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "remote");
+    assert(z_publisher_put(z_loan(remote_pub), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(*received == 0);
+
+    z_bytes_from_static_str(&payload, "local");
+    assert(z_publisher_put(z_loan(local_pub), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(*received == 1);
+
+    z_drop(z_move(remote_pub));
+    z_drop(z_move(local_pub));
+}
+
+void test_publisher_self_delivery(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, int* received) {
+    z_publisher_options_t opts;
+    z_publisher_options_default(&opts);
+    assert(opts.self_delivery);
+    opts.self_delivery = false;
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, ke, &opts) == Z_OK);
+
+    *received = 0;
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "self");
+    assert(z_publisher_put(z_loan(pub), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(*received == 0);
+    z_drop(z_move(pub));
+
+    z_publisher_options_default(&opts);
+    opts.self_delivery = false;
+    opts.allowed_destination = ZC_LOCALITY_SESSION_LOCAL;
+    assert(z_declare_publisher(s, &pub, ke, &opts) == Z_EINVAL);
+    assert(!z_internal_check(pub));
+}
+
+void test_put_allowed_destination(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, int* received) {
+    *received = 0;
+    z_put_options_t opts;
+    z_put_options_default(&opts);
+    opts.allowed_destination = ZC_LOCALITY_REMOTE;
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "remote");
+    assert(z_put(s, ke, z_move(payload), &opts) == Z_OK);
+    z_sleep_ms(100);
+    assert(*received == 0);
+
+    z_put_options_default(&opts);
+    opts.allowed_destination = ZC_LOCALITY_SESSION_LOCAL;
+    z_bytes_from_static_str(&payload, "local");
+    assert(z_put(s, ke, z_move(payload), &opts) == Z_OK);
+    z_sleep_ms(100);
+    assert(*received == 1);
+}
+
+void on_query(z_loaned_query_t* q, void* context) {
+    int* queries = (int*)context;
+    (*queries)++;
+}
+
+void on_reply(z_loaned_reply_t* r, void* context) {}
+
+void test_subscriber_allowed_origin(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/locality/subscriber");
+    int received = 0;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_subscriber_options_t opts;
+    z_subscriber_options_default(&opts);
+    opts.allowed_origin = ZC_LOCALITY_REMOTE;
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &opts) == Z_OK);
+    assert(zc_subscriber_allowed_origin(z_loan(sub)) == ZC_LOCALITY_REMOTE);
+    z_view_string_t sub_ke;
+    z_keyexpr_as_view_string(z_subscriber_keyexpr(z_loan(sub)), &sub_ke);
+    assert(z_string_len(z_loan(sub_ke)) == strlen("zenoh/test/locality/subscriber"));
+
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "local");
+    assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(received == 0);
+    z_drop(z_move(sub));
+
+    // subscribers declared without options receive publications from any origin
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    assert(zc_subscriber_allowed_origin(z_loan(sub)) == ZC_LOCALITY_ANY);
+    z_drop(z_move(sub));
+}
+
+void test_queryable_allowed_origin(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/locality/queryable");
+    int queries = 0;
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, (void*)&queries);
+    z_queryable_options_t opts;
+    z_queryable_options_default(&opts);
+    assert(opts.allowed_origin == ZC_LOCALITY_ANY);
+    opts.allowed_origin = ZC_LOCALITY_REMOTE;
+    z_owned_queryable_t remote_queryable;
+    assert(z_declare_queryable(s, &remote_queryable, z_loan(ke), z_move(callback), &opts) == Z_OK);
+
+    z_owned_closure_reply_t reply_callback;
+    z_closure(&reply_callback, on_reply, NULL, NULL);
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(queries == 0);
+
+    z_closure(&callback, on_query, NULL, (void*)&queries);
+    z_queryable_options_default(&opts);
+    opts.allowed_origin = ZC_LOCALITY_SESSION_LOCAL;
+    z_owned_queryable_t local_queryable;
+    assert(z_declare_queryable(s, &local_queryable, z_loan(ke), z_move(callback), &opts) == Z_OK);
+
+    z_closure(&reply_callback, on_reply, NULL, NULL);
+    assert(z_get(s, z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(queries == 1);
+
+    z_drop(z_move(remote_queryable));
+    z_drop(z_move(local_queryable));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    int received = 0;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    test_publisher_allowed_destination(z_loan(s), z_loan(ke), &received);
+    test_put_allowed_destination(z_loan(s), z_loan(ke), &received);
+    test_publisher_self_delivery(z_loan(s), z_loan(ke), &received);
+    test_subscriber_allowed_origin(z_loan(s));
+    test_queryable_allowed_origin(z_loan(s));
+
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
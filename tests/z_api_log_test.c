@@ -0,0 +1,71 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+typedef struct log_stats_t {
+    int errors;
+    int keyexpr_errors;
+    int lower;
+} log_stats_t;
+
+void on_log_record(zc_log_severity_t severity, const z_loaned_string_t* target, const z_loaned_string_t* msg,
+                   void* context) {
+    log_stats_t* stats = (log_stats_t*)context;
+    if (severity < ZC_LOG_SEVERITY_ERROR) {
+        stats->lower++;
+        return;
+    }
+    stats->errors++;
+    const char* keyexpr_target = "zenohc::keyexpr";
+    if (z_string_len(target) == strlen(keyexpr_target) &&
+        strncmp(z_string_data(target), keyexpr_target, strlen(keyexpr_target)) == 0) {
+        stats->keyexpr_errors++;
+    }
+}
+
+int main(int argc, char** argv) {
+    log_stats_t stats = {0, 0, 0};
+    zc_owned_closure_log_record_t callback;
+    z_closure(&callback, on_log_record, NULL, (void*)&stats);
+    zc_init_log_with_record_callback(ZC_LOG_SEVERITY_ERROR, z_move(callback));
+
+    z_owned_keyexpr_t ke;
+    assert(z_keyexpr_from_str(&ke, "zenoh//invalid") != Z_OK);
+    assert(stats.errors == 1);
+    assert(stats.keyexpr_errors == 1);
+    assert(stats.lower == 0);
+
+    zc_log_set_min_severity(ZC_LOG_SEVERITY_TRACE);
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+    z_drop(z_move(s));
+    assert(stats.lower > 0);
+
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
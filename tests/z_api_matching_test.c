@@ -0,0 +1,265 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* pub_keyexpr = "zenoh/test/matching/pub";
+const char* querier_keyexpr = "zenoh/test/matching/querier";
+
+void on_sample(z_loaned_sample_t* s, void* context) {}
+void on_query(z_loaned_query_t* q, void* context) {}
+
+void on_matching_status(const zc_matching_status_t* status, void* context) {
+    int* changes = (int*)context;
+    (*changes)++;
+}
+
+void test_publisher_get_matching_status(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), NULL) == Z_OK);
+
+    zc_matching_status_t status;
+    assert(zc_publisher_get_matching_status(z_loan(pub), &status) == Z_OK);
+    assert(!status.matching);
+
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(zc_publisher_get_matching_status(z_loan(pub), &status) == Z_OK);
+    assert(status.matching);
+
+    z_drop(z_move(sub));
+    z_sleep_ms(100);
+    assert(zc_publisher_get_matching_status(z_loan(pub), &status) == Z_OK);
+    assert(!status.matching);
+
+    z_drop(z_move(pub));
+}
+
+void test_querier_get_matching_status(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, querier_keyexpr);
+    z_owned_querier_t querier;
+    assert(z_declare_querier(s, &querier, z_loan(ke), NULL) == Z_OK);
+
+    zc_matching_status_t status;
+    assert(zc_querier_get_matching_status(z_loan(querier), &status) == Z_OK);
+    assert(!status.matching);
+
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(s, &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(zc_querier_get_matching_status(z_loan(querier), &status) == Z_OK);
+    assert(status.matching);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(querier));
+}
+
+void test_publisher_matching_listener(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), NULL) == Z_OK);
+
+    int changes = 0;
+    zc_owned_closure_matching_status_t callback;
+    z_closure(&callback, on_matching_status, NULL, (void*)&changes);
+    zc_owned_matching_listener_t listener;
+    assert(zc_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(callback)) == Z_OK);
+    assert(z_internal_check(listener));
+
+    z_owned_closure_sample_t sub_callback;
+    z_closure(&sub_callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(sub_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(changes == 1);
+
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    assert(!z_internal_check(listener));
+
+    z_drop(z_move(sub));
+    z_sleep_ms(100);
+    assert(changes == 1);
+
+    z_drop(z_move(pub));
+}
+
+void test_publisher_matching_channel(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), NULL) == Z_OK);
+
+    zc_owned_closure_matching_status_t callback;
+    zc_owned_fifo_handler_matching_status_t handler;
+    zc_fifo_channel_matching_status_new(&callback, &handler, 16);
+    zc_owned_matching_listener_t listener;
+    assert(zc_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(callback)) == Z_OK);
+
+    zc_matching_status_t status;
+    assert(z_try_recv(z_loan(handler), &status) == Z_CHANNEL_NODATA);
+
+    z_owned_closure_sample_t sub_callback;
+    z_closure(&sub_callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(sub_callback), NULL) == Z_OK);
+    assert(z_recv(z_loan(handler), &status) == Z_OK);
+    assert(status.matching);
+
+    z_drop(z_move(sub));
+    assert(z_recv(z_loan(handler), &status) == Z_OK);
+    assert(!status.matching);
+
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    assert(z_recv(z_loan(handler), &status) == Z_CHANNEL_DISCONNECTED);
+
+    z_drop(z_move(handler));
+    z_drop(z_move(pub));
+}
+
+void test_matching_listener_pause(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), NULL) == Z_OK);
+
+    int changes = 0;
+    zc_owned_closure_matching_status_t callback;
+    z_closure(&callback, on_matching_status, NULL, (void*)&changes);
+    zc_owned_matching_listener_t listener;
+    assert(zc_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(callback)) == Z_OK);
+    assert(!zc_matching_listener_is_paused(&listener));
+
+    // the changes are ignored while paused
+    assert(zc_matching_listener_pause(&listener) == Z_OK);
+    assert(zc_matching_listener_is_paused(&listener));
+    z_owned_closure_sample_t sub_callback;
+    z_closure(&sub_callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(sub_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(changes == 0);
+
+    // the listener is still declared after resuming
+    assert(zc_matching_listener_resume(&listener) == Z_OK);
+    assert(!zc_matching_listener_is_paused(&listener));
+    z_drop(z_move(sub));
+    z_sleep_ms(100);
+    assert(changes == 1);
+
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    assert(zc_matching_listener_pause(&listener) == Z_EINVAL);
+    assert(!zc_matching_listener_is_paused(&listener));
+    z_drop(z_move(pub));
+}
+
+void test_matching_listener_dependencies(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), NULL) == Z_OK);
+
+    zc_owned_closure_matching_status_t callback;
+    zc_owned_fifo_handler_matching_status_t handler;
+    zc_fifo_channel_matching_status_new(&callback, &handler, 16);
+    zc_owned_matching_listener_t listener;
+    assert(zc_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(callback)) == Z_OK);
+
+    // the listener is undeclared along with its publisher, and its channel is disconnected
+    assert(z_undeclare_publisher(z_move(pub)) == Z_OK);
+    zc_matching_status_t status;
+    assert(z_recv(z_loan(handler), &status) == Z_CHANNEL_DISCONNECTED);
+    assert(z_internal_check(listener));
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    assert(!z_internal_check(listener));
+    z_drop(z_move(handler));
+
+    z_view_keyexpr_from_str(&ke, querier_keyexpr);
+    z_owned_querier_t querier;
+    assert(z_declare_querier(s, &querier, z_loan(ke), NULL) == Z_OK);
+    int changes = 0;
+    z_closure(&callback, on_matching_status, NULL, (void*)&changes);
+    assert(zc_querier_declare_matching_listener(z_loan(querier), &listener, z_move(callback)) == Z_OK);
+    z_drop(z_move(querier));
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, NULL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(s, &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    assert(changes == 0);
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    z_drop(z_move(queryable));
+}
+
+void test_matching_listener_session_close(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, pub_keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    int changes = 0;
+    zc_owned_closure_matching_status_t callback;
+    z_closure(&callback, on_matching_status, NULL, (void*)&changes);
+    zc_owned_matching_listener_t listener;
+    assert(zc_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(callback)) == Z_OK);
+
+    // the entities can be undeclared in any order once the session is closed
+    assert(z_close(z_loan_mut(s), NULL) == Z_OK);
+    z_drop(z_move(pub));
+    assert(zc_undeclare_matching_listener(z_move(listener)) == Z_OK);
+    assert(changes == 0);
+    z_drop(z_move(s));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_publisher_get_matching_status(z_loan(s));
+    test_querier_get_matching_status(z_loan(s));
+    test_publisher_matching_listener(z_loan(s));
+    test_publisher_matching_channel(z_loan(s));
+    test_matching_listener_pause(z_loan(s));
+    test_matching_listener_dependencies(z_loan(s));
+    test_matching_listener_session_close();
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
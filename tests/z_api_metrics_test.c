@@ -0,0 +1,94 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/metrics";
+
+typedef struct metrics_context_t {
+    int calls;
+    zc_metrics_t last;
+    volatile bool dropped;
+} metrics_context_t;
+
+void on_metrics(const zc_metrics_t* metrics, void* context) {
+    metrics_context_t* ctx = (metrics_context_t*)context;
+    ctx->calls++;
+    ctx->last = *metrics;
+}
+
+void on_metrics_drop(void* context) { ((metrics_context_t*)context)->dropped = true; }
+
+void on_sample(z_loaned_sample_t* sample, void* context) {}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), "adminspace/enabled", "true") == Z_OK);
+    assert(zc_config_insert_json5(z_loan_mut(config), "adminspace/permissions/read", "true") == Z_OK);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_sample_t sample_callback;
+    z_closure(&sample_callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+
+    // a zero period is rejected
+    metrics_context_t ctx = {0};
+    z_owned_closure_metrics_t callback;
+    z_closure(&callback, on_metrics, on_metrics_drop, (void*)&ctx);
+    zc_owned_metrics_subscriber_t metrics_sub;
+    assert(zc_metrics_subscribe(z_loan(s), &metrics_sub, z_move(callback), 0) == Z_EINVAL);
+    assert(!z_internal_check(metrics_sub));
+    assert(ctx.dropped);
+
+    ctx.dropped = false;
+    z_closure(&callback, on_metrics, on_metrics_drop, (void*)&ctx);
+    assert(zc_metrics_subscribe(z_loan(s), &metrics_sub, z_move(callback), 100) == Z_OK);
+    assert(z_internal_check(metrics_sub));
+    z_sleep_ms(1000);
+    z_drop(z_move(metrics_sub));
+    for (int i = 0; i < 50 && !ctx.dropped; i++) {
+        z_sleep_ms(100);
+    }
+    assert(ctx.dropped);
+    // the metrics are polled periodically
+    assert(ctx.calls >= 2);
+    // the node knows about its own subscriber
+    assert(ctx.last.links >= ctx.last.sessions);
+    assert(ctx.last.subscribers >= 1);
+    int calls = ctx.calls;
+    z_sleep_ms(300);
+    assert(ctx.calls == calls);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
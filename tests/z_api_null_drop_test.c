@@ -45,6 +45,183 @@
         assert(!z_internal_check(v)); \
     }
 
+// make sure that the drop and undeclare functions ignore NULL
+void test_null_pointers(void) {
+    z_session_drop(NULL);
+    z_keyexpr_drop(NULL);
+    z_config_drop(NULL);
+    z_hello_drop(NULL);
+    z_closure_sample_drop(NULL);
+    z_closure_query_drop(NULL);
+    z_closure_reply_drop(NULL);
+    z_closure_hello_drop(NULL);
+    z_string_drop(NULL);
+    z_string_array_drop(NULL);
+    z_sample_drop(NULL);
+    z_query_drop(NULL);
+    z_slice_drop(NULL);
+    z_bytes_drop(NULL);
+    z_bytes_writer_drop(NULL);
+    z_encoding_drop(NULL);
+    z_publisher_drop(NULL);
+    z_subscriber_drop(NULL);
+    z_queryable_drop(NULL);
+    z_reply_drop(NULL);
+    z_reply_err_drop(NULL);
+    z_fifo_handler_sample_drop(NULL);
+    z_ring_handler_reply_drop(NULL);
+    z_liveliness_token_drop(NULL);
+    ze_serializer_drop(NULL);
+    z_task_drop(NULL);
+    z_mutex_drop(NULL);
+    z_condvar_drop(NULL);
+
+    assert(z_undeclare_publisher(NULL) == Z_OK);
+    assert(z_undeclare_subscriber(NULL) == Z_OK);
+    assert(z_undeclare_queryable(NULL) == Z_OK);
+    assert(z_liveliness_undeclare_token(NULL) == Z_OK);
+    assert(z_task_join(NULL) == Z_OK);
+    z_task_detach(NULL);
+}
+
+typedef struct null_reply_results_t {
+    z_result_t reply;
+    z_result_t reply_err;
+    int replies;
+} null_reply_results_t;
+
+void on_query(z_loaned_query_t* query, void* context) {
+    null_reply_results_t* results = (null_reply_results_t*)context;
+    results->reply = z_query_reply(query, z_query_keyexpr(query), NULL, NULL);
+    results->reply_err = z_query_reply_err(query, NULL, NULL);
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "reply");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void on_reply(z_loaned_reply_t* reply, void* context) { ((null_reply_results_t*)context)->replies++; }
+
+// make sure that the consuming functions reject a NULL moved argument, and still consume the options
+void test_null_moved_arguments(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/null_drop");
+
+    assert(z_undeclare_keyexpr(z_loan(s), NULL) == Z_OK);
+
+    z_owned_bytes_t attachment;
+    z_bytes_from_static_str(&attachment, "attachment");
+    z_put_options_t put_opts;
+    z_put_options_default(&put_opts);
+    put_opts.attachment = z_move(attachment);
+    assert(z_put(z_loan(s), z_loan(ke), NULL, &put_opts) == Z_EINVAL);
+    assert(!z_internal_check(attachment));
+
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    z_bytes_from_static_str(&attachment, "attachment");
+    z_publisher_put_options_t pub_put_opts;
+    z_publisher_put_options_default(&pub_put_opts);
+    pub_put_opts.attachment = z_move(attachment);
+    assert(z_publisher_put(z_loan(pub), NULL, &pub_put_opts) == Z_EINVAL);
+    assert(!z_internal_check(attachment));
+    z_drop(z_move(pub));
+
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "payload");
+    z_get_options_t get_opts;
+    z_get_options_default(&get_opts);
+    get_opts.payload = z_move(payload);
+    assert(z_get(z_loan(s), z_loan(ke), "", NULL, &get_opts) == Z_EINVAL);
+    assert(!z_internal_check(payload));
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_owned_querier_t querier;
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(ke), NULL) == Z_OK);
+    z_bytes_from_static_str(&payload, "payload");
+    z_querier_get_options_t querier_get_opts;
+    z_querier_get_options_default(&querier_get_opts);
+    querier_get_opts.payload = z_move(payload);
+    assert(z_querier_get(z_loan(querier), "", NULL, &querier_get_opts) == Z_EINVAL);
+    assert(!z_internal_check(payload));
+    z_drop(z_move(querier));
+#endif
+
+    null_reply_results_t results = {Z_OK, Z_OK, 0};
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, (void*)&results);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    z_owned_closure_reply_t reply_callback;
+    z_closure(&reply_callback, on_reply, NULL, (void*)&results);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    for (int i = 0; i < 50 && results.replies == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(results.replies == 1);
+    assert(results.reply == Z_EINVAL);
+    assert(results.reply_err == Z_EINVAL);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(s));
+}
+
+// make sure that the functions consuming a callback or a config reject a NULL one, leaving their output in gravestone state
+void test_null_moved_callbacks(void) {
+    z_owned_session_t s;
+    assert(z_open(&s, NULL, NULL) == Z_EINVAL);
+    assert(!z_internal_check(s));
+    assert(z_scout(NULL, NULL, NULL) == Z_EINVAL);
+
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/null_drop");
+
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), NULL, NULL) == Z_EINVAL);
+    assert(!z_internal_check(sub));
+    assert(z_declare_background_subscriber(z_loan(s), z_loan(ke), NULL, NULL) == Z_EINVAL);
+    assert(z_liveliness_declare_subscriber(z_loan(s), &sub, z_loan(ke), NULL, NULL) == Z_EINVAL);
+    assert(!z_internal_check(sub));
+    assert(z_liveliness_get(z_loan(s), z_loan(ke), NULL, NULL) == Z_EINVAL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), NULL, NULL) == Z_EINVAL);
+    assert(!z_internal_check(queryable));
+    assert(z_declare_background_queryable(z_loan(s), z_loan(ke), NULL, NULL) == Z_EINVAL);
+    assert(z_info_peers_zid(z_loan(s), NULL) == Z_EINVAL);
+    assert(z_info_routers_zid(z_loan(s), NULL) == Z_EINVAL);
+
+    z_owned_bytes_t bytes;
+    z_bytes_from_slice(&bytes, NULL);
+    assert(z_bytes_len(z_loan(bytes)) == 0);
+    z_drop(z_move(bytes));
+    z_bytes_from_string(&bytes, NULL);
+    assert(z_bytes_len(z_loan(bytes)) == 0);
+    z_drop(z_move(bytes));
+    z_bytes_writer_finish(NULL, &bytes);
+    assert(z_bytes_len(z_loan(bytes)) == 0);
+    z_drop(z_move(bytes));
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_owned_string_array_t excluded;
+    z_string_array_new(&excluded);
+    z_subscriber_options_t sub_opts;
+    z_subscriber_options_default(&sub_opts);
+    sub_opts.excluded_key_exprs = z_move(excluded);
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), NULL, &sub_opts) == Z_EINVAL);
+    assert(!z_internal_check(excluded));
+    assert(zc_concurrent_close_handle_wait(NULL) == Z_EINVAL);
+#endif
+
+    z_drop(z_move(s));
+}
+
 int main(void) {
     TEST(z_owned_session_t)
     TEST(z_owned_keyexpr_t)
@@ -76,5 +253,9 @@ int main(void) {
     // TEST(z_owned_mutex_t)
     // TEST(z_owned_condvar_t)
 
+    test_null_pointers();
+    test_null_moved_arguments();
+    test_null_moved_callbacks();
+
     return 0;
 }
\ No newline at end of file
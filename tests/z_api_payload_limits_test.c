@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/payload_limits";
+
+z_result_t put_bytes(const z_loaned_publisher_t* pub, size_t len) {
+    uint8_t* data = (uint8_t*)calloc(len, 1);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, data, len);
+    free(data);
+    return z_publisher_put(pub, z_move(payload), NULL);
+}
+
+z_result_t put_bytes_with_attachment(const z_loaned_publisher_t* pub, size_t len, size_t attachment_len) {
+    uint8_t* data = (uint8_t*)calloc(len + attachment_len, 1);
+    z_owned_bytes_t payload, attachment;
+    z_bytes_copy_from_buf(&payload, data, len);
+    z_bytes_copy_from_buf(&attachment, data, attachment_len);
+    free(data);
+    z_publisher_put_options_t opts;
+    z_publisher_put_options_default(&opts);
+    opts.attachment = z_move(attachment);
+    return z_publisher_put(pub, z_move(payload), &opts);
+}
+
+z_result_t session_put_bytes(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, size_t len) {
+    uint8_t* data = (uint8_t*)calloc(len, 1);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, data, len);
+    free(data);
+    return z_put(s, ke, z_move(payload), NULL);
+}
+
+z_result_t advanced_put_bytes(const ze_loaned_advanced_publisher_t* pub, size_t len) {
+    uint8_t* data = (uint8_t*)calloc(len, 1);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, data, len);
+    free(data);
+    return ze_advanced_publisher_put(pub, z_move(payload), NULL);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), "transport/link/tx/batch_size", "2048") == Z_OK);
+    assert(zc_config_insert_json5(z_loan_mut(config), "transport/link/rx/max_message_size", "100000") == Z_OK);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    // fragmented publications are limited by the maximum reassembled message size
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    assert(zc_publisher_max_payload_size(z_loan(pub)) == 100000);
+    assert(put_bytes(z_loan(pub), 50000) == Z_OK);
+    assert(put_bytes(z_loan(pub), 100001) == Z_EFRAGMENT);
+    z_drop(z_move(pub));
+
+    // unfragmented publications are limited by the batch size
+    z_publisher_options_t opts;
+    z_publisher_options_default(&opts);
+    assert(opts.allow_fragmentation);
+    opts.allow_fragmentation = false;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), &opts) == Z_OK);
+    size_t max_size = zc_publisher_max_payload_size(z_loan(pub));
+    assert(max_size > 0);
+    assert(max_size < 2048);
+    assert(put_bytes(z_loan(pub), max_size) == Z_OK);
+    assert(put_bytes(z_loan(pub), max_size + 1) == Z_EFRAGMENT);
+    assert(put_bytes(z_loan(pub), 50000) == Z_EFRAGMENT);
+    // the attachment counts in the publication size
+    assert(put_bytes_with_attachment(z_loan(pub), max_size - 10, 10) == Z_OK);
+    assert(put_bytes_with_attachment(z_loan(pub), max_size - 10, 11) == Z_EFRAGMENT);
+    z_drop(z_move(pub));
+
+    // advanced publishers have the same limits
+    ze_advanced_publisher_options_t adv_opts;
+    ze_advanced_publisher_options_default(&adv_opts);
+    adv_opts.publisher_options.allow_fragmentation = false;
+    ze_owned_advanced_publisher_t adv_pub;
+    assert(ze_declare_advanced_publisher(z_loan(s), &adv_pub, z_loan(ke), &adv_opts) == Z_OK);
+    assert(advanced_put_bytes(z_loan(adv_pub), max_size) == Z_OK);
+    assert(advanced_put_bytes(z_loan(adv_pub), max_size + 1) == Z_EFRAGMENT);
+    z_drop(z_move(adv_pub));
+
+    // puts are fragmented, hence limited by the maximum reassembled message size
+    assert(session_put_bytes(z_loan(s), z_loan(ke), 50000) == Z_OK);
+    assert(session_put_bytes(z_loan(s), z_loan(ke), 100001) == Z_EFRAGMENT);
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
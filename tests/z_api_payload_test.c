@@ -103,6 +103,53 @@ void test_writer(void) {
     z_drop(z_move(payload));
 }
 
+void test_reader_fragmented(void) {
+    static uint8_t fragments[3][4] = {{0, 1, 2, 3}, {4, 5, 6, 7}, {8, 9, 10, 11}};
+    uint8_t data_out[12] = {0};
+
+    z_owned_bytes_writer_t writer;
+    z_bytes_writer_empty(&writer);
+    for (size_t i = 0; i < 3; i++) {
+        z_owned_bytes_t b;
+        z_bytes_from_static_buf(&b, fragments[i], 4);
+        assert(z_bytes_writer_append(z_loan_mut(writer), z_move(b)) == 0);
+    }
+    z_owned_bytes_t payload;
+    z_bytes_writer_finish(z_move(writer), &payload);
+    size_t n_fragments = 0;
+    z_bytes_slice_iterator_t it = z_bytes_get_slice_iterator(z_loan(payload));
+    z_view_slice_t v;
+    while (z_bytes_slice_iterator_next(&it, &v)) {
+        n_fragments++;
+    }
+    assert(n_fragments == 3);
+
+    // seeking and reading across the fragments, without making the payload contiguous
+    z_bytes_reader_t reader = z_bytes_get_reader(z_loan(payload));
+    assert(0 == z_bytes_reader_seek(&reader, 3, SEEK_SET));
+    assert(z_bytes_reader_tell(&reader) == 3);
+    assert(6 == z_bytes_reader_read(&reader, data_out, 6));
+    assert(!memcmp(data_out, (uint8_t[]){3, 4, 5, 6, 7, 8}, 6));
+    assert(z_bytes_reader_tell(&reader) == 9);
+    assert(3 == z_bytes_reader_remaining(&reader));
+
+    assert(0 == z_bytes_reader_seek(&reader, -5, SEEK_CUR));
+    assert(z_bytes_reader_tell(&reader) == 4);
+    assert(2 == z_bytes_reader_read(&reader, data_out, 2));
+    assert(data_out[0] == 4 && data_out[1] == 5);
+
+    assert(0 == z_bytes_reader_seek(&reader, -7, SEEK_END));
+    assert(z_bytes_reader_tell(&reader) == 5);
+    assert(7 == z_bytes_reader_read(&reader, data_out, 12));
+    assert(!memcmp(data_out, (uint8_t[]){5, 6, 7, 8, 9, 10, 11}, 7));
+    assert(z_bytes_reader_tell(&reader) == 12);
+    assert(0 == z_bytes_reader_read(&reader, data_out, 1));
+
+    assert(z_bytes_reader_seek(&reader, 13, SEEK_SET) < 0);
+
+    z_drop(z_move(payload));
+}
+
 void test_append(void) {
     uint8_t data[] = {0, 1, 2, 3, 4, 5, 6, 7, 8, 9};
     uint8_t data_out[10] = {0};
@@ -130,6 +177,36 @@ void test_append(void) {
     z_drop(z_move(payload));
 }
 
+void test_append_no_copy(void) {
+    static uint8_t first[] = {0, 1, 2, 3, 4};
+    static uint8_t second[] = {5, 6, 7, 8, 9};
+
+    z_owned_bytes_writer_t writer;
+    z_bytes_writer_empty(&writer);
+    z_owned_bytes_t b;
+    z_bytes_from_static_buf(&b, first, 5);
+    assert(z_bytes_writer_append(z_loan_mut(writer), z_move(b)) == 0);
+    assert(!z_internal_check(b));
+    z_bytes_from_static_buf(&b, second, 5);
+    assert(z_bytes_writer_append(z_loan_mut(writer), z_move(b)) == 0);
+    z_owned_bytes_t payload;
+    z_bytes_writer_finish(z_move(writer), &payload);
+    assert(z_bytes_len(z_loan(payload)) == 10);
+
+    // the appended payloads are referenced by the result, not copied into it
+    z_bytes_slice_iterator_t it = z_bytes_get_slice_iterator(z_loan(payload));
+    z_view_slice_t v;
+    assert(z_bytes_slice_iterator_next(&it, &v));
+    assert(z_slice_data(z_loan(v)) == first);
+    assert(z_slice_len(z_loan(v)) == 5);
+    assert(z_bytes_slice_iterator_next(&it, &v));
+    assert(z_slice_data(z_loan(v)) == second);
+    assert(z_slice_len(z_loan(v)) == 5);
+    assert(!z_bytes_slice_iterator_next(&it, &v));
+
+    z_drop(z_move(payload));
+}
+
 void custom_deleter(void *data, void *context) {
     (void)data;
     size_t *cnt = (size_t *)context;
@@ -211,6 +288,37 @@ void test_arithmetic(void) {
     TEST_ARITHMETIC(double, double, -105.001);
 }
 
+void test_deserialize_errors(void) {
+    z_owned_bytes_t payload;
+    // 3 bytes are neither a valid uint16 nor a valid uint32
+    uint8_t data[] = {1, 2, 3};
+    z_bytes_copy_from_buf(&payload, data, 3);
+    uint16_t u16;
+    uint32_t u32;
+    assert(ze_deserialize_uint16(z_loan(payload), &u16) == Z_EDESERIALIZE);
+    assert(ze_deserialize_uint32(z_loan(payload), &u32) == Z_EDESERIALIZE);
+    z_drop(z_move(payload));
+
+    // a length prefix announcing more bytes than the payload contains
+    ze_serialize_uint8(&payload, 10);
+    z_owned_string_t s;
+    assert(ze_deserialize_string(z_loan(payload), &s) == Z_EDESERIALIZE);
+    assert(!z_internal_check(s));
+    z_owned_slice_t slice;
+    assert(ze_deserialize_slice(z_loan(payload), &slice) == Z_EDESERIALIZE);
+    assert(!z_internal_check(slice));
+    z_drop(z_move(payload));
+
+    // payloads serialized by the other zenoh bindings use the same encoding, e.g. little endian integers
+    ze_serialize_uint32(&payload, 0x04030201);
+    z_owned_slice_t raw;
+    z_bytes_to_slice(z_loan(payload), &raw);
+    assert(z_slice_len(z_loan(raw)) == 4);
+    assert(memcmp(z_slice_data(z_loan(raw)), (uint8_t[]){1, 2, 3, 4}, 4) == 0);
+    z_drop(z_move(raw));
+    z_drop(z_move(payload));
+}
+
 bool check_slice(const z_loaned_bytes_t *b, const uint8_t *data, size_t len) {
     z_bytes_slice_iterator_t it = z_bytes_get_slice_iterator(b);
     uint8_t *data_out = (uint8_t *)malloc(len);
@@ -228,6 +336,33 @@ bool check_slice(const z_loaned_bytes_t *b, const uint8_t *data, size_t len) {
     return true;
 }
 
+void test_slice_iterator_views(void) {
+    static uint8_t data[] = {0, 1, 2, 3, 4, 5, 6, 7, 8, 9};
+    z_view_slice_t v;
+
+    // an empty payload has no slices
+    z_owned_bytes_t payload;
+    z_bytes_empty(&payload);
+    z_bytes_slice_iterator_t it = z_bytes_get_slice_iterator(z_loan(payload));
+    assert(!z_bytes_slice_iterator_next(&it, &v));
+    z_drop(z_move(payload));
+
+    // a contiguous payload has a single slice, viewing the original buffer
+    z_bytes_from_static_buf(&payload, data, 10);
+    it = z_bytes_get_slice_iterator(z_loan(payload));
+    assert(z_bytes_slice_iterator_next(&it, &v));
+    assert(z_slice_data(z_loan(v)) == data);
+    assert(z_slice_len(z_loan(v)) == 10);
+    assert(!z_bytes_slice_iterator_next(&it, &v));
+    // while z_bytes_to_slice() copies it
+    z_owned_slice_t copy;
+    z_bytes_to_slice(z_loan(payload), &copy);
+    assert(z_slice_data(z_loan(copy)) != data);
+    assert(memcmp(z_slice_data(z_loan(copy)), data, 10) == 0);
+    z_drop(z_move(copy));
+    z_drop(z_move(payload));
+}
+
 void test_slices(void) {
     uint8_t data[] = {0, 1, 2, 3, 4, 5, 6, 7, 8, 9};
     z_owned_bytes_t payload;
@@ -289,6 +424,36 @@ void test_serialize_simple(void) {
     z_drop(z_move(b));
 }
 
+void test_serialize_substr(void) {
+    // neither a prefix of a longer string nor a string with embedded null character is null-terminated
+    const char data[] = {'a', '\0', 'b', 'c', 'd'};
+    z_owned_bytes_t b;
+    assert(ze_serialize_substr(&b, data, 3) == Z_OK);
+    z_owned_string_t s;
+    assert(ze_deserialize_string(z_loan(b), &s) == Z_OK);
+    assert(z_string_len(z_loan(s)) == 3);
+    assert(memcmp(data, z_string_data(z_loan(s)), 3) == 0);
+    z_drop(z_move(s));
+    z_drop(z_move(b));
+
+    ze_owned_serializer_t serializer;
+    ze_serializer_empty(&serializer);
+    ze_serializer_serialize_substr(z_loan_mut(serializer), data + 2, 2);
+    ze_serializer_serialize_substr(z_loan_mut(serializer), data, 0);
+    ze_serializer_finish(z_move(serializer), &b);
+
+    ze_deserializer_t deserializer = ze_deserializer_from_bytes(z_loan(b));
+    assert(ze_deserializer_deserialize_string(&deserializer, &s) == Z_OK);
+    assert(z_string_len(z_loan(s)) == 2);
+    assert(strncmp("bc", z_string_data(z_loan(s)), 2) == 0);
+    z_drop(z_move(s));
+    assert(ze_deserializer_deserialize_string(&deserializer, &s) == Z_OK);
+    assert(z_string_len(z_loan(s)) == 0);
+    z_drop(z_move(s));
+    assert(ze_deserializer_is_done(&deserializer));
+    z_drop(z_move(b));
+}
+
 void test_serialize_sequence(void) {
     uint32_t input[6] = {1, 2, 3, 100, 10000, 100000};
     z_owned_bytes_t b;
@@ -315,14 +480,116 @@ void test_serialize_sequence(void) {
     z_drop(z_move(b));
 }
 
+#if defined(Z_FEATURE_UNSTABLE_API)
+static const uint8_t STATIC_DATA[] = {'z', 'e', 'n', 'o', 'h'};
+
+void test_static_buf(void) {
+    z_owned_bytes_t payload;
+    assert(z_bytes_from_static_buf(&payload, STATIC_DATA, sizeof(STATIC_DATA)) == Z_OK);
+    // the static data is aliased by the payload and all its clones, and never freed
+    for (int i = 0; i < 3; i++) {
+        z_owned_bytes_t clone;
+        z_bytes_clone(&clone, z_loan(payload));
+        z_view_slice_t view;
+        assert(z_bytes_get_contiguous_view(z_loan(clone), &view) == Z_OK);
+        assert(z_slice_data(z_loan(view)) == STATIC_DATA);
+        assert(z_slice_len(z_loan(view)) == sizeof(STATIC_DATA));
+        z_drop(z_move(clone));
+    }
+    z_drop(z_move(payload));
+    assert(STATIC_DATA[0] == 'z');
+}
+
+void test_checksums(void) {
+    z_owned_bytes_t empty;
+    z_bytes_empty(&empty);
+    assert(z_bytes_crc32(z_loan(empty)) == 0);
+    assert(z_bytes_xxhash64(z_loan(empty), 0) == 0xEF46DB3751D8E999ULL);
+    z_drop(z_move(empty));
+
+    // the checksums do not depend on how the payload is fragmented
+    z_owned_bytes_t payload, part;
+    z_owned_bytes_writer_t writer;
+    z_bytes_writer_empty(&writer);
+    z_bytes_copy_from_str(&part, "1234");
+    z_bytes_writer_append(z_loan_mut(writer), z_move(part));
+    z_bytes_copy_from_str(&part, "56789");
+    z_bytes_writer_append(z_loan_mut(writer), z_move(part));
+    z_bytes_writer_finish(z_move(writer), &payload);
+    z_view_slice_t view;
+    assert(z_bytes_get_contiguous_view(z_loan(payload), &view) != Z_OK);
+
+    assert(z_bytes_crc32(z_loan(payload)) == 0xCBF43926);
+    assert(z_bytes_xxhash64(z_loan(payload), 0) == 0x8CB841DB40E6AE83ULL);
+    assert(z_bytes_xxhash64(z_loan(payload), 42) == 0xA18395713E7331F3ULL);
+    z_drop(z_move(payload));
+}
+
+void test_encrypt(void) {
+    uint8_t key[32] = {0};
+    uint8_t nonce[12] = {0};
+    for (size_t i = 0; i < 32; i++) key[i] = (uint8_t)i;
+    nonce[0] = 1;
+
+    z_owned_bytes_t payload, encrypted, decrypted;
+    z_bytes_copy_from_str(&payload, "secret data");
+    assert(zc_bytes_encrypt(z_loan(payload), key, 32, nonce, 12, &encrypted) == Z_OK);
+    assert(z_bytes_len(z_loan(encrypted)) == z_bytes_len(z_loan(payload)) + 16);
+    assert(!check_slice(z_loan(encrypted), (const uint8_t *)"secret data", 11));
+
+    assert(zc_bytes_decrypt(z_loan(encrypted), key, 32, nonce, 12, &decrypted) == Z_OK);
+    assert(check_slice(z_loan(decrypted), (const uint8_t *)"secret data", 11));
+    z_drop(z_move(decrypted));
+
+    nonce[0] = 2;
+    assert(zc_bytes_decrypt(z_loan(encrypted), key, 32, nonce, 12, &decrypted) == Z_EDESERIALIZE);
+    assert(z_bytes_len(z_loan(decrypted)) == 0);
+    z_drop(z_move(decrypted));
+
+    assert(zc_bytes_encrypt(z_loan(payload), key, 16, nonce, 12, &encrypted) == Z_EINVAL);
+    z_drop(z_move(encrypted));
+    z_drop(z_move(payload));
+
+    z_owned_encoding_t encoding;
+    z_encoding_from_str(&encoding, "text/plain;utf-8");
+    assert(!zc_encoding_is_encrypted(z_loan(encoding)));
+    zc_encoding_set_encrypted(z_loan_mut(encoding));
+    zc_encoding_set_encrypted(z_loan_mut(encoding));
+    assert(zc_encoding_is_encrypted(z_loan(encoding)));
+    z_owned_string_t s;
+    z_encoding_to_string(z_loan(encoding), &s);
+    const char *expected = "text/plain;utf-8;aead=chacha20-poly1305";
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+    assert(zc_encoding_unset_encrypted(z_loan_mut(encoding)));
+    assert(!zc_encoding_unset_encrypted(z_loan_mut(encoding)));
+    z_encoding_to_string(z_loan(encoding), &s);
+    assert(z_string_len(z_loan(s)) == strlen("text/plain;utf-8"));
+    assert(strncmp(z_string_data(z_loan(s)), "text/plain;utf-8", strlen("text/plain;utf-8")) == 0);
+    z_drop(z_move(s));
+    z_drop(z_move(encoding));
+}
+#endif
+
 int main(void) {
     test_reader_seek();
     test_reader_read();
+    test_reader_fragmented();
     test_writer();
     test_slice();
     test_arithmetic();
+    test_deserialize_errors();
     test_append();
+    test_append_no_copy();
     test_slices();
+    test_slice_iterator_views();
     test_serialize_simple();
+    test_serialize_substr();
     test_serialize_sequence();
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_static_buf();
+    test_checksums();
+    test_encrypt();
+#endif
 }
@@ -0,0 +1,154 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/publication_cache";
+const char* prefix = "zenoh/test/prefix";
+const char* values[] = {"value_1", "value_2", "value_3"};
+const size_t values_count = sizeof(values) / sizeof(values[0]);
+
+void put_values(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    for (size_t i = 0; i < values_count; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_copy_from_str(&payload, values[i]);
+        assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+    }
+    z_sleep_ms(100);
+}
+
+// Returns the number of replies, checking they hold the last values in order.
+size_t fetch(const z_loaned_session_t* s, const char* selector) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, selector);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.consolidation = z_query_consolidation_none();
+    opts.timeout_ms = 1000;
+    assert(z_get(s, z_loan(ke), "", z_move(callback), &opts) == Z_OK);
+
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        count++;
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+    return count;
+}
+
+void check_last_value(const z_loaned_session_t* s, const char* selector) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, selector);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(callback), NULL) == Z_OK);
+
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(z_reply_ok(z_loan(reply))), &value);
+    const char* expected = values[values_count - 1];
+    assert(z_string_len(z_loan(value)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(value)), expected, strlen(expected)) == 0);
+    z_drop(z_move(value));
+    z_drop(z_move(reply));
+    z_drop(z_move(replies));
+}
+
+void test_history(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    ze_publication_cache_options_t opts;
+    ze_publication_cache_options_default(&opts);
+    assert(opts.history == 1);
+    opts.history = 2;
+    ze_owned_publication_cache_t cache;
+    assert(ze_declare_publication_cache(s, &cache, z_loan(ke), &opts) == Z_OK);
+    assert(z_internal_check(cache));
+
+    z_view_string_t cache_ke;
+    z_keyexpr_as_view_string(ze_publication_cache_keyexpr(z_loan(cache)), &cache_ke);
+    assert(z_string_len(z_loan(cache_ke)) == strlen(keyexpr));
+    assert(strncmp(z_string_data(z_loan(cache_ke)), keyexpr, strlen(keyexpr)) == 0);
+    z_sleep_ms(100);
+
+    put_values(s);
+    // only the last samples are kept
+    assert(fetch(s, keyexpr) == 2);
+    check_last_value(s, keyexpr);
+
+    assert(ze_undeclare_publication_cache(z_move(cache)) == Z_OK);
+    assert(!z_internal_check(cache));
+    z_sleep_ms(100);
+    assert(fetch(s, keyexpr) == 0);
+}
+
+void test_queryable_prefix(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke, prefix_ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_view_keyexpr_from_str(&prefix_ke, prefix);
+    ze_publication_cache_options_t opts;
+    ze_publication_cache_options_default(&opts);
+    opts.queryable_prefix = z_loan(prefix_ke);
+    opts.history = 10;
+    ze_owned_publication_cache_t cache;
+    assert(ze_declare_publication_cache(s, &cache, z_loan(ke), &opts) == Z_OK);
+    z_sleep_ms(100);
+
+    put_values(s);
+    // the cache is queried on the prefixed key expression only
+    char prefixed[128];
+    snprintf(prefixed, sizeof(prefixed), "%s/%s", prefix, keyexpr);
+    assert(fetch(s, prefixed) == values_count);
+    check_last_value(s, prefixed);
+    assert(fetch(s, keyexpr) == 0);
+
+    z_drop(z_move(cache));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), Z_CONFIG_ADD_TIMESTAMP_KEY, "true") == Z_OK);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_history(z_loan(s));
+    test_queryable_prefix(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
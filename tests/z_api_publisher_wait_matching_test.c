@@ -0,0 +1,81 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/publisher_wait_matching";
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s1, s2;
+    assert(z_open(&s1, z_move(config), NULL) == Z_OK);
+    z_config_default(&config);
+    assert(z_open(&s2, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s1), &pub, z_loan(ke), NULL) == Z_OK);
+
+    // no subscriber: the wait times out
+    z_clock_t start = z_clock_now();
+    assert(zc_publisher_wait_matching(z_loan(pub), 200) == Z_EUNAVAILABLE);
+    uint64_t elapsed = z_clock_elapsed_ms(&start);
+    assert(elapsed >= 150);
+    assert(elapsed < 5000);
+
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 16);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s2), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    // the remote subscriber declaration reached the publisher session, so that the first publication reaches it
+    assert(zc_publisher_wait_matching(z_loan(pub), 10000) == Z_OK);
+    zc_matching_status_t status;
+    assert(zc_publisher_get_matching_status(z_loan(pub), &status) == Z_OK);
+    assert(status.matching);
+    // a publisher which already has matching subscribers returns right away
+    start = z_clock_now();
+    assert(zc_publisher_wait_matching(z_loan(pub), 10000) == Z_OK);
+    assert(z_clock_elapsed_ms(&start) < 5000);
+
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "first");
+    assert(z_publisher_put(z_loan(pub), z_move(payload), NULL) == Z_OK);
+    z_owned_sample_t sample;
+    assert(z_recv(z_loan(samples), &sample) == Z_OK);
+    z_drop(z_move(sample));
+
+    z_drop(z_move(pub));
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,79 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* keyexpr = "zenoh/test/put_string";
+
+void check_next(const z_loaned_fifo_handler_sample_t* handler, const char* expected,
+                const z_loaned_encoding_t* encoding) {
+    z_owned_sample_t sample;
+    assert(z_recv(handler, &sample) == Z_OK);
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(z_loan(sample)), &value);
+    assert(z_string_len(z_loan(value)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(value)), expected, strlen(expected)) == 0);
+    assert(z_encoding_equals(z_sample_encoding(z_loan(sample)), encoding));
+    z_drop(z_move(value));
+    z_drop(z_move(sample));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_sample_t callback;
+    z_owned_fifo_handler_sample_t handler;
+    z_fifo_channel_sample_new(&callback, &handler, 16);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    assert(z_put_string(z_loan(s), z_loan(ke), "hello", NULL) == Z_OK);
+    check_next(z_loan(handler), "hello", z_encoding_text_plain());
+
+    const char* json = "{\"answer\": 42}";
+    assert(z_put_json(z_loan(s), z_loan(ke), json, NULL) == Z_OK);
+    check_next(z_loan(handler), json, z_encoding_application_json());
+
+    // the options are consumed even if the string is NULL
+    z_put_options_t opts;
+    z_put_options_default(&opts);
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&attachment, "attachment");
+    opts.attachment = z_move(attachment);
+    assert(z_put_string(z_loan(s), z_loan(ke), NULL, &opts) == Z_EINVAL);
+    assert(!z_internal_check(attachment));
+    assert(z_put_json(z_loan(s), z_loan(ke), NULL, NULL) == Z_EINVAL);
+
+    z_owned_sample_t sample;
+    z_sleep_ms(100);
+    assert(z_try_recv(z_loan(handler), &sample) == Z_CHANNEL_NODATA);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(handler));
+    z_drop(z_move(s));
+    return 0;
+}
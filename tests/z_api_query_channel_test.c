@@ -0,0 +1,107 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* keyexpr = "zenoh/test/query_channel";
+
+void send_get(const z_loaned_session_t* s, z_owned_fifo_handler_reply_t* handler) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, handler, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(closure), NULL) == Z_OK);
+}
+
+void reply_and_drop(z_owned_query_t* query) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "reply");
+    assert(z_query_reply(z_loan(*query), z_query_keyexpr(z_loan(*query)), z_move(payload), NULL) == Z_OK);
+    // the query is finalized once the owned query is dropped
+    z_drop(z_move(*query));
+}
+
+void check_single_reply(z_owned_fifo_handler_reply_t* handler) {
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(*handler), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    assert(z_recv(z_loan(*handler), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(*handler));
+}
+
+void test_fifo_query_channel(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t closure;
+    z_owned_fifo_handler_query_t handler;
+    z_fifo_channel_query_new(&closure, &handler, 16);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(s, &queryable, z_loan(ke), z_move(closure), NULL) == Z_OK);
+
+    z_owned_query_t query;
+    assert(z_try_recv(z_loan(handler), &query) == Z_CHANNEL_NODATA);
+
+    z_owned_fifo_handler_reply_t replies;
+    send_get(s, &replies);
+    assert(z_recv(z_loan(handler), &query) == Z_OK);
+    assert(z_internal_check(query));
+    reply_and_drop(&query);
+    check_single_reply(&replies);
+
+    z_drop(z_move(queryable));
+    assert(z_recv(z_loan(handler), &query) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(handler));
+}
+
+void test_ring_query_channel(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t closure;
+    z_owned_ring_handler_query_t handler;
+    z_ring_channel_query_new(&closure, &handler, 1);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(s, &queryable, z_loan(ke), z_move(closure), NULL) == Z_OK);
+
+    z_owned_fifo_handler_reply_t replies;
+    send_get(s, &replies);
+    z_sleep_ms(100);
+    z_owned_query_t query;
+    assert(z_try_recv(z_loan(handler), &query) == Z_OK);
+    reply_and_drop(&query);
+    check_single_reply(&replies);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(handler));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_fifo_query_channel(z_loan(s));
+    test_ring_query_channel(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
@@ -0,0 +1,153 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/diagnostics";
+
+typedef struct diagnostics_result_t {
+    int calls;
+    zc_query_diagnostics_t diagnostics;
+} diagnostics_result_t;
+
+void on_query_ok(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "ok");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void on_query_err(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "err");
+    z_query_reply_err(query, z_move(payload), NULL);
+}
+
+// Keeps the query open without replying, so that it times out.
+void on_query_silent(z_loaned_query_t* query, void* context) {
+    z_owned_query_t* pending = (z_owned_query_t*)context;
+    z_drop(z_move(*pending));
+    z_query_clone(pending, query);
+}
+
+void on_reply(z_loaned_reply_t* reply, void* context) {}
+
+void on_diagnostics(const zc_query_diagnostics_t* diagnostics, void* context) {
+    diagnostics_result_t* result = (diagnostics_result_t*)context;
+    result->diagnostics = *diagnostics;
+    result->calls++;
+}
+
+void wait_diagnostics(const diagnostics_result_t* result) {
+    for (int i = 0; i < 50 && result->calls == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(result->calls == 1);
+}
+
+void get_with_diagnostics(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, const z_id_t* replier_id,
+                          diagnostics_result_t* result) {
+    z_owned_closure_reply_t reply_callback;
+    z_closure(&reply_callback, on_reply, NULL, NULL);
+    zc_owned_closure_query_diagnostics_t diagnostics_callback;
+    zc_closure_query_diagnostics(&diagnostics_callback, on_diagnostics, NULL, (void*)result);
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    assert(opts.diagnostics == NULL);
+    opts.target = Z_QUERY_TARGET_ALL;
+    opts.consolidation = z_query_consolidation_none();
+    opts.timeout_ms = 1000;
+    opts.diagnostics = z_move(diagnostics_callback);
+    opts.replier_id = replier_id;
+    assert(z_get(s, ke, "", z_move(reply_callback), &opts) == Z_OK);
+    wait_diagnostics(result);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    diagnostics_result_t result = {0};
+    get_with_diagnostics(z_loan(s), z_loan(ke), NULL, &result);
+    assert(result.diagnostics.replies == 0);
+    assert(result.diagnostics.errors == 0);
+    assert(result.diagnostics.repliers == 0);
+    assert(result.diagnostics.status == Z_OK);
+
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query_ok, NULL, NULL);
+    z_owned_queryable_t ok_queryable;
+    assert(z_declare_queryable(z_loan(s), &ok_queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_closure(&callback, on_query_err, NULL, NULL);
+    z_owned_queryable_t err_queryable;
+    assert(z_declare_queryable(z_loan(s), &err_queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    memset(&result, 0, sizeof(result));
+    get_with_diagnostics(z_loan(s), z_loan(ke), NULL, &result);
+    assert(result.diagnostics.replies == 2);
+    assert(result.diagnostics.errors == 1);
+    assert(result.diagnostics.repliers == 1);
+    assert(result.diagnostics.status == Z_OK);
+
+    // the replies discarded by the replier filter are not counted
+    z_id_t other = z_info_zid(z_loan(s));
+    other.id[0] ^= 0xff;
+    memset(&result, 0, sizeof(result));
+    get_with_diagnostics(z_loan(s), z_loan(ke), &other, &result);
+    assert(result.diagnostics.replies == 0);
+    assert(result.diagnostics.errors == 0);
+    assert(result.diagnostics.repliers == 0);
+    assert(result.diagnostics.status == Z_OK);
+
+    z_drop(z_move(ok_queryable));
+    z_drop(z_move(err_queryable));
+
+    // the timeout error reply is reported through the status only
+    z_owned_query_t pending;
+    z_internal_null(&pending);
+    z_closure(&callback, on_query_silent, NULL, (void*)&pending);
+    z_owned_queryable_t silent_queryable;
+    assert(z_declare_queryable(z_loan(s), &silent_queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    memset(&result, 0, sizeof(result));
+    get_with_diagnostics(z_loan(s), z_loan(ke), NULL, &result);
+    assert(result.diagnostics.replies == 0);
+    assert(result.diagnostics.errors == 0);
+    assert(result.diagnostics.repliers == 0);
+    assert(result.diagnostics.status == Z_QUERY_TIMED_OUT);
+
+    z_drop(z_move(silent_queryable));
+    z_drop(z_move(pending));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/reply_entries/**";
+const char* keys[] = {"zenoh/test/reply_entries/a", "zenoh/test/reply_entries/b", "zenoh/test/other/c",
+                      "zenoh/test/reply_entries/d/e"};
+const size_t keys_count = sizeof(keys) / sizeof(keys[0]);
+const size_t matching_count = 3;
+static int drops = 0;
+
+typedef struct entries_context_t {
+    size_t next;
+    size_t replied;
+    size_t skipped;
+    z_result_t res;
+} entries_context_t;
+
+bool next_entry(z_owned_keyexpr_t* key_expr, z_owned_bytes_t* payload, void* context) {
+    entries_context_t* ctx = (entries_context_t*)context;
+    if (ctx->next == keys_count) {
+        return false;
+    }
+    const char* key = keys[ctx->next++];
+    assert(z_keyexpr_from_str(key_expr, key) == Z_OK);
+    z_bytes_copy_from_str(payload, key);
+    return true;
+}
+
+void drop_entries(void* context) { drops++; }
+
+void on_query(z_loaned_query_t* query, void* context) {
+    entries_context_t* ctx = (entries_context_t*)context;
+    ctx->next = 0;
+    z_owned_query_t owned;
+    z_query_clone(&owned, query);
+    zc_owned_closure_reply_entry_t next;
+    z_closure(&next, next_entry, drop_entries, context);
+    ctx->res = zc_query_reply_entries(z_move(owned), z_move(next), &ctx->replied, &ctx->skipped);
+    assert(!z_internal_check(owned));
+    assert(!z_internal_check(next));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    entries_context_t ctx = {0};
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, (void*)&ctx);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(z_loan(s), &q, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.consolidation = z_query_consolidation_none();
+    opts.timeout_ms = 1000;
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+
+    // only the entries matching the query are received, each with its own key expression
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        const z_loaned_sample_t* sample = z_reply_ok(z_loan(reply));
+        z_view_string_t key;
+        z_keyexpr_as_view_string(z_sample_keyexpr(sample), &key);
+        z_owned_string_t value;
+        z_bytes_to_string(z_sample_payload(sample), &value);
+        assert(z_string_len(z_loan(key)) == z_string_len(z_loan(value)));
+        assert(strncmp(z_string_data(z_loan(key)), z_string_data(z_loan(value)), z_string_len(z_loan(key))) == 0);
+        z_drop(z_move(value));
+        z_drop(z_move(reply));
+        count++;
+    }
+    z_drop(z_move(replies));
+
+    assert(ctx.res == Z_OK);
+    assert(ctx.next == keys_count);
+    assert(ctx.replied == matching_count);
+    assert(ctx.skipped == keys_count - matching_count);
+    assert(drops == 1);
+    assert(count == matching_count);
+
+    z_drop(z_move(q));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,240 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/query_timeout";
+
+typedef struct diagnostics_result_t {
+    int calls;
+    zc_query_diagnostics_t diagnostics;
+} diagnostics_result_t;
+
+// Replies once, and keeps the query open so that it times out.
+void on_query(z_loaned_query_t* query, void* context) {
+    z_owned_query_t* pending = (z_owned_query_t*)context;
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "partial");
+    assert(z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL) == Z_OK);
+    z_drop(z_move(*pending));
+    z_query_clone(pending, query);
+}
+
+// Replies with an error looking like the timeout one, which must not be taken for it.
+void on_query_err(z_loaned_query_t* query, void* context) {
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "Timeout");
+    assert(z_query_reply_err(query, z_move(payload), NULL) == Z_OK);
+}
+
+// Keeps the query open without replying, so that it can be replied to from the test.
+void on_query_silent(z_loaned_query_t* query, void* context) {
+    z_owned_query_t* pending = (z_owned_query_t*)context;
+    z_drop(z_move(*pending));
+    z_query_clone(pending, query);
+}
+
+void on_diagnostics(const zc_query_diagnostics_t* diagnostics, void* context) {
+    diagnostics_result_t* result = (diagnostics_result_t*)context;
+    result->diagnostics = *diagnostics;
+    result->calls++;
+}
+
+// Returns the number of ok and error replies received before the end of the query.
+void get(const z_loaned_session_t* s, bool suppress_timeout_error_reply, size_t* ok, size_t* err, diagnostics_result_t* result) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    zc_owned_closure_query_diagnostics_t diagnostics_callback;
+    zc_closure_query_diagnostics(&diagnostics_callback, on_diagnostics, NULL, (void*)result);
+
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    assert(!opts.suppress_timeout_error_reply);
+    opts.consolidation = z_query_consolidation_none();
+    opts.timeout_ms = 500;
+    opts.diagnostics = z_move(diagnostics_callback);
+    opts.suppress_timeout_error_reply = suppress_timeout_error_reply;
+    assert(z_get(s, z_loan(ke), "", z_move(callback), &opts) == Z_OK);
+
+    *ok = 0;
+    *err = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        if (z_reply_is_ok(z_loan(reply))) {
+            (*ok)++;
+        } else {
+            (*err)++;
+        }
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+    for (int i = 0; i < 50 && result->calls == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(result->calls == 1);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_owned_query_t pending;
+    z_internal_null(&pending);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, (void*)&pending);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(z_loan(s), &q, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    // by default, the timeout is notified by an error reply
+    size_t ok, err;
+    diagnostics_result_t result = {0};
+    get(z_loan(s), false, &ok, &err, &result);
+    assert(ok == 1);
+    assert(err == 1);
+    // the timeout reply is delivered, but not counted as a reply of the queryables
+    assert(result.diagnostics.replies == 1);
+    assert(result.diagnostics.errors == 0);
+    assert(result.diagnostics.status == Z_QUERY_TIMED_OUT);
+    assert(Z_QUERY_TIMED_OUT > 0);
+
+    // the partial results can be told apart from the timeout
+    memset(&result, 0, sizeof(result));
+    get(z_loan(s), true, &ok, &err, &result);
+    assert(ok == 1);
+    assert(err == 0);
+    assert(result.diagnostics.replies == 1);
+    assert(result.diagnostics.errors == 0);
+    assert(result.diagnostics.status == Z_QUERY_TIMED_OUT);
+
+    // queries completed before the timeout are not affected
+    memset(&result, 0, sizeof(result));
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    zc_owned_closure_query_diagnostics_t diagnostics_callback;
+    zc_closure_query_diagnostics(&diagnostics_callback, on_diagnostics, NULL, (void*)&result);
+    opts.consolidation = z_query_consolidation_none();
+    opts.timeout_ms = 5000;
+    opts.suppress_timeout_error_reply = true;
+    opts.diagnostics = z_move(diagnostics_callback);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), &opts) == Z_OK);
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    z_drop(z_move(reply));
+    // releasing the query finalizes it
+    z_sleep_ms(100);
+    z_drop(z_move(pending));
+    assert(z_recv(z_loan(replies), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(replies));
+    for (int i = 0; i < 50 && result.calls == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(result.calls == 1);
+    assert(result.diagnostics.status == Z_OK);
+
+    // the error replies of the queryables are delivered, even when they look like the timeout one
+    z_view_keyexpr_t err_ke;
+    z_view_keyexpr_from_str(&err_ke, "zenoh/test/query_timeout_err");
+    z_owned_closure_query_t err_callback;
+    z_closure(&err_callback, on_query_err, NULL, NULL);
+    z_owned_queryable_t err_q;
+    assert(z_declare_queryable(z_loan(s), &err_q, z_loan(err_ke), z_move(err_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    memset(&result, 0, sizeof(result));
+    z_get_options_default(&opts);
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    zc_closure_query_diagnostics(&diagnostics_callback, on_diagnostics, NULL, (void*)&result);
+    opts.timeout_ms = 5000;
+    opts.suppress_timeout_error_reply = true;
+    opts.diagnostics = z_move(diagnostics_callback);
+    assert(z_get(z_loan(s), z_loan(err_ke), "", z_move(reply_callback), &opts) == Z_OK);
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    assert(z_recv(z_loan(replies), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(replies));
+    for (int i = 0; i < 50 && result.calls == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(result.calls == 1);
+    assert(result.diagnostics.errors == 1);
+    assert(result.diagnostics.status == Z_OK);
+    z_drop(z_move(err_q));
+
+    // an error reply looking like the timeout one, sent by a local queryable shortly before the deadline, is delivered
+    z_view_keyexpr_t boundary_ke;
+    z_view_keyexpr_from_str(&boundary_ke, "zenoh/test/query_timeout_boundary");
+    z_owned_query_t silent_pending;
+    z_internal_null(&silent_pending);
+    z_owned_closure_query_t silent_callback;
+    z_closure(&silent_callback, on_query_silent, NULL, (void*)&silent_pending);
+    z_owned_queryable_t silent_q;
+    assert(z_declare_queryable(z_loan(s), &silent_q, z_loan(boundary_ke), z_move(silent_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+    memset(&result, 0, sizeof(result));
+    z_get_options_default(&opts);
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    zc_closure_query_diagnostics(&diagnostics_callback, on_diagnostics, NULL, (void*)&result);
+    opts.timeout_ms = 1000;
+    opts.suppress_timeout_error_reply = true;
+    opts.diagnostics = z_move(diagnostics_callback);
+    assert(z_get(z_loan(s), z_loan(boundary_ke), "", z_move(reply_callback), &opts) == Z_OK);
+    z_sleep_ms(800);
+    assert(z_internal_check(silent_pending));
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "Timeout");
+    assert(z_query_reply_err(z_loan(silent_pending), z_move(payload), NULL) == Z_OK);
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    // the actual timeout reply is then suppressed
+    assert(z_recv(z_loan(replies), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(replies));
+    for (int i = 0; i < 50 && result.calls == 0; i++) {
+        z_sleep_ms(100);
+    }
+    assert(result.calls == 1);
+    assert(result.diagnostics.replies == 1);
+    assert(result.diagnostics.errors == 1);
+    assert(result.diagnostics.status == Z_QUERY_TIMED_OUT);
+    z_drop(z_move(silent_pending));
+    z_drop(z_move(silent_q));
+
+    z_drop(z_move(q));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
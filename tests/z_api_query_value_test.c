@@ -0,0 +1,144 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* keyexpr = "zenoh/test/query_value";
+const char* payload_value = "request body";
+const char* attachment_value = "request attachment";
+
+void assert_bytes_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    assert(bytes != NULL);
+    z_owned_string_t s;
+    z_bytes_to_string(bytes, &s);
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+}
+
+void assert_bytes_opt_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    if (expected == NULL) {
+        assert(bytes == NULL);
+    } else {
+        assert_bytes_eq(bytes, expected);
+    }
+}
+
+void get(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, z_owned_fifo_handler_query_t* queries,
+         z_get_options_t* opts, const char* expected_payload, const z_loaned_encoding_t* expected_encoding,
+         const char* expected_attachment) {
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, ke, "", z_move(callback), opts) == Z_OK);
+
+    z_owned_query_t query;
+    assert(z_recv(z_loan(*queries), &query) == Z_OK);
+    assert_bytes_opt_eq(z_query_payload(z_loan(query)), expected_payload);
+    assert_bytes_opt_eq(z_query_attachment(z_loan(query)), expected_attachment);
+    const z_loaned_encoding_t* encoding = z_query_encoding(z_loan(query));
+    if (expected_encoding == NULL) {
+        assert(encoding == NULL);
+    } else {
+        assert(encoding != NULL);
+        assert(z_encoding_equals(encoding, expected_encoding));
+    }
+    z_drop(z_move(query));
+    z_drop(z_move(replies));
+}
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+void querier_get(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, z_owned_fifo_handler_query_t* queries) {
+    z_owned_querier_t querier;
+    assert(z_declare_querier(s, &querier, ke, NULL) == Z_OK);
+
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_querier_get_options_t opts;
+    z_querier_get_options_default(&opts);
+    opts.attachment = z_move(attachment);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_querier_get(z_loan(querier), "", z_move(callback), &opts) == Z_OK);
+    assert(!z_internal_check(attachment));
+
+    z_owned_query_t query;
+    assert(z_recv(z_loan(*queries), &query) == Z_OK);
+    assert(z_query_payload(z_loan(query)) == NULL);
+    assert_bytes_eq(z_query_attachment(z_loan(query)), attachment_value);
+    z_drop(z_move(query));
+    z_drop(z_move(replies));
+    z_drop(z_move(querier));
+}
+#endif
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t callback;
+    z_fifo_channel_query_new(&callback, &queries, 16);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    get(z_loan(s), z_loan(ke), &queries, NULL, NULL, NULL, NULL);
+
+    z_owned_bytes_t payload, attachment;
+    z_bytes_copy_from_str(&payload, payload_value);
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_owned_encoding_t encoding;
+    z_encoding_clone(&encoding, z_encoding_text_plain());
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.payload = z_move(payload);
+    opts.attachment = z_move(attachment);
+    opts.encoding = z_move(encoding);
+    get(z_loan(s), z_loan(ke), &queries, &opts, payload_value, z_encoding_text_plain(), attachment_value);
+
+    // the payload is sent with the default encoding if none is specified
+    z_bytes_copy_from_str(&payload, payload_value);
+    z_get_options_default(&opts);
+    opts.payload = z_move(payload);
+    get(z_loan(s), z_loan(ke), &queries, &opts, payload_value, z_encoding_zenoh_bytes(), NULL);
+    assert(!z_internal_check(payload));
+
+    // the attachment alone
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_get_options_default(&opts);
+    opts.attachment = z_move(attachment);
+    get(z_loan(s), z_loan(ke), &queries, &opts, NULL, NULL, attachment_value);
+#if defined(Z_FEATURE_UNSTABLE_API)
+    querier_get(z_loan(s), z_loan(ke), &queries);
+#endif
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(queries));
+    z_drop(z_move(s));
+    return 0;
+}
@@ -0,0 +1,124 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/queryable_stats";
+const char* channel_keyexpr = "zenoh/test/queryable_stats/channel";
+
+void on_query(z_loaned_query_t* query, void* context) {
+    z_view_string_t params;
+    z_query_parameters(query, &params);
+    z_owned_bytes_t payload;
+    if (z_string_len(z_loan(params)) > 0) {
+        z_bytes_from_static_str(&payload, "err");
+        z_query_reply_err(query, z_move(payload), NULL);
+        return;
+    }
+    z_bytes_from_static_str(&payload, "ok");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+    z_bytes_from_static_str(&payload, "ok again");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void get(const z_loaned_session_t* s, const z_loaned_keyexpr_t* ke, const char* params) {
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, ke, params, z_move(callback), NULL) == Z_OK);
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    zc_queryable_stats_t stats = zc_queryable_stats(z_loan(queryable));
+    assert(stats.queries == 0);
+    assert(stats.replies == 0);
+    assert(stats.errors == 0);
+    assert(stats.average_handling_time_us == 0);
+    z_sleep_ms(100);
+
+    get(z_loan(s), z_loan(ke), "");
+    get(z_loan(s), z_loan(ke), "");
+    get(z_loan(s), z_loan(ke), "err");
+
+    stats = zc_queryable_stats(z_loan(queryable));
+    assert(stats.queries == 3);
+    assert(stats.replies == 5);
+    assert(stats.errors == 1);
+    z_drop(z_move(queryable));
+
+    // the replies sent to the queries received through a channel, out of the queryable callback, are counted too
+    z_view_keyexpr_from_str(&ke, channel_keyexpr);
+    z_owned_fifo_handler_query_t queries;
+    z_fifo_channel_query_new(&callback, &queries, 16);
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    z_owned_query_t query;
+    assert(z_recv(z_loan(queries), &query) == Z_OK);
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "ok");
+    assert(z_query_reply(z_loan(query), z_loan(ke), z_move(payload), NULL) == Z_OK);
+    z_bytes_from_static_str(&payload, "err");
+    assert(z_query_reply_err(z_loan(query), z_move(payload), NULL) == Z_OK);
+    z_drop(z_move(query));
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+
+    stats = zc_queryable_stats(z_loan(queryable));
+    assert(stats.queries == 1);
+    assert(stats.replies == 2);
+    assert(stats.errors == 1);
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(queries));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
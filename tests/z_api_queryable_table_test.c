@@ -0,0 +1,112 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+#define TABLE_SIZE 3
+
+typedef struct table_t {
+    const char* keys[TABLE_SIZE];
+    const char* values[TABLE_SIZE];
+} table_t;
+
+bool table_get(const z_loaned_string_t* key, z_owned_bytes_t* value, void* context) {
+    table_t* table = (table_t*)context;
+    for (size_t i = 0; i < TABLE_SIZE; i++) {
+        if (table->values[i] != NULL && strlen(table->keys[i]) == z_string_len(key) &&
+            strncmp(table->keys[i], z_string_data(key), z_string_len(key)) == 0) {
+            z_bytes_copy_from_str(value, table->values[i]);
+            return true;
+        }
+    }
+    return false;
+}
+
+static int drops = 0;
+
+void table_drop(void* context) { drops++; }
+
+void table_list(z_loaned_string_array_t* keys, void* context) {
+    table_t* table = (table_t*)context;
+    for (size_t i = 0; i < TABLE_SIZE; i++) {
+        z_view_string_t key;
+        z_view_string_from_str(&key, table->keys[i]);
+        z_string_array_push_by_copy(keys, z_loan(key));
+    }
+}
+
+size_t get_count(const z_loaned_session_t* s, const char* keyexpr) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_reply_t closure;
+    z_owned_fifo_handler_reply_t handler;
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(closure), NULL) == Z_OK);
+    size_t count = 0;
+    z_owned_reply_t reply;
+    for (z_result_t res = z_recv(z_loan(handler), &reply); res == Z_OK; res = z_recv(z_loan(handler), &reply)) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        count++;
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(handler));
+    return count;
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    // "b" is listed but has no value
+    table_t table = {{"a", "b", "c/d"}, {"1", NULL, "3"}};
+    z_view_keyexpr_t prefix;
+    z_view_keyexpr_from_str(&prefix, "zenoh/test/table");
+    z_owned_queryable_t queryable;
+    zc_owned_closure_table_get_t get;
+    zc_owned_closure_table_list_t list;
+    z_closure(&list, table_list, table_drop, &table);
+    assert(zc_queryable_serve_table(z_loan(s), &queryable, z_loan(prefix), NULL, z_move(list)) == Z_EINVAL);
+    assert(!z_internal_check(queryable));
+    assert(drops == 1);
+
+    z_closure(&get, table_get, table_drop, &table);
+    z_closure(&list, table_list, table_drop, &table);
+    assert(zc_queryable_serve_table(z_loan(s), &queryable, z_loan(prefix), z_move(get), z_move(list)) == Z_OK);
+
+    assert(get_count(z_loan(s), "zenoh/test/table/**") == 2);
+    assert(get_count(z_loan(s), "zenoh/test/table/a") == 1);
+    assert(get_count(z_loan(s), "zenoh/test/table/b") == 0);
+    assert(get_count(z_loan(s), "zenoh/test/table/c/*") == 1);
+    assert(get_count(z_loan(s), "zenoh/test/table/e") == 0);
+
+    z_drop(z_move(queryable));
+    assert(drops == 3);
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,147 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/querying_subscriber";
+const char* values[] = {"value_1", "value_2", "value_3", "value_4", "value_5"};
+const size_t values_count = sizeof(values) / sizeof(values[0]);
+const size_t history_count = 3;
+
+void put_values(const z_loaned_publisher_t* pub, size_t from, size_t to) {
+    for (size_t i = from; i < to; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_copy_from_str(&payload, values[i]);
+        assert(z_publisher_put(pub, z_move(payload), NULL) == Z_OK);
+    }
+}
+
+void check_values(const z_loaned_fifo_handler_sample_t* samples, size_t from, size_t to) {
+    for (size_t i = from; i < to; i++) {
+        z_owned_sample_t sample;
+        assert(z_recv(samples, &sample) == Z_OK);
+        z_owned_string_t value;
+        z_bytes_to_string(z_sample_payload(z_loan(sample)), &value);
+        assert(z_string_len(z_loan(value)) == strlen(values[i]));
+        assert(strncmp(z_string_data(z_loan(value)), values[i], strlen(values[i])) == 0);
+        z_drop(z_move(value));
+        z_drop(z_move(sample));
+    }
+}
+
+typedef struct history_end_context_t {
+    const z_loaned_fifo_handler_sample_t* samples;
+    size_t received;
+    size_t calls;
+} history_end_context_t;
+
+void on_history_end(void* context) {
+    // the samples returned by the initial query are already in the channel
+    history_end_context_t* ctx = (history_end_context_t*)context;
+    z_owned_sample_t sample;
+    while (z_try_recv(ctx->samples, &sample) == Z_OK) {
+        ctx->received++;
+        z_drop(z_move(sample));
+    }
+    ctx->calls++;
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), Z_CONFIG_ADD_TIMESTAMP_KEY, "true") == Z_OK);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    ze_publication_cache_options_t cache_opts;
+    ze_publication_cache_options_default(&cache_opts);
+    cache_opts.history = values_count;
+    ze_owned_publication_cache_t cache;
+    assert(ze_declare_publication_cache(z_loan(s), &cache, z_loan(ke), &cache_opts) == Z_OK);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    // samples published before the subscriber is declared are fetched from the cache
+    put_values(z_loan(pub), 0, history_count);
+    z_sleep_ms(100);
+
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 16);
+    ze_querying_subscriber_options_t sub_opts;
+    ze_querying_subscriber_options_default(&sub_opts);
+    ze_owned_querying_subscriber_t sub;
+    assert(ze_declare_querying_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), &sub_opts) == Z_OK);
+    assert(z_internal_check(sub));
+    z_sleep_ms(100);
+
+    // followed by the live samples, in a single ordered stream
+    put_values(z_loan(pub), history_count, values_count);
+    check_values(z_loan(samples), 0, values_count);
+    z_sleep_ms(100);
+    z_owned_sample_t sample;
+    assert(z_try_recv(z_loan(samples), &sample) == Z_CHANNEL_NODATA);
+
+    // the history can be fetched again on demand
+    z_get_options_t get_opts;
+    z_get_options_default(&get_opts);
+    get_opts.consolidation = z_query_consolidation_none();
+    get_opts.timeout_ms = 1000;
+    assert(ze_querying_subscriber_get(z_loan(sub), z_loan(ke), &get_opts) == Z_OK);
+    check_values(z_loan(samples), 0, values_count);
+    z_sleep_ms(100);
+    assert(z_try_recv(z_loan(samples), &sample) == Z_CHANNEL_NODATA);
+
+    assert(ze_undeclare_querying_subscriber(z_move(sub)) == Z_OK);
+    assert(!z_internal_check(sub));
+    z_drop(z_move(samples));
+
+    // the end of the history is notified once its samples have been delivered
+    z_fifo_channel_sample_new(&callback, &samples, 16);
+    history_end_context_t ctx = {z_loan(samples), 0, 0};
+    ze_owned_closure_history_end_t history_end;
+    z_closure(&history_end, on_history_end, NULL, &ctx);
+    ze_querying_subscriber_options_default(&sub_opts);
+    sub_opts.history_end = z_move(history_end);
+    assert(ze_declare_querying_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), &sub_opts) == Z_OK);
+    assert(!z_internal_check(history_end));
+    z_sleep_ms(100);
+    assert(ctx.calls == 1);
+    assert(ctx.received == values_count);
+    put_values(z_loan(pub), 0, 1);
+    check_values(z_loan(samples), 0, 1);
+    assert(ctx.calls == 1);
+    assert(ze_undeclare_querying_subscriber(z_move(sub)) == Z_OK);
+    z_drop(z_move(samples));
+    z_drop(z_move(pub));
+    z_drop(z_move(cache));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,147 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+const char* keyexpr = "zenoh/test/reply_options";
+const char* payload_value = "reply body";
+const char* attachment_value = "reply attachment";
+const char* error_value = "reply error";
+
+void assert_bytes_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    assert(bytes != NULL);
+    z_owned_string_t s;
+    z_bytes_to_string(bytes, &s);
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t query_callback;
+    z_fifo_channel_query_new(&query_callback, &queries, 16);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t reply_callback;
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+
+    z_owned_query_t query;
+    assert(z_recv(z_loan(queries), &query) == Z_OK);
+
+    z_owned_bytes_t payload, attachment;
+    z_bytes_copy_from_str(&payload, payload_value);
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_owned_encoding_t encoding;
+    z_encoding_clone(&encoding, z_encoding_text_plain());
+    z_timestamp_t ts;
+    assert(z_timestamp_new(&ts, z_loan(s)) == Z_OK);
+    z_query_reply_options_t opts;
+    z_query_reply_options_default(&opts);
+    opts.encoding = z_move(encoding);
+    opts.attachment = z_move(attachment);
+    opts.timestamp = &ts;
+    opts.priority = Z_PRIORITY_DATA_HIGH;
+    opts.congestion_control = Z_CONGESTION_CONTROL_DROP;
+    opts.is_express = true;
+    assert(z_query_reply(z_loan(query), z_loan(ke), z_move(payload), &opts) == Z_OK);
+    z_drop(z_move(query));
+
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    const z_loaned_sample_t* sample = z_reply_ok(z_loan(reply));
+    assert(z_sample_kind(sample) == Z_SAMPLE_KIND_PUT);
+    assert_bytes_eq(z_sample_payload(sample), payload_value);
+    assert_bytes_eq(z_sample_attachment(sample), attachment_value);
+    assert(z_encoding_equals(z_sample_encoding(sample), z_encoding_text_plain()));
+    const z_timestamp_t* sample_ts = z_sample_timestamp(sample);
+    assert(sample_ts != NULL);
+    assert(z_timestamp_ntp64_time(sample_ts) == z_timestamp_ntp64_time(&ts));
+#if defined(Z_FEATURE_UNSTABLE_API)
+    // the reply was sent by this session
+    z_id_t replier_id;
+    assert(z_reply_replier_id(z_loan(reply), &replier_id));
+    z_id_t session_id = z_info_zid(z_loan(s));
+    assert(memcmp(&replier_id, &session_id, sizeof(z_id_t)) == 0);
+#endif
+    z_drop(z_move(reply));
+    z_drop(z_move(replies));
+
+    // error reply
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    assert(z_recv(z_loan(queries), &query) == Z_OK);
+    z_bytes_copy_from_str(&payload, error_value);
+    z_encoding_clone(&encoding, z_encoding_text_plain());
+    z_query_reply_err_options_t err_opts;
+    z_query_reply_err_options_default(&err_opts);
+    err_opts.encoding = z_move(encoding);
+    assert(z_query_reply_err(z_loan(query), z_move(payload), &err_opts) == Z_OK);
+    z_drop(z_move(query));
+
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    assert(z_reply_ok(z_loan(reply)) == NULL);
+    const z_loaned_reply_err_t* err = z_reply_err(z_loan(reply));
+    assert(err != NULL);
+    assert_bytes_eq(z_reply_err_payload(err), error_value);
+    assert(z_encoding_equals(z_reply_err_encoding(err), z_encoding_text_plain()));
+    z_drop(z_move(reply));
+    z_drop(z_move(replies));
+
+    // delete reply
+    z_fifo_channel_reply_new(&reply_callback, &replies, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(reply_callback), NULL) == Z_OK);
+    assert(z_recv(z_loan(queries), &query) == Z_OK);
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_query_reply_del_options_t del_opts;
+    z_query_reply_del_options_default(&del_opts);
+    del_opts.attachment = z_move(attachment);
+    assert(z_query_reply_del(z_loan(query), z_loan(ke), &del_opts) == Z_OK);
+    z_drop(z_move(query));
+
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    sample = z_reply_ok(z_loan(reply));
+    assert(z_sample_kind(sample) == Z_SAMPLE_KIND_DELETE);
+    assert(z_bytes_len(z_sample_payload(sample)) == 0);
+    assert_bytes_eq(z_sample_attachment(sample), attachment_value);
+    z_drop(z_move(reply));
+    z_drop(z_move(replies));
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(queries));
+    z_drop(z_move(s));
+    return 0;
+}
@@ -0,0 +1,151 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/reply_sample/**";
+const char* sample_keyexpr = "zenoh/test/reply_sample/data";
+const char* payload_value = "sample body";
+const char* attachment_value = "sample attachment";
+const char* override_value = "override attachment";
+
+void assert_bytes_eq(const z_loaned_bytes_t* bytes, const char* expected) {
+    assert(bytes != NULL);
+    z_owned_string_t s;
+    z_bytes_to_string(bytes, &s);
+    assert(z_string_len(z_loan(s)) == strlen(expected));
+    assert(strncmp(z_string_data(z_loan(s)), expected, strlen(expected)) == 0);
+    z_drop(z_move(s));
+}
+
+// Forwards the sample as a reply to a query and returns the received reply.
+void forward(const z_loaned_session_t* s, z_owned_fifo_handler_query_t* queries, const z_loaned_sample_t* sample,
+             z_query_reply_sample_options_t* opts, z_owned_reply_t* reply) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "", z_move(callback), NULL) == Z_OK);
+
+    z_owned_query_t query;
+    assert(z_recv(z_loan(*queries), &query) == Z_OK);
+    assert(z_query_reply_sample(z_loan(query), sample, opts) == Z_OK);
+    z_drop(z_move(query));
+
+    assert(z_recv(z_loan(replies), reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(*reply)));
+    z_drop(z_move(replies));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke, sample_ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_view_keyexpr_from_str(&sample_ke, sample_keyexpr);
+
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t sample_callback;
+    z_fifo_channel_sample_new(&sample_callback, &samples, 16);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+    z_owned_fifo_handler_query_t queries;
+    z_owned_closure_query_t query_callback;
+    z_fifo_channel_query_new(&query_callback, &queries, 16);
+    z_owned_queryable_t queryable;
+    assert(z_declare_queryable(z_loan(s), &queryable, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_bytes_t payload, attachment;
+    z_bytes_copy_from_str(&payload, payload_value);
+    z_bytes_copy_from_str(&attachment, attachment_value);
+    z_owned_encoding_t encoding;
+    z_encoding_clone(&encoding, z_encoding_text_plain());
+    z_timestamp_t ts;
+    assert(z_timestamp_new(&ts, z_loan(s)) == Z_OK);
+    z_put_options_t put_opts;
+    z_put_options_default(&put_opts);
+    put_opts.encoding = z_move(encoding);
+    put_opts.attachment = z_move(attachment);
+    put_opts.timestamp = &ts;
+    put_opts.priority = Z_PRIORITY_DATA_HIGH;
+    assert(z_put(z_loan(s), z_loan(sample_ke), z_move(payload), &put_opts) == Z_OK);
+    z_owned_sample_t sample;
+    assert(z_recv(z_loan(samples), &sample) == Z_OK);
+
+    // the metadata of the sample is preserved
+    z_owned_reply_t reply;
+    forward(z_loan(s), &queries, z_loan(sample), NULL, &reply);
+    const z_loaned_sample_t* reply_sample = z_reply_ok(z_loan(reply));
+    assert(z_sample_kind(reply_sample) == Z_SAMPLE_KIND_PUT);
+    assert(z_keyexpr_equals(z_sample_keyexpr(reply_sample), z_loan(sample_ke)));
+    assert_bytes_eq(z_sample_payload(reply_sample), payload_value);
+    assert_bytes_eq(z_sample_attachment(reply_sample), attachment_value);
+    assert(z_encoding_equals(z_sample_encoding(reply_sample), z_encoding_text_plain()));
+    assert(z_sample_timestamp(reply_sample) != NULL);
+    assert(z_timestamp_ntp64_time(z_sample_timestamp(reply_sample)) == z_timestamp_ntp64_time(&ts));
+    z_drop(z_move(reply));
+
+    // or overridden by the options
+    z_view_keyexpr_t override_ke;
+    z_view_keyexpr_from_str(&override_ke, "zenoh/test/reply_sample/override");
+    z_bytes_copy_from_str(&attachment, override_value);
+    z_query_reply_sample_options_t opts;
+    z_query_reply_sample_options_default(&opts);
+    opts.key_expr = z_loan(override_ke);
+    opts.attachment = z_move(attachment);
+    forward(z_loan(s), &queries, z_loan(sample), &opts, &reply);
+    assert(!z_internal_check(attachment));
+    reply_sample = z_reply_ok(z_loan(reply));
+    assert(z_keyexpr_equals(z_sample_keyexpr(reply_sample), z_loan(override_ke)));
+    assert_bytes_eq(z_sample_payload(reply_sample), payload_value);
+    assert_bytes_eq(z_sample_attachment(reply_sample), override_value);
+    assert(z_encoding_equals(z_sample_encoding(reply_sample), z_encoding_text_plain()));
+    z_drop(z_move(reply));
+    z_drop(z_move(sample));
+
+    // delete samples are forwarded as delete replies
+    assert(z_delete(z_loan(s), z_loan(sample_ke), NULL) == Z_OK);
+    assert(z_recv(z_loan(samples), &sample) == Z_OK);
+    forward(z_loan(s), &queries, z_loan(sample), NULL, &reply);
+    reply_sample = z_reply_ok(z_loan(reply));
+    assert(z_sample_kind(reply_sample) == Z_SAMPLE_KIND_DELETE);
+    assert(z_keyexpr_equals(z_sample_keyexpr(reply_sample), z_loan(sample_ke)));
+    z_drop(z_move(reply));
+    z_drop(z_move(sample));
+
+    z_drop(z_move(queryable));
+    z_drop(z_move(queries));
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
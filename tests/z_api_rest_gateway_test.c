@@ -0,0 +1,176 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_REST_GATEWAY) && defined(Z_FEATURE_UNSTABLE_API) && !defined(_WIN32)
+
+#include <arpa/inet.h>
+#include <netinet/in.h>
+#include <sys/socket.h>
+#include <unistd.h>
+
+const char* keyexpr = "zenoh/test/rest_gateway";
+
+// Sends the request to the gateway, and returns the length of the response read in `response`.
+size_t request(uint16_t port, const char* req, char* response, size_t len) {
+    int fd = socket(AF_INET, SOCK_STREAM, 0);
+    assert(fd >= 0);
+    struct sockaddr_in addr = {0};
+    addr.sin_family = AF_INET;
+    addr.sin_port = htons(port);
+    addr.sin_addr.s_addr = htonl(INADDR_LOOPBACK);
+    assert(connect(fd, (struct sockaddr*)&addr, sizeof(addr)) == 0);
+    assert(write(fd, req, strlen(req)) == (ssize_t)strlen(req));
+    size_t total = 0;
+    ssize_t n;
+    while (total < len - 1 && (n = read(fd, response + total, len - 1 - total)) > 0) {
+        total += n;
+    }
+    response[total] = '\0';
+    close(fd);
+    return total;
+}
+
+void on_sample(z_loaned_sample_t* sample, void* context) {
+    z_owned_string_t* value = (z_owned_string_t*)context;
+    z_bytes_to_string(z_sample_payload(sample), value);
+}
+
+// Sends a GET request on the target with a valid Host header.
+size_t get(uint16_t port, const char* target, char* response, size_t len) {
+    char req[256];
+    snprintf(req, sizeof(req), "GET %s HTTP/1.1\r\nHost: 127.0.0.1:%u\r\n\r\n", target, port);
+    return request(port, req, response, len);
+}
+
+// Replies with the query parameters if any, so that their decoding can be checked.
+void on_query(z_loaned_query_t* query, void* context) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_view_string_t params;
+    z_query_parameters(query, &params);
+    z_owned_bytes_t payload;
+    if (z_string_len(z_loan(params)) > 0) {
+        z_bytes_copy_from_buf(&payload, (const uint8_t*)z_string_data(z_loan(params)), z_string_len(z_loan(params)));
+    } else {
+        z_bytes_copy_from_str(&payload, "stored_value");
+    }
+    z_query_reply(query, z_loan(ke), z_move(payload), NULL);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_string_t received;
+    z_internal_null(&received);
+    z_owned_closure_sample_t sample_callback;
+    z_closure(&sample_callback, on_sample, NULL, (void*)&received);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(sample_callback), NULL) == Z_OK);
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, NULL);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(z_loan(s), &q, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+
+    zc_owned_rest_gateway_t gateway;
+    assert(zc_rest_gateway_start(z_loan(s), &gateway, 0) == Z_OK);
+    assert(z_internal_check(gateway));
+    uint16_t port = zc_rest_gateway_port(z_loan(gateway));
+    assert(port != 0);
+    z_sleep_ms(100);
+
+    // a PUT request is published on the key expression
+    char response[4096];
+    char req[256];
+    snprintf(req, sizeof(req),
+             "PUT /%s HTTP/1.1\r\nHost: 127.0.0.1:%u\r\nContent-Type: text/plain\r\nContent-Length: 9\r\n\r\nput_value",
+             keyexpr, port);
+    request(port, req, response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 200", strlen("HTTP/1.1 200")) == 0);
+    z_sleep_ms(100);
+    assert(z_internal_check(received));
+    assert(z_string_len(z_loan(received)) == strlen("put_value"));
+    assert(strncmp(z_string_data(z_loan(received)), "put_value", strlen("put_value")) == 0);
+    z_drop(z_move(received));
+
+    // a GET request returns the replies to the query as JSON
+    get(port, "/zenoh/test/rest_gateway", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 200", strlen("HTTP/1.1 200")) == 0);
+    assert(strstr(response, "application/json") != NULL);
+    assert(strstr(response, "\"value\":\"stored_value\"") != NULL);
+
+    // the key expression of the path is percent-decoded
+    get(port, "/zenoh/test/rest%5Fgateway", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 200", strlen("HTTP/1.1 200")) == 0);
+    assert(strstr(response, "\"value\":\"stored_value\"") != NULL);
+
+    // so are the query-string parameters
+    get(port, "/zenoh/test/rest_gateway?greeting=hello%20world&name=%7Ezenoh", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 200", strlen("HTTP/1.1 200")) == 0);
+    assert(strstr(response, "\"value\":\"greeting=hello world;name=~zenoh\"") != NULL);
+
+    get(port, "/zenoh//invalid", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 400", strlen("HTTP/1.1 400")) == 0);
+    get(port, "/zenoh/%zz", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 400", strlen("HTTP/1.1 400")) == 0);
+    get(port, "/zenoh/%3F", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 400", strlen("HTTP/1.1 400")) == 0);
+    get(port, "/zenoh/test/rest_gateway?a=%3B", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 400", strlen("HTTP/1.1 400")) == 0);
+    get(port, "/zenoh/test/rest_gateway?a%3D=b", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 400", strlen("HTTP/1.1 400")) == 0);
+
+    // the requests for another host, or without host, are rejected
+    request(port, "GET /zenoh/test/rest_gateway HTTP/1.1\r\n\r\n", response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 403", strlen("HTTP/1.1 403")) == 0);
+    snprintf(req, sizeof(req), "GET /%s HTTP/1.1\r\nHost: attacker.example:%u\r\n\r\n", keyexpr, port);
+    request(port, req, response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 403", strlen("HTTP/1.1 403")) == 0);
+    snprintf(req, sizeof(req), "GET /%s HTTP/1.1\r\nHost: localhost:%u\r\n\r\n", keyexpr, port);
+    request(port, req, response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 200", strlen("HTTP/1.1 200")) == 0);
+
+    // the body is not read if it is too large
+    snprintf(req, sizeof(req), "PUT /%s HTTP/1.1\r\nHost: 127.0.0.1:%u\r\nContent-Length: 100000000\r\n\r\n", keyexpr,
+             port);
+    request(port, req, response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 413", strlen("HTTP/1.1 413")) == 0);
+    snprintf(req, sizeof(req), "POST /%s HTTP/1.1\r\nHost: 127.0.0.1:%u\r\n\r\n", keyexpr, port);
+    request(port, req, response, sizeof(response));
+    assert(strncmp(response, "HTTP/1.1 405", strlen("HTTP/1.1 405")) == 0);
+
+    z_drop(z_move(gateway));
+    assert(!z_internal_check(gateway));
+    z_drop(z_move(q));
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
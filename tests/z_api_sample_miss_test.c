@@ -0,0 +1,114 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/sample_miss";
+const uint32_t missed = 2;
+
+typedef struct miss_context_t {
+    int misses;
+    uint32_t nb;
+    z_entity_global_id_t source;
+} miss_context_t;
+
+void on_miss(const ze_miss_t* miss, void* context) {
+    miss_context_t* ctx = (miss_context_t*)context;
+    ctx->misses++;
+    ctx->nb += miss->nb;
+    ctx->source = miss->source;
+}
+
+uint32_t recv_sn(const z_loaned_fifo_handler_sample_t* samples) {
+    z_owned_sample_t sample;
+    assert(z_recv(samples, &sample) == Z_OK);
+    uint32_t sn = z_source_info_sn(z_sample_source_info(z_loan(sample)));
+    z_drop(z_move(sample));
+    return sn;
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    ze_advanced_publisher_options_t pub_opts;
+    ze_advanced_publisher_options_default(&pub_opts);
+    pub_opts.sample_miss_detection = true;
+    ze_owned_advanced_publisher_t pub;
+    assert(ze_declare_advanced_publisher(z_loan(s), &pub, z_loan(ke), &pub_opts) == Z_OK);
+
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 16);
+    ze_owned_advanced_subscriber_t sub;
+    assert(ze_declare_advanced_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    miss_context_t ctx = {0};
+    ze_owned_closure_miss_t miss_callback;
+    z_closure(&miss_callback, on_miss, NULL, (void*)&ctx);
+    ze_owned_sample_miss_listener_t listener;
+    assert(ze_advanced_subscriber_declare_sample_miss_listener(z_loan(sub), &listener, z_move(miss_callback)) ==
+           Z_OK);
+    z_sleep_ms(500);
+
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "value");
+    assert(ze_advanced_publisher_put(z_loan(pub), z_move(payload), NULL) == Z_OK);
+    uint32_t sn = recv_sn(z_loan(samples));
+    z_sleep_ms(100);
+    assert(ctx.misses == 0);
+
+    // a sample of the same source skipping sequence numbers reveals a gap that can't be recovered
+    z_entity_global_id_t id = ze_advanced_publisher_id(z_loan(pub));
+    z_owned_source_info_t info;
+    assert(z_source_info_new(&info, &id, sn + missed + 1) == Z_OK);
+    z_put_options_t put_opts;
+    z_put_options_default(&put_opts);
+    put_opts.source_info = z_move(info);
+    z_bytes_copy_from_str(&payload, "value");
+    assert(z_put(z_loan(s), z_loan(ke), z_move(payload), &put_opts) == Z_OK);
+    assert(recv_sn(z_loan(samples)) == sn + missed + 1);
+    z_sleep_ms(100);
+
+    assert(ctx.misses == 1);
+    assert(ctx.nb == missed);
+    z_id_t source_zid = z_entity_global_id_zid(&ctx.source);
+    z_id_t zid = z_entity_global_id_zid(&id);
+    assert(memcmp(&source_zid, &zid, sizeof(z_id_t)) == 0);
+    assert(z_entity_global_id_eid(&ctx.source) == z_entity_global_id_eid(&id));
+
+    assert(ze_undeclare_sample_miss_listener(z_move(listener)) == Z_OK);
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(pub));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
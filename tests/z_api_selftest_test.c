@@ -0,0 +1,61 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+size_t received = 0;
+
+void on_sample(z_loaned_sample_t* sample, void* context) { received++; }
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    // the self-check messages are not delivered to the application subscribers
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "**");
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    uint64_t latency_us = UINT64_MAX;
+    assert(zc_selftest_pubsub_loopback(z_loan(s), 1000, &latency_us) == Z_OK);
+    assert(latency_us < 1000000);
+    assert(zc_selftest_pubsub_loopback(z_loan(s), 1000, NULL) == Z_OK);
+
+    z_sleep_ms(100);
+    assert(received == 0);
+
+    z_drop(z_move(sub));
+    z_close(z_loan_mut(s), NULL);
+    assert(zc_selftest_pubsub_loopback(z_loan(s), 1000, NULL) == Z_ESESSION_CLOSED);
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -83,9 +83,185 @@ void close_concurrent() {
 #endif
 }
 
+void on_sample(z_loaned_sample_t *sample, void *context) {}
+
+void close_with_entities() {
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), NULL) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/close");
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+
+    if (z_close(z_loan_mut(s), NULL) < 0) {
+        perror("Error closing session!");
+        exit(-1);
+    }
+
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "data");
+    assert(z_publisher_put(z_loan(pub), z_move(payload), NULL) == Z_ESESSION_CLOSED);
+
+    z_drop(z_move(s));
+    z_drop(z_move(sub));
+    z_drop(z_move(pub));
+}
+
+void entity_limits() {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_open_options_t open_options;
+    z_open_options_default(&open_options);
+    open_options.max_publishers = 1;
+    open_options.max_subscribers = 1;
+    open_options.max_queriers = 1;
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), &open_options) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/limits");
+
+    z_owned_publisher_t pub1, pub2;
+    assert(z_declare_publisher(z_loan(s), &pub1, z_loan(ke), NULL) == Z_OK);
+    assert(z_declare_publisher(z_loan(s), &pub2, z_loan(ke), NULL) == Z_ELIMIT);
+    assert(!z_internal_check(pub2));
+
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub1, sub2;
+    assert(z_declare_subscriber(z_loan(s), &sub1, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(z_declare_subscriber(z_loan(s), &sub2, z_loan(ke), z_move(callback), NULL) == Z_ELIMIT);
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(z_declare_background_subscriber(z_loan(s), z_loan(ke), z_move(callback), NULL) == Z_ELIMIT);
+
+    ze_owned_advanced_publisher_t adv_pub;
+    assert(ze_declare_advanced_publisher(z_loan(s), &adv_pub, z_loan(ke), NULL) == Z_ELIMIT);
+    ze_owned_advanced_subscriber_t adv_sub;
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(ze_declare_advanced_subscriber(z_loan(s), &adv_sub, z_loan(ke), z_move(callback), NULL) == Z_ELIMIT);
+
+    z_owned_querier_t querier1, querier2;
+    assert(z_declare_querier(z_loan(s), &querier1, z_loan(ke), NULL) == Z_OK);
+    assert(z_declare_querier(z_loan(s), &querier2, z_loan(ke), NULL) == Z_ELIMIT);
+
+    zc_session_entity_counts_t counts;
+    zc_session_get_entity_counts(z_loan(s), &counts);
+    assert(counts.publishers == 1);
+    assert(counts.subscribers == 1);
+    assert(counts.queryables == 0);
+    assert(counts.queriers == 1);
+
+    assert(z_undeclare_publisher(z_move(pub1)) == Z_OK);
+    z_drop(z_move(sub1));
+    z_drop(z_move(querier1));
+    zc_session_get_entity_counts(z_loan(s), &counts);
+    assert(counts.publishers == 0);
+    assert(counts.subscribers == 0);
+    assert(counts.queriers == 0);
+
+    assert(ze_declare_advanced_publisher(z_loan(s), &adv_pub, z_loan(ke), NULL) == Z_OK);
+    z_closure(&callback, on_sample, NULL, NULL);
+    assert(z_declare_background_subscriber(z_loan(s), z_loan(ke), z_move(callback), NULL) == Z_OK);
+    zc_session_get_entity_counts(z_loan(s), &counts);
+    assert(counts.publishers == 1);
+    assert(counts.subscribers == 1);
+
+    z_drop(z_move(adv_pub));
+    zc_session_get_entity_counts(z_loan(s), &counts);
+    assert(counts.publishers == 0);
+
+    // background entities are released when the session is closed
+    assert(z_close(z_loan_mut(s), NULL) == Z_OK);
+    zc_session_get_entity_counts(z_loan(s), &counts);
+    assert(counts.subscribers == 0);
+
+    z_drop(z_move(s));
+#endif
+}
+
+void entity_ids() {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_owned_config_t config;
+    z_config_default(&config);
+
+    z_owned_session_t s;
+    if (z_open(&s, z_move(config), NULL) < 0) {
+        perror("Unable to open session!");
+        exit(-1);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/ids");
+    z_owned_publisher_t pub1, pub2;
+    assert(z_declare_publisher(z_loan(s), &pub1, z_loan(ke), NULL) == Z_OK);
+    assert(z_declare_publisher(z_loan(s), &pub2, z_loan(ke), NULL) == Z_OK);
+
+    z_id_t session_zid = z_info_zid(z_loan(s));
+    z_entity_global_id_t id1 = z_publisher_id(z_loan(pub1));
+    z_entity_global_id_t id2 = z_publisher_id(z_loan(pub2));
+    z_id_t zid1 = z_entity_global_id_zid(&id1);
+    z_id_t zid2 = z_entity_global_id_zid(&id2);
+    assert(memcmp(&zid1, &session_zid, sizeof(z_id_t)) == 0);
+    assert(memcmp(&zid2, &session_zid, sizeof(z_id_t)) == 0);
+    assert(z_entity_global_id_eid(&id1) != z_entity_global_id_eid(&id2));
+
+    z_entity_global_id_t id1_again = z_publisher_id(z_loan(pub1));
+    assert(z_entity_global_id_eid(&id1) == z_entity_global_id_eid(&id1_again));
+
+    z_drop(z_move(pub1));
+    z_drop(z_move(pub2));
+    z_drop(z_move(s));
+#endif
+}
+
+void open_with_retry() {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    z_owned_config_t config;
+    z_config_default(&config);
+    assert(zc_config_insert_json5(z_loan_mut(config), "mode", "'client'") == Z_OK);
+    assert(zc_config_insert_json5(z_loan_mut(config), "connect/endpoints", "['tcp/127.0.0.1:1']") == Z_OK);
+    assert(zc_config_insert_json5(z_loan_mut(config), "scouting/multicast/enabled", "false") == Z_OK);
+
+    z_open_options_t open_options;
+    z_open_options_default(&open_options);
+    assert(open_options.retry_attempts == 0);
+    open_options.retry_attempts = 2;
+    open_options.retry_backoff_ms = 50;
+
+    z_clock_t start = z_clock_now();
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), &open_options) == Z_ENETWORK);
+    assert(!z_internal_check(s));
+    // 50 ms before the first retry and 100 ms before the second one
+    assert(z_clock_elapsed_ms(&start) >= 150);
+#endif
+}
+
 int main(int argc, char **argv) {
     zc_try_init_log_from_env();
     close_drop();
     close_sync();
     close_concurrent();
+    close_with_entities();
+    entity_limits();
+    entity_ids();
+    open_with_retry();
 }
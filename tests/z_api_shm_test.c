@@ -11,6 +11,7 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+#include <ctype.h>
 #include <string.h>
 
 #include "zenoh.h"
@@ -173,6 +174,7 @@ typedef struct {
     bool* busy_flags;
     size_t count;
     size_t available;
+    size_t defragmented;
 } test_provider_context;
 
 void delete_fn(void* context) {
@@ -236,6 +238,9 @@ void free_fn(const struct z_chunk_descriptor_t* chunk, void* context) {
 }
 size_t defragment_fn(void* context) {
     assert(context);
+
+    test_provider_context* c = (test_provider_context*)context;
+    c->defragmented++;
     return 0;
 }
 size_t available_fn(void* context) {
@@ -268,6 +273,7 @@ int run_c_provider() {
     test_provider_context test_context;
     test_context.available = size;
     test_context.count = size;
+    test_context.defragmented = 0;
     test_context.busy_flags = (bool*)malloc(sizeof(bool) * size);
     test_context.bytes = (uint8_t*)malloc(sizeof(uint8_t) * size);
     zc_context_t context = {&test_context, &delete_fn};
@@ -295,6 +301,51 @@ int run_c_provider() {
     return Z_OK;
 }
 
+int run_c_threadsafe_provider() {
+    const z_protocol_id_t id = 100501;
+    const size_t size = 16;
+
+    // init test context
+    test_provider_context test_context;
+    test_context.available = size;
+    test_context.count = size;
+    test_context.defragmented = 0;
+    test_context.busy_flags = (bool*)calloc(size, sizeof(bool));
+    test_context.bytes = (uint8_t*)malloc(sizeof(uint8_t) * size);
+    zc_threadsafe_context_t context = {{&test_context}, &delete_fn};
+
+    // init callbacks
+    zc_shm_provider_backend_callbacks_t callbacks = {&alloc_fn, &free_fn, &defragment_fn, &available_fn,
+                                                     &layout_for_fn};
+    // create provider
+    z_owned_shm_provider_t provider;
+    z_shm_provider_threadsafe_new(&provider, id, context, callbacks);
+    ASSERT_CHECK(provider)
+
+    // the provider relies on the backend callbacks
+    ASSERT_TRUE(z_shm_provider_available(z_loan(provider)) == size);
+    z_alloc_alignment_t alignment = {0};
+    z_buf_layout_alloc_result_t alloc;
+    z_shm_provider_alloc(&alloc, z_loan(provider), 1, alignment);
+    ASSERT_TRUE(alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    ASSERT_TRUE(z_shm_provider_available(z_loan(provider)) == size - 1);
+    z_drop(z_move(alloc.buf));
+    z_shm_provider_garbage_collect(z_loan(provider));
+    ASSERT_TRUE(z_shm_provider_available(z_loan(provider)) == size);
+    z_shm_provider_defragment(z_loan(provider));
+    ASSERT_TRUE(test_context.defragmented == 1);
+
+    // drop provider
+    z_drop(z_move(provider));
+    ASSERT_CHECK_ERR(provider);
+
+    // check that delete_fn executed
+    ASSERT_TRUE(test_context.busy_flags == NULL);
+    ASSERT_TRUE(test_context.bytes == NULL);
+
+    return Z_OK;
+}
+
 int run_posix_provider() {
     const size_t total_size = 4096;
     const size_t buf_ok_size = total_size / 4;
@@ -321,6 +372,195 @@ int run_posix_provider() {
     return Z_OK;
 }
 
+int run_alloc_policies() {
+    const size_t total_size = 4096;
+    const size_t buf_size = total_size / 4;
+    const size_t buf_count = total_size / buf_size;
+    z_alloc_alignment_t alignment = {6};
+
+    z_owned_memory_layout_t layout;
+    ASSERT_OK(z_memory_layout_new(&layout, total_size, alignment));
+    z_owned_shm_provider_t provider;
+    ASSERT_OK(z_posix_shm_provider_new(&provider, z_loan(layout)));
+
+    // layouts are validated when precomputed
+    z_owned_alloc_layout_t alloc_layout;
+    ASSERT_ERR(z_alloc_layout_new(&alloc_layout, z_loan(provider), 0, alignment));
+    ASSERT_OK(z_alloc_layout_new(&alloc_layout, z_loan(provider), buf_size, alignment));
+
+    // the buffers honour the requested alignment
+    z_owned_shm_mut_t bufs[4];
+    for (size_t i = 0; i < buf_count; i++) {
+        z_buf_alloc_result_t alloc;
+        z_alloc_layout_alloc(&alloc, z_loan(alloc_layout));
+        ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_OK);
+        ASSERT_TRUE((uintptr_t)z_shm_mut_data_mut(z_loan_mut(alloc.buf)) % 64 == 0);
+        z_take(&bufs[i], z_move(alloc.buf));
+    }
+
+    // allocations fail deterministically while the memory is in use, whatever the policy
+    z_buf_alloc_result_t alloc;
+    z_alloc_layout_alloc(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_ALLOC_ERROR);
+    z_alloc_layout_alloc_gc(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_ALLOC_ERROR);
+    z_alloc_layout_alloc_gc_defrag(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_ALLOC_ERROR);
+
+    // and the released memory is reclaimed by the garbage collecting policies
+    for (size_t i = 0; i < buf_count; i++) {
+        z_drop(z_move(bufs[i]));
+    }
+    z_alloc_layout_alloc_gc(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_OK);
+    z_drop(z_move(alloc.buf));
+    z_alloc_layout_alloc_gc_defrag(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_OK);
+    z_drop(z_move(alloc.buf));
+    z_alloc_layout_alloc_gc_defrag_blocking(&alloc, z_loan(alloc_layout));
+    ASSERT_TRUE(alloc.status == ZC_BUF_ALLOC_STATUS_OK);
+    z_drop(z_move(alloc.buf));
+    z_drop(z_move(alloc_layout));
+
+    // the same policies are available without precomputed layout
+    z_buf_layout_alloc_result_t layout_alloc;
+    z_shm_provider_alloc_gc_defrag(&layout_alloc, z_loan(provider), buf_size, alignment);
+    ASSERT_TRUE(layout_alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    ASSERT_TRUE((uintptr_t)z_shm_mut_data_mut(z_loan_mut(layout_alloc.buf)) % 64 == 0);
+    z_drop(z_move(layout_alloc.buf));
+    z_shm_provider_alloc_gc_defrag_blocking(&layout_alloc, z_loan(provider), buf_size, alignment);
+    ASSERT_TRUE(layout_alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    z_drop(z_move(layout_alloc.buf));
+
+    z_drop(z_move(provider));
+    z_drop(z_move(layout));
+    return Z_OK;
+}
+
+int run_zero_copy_publication() {
+    const char* expr = "zenoh/test/shm";
+    const char* value = "shm_value";
+    const size_t total_size = 4096;
+    z_alloc_alignment_t alignment = {0};
+
+    z_owned_memory_layout_t layout;
+    ASSERT_OK(z_memory_layout_new(&layout, total_size, alignment));
+    z_owned_shm_provider_t provider;
+    ASSERT_OK(z_posix_shm_provider_new(&provider, z_loan(layout)));
+
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    ASSERT_OK(z_open(&s, z_move(config), NULL));
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, expr);
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 1);
+    z_owned_subscriber_t sub;
+    ASSERT_OK(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL));
+    z_owned_publisher_t pub;
+    ASSERT_OK(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL));
+    z_sleep_ms(100);
+
+    // the buffer is written in place and published without copy
+    z_buf_layout_alloc_result_t alloc;
+    z_shm_provider_alloc_gc(&alloc, z_loan(provider), strlen(value), alignment);
+    ASSERT_TRUE(alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    memcpy(z_shm_mut_data_mut(z_loan_mut(alloc.buf)), value, strlen(value));
+    z_owned_bytes_t payload;
+    ASSERT_OK(z_bytes_from_shm_mut(&payload, z_move(alloc.buf)));
+    ASSERT_OK(z_publisher_put(z_loan(pub), z_move(payload), NULL));
+
+    z_owned_sample_t sample;
+    ASSERT_OK(z_recv(z_loan(samples), &sample));
+    const z_loaned_shm_t* shm = NULL;
+    ASSERT_OK(z_bytes_as_loaned_shm(z_sample_payload(z_loan(sample)), &shm));
+    ASSERT_TRUE(z_shm_len(shm) >= strlen(value));
+    ASSERT_TRUE(memcmp(z_shm_data(shm), value, strlen(value)) == 0);
+    z_drop(z_move(sample));
+
+    z_drop(z_move(pub));
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(s));
+    z_drop(z_move(provider));
+    z_drop(z_move(layout));
+    return Z_OK;
+}
+
+int run_in_place_processing() {
+    const char* expr = "zenoh/test/shm/in_place";
+    const char* value = "shm_value";
+    const size_t total_size = 4096;
+    z_alloc_alignment_t alignment = {0};
+
+    z_owned_memory_layout_t layout;
+    ASSERT_OK(z_memory_layout_new(&layout, total_size, alignment));
+    z_owned_shm_provider_t provider;
+    ASSERT_OK(z_posix_shm_provider_new(&provider, z_loan(layout)));
+
+    // payloads not backed by shared memory can't be borrowed as such
+    z_owned_bytes_t raw;
+    z_bytes_copy_from_str(&raw, value);
+    const z_loaned_shm_t* raw_shm = NULL;
+    ASSERT_ERR(z_bytes_as_loaned_shm(z_loan(raw), &raw_shm));
+    z_loaned_shm_t* raw_shm_mut = NULL;
+    ASSERT_ERR(z_bytes_as_mut_loaned_shm(z_loan_mut(raw), &raw_shm_mut));
+    z_drop(z_move(raw));
+
+    z_buf_layout_alloc_result_t alloc;
+    z_shm_provider_alloc_gc(&alloc, z_loan(provider), strlen(value), alignment);
+    ASSERT_TRUE(alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    memcpy(z_shm_mut_data_mut(z_loan_mut(alloc.buf)), value, strlen(value));
+    z_owned_bytes_t payload;
+    ASSERT_OK(z_bytes_from_shm_mut(&payload, z_move(alloc.buf)));
+
+    // the buffer can only be mutated while uniquely owned
+    z_owned_bytes_t copy;
+    z_bytes_clone(&copy, z_loan(payload));
+    z_loaned_shm_t* shm = NULL;
+    ASSERT_OK(z_bytes_as_mut_loaned_shm(z_loan_mut(payload), &shm));
+    ASSERT_TRUE(z_shm_try_reloan_mut(shm) == NULL);
+    z_drop(z_move(copy));
+    ASSERT_OK(z_bytes_as_mut_loaned_shm(z_loan_mut(payload), &shm));
+    z_loaned_shm_mut_t* shm_mut = z_shm_try_reloan_mut(shm);
+    ASSERT_TRUE(shm_mut != NULL);
+    unsigned char* data = z_shm_mut_data_mut(shm_mut);
+    for (size_t i = 0; i < strlen(value); i++) {
+        data[i] = (unsigned char)toupper(data[i]);
+    }
+
+    // and then republished without copy
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    ASSERT_OK(z_open(&s, z_move(config), NULL));
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, expr);
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 1);
+    z_owned_subscriber_t sub;
+    ASSERT_OK(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(callback), NULL));
+    z_sleep_ms(100);
+    ASSERT_OK(z_put(z_loan(s), z_loan(ke), z_move(payload), NULL));
+
+    z_owned_sample_t sample;
+    ASSERT_OK(z_recv(z_loan(samples), &sample));
+    const z_loaned_shm_t* received = NULL;
+    ASSERT_OK(z_bytes_as_loaned_shm(z_sample_payload(z_loan(sample)), &received));
+    ASSERT_TRUE(memcmp(z_shm_data(received), "SHM_VALUE", strlen(value)) == 0);
+    z_drop(z_move(sample));
+
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(s));
+    z_drop(z_move(provider));
+    z_drop(z_move(layout));
+    return Z_OK;
+}
+
 int test_client_storage(z_owned_shm_client_storage_t* storage) {
     ASSERT_CHECK(*storage);
 
@@ -414,6 +654,71 @@ bool attach_fn(struct z_shm_segment_t* out_segment, z_segment_id_t id, void* con
     return true;
 }
 
+int run_remote_subscription() {
+    const char* expr = "zenoh/test/shm_remote";
+    const char* value = "shm_remote_value";
+    const size_t total_size = 4096;
+    z_alloc_alignment_t alignment = {0};
+
+    z_owned_memory_layout_t layout;
+    ASSERT_OK(z_memory_layout_new(&layout, total_size, alignment));
+    z_owned_shm_provider_t provider;
+    ASSERT_OK(z_posix_shm_provider_new(&provider, z_loan(layout)));
+
+    // the subscriber session maps the segments of its peers with its own client storage
+    zc_owned_shm_client_list_t list;
+    zc_shm_client_list_new(&list);
+    z_owned_shm_client_t client;
+    z_posix_shm_client_new(&client);
+    ASSERT_OK(zc_shm_client_list_add_client(z_loan_mut(list), Z_SHM_POSIX_PROTOCOL_ID, z_move(client)));
+    z_owned_shm_client_storage_t storage;
+    ASSERT_OK(z_shm_client_storage_new(&storage, z_loan(list), false));
+    z_drop(z_move(list));
+
+    z_owned_config_t pub_config, sub_config;
+    z_config_default(&pub_config);
+    z_config_default(&sub_config);
+    ASSERT_OK(zc_config_insert_json5(z_loan_mut(pub_config), "transport/shared_memory/enabled", "true"));
+    ASSERT_OK(zc_config_insert_json5(z_loan_mut(sub_config), "transport/shared_memory/enabled", "true"));
+    z_owned_session_t pub_session, sub_session;
+    ASSERT_OK(z_open(&pub_session, z_move(pub_config), NULL));
+    ASSERT_OK(z_open_with_custom_shm_clients(&sub_session, z_move(sub_config), z_loan(storage)));
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, expr);
+    z_owned_fifo_handler_sample_t samples;
+    z_owned_closure_sample_t callback;
+    z_fifo_channel_sample_new(&callback, &samples, 1);
+    z_owned_subscriber_t sub;
+    ASSERT_OK(z_declare_subscriber(z_loan(sub_session), &sub, z_loan(ke), z_move(callback), NULL));
+    z_sleep_s(1);
+
+    z_buf_layout_alloc_result_t alloc;
+    z_shm_provider_alloc_gc(&alloc, z_loan(provider), strlen(value), alignment);
+    ASSERT_TRUE(alloc.status == ZC_BUF_LAYOUT_ALLOC_STATUS_OK);
+    memcpy(z_shm_mut_data_mut(z_loan_mut(alloc.buf)), value, strlen(value));
+    z_owned_bytes_t payload;
+    ASSERT_OK(z_bytes_from_shm_mut(&payload, z_move(alloc.buf)));
+    ASSERT_OK(z_put(z_loan(pub_session), z_loan(ke), z_move(payload), NULL));
+
+    // the payload is received as a shared-memory buffer
+    z_owned_sample_t sample;
+    ASSERT_OK(z_recv(z_loan(samples), &sample));
+    const z_loaned_shm_t* shm = NULL;
+    ASSERT_OK(z_bytes_as_loaned_shm(z_sample_payload(z_loan(sample)), &shm));
+    ASSERT_TRUE(memcmp(z_shm_data(shm), value, strlen(value)) == 0);
+    z_drop(z_move(sample));
+
+    z_drop(z_move(sub));
+    z_drop(z_move(samples));
+    z_drop(z_move(sub_session));
+    z_drop(z_move(pub_session));
+    z_drop(z_move(storage));
+    z_drop(z_move(provider));
+    z_drop(z_move(layout));
+    return Z_OK;
+}
+
 int run_c_client() {
     // create client list
     zc_owned_shm_client_list_t list;
@@ -457,9 +762,14 @@ int run_cleanup() {
 int main() {
     ASSERT_OK(run_posix_provider());
     ASSERT_OK(run_c_provider());
+    ASSERT_OK(run_c_threadsafe_provider());
+    ASSERT_OK(run_alloc_policies());
+    ASSERT_OK(run_zero_copy_publication());
+    ASSERT_OK(run_in_place_processing());
     ASSERT_OK(run_default_client_storage());
     ASSERT_OK(run_global_client_storage());
     ASSERT_OK(run_client_storage());
+    ASSERT_OK(run_remote_subscription());
     ASSERT_OK(run_c_client());
     ASSERT_OK(run_cleanup());
     return Z_OK;
@@ -0,0 +1,219 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/stream";
+const char* message = "the quick brown fox jumps over the lazy dog";
+const char* answer = "done";
+
+typedef struct server_context_t {
+    const zc_loaned_stream_listener_t* listener;
+    char received[128];
+} server_context_t;
+
+void* serve(void* arg) {
+    server_context_t* ctx = (server_context_t*)arg;
+    zc_owned_stream_t stream;
+    assert(zc_stream_accept(ctx->listener, &stream, 0) == Z_OK);
+    assert(z_internal_check(stream));
+
+    // reads with a buffer smaller than the messages, so that they are split across reads
+    size_t total = 0;
+    while (total < strlen(message)) {
+        size_t read = 0;
+        assert(zc_stream_read(z_loan(stream), (uint8_t*)ctx->received + total, 3, &read) == Z_OK);
+        assert(read > 0 && read <= 3);
+        total += read;
+    }
+    assert(zc_stream_write(z_loan(stream), (const uint8_t*)answer, strlen(answer)) == Z_OK);
+    z_drop(z_move(stream));
+    return NULL;
+}
+
+void test_no_listener(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/stream/nobody");
+    zc_stream_options_t opts;
+    zc_stream_options_default(&opts);
+    opts.open_timeout_ms = 200;
+    zc_owned_stream_t stream;
+    assert(zc_stream_open(s, &stream, z_loan(ke), &opts) == Z_EUNAVAILABLE);
+    assert(!z_internal_check(stream));
+}
+
+void test_accept_timeout_and_invalid_id(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/stream/invalid");
+    zc_owned_stream_listener_t listener;
+    assert(zc_declare_stream_listener(s, &listener, z_loan(ke), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    zc_owned_stream_t stream;
+    assert(zc_stream_accept(z_loan(listener), &stream, 200) == Z_EUNAVAILABLE);
+    assert(!z_internal_check(stream));
+
+    // a stream id addressing several streams is rejected, and is not accepted
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    assert(z_get(s, z_loan(ke), "_stream=*", z_move(callback), NULL) == Z_OK);
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(replies), &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    z_drop(z_move(replies));
+    assert(zc_stream_accept(z_loan(listener), &stream, 200) == Z_EUNAVAILABLE);
+
+    z_drop(z_move(listener));
+}
+
+void test_stale_open(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/stream/stale");
+    zc_owned_stream_listener_t listener;
+    assert(zc_declare_stream_listener(s, &listener, z_loan(ke), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    // the stream is not accepted before the opening end gives up, so it is dropped instead of being accepted later
+    zc_stream_options_t opts;
+    zc_stream_options_default(&opts);
+    opts.open_timeout_ms = 200;
+    zc_owned_stream_t stream;
+    assert(zc_stream_open(s, &stream, z_loan(ke), &opts) == Z_EUNAVAILABLE);
+    z_sleep_ms(100);
+    assert(zc_stream_accept(z_loan(listener), &stream, 200) == Z_EUNAVAILABLE);
+    assert(!z_internal_check(stream));
+
+    z_drop(z_move(listener));
+}
+
+typedef struct idle_context_t {
+    const zc_loaned_stream_listener_t* listener;
+    zc_owned_stream_t stream;
+} idle_context_t;
+
+void* accept_idle(void* arg) {
+    idle_context_t* ctx = (idle_context_t*)arg;
+    assert(zc_stream_accept(ctx->listener, &ctx->stream, 0) == Z_OK);
+    return NULL;
+}
+
+void test_write_timeout(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/stream/idle");
+    zc_stream_options_t opts;
+    zc_stream_options_default(&opts);
+    opts.window = 1;
+    opts.chunk_size = 4;
+    opts.write_timeout_ms = 200;
+
+    zc_owned_stream_listener_t listener;
+    assert(zc_declare_stream_listener(s, &listener, z_loan(ke), &opts) == Z_OK);
+    z_sleep_ms(100);
+
+    idle_context_t ctx;
+    ctx.listener = z_loan(listener);
+    z_owned_task_t task;
+    assert(z_task_init(&task, NULL, accept_idle, &ctx) == Z_OK);
+
+    // the accepting end never reads, so the writer gives up once the window is full
+    zc_owned_stream_t stream;
+    assert(zc_stream_open(s, &stream, z_loan(ke), &opts) == Z_OK);
+    assert(zc_stream_write(z_loan(stream), (const uint8_t*)message, strlen(message)) == Z_EIO);
+    assert(zc_stream_write(z_loan(stream), (const uint8_t*)message, strlen(message)) == Z_EIO);
+
+    assert(z_task_join(z_move(task)) == Z_OK);
+    z_drop(z_move(ctx.stream));
+    z_drop(z_move(stream));
+    z_drop(z_move(listener));
+}
+
+void test_stream(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    // small messages and window, so that the writer has to wait for the reader
+    zc_stream_options_t opts;
+    zc_stream_options_default(&opts);
+    assert(opts.window > 0 && opts.chunk_size > 0);
+    opts.window = 2;
+    opts.chunk_size = 4;
+
+    zc_owned_stream_listener_t listener;
+    assert(zc_declare_stream_listener(s, &listener, z_loan(ke), &opts) == Z_OK);
+    assert(z_internal_check(listener));
+    z_sleep_ms(100);
+
+    server_context_t ctx = {0};
+    ctx.listener = z_loan(listener);
+    z_owned_task_t task;
+    assert(z_task_init(&task, NULL, serve, &ctx) == Z_OK);
+
+    zc_owned_stream_t stream;
+    assert(zc_stream_open(s, &stream, z_loan(ke), &opts) == Z_OK);
+    assert(z_internal_check(stream));
+    assert(zc_stream_write(z_loan(stream), (const uint8_t*)message, strlen(message)) == Z_OK);
+
+    // the answer is followed by the end of the stream
+    char received[16] = {0};
+    size_t read = 0;
+    size_t total = 0;
+    z_result_t res;
+    while ((res = zc_stream_read(z_loan(stream), (uint8_t*)received + total, sizeof(received) - total, &read)) ==
+           Z_OK) {
+        total += read;
+    }
+    assert(res == Z_CHANNEL_DISCONNECTED);
+    assert(read == 0);
+    assert(total == strlen(answer));
+    assert(strncmp(received, answer, strlen(answer)) == 0);
+    assert(zc_stream_write(z_loan(stream), (const uint8_t*)message, strlen(message)) == Z_CHANNEL_DISCONNECTED);
+
+    assert(z_task_join(z_move(task)) == Z_OK);
+    assert(strncmp(ctx.received, message, strlen(message)) == 0);
+
+    z_drop(z_move(stream));
+    assert(!z_internal_check(stream));
+    z_drop(z_move(listener));
+    assert(!z_internal_check(listener));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_no_listener(z_loan(s));
+    test_accept_timeout_and_invalid_id(z_loan(s));
+    test_stale_open(z_loan(s));
+    test_write_timeout(z_loan(s));
+    test_stream(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
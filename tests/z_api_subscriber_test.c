@@ -0,0 +1,370 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+void on_receive(z_loaned_sample_t* s, void* context) {
+    int* received = (int*)context;
+    (*received)++;
+}
+
+void put_str(const z_loaned_session_t* s, const char* keyexpr) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "data");
+    assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+}
+
+void test_excluded_key_exprs(const z_loaned_session_t* s) {
+    z_owned_string_array_t excluded;
+    z_string_array_new(&excluded);
+    z_view_string_t debug;
+    z_view_string_from_str(&debug, "zenoh/test/exclusion/debug/**");
+    z_string_array_push_by_copy(z_loan_mut(excluded), z_loan(debug));
+
+    z_subscriber_options_t opts;
+    z_subscriber_options_default(&opts);
+    opts.excluded_key_exprs = z_move(excluded);
+
+    int received = 0;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/exclusion/**");
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &opts) == Z_OK);
+
+    put_str(s, "zenoh/test/exclusion/data");
+    put_str(s, "zenoh/test/exclusion/debug/log");
+    put_str(s, "zenoh/test/exclusion/debug");
+    z_sleep_ms(100);
+    assert(received == 1);
+
+    z_drop(z_move(sub));
+}
+
+void test_invalid_excluded_key_exprs(const z_loaned_session_t* s) {
+    z_owned_string_array_t excluded;
+    z_string_array_new(&excluded);
+    z_view_string_t invalid;
+    z_view_string_from_str(&invalid, "zenoh//invalid");
+    z_string_array_push_by_copy(z_loan_mut(excluded), z_loan(invalid));
+
+    z_subscriber_options_t opts;
+    z_subscriber_options_default(&opts);
+    opts.excluded_key_exprs = z_move(excluded);
+
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, NULL);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/exclusion/**");
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &opts) == Z_EINVAL);
+    assert(!z_internal_check(sub));
+    // moved arguments are consumed even if the declaration fails
+    assert(!z_internal_check(callback));
+    assert(!z_internal_check(excluded));
+}
+
+void xor_transform(const z_loaned_bytes_t* payload, z_owned_bytes_t* transformed, void* context) {
+    z_owned_slice_t slice;
+    z_bytes_to_slice(payload, &slice);
+    size_t len = z_slice_len(z_loan(slice));
+    uint8_t buf[64];
+    assert(len <= sizeof(buf));
+    for (size_t i = 0; i < len; i++) {
+        buf[i] = z_slice_data(z_loan(slice))[i] ^ 0x5A;
+    }
+    z_bytes_copy_from_buf(transformed, buf, len);
+    z_drop(z_move(slice));
+}
+
+void on_receive_str(z_loaned_sample_t* s, void* context) {
+    z_owned_string_t* received = (z_owned_string_t*)context;
+    z_bytes_to_string(z_sample_payload(s), received);
+}
+
+void test_payload_transform(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/transform");
+
+    z_subscriber_options_t sub_opts;
+    z_subscriber_options_default(&sub_opts);
+    zc_owned_closure_payload_transform_t sub_transform;
+    z_closure(&sub_transform, xor_transform, NULL, NULL);
+    sub_opts.payload_transform = z_move(sub_transform);
+    z_owned_string_t decoded;
+    z_internal_null(&decoded);
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive_str, NULL, (void*)&decoded);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &sub_opts) == Z_OK);
+
+    z_owned_string_t raw;
+    z_internal_null(&raw);
+    z_owned_closure_sample_t raw_callback;
+    z_closure(&raw_callback, on_receive_str, NULL, (void*)&raw);
+    z_owned_subscriber_t raw_sub;
+    assert(z_declare_subscriber(s, &raw_sub, z_loan(ke), z_move(raw_callback), NULL) == Z_OK);
+
+    z_publisher_options_t pub_opts;
+    z_publisher_options_default(&pub_opts);
+    zc_owned_closure_payload_transform_t pub_transform;
+    z_closure(&pub_transform, xor_transform, NULL, NULL);
+    pub_opts.payload_transform = z_move(pub_transform);
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(s, &pub, z_loan(ke), &pub_opts) == Z_OK);
+    z_sleep_ms(100);
+
+    z_owned_bytes_t payload;
+    z_bytes_from_static_str(&payload, "data");
+    assert(z_publisher_put(z_loan(pub), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    assert(z_internal_check(decoded));
+    assert(z_string_len(z_loan(decoded)) == 4);
+    assert(strncmp(z_string_data(z_loan(decoded)), "data", 4) == 0);
+    assert(z_internal_check(raw));
+    assert(z_string_len(z_loan(raw)) == 4);
+    assert(strncmp(z_string_data(z_loan(raw)), "data", 4) != 0);
+    z_drop(z_move(decoded));
+    z_drop(z_move(raw));
+
+    // the transform of an advanced publisher applies to its publications as well
+    ze_advanced_publisher_options_t adv_opts;
+    ze_advanced_publisher_options_default(&adv_opts);
+    z_closure(&pub_transform, xor_transform, NULL, NULL);
+    adv_opts.publisher_options.payload_transform = z_move(pub_transform);
+    ze_owned_advanced_publisher_t adv_pub;
+    assert(ze_declare_advanced_publisher(s, &adv_pub, z_loan(ke), &adv_opts) == Z_OK);
+    z_sleep_ms(100);
+
+    z_bytes_from_static_str(&payload, "data");
+    assert(ze_advanced_publisher_put(z_loan(adv_pub), z_move(payload), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    assert(z_internal_check(decoded));
+    assert(strncmp(z_string_data(z_loan(decoded)), "data", 4) == 0);
+    assert(z_internal_check(raw));
+    assert(strncmp(z_string_data(z_loan(raw)), "data", 4) != 0);
+
+    z_drop(z_move(adv_pub));
+    z_drop(z_move(pub));
+    z_drop(z_move(raw_sub));
+    z_drop(z_move(sub));
+    z_drop(z_move(raw));
+    z_drop(z_move(decoded));
+}
+
+void test_fifo_channel(const z_loaned_session_t* s) {
+    const char* keyexpr = "zenoh/test/subscriber/fifo";
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_sample_t closure;
+    z_owned_fifo_handler_sample_t handler;
+    z_fifo_channel_sample_new(&closure, &handler, 4);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(closure), NULL) == Z_OK);
+
+    z_owned_sample_t sample;
+    assert(z_try_recv(z_loan(handler), &sample) == Z_CHANNEL_NODATA);
+    assert(!z_internal_check(sample));
+
+    put_str(s, keyexpr);
+    put_str(s, keyexpr);
+    assert(z_recv(z_loan(handler), &sample) == Z_OK);
+    assert(z_internal_check(sample));
+    assert(z_keyexpr_equals(z_sample_keyexpr(z_loan(sample)), z_loan(ke)));
+    z_owned_keyexpr_t sample_ke;
+    z_sample_keyexpr_clone(z_loan(sample), &sample_ke);
+    z_drop(z_move(sample));
+    assert(z_keyexpr_equals(z_loan(sample_ke), z_loan(ke)));
+    z_drop(z_move(sample_ke));
+    z_sleep_ms(100);
+    assert(z_try_recv(z_loan(handler), &sample) == Z_OK);
+    z_drop(z_move(sample));
+    assert(z_try_recv(z_loan(handler), &sample) == Z_CHANNEL_NODATA);
+
+    z_drop(z_move(sub));
+    assert(z_recv(z_loan(handler), &sample) == Z_CHANNEL_DISCONNECTED);
+    assert(!z_internal_check(sample));
+    z_drop(z_move(handler));
+}
+
+void test_ring_channel(const z_loaned_session_t* s) {
+    const char* keyexpr = "zenoh/test/subscriber/ring";
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_sample_t closure;
+    z_owned_ring_handler_sample_t handler;
+    z_ring_channel_sample_new(&closure, &handler, 2);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(closure), NULL) == Z_OK);
+
+    z_owned_sample_t sample;
+    assert(z_try_recv(z_loan(handler), &sample) == Z_CHANNEL_NODATA);
+
+    const char* values[] = {"1", "2", "3"};
+    for (size_t i = 0; i < 3; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_from_static_str(&payload, values[i]);
+        assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+    }
+    z_sleep_ms(100);
+
+    // the oldest sample was dropped since the ring is full
+    for (size_t i = 1; i < 3; i++) {
+        assert(z_try_recv(z_loan(handler), &sample) == Z_OK);
+        z_owned_string_t value;
+        z_bytes_to_string(z_sample_payload(z_loan(sample)), &value);
+        assert(strncmp(z_string_data(z_loan(value)), values[i], z_string_len(z_loan(value))) == 0);
+        z_drop(z_move(value));
+        z_drop(z_move(sample));
+    }
+    assert(z_try_recv(z_loan(handler), &sample) == Z_CHANNEL_NODATA);
+
+    z_drop(z_move(sub));
+    assert(z_recv(z_loan(handler), &sample) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(handler));
+}
+
+void test_pull_mode(const z_loaned_session_t* s) {
+    const char* keyexpr = "zenoh/test/subscriber/pull";
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    int received = 0;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_subscriber_options_t opts;
+    z_subscriber_options_default(&opts);
+    assert(opts.pull_capacity == 0);
+    opts.pull_capacity = 2;
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &opts) == Z_OK);
+
+    zc_session_drop_counters_t before;
+    zc_session_drop_counters(s, &before);
+    assert(z_subscriber_pull(z_loan(sub)) == 0);
+    put_str(s, keyexpr);
+    put_str(s, keyexpr);
+    put_str(s, keyexpr);
+    z_sleep_ms(100);
+    // samples are only passed to the callback when pulled, the oldest one was dropped
+    assert(received == 0);
+    zc_session_drop_counters_t after;
+    zc_session_drop_counters(s, &after);
+    assert(after.full_buffers == before.full_buffers + 1);
+    assert(after.panicked_callbacks == before.panicked_callbacks);
+    assert(after.decode_failures == before.decode_failures);
+    assert(z_subscriber_pull(z_loan(sub)) == 2);
+    assert(received == 2);
+    assert(z_subscriber_pull(z_loan(sub)) == 0);
+    z_drop(z_move(sub));
+
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_owned_subscriber_t push_sub;
+    assert(z_declare_subscriber(s, &push_sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    put_str(s, keyexpr);
+    z_sleep_ms(100);
+    assert(received == 3);
+    assert(z_subscriber_pull(z_loan(push_sub)) == 0);
+    z_drop(z_move(push_sub));
+}
+
+typedef struct key_counts_t {
+    uint64_t first;
+    uint64_t second;
+    int keys;
+} key_counts_t;
+
+void on_key_stats(const z_loaned_keyexpr_t* key_expr, uint64_t samples, void* context) {
+    key_counts_t* counts = (key_counts_t*)context;
+    z_view_string_t ke_str;
+    z_keyexpr_as_view_string(key_expr, &ke_str);
+    counts->keys++;
+    if (strncmp(z_string_data(z_loan(ke_str)), "zenoh/test/key_stats/first", z_string_len(z_loan(ke_str))) == 0) {
+        counts->first = samples;
+    } else if (strncmp(z_string_data(z_loan(ke_str)), "zenoh/test/key_stats/second",
+                       z_string_len(z_loan(ke_str))) == 0) {
+        counts->second = samples;
+    }
+}
+
+void test_key_stats(const z_loaned_session_t* s) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/key_stats/**");
+    int received = 0;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    z_subscriber_options_t opts;
+    z_subscriber_options_default(&opts);
+    assert(!opts.key_stats);
+    opts.key_stats = true;
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), &opts) == Z_OK);
+
+    put_str(s, "zenoh/test/key_stats/first");
+    put_str(s, "zenoh/test/key_stats/first");
+    put_str(s, "zenoh/test/key_stats/second");
+    z_sleep_ms(100);
+    assert(received == 3);
+
+    key_counts_t counts = {0, 0, 0};
+    zc_owned_closure_key_stats_t stats_callback;
+    zc_closure_key_stats(&stats_callback, on_key_stats, NULL, (void*)&counts);
+    assert(zc_subscriber_key_stats(z_loan(sub), z_move(stats_callback)) == Z_OK);
+    assert(counts.keys == 2);
+    assert(counts.first == 2);
+    assert(counts.second == 1);
+    z_drop(z_move(sub));
+
+    // statistics are not collected by default
+    z_closure(&callback, on_receive, NULL, (void*)&received);
+    assert(z_declare_subscriber(s, &sub, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    zc_closure_key_stats(&stats_callback, on_key_stats, NULL, (void*)&counts);
+    assert(zc_subscriber_key_stats(z_loan(sub), z_move(stats_callback)) == Z_EUNAVAILABLE);
+    z_drop(z_move(sub));
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    test_excluded_key_exprs(z_loan(s));
+    test_invalid_excluded_key_exprs(z_loan(s));
+    test_payload_transform(z_loan(s));
+    test_fifo_channel(z_loan(s));
+    test_ring_channel(z_loan(s));
+    test_pull_mode(z_loan(s));
+    test_key_stats(z_loan(s));
+
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
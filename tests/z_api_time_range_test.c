@@ -0,0 +1,160 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/time_range";
+
+typedef struct query_context_t {
+    int queries;
+    z_result_t res;
+    zc_time_range_t range;
+} query_context_t;
+
+void on_query(z_loaned_query_t* query, void* context) {
+    query_context_t* ctx = (query_context_t*)context;
+    ctx->queries++;
+    ctx->res = zc_query_time_range(query, &ctx->range);
+}
+
+void query(const z_loaned_session_t* s, const char* params) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_fifo_handler_reply_t replies;
+    z_owned_closure_reply_t callback;
+    z_fifo_channel_reply_new(&callback, &replies, 16);
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.timeout_ms = 500;
+    assert(z_get(s, z_loan(ke), params, z_move(callback), &opts) == Z_OK);
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(replies), &reply) == Z_OK) {
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(replies));
+}
+
+void test_parameter(const z_loaned_session_t* s, query_context_t* ctx) {
+    z_timestamp_t start, end;
+    assert(z_timestamp_new(&start, s) == Z_OK);
+    z_sleep_ms(10);
+    assert(z_timestamp_new(&end, s) == Z_OK);
+
+    zc_time_range_t range;
+    zc_time_range_from_timestamps(&range, &start, &end);
+    assert(range.start.kind == ZC_TIME_BOUND_KIND_INCLUSIVE);
+    assert(range.end.kind == ZC_TIME_BOUND_KIND_INCLUSIVE);
+    assert(range.start.epoch_ms < range.end.epoch_ms);
+
+    z_owned_string_t param;
+    zc_time_range_to_parameter(&range, &param);
+    assert(z_string_len(z_loan(param)) > strlen("_time=[..]"));
+    assert(strncmp(z_string_data(z_loan(param)), "_time=[", strlen("_time=[")) == 0);
+
+    // the queryable gets back the range sent along with the query
+    char params[256];
+    snprintf(params, sizeof(params), "a=1;%.*s", (int)z_string_len(z_loan(param)), z_string_data(z_loan(param)));
+    z_drop(z_move(param));
+    query(s, params);
+    assert(ctx->queries == 1);
+    assert(ctx->res == Z_OK);
+    assert(ctx->range.start.kind == ZC_TIME_BOUND_KIND_INCLUSIVE);
+    assert(ctx->range.start.epoch_ms == range.start.epoch_ms);
+    assert(ctx->range.end.kind == ZC_TIME_BOUND_KIND_INCLUSIVE);
+    assert(ctx->range.end.epoch_ms == range.end.epoch_ms);
+
+    // bounds can be open on either side
+    zc_time_range_from_timestamps(&range, NULL, &end);
+    assert(range.start.kind == ZC_TIME_BOUND_KIND_UNBOUNDED);
+    range.end.kind = ZC_TIME_BOUND_KIND_EXCLUSIVE;
+    zc_time_range_to_parameter(&range, &param);
+    snprintf(params, sizeof(params), "%.*s", (int)z_string_len(z_loan(param)), z_string_data(z_loan(param)));
+    z_drop(z_move(param));
+    query(s, params);
+    assert(ctx->queries == 2);
+    assert(ctx->res == Z_OK);
+    assert(ctx->range.start.kind == ZC_TIME_BOUND_KIND_UNBOUNDED);
+    assert(ctx->range.end.kind == ZC_TIME_BOUND_KIND_EXCLUSIVE);
+    assert(ctx->range.end.epoch_ms == range.end.epoch_ms);
+
+    zc_time_range_default(&range);
+    zc_time_range_to_parameter(&range, &param);
+    assert(z_string_len(z_loan(param)) == strlen("_time=[..]"));
+    assert(strncmp(z_string_data(z_loan(param)), "_time=[..]", strlen("_time=[..]")) == 0);
+    z_drop(z_move(param));
+}
+
+void test_relative(const z_loaned_session_t* s, query_context_t* ctx) {
+    z_timestamp_t now;
+    assert(z_timestamp_new(&now, s) == Z_OK);
+    zc_time_range_t range;
+    zc_time_range_from_timestamps(&range, &now, NULL);
+
+    // relative times are resolved on the queryable side
+    query(s, "_time=[now(-1h)..]");
+    assert(ctx->queries == 3);
+    assert(ctx->res == Z_OK);
+    assert(ctx->range.start.kind == ZC_TIME_BOUND_KIND_INCLUSIVE);
+    assert(ctx->range.end.kind == ZC_TIME_BOUND_KIND_UNBOUNDED);
+    uint64_t expected = range.start.epoch_ms - 3600000;
+    assert(ctx->range.start.epoch_ms >= expected - 60000);
+    assert(ctx->range.start.epoch_ms <= expected + 60000);
+}
+
+void test_invalid(const z_loaned_session_t* s, query_context_t* ctx) {
+    query(s, "a=1");
+    assert(ctx->queries == 4);
+    assert(ctx->res == Z_EUNAVAILABLE);
+
+    query(s, "_time=yesterday");
+    assert(ctx->queries == 5);
+    assert(ctx->res == Z_EPARSE);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    query_context_t ctx = {0};
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, (void*)&ctx);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(z_loan(s), &q, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    test_parameter(z_loan(s), &ctx);
+    test_relative(z_loan(s), &ctx);
+    test_invalid(z_loan(s), &ctx);
+
+    z_drop(z_move(q));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif
@@ -0,0 +1,123 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+
+const char* keyexpr = "zenoh/test/watch/**";
+const char* key = "zenoh/test/watch/a";
+
+#define MAX_ENTRIES 8
+
+typedef struct watch_context_t {
+    size_t count;
+    bool is_initial[MAX_ENTRIES];
+    char values[MAX_ENTRIES][16];
+} watch_context_t;
+
+const z_loaned_session_t* session = NULL;
+
+void on_query(z_loaned_query_t* query, void* context) {
+    // the update published while the query is in progress supersedes the value of the reply
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "live");
+    assert(z_put(session, z_loan(ke), z_move(payload), NULL) == Z_OK);
+    z_bytes_copy_from_str(&payload, "initial");
+    assert(z_query_reply(query, z_loan(ke), z_move(payload), NULL) == Z_OK);
+}
+
+void on_watch(z_loaned_sample_t* sample, bool is_initial, void* context) {
+    watch_context_t* ctx = (watch_context_t*)context;
+    assert(ctx->count < MAX_ENTRIES);
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(sample), &value);
+    size_t len = z_string_len(z_loan(value));
+    assert(len < 16);
+    memcpy(ctx->values[ctx->count], z_string_data(z_loan(value)), len);
+    ctx->is_initial[ctx->count] = is_initial;
+    ctx->count++;
+    z_drop(z_move(value));
+}
+
+void put(const char* value) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    assert(z_put(session, z_loan(ke), z_move(payload), NULL) == Z_OK);
+}
+
+int main(int argc, char** argv) {
+    zc_try_init_log_from_env();
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+    session = z_loan(s);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, keyexpr);
+    z_owned_closure_query_t query_callback;
+    z_closure(&query_callback, on_query, NULL, NULL);
+    z_owned_queryable_t q;
+    assert(z_declare_queryable(session, &q, z_loan(ke), z_move(query_callback), NULL) == Z_OK);
+    z_sleep_ms(100);
+
+    watch_context_t ctx = {0};
+    z_owned_closure_watch_t callback;
+    z_closure(&callback, on_watch, NULL, (void*)&ctx);
+    zc_watch_options_t opts;
+    zc_watch_options_default(&opts);
+    assert(opts.query_timeout_ms == 0);
+    opts.query_timeout_ms = 1000;
+    zc_owned_watch_t watch;
+    assert(zc_declare_watch(session, &watch, z_loan(ke), z_move(callback), &opts) == Z_OK);
+    assert(z_internal_check(watch));
+    assert(!z_internal_check(callback));
+    z_sleep_ms(500);
+
+    // the update received during the initial query is delivered after the initial value
+    assert(ctx.count == 2);
+    assert(ctx.is_initial[0] && strcmp(ctx.values[0], "initial") == 0);
+    assert(!ctx.is_initial[1] && strcmp(ctx.values[1], "live") == 0);
+
+    put("update");
+    z_sleep_ms(100);
+    assert(ctx.count == 3);
+    assert(!ctx.is_initial[2] && strcmp(ctx.values[2], "update") == 0);
+
+    // no more sample is delivered once the watch is undeclared
+    assert(zc_undeclare_watch(z_move(watch)) == Z_OK);
+    assert(!z_internal_check(watch));
+    put("ignored");
+    z_sleep_ms(100);
+    assert(ctx.count == 3);
+
+    z_drop(z_move(q));
+    z_drop(z_move(s));
+    return 0;
+}
+
+#else
+int main(int argc, char** argv) { return 0; }
+#endif